@@ -22,9 +22,9 @@ use cumulus_client_consensus_common::{self as consensus_common, ParachainBlockIm
 use cumulus_client_consensus_proposer::ProposerInterface;
 use cumulus_primitives_aura::AuraUnincludedSegmentApi;
 use cumulus_primitives_core::{GetCoreSelectorApi, PersistedValidationData};
-use cumulus_relay_chain_interface::RelayChainInterface;
+use cumulus_relay_chain_interface::{PHeader, RelayChainInterface};
 
-use polkadot_primitives::{Block as RelayBlock, Id as ParaId};
+use polkadot_primitives::{Block as RelayBlock, Hash as RelayHash, Id as ParaId};
 
 use futures::prelude::*;
 use sc_client_api::{backend::AuxStore, BlockBackend, BlockOf, UsageProvider};
@@ -38,7 +38,7 @@ use sp_inherents::CreateInherentDataProviders;
 use sp_keystore::KeystorePtr;
 use sp_runtime::traits::{Block as BlockT, Header as HeaderT, Member};
 use sp_timestamp::Timestamp;
-use std::{sync::Arc, time::Duration};
+use std::{pin::Pin, sync::Arc, time::Duration};
 
 use super::CollatorMessage;
 use crate::{
@@ -47,6 +47,7 @@ use crate::{
 		check_validation_code_or_log,
 		slot_based::{
 			core_selector,
+			decision_log::{Decision, DecisionLog},
 			relay_chain_data_cache::{RelayChainData, RelayChainDataCache},
 		},
 	},
@@ -98,6 +99,10 @@ pub struct BuilderTaskParams<
 	/// likelihood of encountering unfavorable notification arrival timings (i.e. we don't want to
 	/// wait for relay chain notifications because we woke up too early).
 	pub slot_drift: Duration,
+	/// The log to record the decisions taken at each relay parent in.
+	pub decision_log: DecisionLog<Block>,
+	/// Whether to wait for the next relay parent when all cores are claimed at the best one.
+	pub relay_parent_lookahead: bool,
 }
 
 #[derive(Debug)]
@@ -131,6 +136,34 @@ fn time_until_next_slot(slot_duration: Duration, drift: Duration) -> Duration {
 	Duration::from_millis(remaining_millis as u64)
 }
 
+/// Wait for at most `timeout` until the best relay chain block is not `relay_parent` anymore.
+///
+/// Returns the new best relay chain block.
+async fn wait_for_next_relay_parent<RelayClient: RelayChainInterface>(
+	relay_client: &RelayClient,
+	best_notifications: &mut Pin<Box<dyn Stream<Item = PHeader> + Send>>,
+	relay_parent: RelayHash,
+	timeout: Duration,
+) -> Option<RelayHash> {
+	// Drop the notifications that arrived since we last waited, we only need to know about new
+	// ones.
+	while let Some(Some(_)) = best_notifications.next().now_or_never() {}
+
+	let wait = async {
+		loop {
+			match relay_client.best_block_hash().await {
+				Ok(best) if best != relay_parent => return Some(best),
+				Ok(_) => {},
+				Err(_) => return None,
+			}
+
+			best_notifications.next().await?;
+		}
+	};
+
+	tokio::time::timeout(timeout, wait).await.ok().flatten()
+}
+
 impl<Block, Client, P> SlotTimer<Block, Client, P>
 where
 	Block: BlockT,
@@ -203,6 +236,8 @@ where
 			authoring_duration,
 			para_backend,
 			slot_drift,
+			decision_log,
+			relay_parent_lookahead,
 		} = params;
 
 		let slot_timer = SlotTimer::<_, _, P>::new_with_drift(para_client.clone(), slot_drift);
@@ -223,15 +258,37 @@ where
 
 		let mut relay_chain_data_cache = RelayChainDataCache::new(relay_client.clone(), para_id);
 
+		let mut best_notifications = if relay_parent_lookahead {
+			match relay_client.new_best_notification_stream().await {
+				Ok(stream) => Some(stream),
+				Err(err) => {
+					tracing::warn!(target: LOG_TARGET, ?err, "Unable to subscribe to relay chain best blocks, relay parent lookahead is disabled.");
+					None
+				},
+			}
+		} else {
+			None
+		};
+
+		// Slot to build in again, on top of a newer relay parent.
+		let mut retry_slot = None;
+
 		loop {
-			// We wait here until the next slot arrives.
-			let Ok(para_slot) = slot_timer.wait_until_next_slot().await else {
-				return;
-			};
+			let (para_slot, relay_parent) = match retry_slot.take() {
+				Some(retry) => retry,
+				None => {
+					// We wait here until the next slot arrives.
+					let Ok(para_slot) = slot_timer.wait_until_next_slot().await else {
+						return;
+					};
 
-			let Ok(relay_parent) = relay_client.best_block_hash().await else {
-				tracing::warn!(target: crate::LOG_TARGET, "Unable to fetch latest relay chain block hash.");
-				continue
+					let Ok(relay_parent) = relay_client.best_block_hash().await else {
+						tracing::warn!(target: crate::LOG_TARGET, "Unable to fetch latest relay chain block hash.");
+						continue
+					};
+
+					(para_slot, relay_parent)
+				},
 			};
 
 			let Some((included_block, parent)) =
@@ -271,6 +328,7 @@ where
 
 			if scheduled_cores.is_empty() {
 				tracing::debug!(target: LOG_TARGET, "Parachain not scheduled, skipping slot.");
+				decision_log.record(relay_parent, Decision::NotScheduled);
 				continue;
 			} else {
 				tracing::debug!(
@@ -294,9 +352,51 @@ where
 					"Core {:?} was already claimed at this relay chain slot",
 					core_index
 				);
+				decision_log.record(
+					relay_parent,
+					Decision::CoreAlreadyClaimed { core_index: core_index.0 },
+				);
+
+				// With multiple cores assigned, the next relay parent brings fresh claims. Build
+				// the block of this slot on top of it instead of dropping the slot.
+				if let (true, Some(best_notifications)) =
+					(scheduled_cores.len() > 1, best_notifications.as_mut())
+				{
+					let Ok(slot_duration) = crate::slot_duration(&*para_client) else { continue };
+					let slot_end = para_slot.timestamp.as_duration() + slot_duration.as_duration();
+					let timeout = slot_end
+						.saturating_sub(authoring_duration)
+						.saturating_sub(Timestamp::current().as_duration());
+
+					if let Some(next_relay_parent) = wait_for_next_relay_parent(
+						&relay_client,
+						best_notifications,
+						relay_parent,
+						timeout,
+					)
+					.await
+					{
+						tracing::debug!(
+							target: LOG_TARGET,
+							%relay_parent,
+							%next_relay_parent,
+							"All cores claimed, building on top of the next relay parent."
+						);
+						decision_log.record(
+							relay_parent,
+							Decision::AwaitedNextRelayParent { next_relay_parent },
+						);
+						retry_slot = Some((para_slot, next_relay_parent));
+					}
+				}
 				continue
 			}
 
+			decision_log.record(
+				relay_parent,
+				Decision::CoreClaimed { core_index: core_index.0, slot: *para_slot.slot },
+			);
+
 			let parent_header = parent.header;
 
 			// We mainly call this to inform users at genesis if there is a mismatch with the
@@ -334,6 +434,13 @@ where
 						parent = %parent_hash,
 						"Not building block."
 					);
+					decision_log.record(
+						relay_parent,
+						Decision::SlotNotClaimed {
+							core_index: core_index.0,
+							slot: *para_slot.slot,
+						},
+					);
 					continue
 				},
 			};
@@ -410,10 +517,20 @@ where
 				.await
 			else {
 				tracing::error!(target: crate::LOG_TARGET, "Unable to build block at slot.");
+				decision_log
+					.record(relay_parent, Decision::BlockBuildFailed { core_index: core_index.0 });
 				continue;
 			};
 
 			let new_block_hash = candidate.block.header().hash();
+			decision_log.record(
+				relay_parent,
+				Decision::BlockBuilt {
+					core_index: core_index.0,
+					block_hash: new_block_hash,
+					block_number: *candidate.block.header().number(),
+				},
+			);
 
 			// Announce the newly built block to our peers.
 			collator.collator_service().announce_block(new_block_hash, None);
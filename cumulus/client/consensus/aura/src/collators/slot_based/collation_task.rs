@@ -15,16 +15,16 @@
 // You should have received a copy of the GNU General Public License
 // along with Cumulus. If not, see <https://www.gnu.org/licenses/>.
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use std::path::PathBuf;
 
 use cumulus_client_collator::service::ServiceInterface as CollatorServiceInterface;
-use cumulus_relay_chain_interface::RelayChainInterface;
+use cumulus_relay_chain_interface::{PHeader, RelayChainInterface};
 
 use polkadot_node_primitives::{MaybeCompressedPoV, SubmitCollationParams};
 use polkadot_node_subsystem::messages::CollationGenerationMessage;
 use polkadot_overseer::Handle as OverseerHandle;
-use polkadot_primitives::{CollatorPair, Id as ParaId};
+use polkadot_primitives::{CollatorPair, Hash as RelayHash, Id as ParaId};
 
 use cumulus_primitives_core::relay_chain::BlockId;
 use futures::prelude::*;
@@ -33,10 +33,16 @@ use crate::export_pov_to_path;
 use sc_utils::mpsc::TracingUnboundedReceiver;
 use sp_runtime::traits::{Block as BlockT, Header};
 
-use super::CollatorMessage;
+use super::{
+	decision_log::{Decision, DecisionLog},
+	CollatorMessage,
+};
 
 const LOG_TARGET: &str = "aura::cumulus::collation_task";
 
+/// The number of submitted collations for which we look out for the candidate being backed.
+const MAX_AWAITED_CANDIDATES: u32 = 64;
+
 /// Parameters for the collation task.
 pub struct Params<Block: BlockT, RClient, CS> {
 	/// A handle to the relay-chain client.
@@ -55,6 +61,8 @@ pub struct Params<Block: BlockT, RClient, CS> {
 	pub block_import_handle: super::SlotBasedBlockImportHandle<Block>,
 	/// When set, the collator will export every produced `POV` to this folder.
 	pub export_pov: Option<PathBuf>,
+	/// The log to record the submitted and backed collations in.
+	pub decision_log: DecisionLog<Block>,
 }
 
 /// Asynchronously executes the collation task for a parachain.
@@ -62,7 +70,8 @@ pub struct Params<Block: BlockT, RClient, CS> {
 /// This function initializes the collator subsystems necessary for producing and submitting
 /// collations to the relay chain. It listens for new best relay chain block notifications and
 /// handles collator messages. If our parachain is scheduled on a core and we have a candidate,
-/// the task will build a collation and send it to the relay chain.
+/// the task will build a collation and send it to the relay chain. On every new best relay chain
+/// block, it checks which of the submitted candidates got backed.
 pub async fn run_collation_task<Block, RClient, CS>(
 	Params {
 		relay_client,
@@ -73,6 +82,7 @@ pub async fn run_collation_task<Block, RClient, CS>(
 		mut collator_receiver,
 		mut block_import_handle,
 		export_pov,
+		decision_log,
 	}: Params<Block, RClient, CS>,
 ) where
 	Block: BlockT,
@@ -92,6 +102,24 @@ pub async fn run_collation_task<Block, RClient, CS>(
 	)
 	.await;
 
+	// Without relay chain best blocks we can still submit collations, we just can not tell which
+	// of them got backed.
+	let mut best_notifications = match relay_client.new_best_notification_stream().await {
+		Ok(stream) => stream.fuse(),
+		Err(err) => {
+			tracing::error!(
+				target: LOG_TARGET,
+				?err,
+				"Failed to subscribe to relay chain best blocks, not tracking backed candidates.",
+			);
+			stream::pending().boxed().fuse()
+		},
+	};
+
+	// Submitted candidates we did not see backed yet, by parachain block hash.
+	let mut awaited_candidates: schnellru::LruMap<Block::Hash, RelayHash> =
+		schnellru::LruMap::new(schnellru::ByLength::new(MAX_AWAITED_CANDIDATES));
+
 	loop {
 		futures::select! {
 			collator_message = collator_receiver.next() => {
@@ -99,7 +127,22 @@ pub async fn run_collation_task<Block, RClient, CS>(
 					return;
 				};
 
-				handle_collation_message(message, &collator_service, &mut overseer_handle,relay_client.clone(),export_pov.clone()).await;
+				if let Some((hash, relay_parent)) = handle_collation_message(message, &collator_service, &mut overseer_handle,relay_client.clone(),export_pov.clone(), &decision_log).await {
+					awaited_candidates.insert(hash, relay_parent);
+				}
+			},
+			relay_header = best_notifications.next() => {
+				let Some(relay_header) = relay_header else {
+					tracing::error!(
+						target: LOG_TARGET,
+						"Relay chain best block stream ended, not tracking backed candidates anymore.",
+					);
+					awaited_candidates.clear();
+					best_notifications = stream::pending().boxed().fuse();
+					continue;
+				};
+
+				note_backed_candidates(&relay_header, para_id, &relay_client, &mut awaited_candidates, &decision_log).await;
 			},
 			block_import_msg = block_import_handle.next().fuse() => {
 				// TODO: Implement me.
@@ -110,16 +153,54 @@ pub async fn run_collation_task<Block, RClient, CS>(
 	}
 }
 
+/// Record the candidates in `awaited_candidates` which are pending availability at the relay
+/// chain block `relay_header`, i.e. which got backed.
+async fn note_backed_candidates<Block: BlockT, RClient: RelayChainInterface>(
+	relay_header: &PHeader,
+	para_id: ParaId,
+	relay_client: &RClient,
+	awaited_candidates: &mut schnellru::LruMap<Block::Hash, RelayHash>,
+	decision_log: &DecisionLog<Block>,
+) {
+	if awaited_candidates.is_empty() {
+		return
+	}
+
+	let backed_in = relay_header.hash();
+	let candidates = match relay_client.candidates_pending_availability(backed_in, para_id).await {
+		Ok(candidates) => candidates,
+		Err(err) => {
+			tracing::debug!(target: LOG_TARGET, ?err, %backed_in, "Failed to fetch candidates pending availability.");
+			return
+		},
+	};
+
+	for candidate in candidates {
+		let Ok(header) = Block::Header::decode(&mut &candidate.commitments.head_data.0[..]) else {
+			continue
+		};
+		let block_hash = header.hash();
+
+		if let Some(relay_parent) = awaited_candidates.remove(&block_hash) {
+			tracing::debug!(target: LOG_TARGET, %block_hash, %backed_in, "Candidate backed.");
+			decision_log.record(relay_parent, Decision::CandidateBacked { block_hash, backed_in });
+		}
+	}
+}
+
 /// Handle an incoming collation message from the block builder task.
 /// This builds the collation from the [`CollatorMessage`] and submits it to
 /// the collation-generation subsystem of the relay chain.
+///
+/// Returns the hash and relay parent of the submitted block.
 async fn handle_collation_message<Block: BlockT, RClient: RelayChainInterface + Clone + 'static>(
 	message: CollatorMessage<Block>,
 	collator_service: &impl CollatorServiceInterface<Block>,
 	overseer_handle: &mut OverseerHandle,
 	relay_client: RClient,
 	export_pov: Option<PathBuf>,
-) {
+	decision_log: &DecisionLog<Block>,
+) -> Option<(Block::Hash, RelayHash)> {
 	let CollatorMessage {
 		parent_header,
 		parachain_candidate,
@@ -135,7 +216,11 @@ async fn handle_collation_message<Block: BlockT, RClient: RelayChainInterface +
 			Some(collation) => collation,
 			None => {
 				tracing::warn!(target: LOG_TARGET, %hash, ?number, ?core_index, "Unable to build collation.");
-				return;
+				decision_log.record(
+					relay_parent,
+					Decision::CollationBuildFailed { core_index: core_index.0, block_hash: hash },
+				);
+				return None;
 			},
 		};
 
@@ -187,4 +272,11 @@ async fn handle_collation_message<Block: BlockT, RClient: RelayChainInterface +
 			"SubmitCollation",
 		)
 		.await;
+
+	decision_log.record(
+		relay_parent,
+		Decision::CollationSubmitted { core_index: core_index.0, block_hash: hash },
+	);
+
+	Some((hash, relay_parent))
 }
@@ -0,0 +1,304 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus. If not, see <https://www.gnu.org/licenses/>.
+
+//! Structured log of the decisions taken by the slot-based collator at each relay parent.
+//!
+//! Every step of the way from a parachain slot to a backed candidate is recorded against the relay
+//! parent it happened at. This makes it possible to tell why a core went unused, i.e. whether the
+//! parachain was not scheduled, the core was already claimed, the slot was not ours, block building
+//! failed or the candidate was never backed. The log is exposed over RPC and the decisions are
+//! counted in Prometheus metrics.
+
+use parking_lot::Mutex;
+use polkadot_primitives::Hash as RelayHash;
+use prometheus_endpoint::{register, CounterVec, Opts, PrometheusError, Registry, U64};
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use std::{
+	collections::VecDeque,
+	sync::Arc,
+	time::{Duration, SystemTime},
+};
+
+/// The number of relay parents for which decisions are kept by default.
+pub const DEFAULT_DECISION_LOG_CAPACITY: usize = 64;
+
+/// A decision taken by the slot-based collator.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "decision")]
+pub enum Decision<Hash, Number> {
+	/// The parachain has no core scheduled at the relay parent.
+	NotScheduled,
+	/// The core selected by the runtime was already claimed by an earlier block at the relay
+	/// parent.
+	#[serde(rename_all = "camelCase")]
+	CoreAlreadyClaimed { core_index: u32 },
+	/// The core selected by the runtime was claimed.
+	#[serde(rename_all = "camelCase")]
+	CoreClaimed { core_index: u32, slot: u64 },
+	/// The core was claimed, but we are not allowed to build in this slot, e.g. because it is not
+	/// our slot or the unincluded segment is full.
+	#[serde(rename_all = "camelCase")]
+	SlotNotClaimed { core_index: u32, slot: u64 },
+	/// The relay parent had no free core left, so we waited for the next relay parent to build
+	/// the block of this slot on top of it.
+	#[serde(rename_all = "camelCase")]
+	AwaitedNextRelayParent { next_relay_parent: RelayHash },
+	/// A block was built and imported.
+	#[serde(rename_all = "camelCase")]
+	BlockBuilt { core_index: u32, block_hash: Hash, block_number: Number },
+	/// Building a block failed.
+	#[serde(rename_all = "camelCase")]
+	BlockBuildFailed { core_index: u32 },
+	/// The collation was submitted to the collation-generation subsystem.
+	#[serde(rename_all = "camelCase")]
+	CollationSubmitted { core_index: u32, block_hash: Hash },
+	/// Building the collation out of the block failed.
+	#[serde(rename_all = "camelCase")]
+	CollationBuildFailed { core_index: u32, block_hash: Hash },
+	/// The candidate was observed as backed (pending availability) at `backed_in`.
+	#[serde(rename_all = "camelCase")]
+	CandidateBacked { block_hash: Hash, backed_in: RelayHash },
+}
+
+impl<Hash, Number> Decision<Hash, Number> {
+	/// The label used for this decision in the metrics.
+	pub fn label(&self) -> &'static str {
+		match self {
+			Self::NotScheduled => "not_scheduled",
+			Self::CoreAlreadyClaimed { .. } => "core_already_claimed",
+			Self::CoreClaimed { .. } => "core_claimed",
+			Self::SlotNotClaimed { .. } => "slot_not_claimed",
+			Self::AwaitedNextRelayParent { .. } => "awaited_next_relay_parent",
+			Self::BlockBuilt { .. } => "block_built",
+			Self::BlockBuildFailed { .. } => "block_build_failed",
+			Self::CollationSubmitted { .. } => "collation_submitted",
+			Self::CollationBuildFailed { .. } => "collation_build_failed",
+			Self::CandidateBacked { .. } => "candidate_backed",
+		}
+	}
+}
+
+/// A [`Decision`] together with the time it was taken.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedDecision<Hash, Number> {
+	/// Milliseconds since the Unix epoch.
+	pub timestamp: u64,
+	/// The decision.
+	#[serde(flatten)]
+	pub decision: Decision<Hash, Number>,
+}
+
+/// All decisions taken at one relay parent, in the order they were taken.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionLogEntry<Hash, Number> {
+	/// The relay parent the decisions were taken at.
+	pub relay_parent: RelayHash,
+	/// The decisions.
+	pub decisions: Vec<RecordedDecision<Hash, Number>>,
+}
+
+/// Shorthand for the [`Decision`] type of `Block`.
+pub type BlockDecision<Block> = Decision<<Block as BlockT>::Hash, NumberFor<Block>>;
+
+/// Shorthand for the [`DecisionLogEntry`] type of `Block`.
+pub type BlockDecisionLogEntry<Block> = DecisionLogEntry<<Block as BlockT>::Hash, NumberFor<Block>>;
+
+#[derive(Clone)]
+struct Metrics {
+	decisions: CounterVec<U64>,
+}
+
+impl Metrics {
+	fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			decisions: register(
+				CounterVec::new(
+					Opts::new(
+						"cumulus_slot_based_collator_decisions_total",
+						"Number of decisions taken by the slot-based collator, by decision",
+					),
+					&["decision"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+}
+
+/// Bounded log of the decisions taken by the slot-based collator.
+///
+/// Cloning the log is cheap and all clones share the same entries.
+pub struct DecisionLog<Block: BlockT> {
+	entries: Arc<Mutex<VecDeque<BlockDecisionLogEntry<Block>>>>,
+	capacity: usize,
+	metrics: Option<Metrics>,
+}
+
+impl<Block: BlockT> Clone for DecisionLog<Block> {
+	fn clone(&self) -> Self {
+		Self {
+			entries: self.entries.clone(),
+			capacity: self.capacity,
+			metrics: self.metrics.clone(),
+		}
+	}
+}
+
+impl<Block: BlockT> Default for DecisionLog<Block> {
+	fn default() -> Self {
+		Self::new(DEFAULT_DECISION_LOG_CAPACITY, None)
+	}
+}
+
+impl<Block: BlockT> DecisionLog<Block> {
+	/// Create a new log keeping the decisions of the last `capacity` relay parents.
+	///
+	/// If a `registry` is given, the decisions are also counted in Prometheus metrics.
+	pub fn new(capacity: usize, registry: Option<&Registry>) -> Self {
+		let metrics = registry.and_then(|registry| {
+			Metrics::register(registry)
+				.map_err(|err| {
+					tracing::warn!(
+						target: crate::LOG_TARGET,
+						?err,
+						"Failed to register slot-based collator metrics."
+					)
+				})
+				.ok()
+		});
+
+		Self { entries: Default::default(), capacity: capacity.max(1), metrics }
+	}
+
+	/// Record `decision` as taken at `relay_parent`.
+	///
+	/// A candidate can be seen as backed multiple times, e.g. after a relay chain reorg. Such
+	/// duplicates are ignored.
+	pub fn record(&self, relay_parent: RelayHash, decision: BlockDecision<Block>) {
+		let mut entries = self.entries.lock();
+
+		let position = entries.iter().rposition(|entry| entry.relay_parent == relay_parent);
+		let entry = match position {
+			Some(position) => &mut entries[position],
+			None => {
+				if entries.len() >= self.capacity {
+					entries.pop_front();
+				}
+				entries.push_back(DecisionLogEntry { relay_parent, decisions: Vec::new() });
+				entries.back_mut().expect("An entry was just pushed; qed")
+			},
+		};
+
+		if matches!(decision, Decision::CandidateBacked { .. }) &&
+			entry.decisions.iter().any(|recorded| recorded.decision == decision)
+		{
+			return
+		}
+
+		if let Some(metrics) = &self.metrics {
+			metrics.decisions.with_label_values(&[decision.label()]).inc();
+		}
+
+		entry.decisions.push(RecordedDecision { timestamp: timestamp_now(), decision });
+	}
+
+	/// Returns the entries of the most recent relay parents first.
+	///
+	/// At most `limit` entries are returned if a limit is given.
+	pub fn entries(&self, limit: Option<usize>) -> Vec<BlockDecisionLogEntry<Block>> {
+		let entries = self.entries.lock();
+		entries.iter().rev().take(limit.unwrap_or(usize::MAX)).cloned().collect()
+	}
+}
+
+/// Milliseconds since the Unix epoch.
+fn timestamp_now() -> u64 {
+	SystemTime::now()
+		.duration_since(SystemTime::UNIX_EPOCH)
+		.unwrap_or(Duration::ZERO)
+		.as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use cumulus_test_client::runtime::{Block, Hash};
+
+	#[test]
+	fn decisions_are_grouped_by_relay_parent() {
+		let log = DecisionLog::<Block>::new(2, None);
+		let (first, second, third) =
+			(RelayHash::repeat_byte(1), RelayHash::repeat_byte(2), RelayHash::repeat_byte(3));
+
+		log.record(first, Decision::NotScheduled);
+		log.record(second, Decision::CoreClaimed { core_index: 0, slot: 10 });
+		log.record(
+			second,
+			Decision::BlockBuilt {
+				core_index: 0,
+				block_hash: Hash::repeat_byte(9),
+				block_number: 1,
+			},
+		);
+
+		let entries = log.entries(None);
+		assert_eq!(entries.len(), 2);
+		assert_eq!(entries[0].relay_parent, second);
+		assert_eq!(entries[0].decisions.len(), 2);
+		assert_eq!(entries[1].relay_parent, first);
+
+		// The oldest relay parent is evicted.
+		log.record(third, Decision::NotScheduled);
+		let entries = log.entries(None);
+		assert_eq!(
+			entries.iter().map(|entry| entry.relay_parent).collect::<Vec<_>>(),
+			vec![third, second]
+		);
+		assert_eq!(log.entries(Some(1)).len(), 1);
+	}
+
+	#[test]
+	fn backed_candidates_are_recorded_once() {
+		let log = DecisionLog::<Block>::default();
+		let relay_parent = RelayHash::repeat_byte(1);
+		let backed = Decision::CandidateBacked {
+			block_hash: Hash::repeat_byte(9),
+			backed_in: RelayHash::repeat_byte(2),
+		};
+
+		log.record(relay_parent, backed.clone());
+		log.record(relay_parent, backed.clone());
+
+		let decisions = &log.entries(None)[0].decisions;
+		assert_eq!(decisions.len(), 1);
+		assert_eq!(decisions[0].decision, backed);
+	}
+
+	#[test]
+	fn decisions_are_serialized_with_a_tag() {
+		let decision: Decision<Hash, u32> = Decision::CoreAlreadyClaimed { core_index: 3 };
+		let recorded = RecordedDecision { timestamp: 5, decision };
+
+		assert_eq!(
+			serde_json::to_value(&recorded).unwrap(),
+			serde_json::json!({ "timestamp": 5, "decision": "coreAlreadyClaimed", "coreIndex": 3 })
+		);
+	}
+}
@@ -28,6 +28,12 @@
 //! chain block. The collator implementation then expects that we have that many cores scheduled
 //! during the relay chain block. After the block is built, the block builder task sends it to
 //! the collation task which compresses it and submits it to the collation-generation subsystem.
+//!
+//! Both tasks record the decisions they take for every relay parent in a [`DecisionLog`], which
+//! is exposed through Prometheus metrics and the [`rpc::SlotBasedCollatorApiServer`] RPC. With
+//! elastic scaling, a slot can find all the cores scheduled at the best relay parent already
+//! claimed. If [`Params::relay_parent_lookahead`] is enabled, the block builder then waits for the
+//! next relay parent instead of skipping the slot.
 
 use self::{block_builder_task::run_block_builder, collation_task::run_collation_task};
 use codec::Codec;
@@ -57,11 +63,16 @@ use sp_runtime::traits::{Block as BlockT, Member, NumberFor, One};
 use std::{path::PathBuf, sync::Arc, time::Duration};
 
 pub use block_import::{SlotBasedBlockImport, SlotBasedBlockImportHandle};
+pub use decision_log::{
+	Decision, DecisionLog, DecisionLogEntry, RecordedDecision, DEFAULT_DECISION_LOG_CAPACITY,
+};
 
 mod block_builder_task;
 mod block_import;
 mod collation_task;
+mod decision_log;
 mod relay_chain_data_cache;
+pub mod rpc;
 
 /// Parameters for [`run`].
 pub struct Params<Block, BI, CIDP, Client, Backend, RClient, CHP, Proposer, CS, Spawner> {
@@ -102,6 +113,14 @@ pub struct Params<Block, BI, CIDP, Client, Backend, RClient, CHP, Proposer, CS,
 	pub spawner: Spawner,
 	/// When set, the collator will export every produced `POV` to this folder.
 	pub export_pov: Option<PathBuf>,
+	/// The log the collator records its decisions in. If `None`, the decisions are only recorded
+	/// in a private log.
+	pub decision_log: Option<DecisionLog<Block>>,
+	/// Whether to wait for the next relay parent when the parachain has multiple cores assigned
+	/// but all of them are already claimed at the best relay parent. The block of the slot is then
+	/// built on top of the next relay parent as soon as it is imported, instead of skipping the
+	/// slot.
+	pub relay_parent_lookahead: bool,
 }
 
 /// Run aura-based block building and collation task.
@@ -151,8 +170,12 @@ pub fn run<Block, P, BI, CIDP, Client, Backend, RClient, CHP, Proposer, CS, Spaw
 		block_import_handle,
 		spawner,
 		export_pov,
+		decision_log,
+		relay_parent_lookahead,
 	} = params;
 
+	let decision_log = decision_log.unwrap_or_default();
+
 	let (tx, rx) = tracing_unbounded("mpsc_builder_to_collator", 100);
 	let collator_task_params = collation_task::Params {
 		relay_client: relay_client.clone(),
@@ -163,6 +186,7 @@ pub fn run<Block, P, BI, CIDP, Client, Backend, RClient, CHP, Proposer, CS, Spaw
 		collator_receiver: rx,
 		block_import_handle,
 		export_pov,
+		decision_log: decision_log.clone(),
 	};
 
 	let collation_task_fut = run_collation_task::<Block, _, _>(collator_task_params);
@@ -181,6 +205,8 @@ pub fn run<Block, P, BI, CIDP, Client, Backend, RClient, CHP, Proposer, CS, Spaw
 		authoring_duration,
 		collator_sender: tx,
 		slot_drift,
+		decision_log,
+		relay_parent_lookahead,
 	};
 
 	let block_builder_fut =
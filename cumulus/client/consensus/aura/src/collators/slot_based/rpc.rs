@@ -0,0 +1,56 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC API for inspecting the slot-based collator.
+
+use super::decision_log::{DecisionLog, DecisionLogEntry};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+
+/// Provides RPC methods for inspecting the slot-based collator.
+#[rpc(client, server)]
+pub trait SlotBasedCollatorApi<Hash, Number> {
+	/// Returns the decisions taken by the collator, grouped by relay parent and starting with the
+	/// most recent relay parent.
+	///
+	/// At most `limit` relay parents are returned if a limit is given.
+	#[method(name = "slotBasedCollator_decisionLog")]
+	fn decision_log(&self, limit: Option<u32>) -> RpcResult<Vec<DecisionLogEntry<Hash, Number>>>;
+}
+
+/// Implements the [`SlotBasedCollatorApiServer`] RPC trait.
+pub struct SlotBasedCollator<Block: BlockT> {
+	decision_log: DecisionLog<Block>,
+}
+
+impl<Block: BlockT> SlotBasedCollator<Block> {
+	/// Create a new instance serving the entries of `decision_log`.
+	pub fn new(decision_log: DecisionLog<Block>) -> Self {
+		Self { decision_log }
+	}
+}
+
+impl<Block: BlockT> SlotBasedCollatorApiServer<Block::Hash, NumberFor<Block>>
+	for SlotBasedCollator<Block>
+{
+	fn decision_log(
+		&self,
+		limit: Option<u32>,
+	) -> RpcResult<Vec<DecisionLogEntry<Block::Hash, NumberFor<Block>>>> {
+		Ok(self.decision_log.entries(limit.map(|limit| limit as usize)))
+	}
+}
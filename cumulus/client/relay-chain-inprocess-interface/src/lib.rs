@@ -43,9 +43,10 @@ use sc_client_api::{
 	StorageProof,
 };
 use sc_telemetry::TelemetryWorkerHandle;
+use sc_transaction_pool_api::OffchainTransactionPoolFactory;
 use sp_api::{CallApiAt, CallApiAtParams, CallContext, ProvideRuntimeApi};
 use sp_consensus::SyncOracle;
-use sp_core::Pair;
+use sp_core::{offchain::TransactionPool as _, Pair};
 use sp_state_machine::{Backend as StateBackend, StorageValue};
 
 /// The timeout in seconds after that the waiting for a block should be aborted.
@@ -59,6 +60,7 @@ pub struct RelayChainInProcessInterface {
 	backend: Arc<FullBackend>,
 	sync_oracle: Arc<dyn SyncOracle + Send + Sync>,
 	overseer_handle: Handle,
+	tx_pool_factory: OffchainTransactionPoolFactory<PBlock>,
 }
 
 impl RelayChainInProcessInterface {
@@ -68,8 +70,9 @@ impl RelayChainInProcessInterface {
 		backend: Arc<FullBackend>,
 		sync_oracle: Arc<dyn SyncOracle + Send + Sync>,
 		overseer_handle: Handle,
+		tx_pool_factory: OffchainTransactionPoolFactory<PBlock>,
 	) -> Self {
-		Self { full_client, backend, sync_oracle, overseer_handle }
+		Self { full_client, backend, sync_oracle, overseer_handle, tx_pool_factory }
 	}
 }
 
@@ -321,6 +324,18 @@ impl RelayChainInterface for RelayChainInProcessInterface {
 	async fn scheduling_lookahead(&self, hash: PHash) -> RelayChainResult<u32> {
 		Ok(self.full_client.runtime_api().scheduling_lookahead(hash)?)
 	}
+
+	async fn submit_extrinsic(&self, extrinsic: Vec<u8>) -> RelayChainResult<()> {
+		let best_hash = self.backend.blockchain().info().best_hash;
+		self.tx_pool_factory
+			.offchain_transaction_pool(best_hash)
+			.submit_transaction(extrinsic)
+			.map_err(|_| {
+				RelayChainError::GenericError(
+					"Extrinsic was rejected by the transaction pool".to_string(),
+				)
+			})
+	}
 }
 
 pub enum BlockCheckStatus {
@@ -386,6 +401,7 @@ fn build_polkadot_full_node(
 			prepare_workers_soft_max_num: None,
 			enable_approval_voting_parallel: false,
 			keep_finalized_for: None,
+			pov_archive: None,
 		},
 	)?;
 
@@ -420,6 +436,7 @@ pub fn build_inprocess_relay_chain(
 		full_node.overseer_handle.clone().ok_or(RelayChainError::GenericError(
 			"Overseer not running in full node.".to_string(),
 		))?,
+		full_node.offchain_tx_pool_factory,
 	));
 
 	task_manager.add_child(full_node.task_manager);
@@ -437,6 +454,7 @@ mod tests {
 		DefaultTestClientBuilderExt, InitPolkadotBlockBuilder, TestClientBuilder,
 		TestClientBuilderExt,
 	};
+	use sc_transaction_pool_api::LocalTransactionPool;
 	use sp_consensus::{BlockOrigin, SyncOracle};
 	use sp_runtime::traits::Block as BlockT;
 	use std::sync::Arc;
@@ -455,6 +473,22 @@ mod tests {
 		}
 	}
 
+	struct DummyTransactionPool {}
+
+	impl LocalTransactionPool for DummyTransactionPool {
+		type Block = PBlock;
+		type Hash = PHash;
+		type Error = sc_transaction_pool_api::error::Error;
+
+		fn submit_local(
+			&self,
+			_: PHash,
+			_: <PBlock as BlockT>::Extrinsic,
+		) -> Result<PHash, Self::Error> {
+			unimplemented!("Not needed for test")
+		}
+	}
+
 	fn build_client_backend_and_block() -> (Arc<Client>, PBlock, RelayChainInProcessInterface) {
 		let builder = TestClientBuilder::new();
 		let backend = builder.backend();
@@ -466,10 +500,17 @@ mod tests {
 
 		let (tx, _rx) = metered::channel(30);
 		let mock_handle = Handle::new(tx);
+		let tx_pool_factory = OffchainTransactionPoolFactory::new(DummyTransactionPool {});
 		(
 			client.clone(),
 			block,
-			RelayChainInProcessInterface::new(client, backend, dummy_network, mock_handle),
+			RelayChainInProcessInterface::new(
+				client,
+				backend,
+				dummy_network,
+				mock_handle,
+				tx_pool_factory,
+			),
 		)
 	}
 
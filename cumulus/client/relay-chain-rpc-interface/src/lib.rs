@@ -287,4 +287,8 @@ impl RelayChainInterface for RelayChainRpcInterface {
 	async fn scheduling_lookahead(&self, relay_parent: RelayHash) -> RelayChainResult<u32> {
 		self.rpc_client.parachain_host_scheduling_lookahead(relay_parent).await
 	}
+
+	async fn submit_extrinsic(&self, extrinsic: Vec<u8>) -> RelayChainResult<()> {
+		self.rpc_client.author_submit_extrinsic(extrinsic).await.map(drop)
+	}
 }
@@ -567,6 +567,15 @@ impl RelayChainRpcClient {
 		self.request("chain_getHeader", params).await
 	}
 
+	/// Submit an extrinsic to the transaction pool of the relay chain node.
+	pub async fn author_submit_extrinsic(
+		&self,
+		extrinsic: Vec<u8>,
+	) -> Result<RelayHash, RelayChainError> {
+		let params = rpc_params![sp_core::Bytes(extrinsic)];
+		self.request("author_submitExtrinsic", params).await
+	}
+
 	/// Get the receipt of a candidate pending availability. This returns `Some` for any paras
 	/// assigned to occupied cores in `availability_cores` and `None` otherwise.
 	pub async fn parachain_host_candidate_pending_availability(
@@ -50,6 +50,9 @@ use sp_core::{traits::SpawnNamed, Decode};
 use sp_runtime::traits::{Block as BlockT, BlockIdTo, Header};
 use std::{sync::Arc, time::Duration};
 
+#[cfg(feature = "on-demand-orders")]
+pub mod on_demand;
+
 pub use cumulus_primitives_proof_size_hostfunction::storage_proof_size;
 
 /// Host functions that should be used in parachain nodes.
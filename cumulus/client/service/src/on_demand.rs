@@ -0,0 +1,752 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placing on-demand coretime orders from a collator.
+//!
+//! Parachains without (enough) bulk coretime need someone to order cores on the relay chain
+//! through its on-demand pallet. Instead of running an external bot, a collator can start the
+//! service in this module with [`start_on_demand_orders`]. It follows the best relay chain block
+//! and orders a core whenever the [`OrderPolicy`] asks for it, for example because transactions
+//! are waiting in the local transaction pool or because no parachain block was included for a
+//! while.
+//!
+//! No orders are placed while the parachain already has a core in the claim queue of the relay
+//! chain, for example because bulk coretime is assigned to it. Orders are paid by the account of
+//! [`OnDemandOrderConfig::signer`] and are rejected by the relay chain if the spot price exceeds
+//! [`OnDemandOrderConfig::max_spot_price`].
+//!
+//! Every collator that runs this service places its own orders, so usually only one collator of a
+//! parachain should enable it.
+//!
+//! The module is only available with the `on-demand-orders` feature.
+
+use cumulus_primitives_core::{
+	relay_chain::{BlockId, HeadData},
+	ParaId,
+};
+use cumulus_relay_chain_interface::{
+	call_runtime_api, BlockNumber, CoreIndex, OccupiedCoreAssumption, PHash, RelayChainError,
+	RelayChainInterface, RelayChainResult,
+};
+use futures::StreamExt;
+use sc_service::TaskManager;
+use sc_telemetry::log;
+use sc_transaction_pool_api::TransactionPool;
+use sp_core::{sr25519, Decode, OpaqueMetadata, Pair};
+use sp_runtime::traits::Header;
+use std::{
+	collections::{BTreeMap, VecDeque},
+	sync::Arc,
+	time::{Duration, Instant},
+};
+use subxt::{
+	client::RuntimeVersion as SubxtRuntimeVersion,
+	config::polkadot::PolkadotExtrinsicParamsBuilder,
+	dynamic::Value,
+	tx::Signer,
+	utils::{AccountId32, MultiAddress, MultiSignature, H256},
+	OfflineClient, PolkadotConfig,
+};
+
+const LOG_TARGET: &str = "cumulus::on-demand";
+
+/// The metadata version used to build the orders. This is the latest version supported by
+/// `subxt`.
+const METADATA_VERSION: u32 = 15;
+
+/// The name of the on-demand pallet in the Polkadot, Kusama, Westend and Rococo runtimes.
+pub const DEFAULT_ON_DEMAND_PALLET: &str = "OnDemandAssignmentProvider";
+
+/// How an on-demand order is paid for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderPayment {
+	/// Pay from the free balance, without reaping the account.
+	KeepAlive,
+	/// Pay from the free balance, allowing the account to be reaped.
+	AllowDeath,
+	/// Pay with on-demand credits bought from the coretime chain.
+	Credits,
+}
+
+impl OrderPayment {
+	/// The name of the on-demand call that places an order with this kind of payment.
+	pub fn call_name(&self) -> &'static str {
+		match self {
+			Self::KeepAlive => "place_order_keep_alive",
+			Self::AllowDeath => "place_order_allow_death",
+			Self::Credits => "place_order_with_credits",
+		}
+	}
+}
+
+/// Decides when an on-demand order is placed.
+///
+/// An order is placed if any of the enabled conditions holds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderPolicy {
+	/// Order a core once at least this many transactions are ready in the transaction pool.
+	pub min_ready_transactions: Option<usize>,
+	/// Order a core if no parachain block was included for this long.
+	pub max_inclusion_interval: Option<Duration>,
+	/// The number of relay chain blocks to wait after placing an order before placing another
+	/// one. Gives the order time to show up in the claim queue.
+	pub order_cooldown: BlockNumber,
+}
+
+impl Default for OrderPolicy {
+	fn default() -> Self {
+		Self { min_ready_transactions: Some(1), max_inclusion_interval: None, order_cooldown: 4 }
+	}
+}
+
+impl OrderPolicy {
+	/// Whether the given number of ready transactions or the time since the last inclusion of a
+	/// parachain block ask for an order.
+	pub fn should_order(&self, ready_transactions: usize, since_inclusion: Duration) -> bool {
+		self.min_ready_transactions.is_some_and(|min| ready_transactions >= min) ||
+			self.max_inclusion_interval.is_some_and(|max| since_inclusion >= max)
+	}
+
+	/// Whether the cooldown after an order placed at `last_order` has passed at `now`.
+	fn cooldown_passed(&self, last_order: Option<BlockNumber>, now: BlockNumber) -> bool {
+		last_order.map_or(true, |last| now.saturating_sub(last) >= self.order_cooldown)
+	}
+}
+
+/// Configuration of the on-demand order service.
+pub struct OnDemandOrderConfig {
+	/// The relay chain account that places and pays for the orders.
+	pub signer: sr25519::Pair,
+	/// The maximal spot price that the service is willing to pay for a core.
+	pub max_spot_price: u128,
+	/// How orders are paid for.
+	pub payment: OrderPayment,
+	/// When orders are placed.
+	pub policy: OrderPolicy,
+	/// The name of the on-demand pallet in the relay chain runtime, usually
+	/// [`DEFAULT_ON_DEMAND_PALLET`].
+	pub pallet_name: String,
+}
+
+/// Parameters given to [`start_on_demand_orders`].
+pub struct StartOnDemandOrdersParams<'a, Pool, RCInterface> {
+	pub para_id: ParaId,
+	pub transaction_pool: Arc<Pool>,
+	pub relay_chain_interface: RCInterface,
+	pub task_manager: &'a TaskManager,
+	pub config: OnDemandOrderConfig,
+}
+
+/// Start placing on-demand orders for the parachain.
+///
+/// The service stops if the relay chain stops producing best block notifications. Failing to
+/// place an order is not fatal, it is retried on the next relay chain block.
+pub fn start_on_demand_orders<Pool, RCInterface>(
+	StartOnDemandOrdersParams {
+		para_id,
+		transaction_pool,
+		relay_chain_interface,
+		task_manager,
+		config,
+	}: StartOnDemandOrdersParams<Pool, RCInterface>,
+) where
+	Pool: TransactionPool + 'static,
+	RCInterface: RelayChainInterface + Clone + 'static,
+{
+	let service = OnDemandOrders {
+		para_id,
+		transaction_pool,
+		relay_chain_interface,
+		config,
+		builder: None,
+		last_head: None,
+		last_order: None,
+		scheduled: false,
+	};
+
+	task_manager
+		.spawn_handle()
+		.spawn("cumulus-on-demand-orders", None, service.run());
+}
+
+/// Builds signed order extrinsics for a specific runtime version of the relay chain.
+struct OrderBuilder {
+	client: OfflineClient<PolkadotConfig>,
+	spec_version: u32,
+}
+
+struct OnDemandOrders<Pool, RCInterface> {
+	para_id: ParaId,
+	transaction_pool: Arc<Pool>,
+	relay_chain_interface: RCInterface,
+	config: OnDemandOrderConfig,
+	builder: Option<OrderBuilder>,
+	/// The last included head of the parachain and when it was first seen.
+	last_head: Option<(HeadData, Instant)>,
+	/// The relay chain block at which the last order was placed.
+	last_order: Option<BlockNumber>,
+	/// Whether the parachain had a core in the claim queue at the last relay chain block.
+	scheduled: bool,
+}
+
+impl<Pool, RCInterface> OnDemandOrders<Pool, RCInterface>
+where
+	Pool: TransactionPool,
+	RCInterface: RelayChainInterface,
+{
+	async fn run(mut self) {
+		let mut best_blocks = match self.relay_chain_interface.new_best_notification_stream().await
+		{
+			Ok(stream) => stream,
+			Err(err) => {
+				log::error!(
+					target: LOG_TARGET,
+					"Unable to follow the relay chain, not placing on-demand orders: {err}",
+				);
+				return
+			},
+		};
+
+		while let Some(header) = best_blocks.next().await {
+			let ready = self.transaction_pool.status().ready;
+			if let Err(err) = self.on_relay_block(header.hash(), *header.number(), ready).await {
+				log::warn!(target: LOG_TARGET, "Failed to place an on-demand order: {err}");
+			}
+		}
+	}
+}
+
+impl<Pool, RCInterface> OnDemandOrders<Pool, RCInterface>
+where
+	RCInterface: RelayChainInterface,
+{
+	/// Place an order at the new best relay chain block if the policy asks for it, given the
+	/// number of transactions that are ready in the transaction pool.
+	async fn on_relay_block(
+		&mut self,
+		relay_parent: PHash,
+		number: BlockNumber,
+		ready: usize,
+	) -> RelayChainResult<()> {
+		let since_inclusion = self.track_inclusion(relay_parent).await?;
+
+		let claim_queue = self.relay_chain_interface.claim_queue(relay_parent).await?;
+		let scheduled = is_scheduled(&claim_queue, self.para_id);
+		if scheduled != self.scheduled {
+			log::info!(
+				target: LOG_TARGET,
+				"Parachain {} {} in the claim queue, {} on-demand orders",
+				self.para_id,
+				if scheduled { "has a core" } else { "has no core" },
+				if scheduled { "pausing" } else { "resuming" },
+			);
+			self.scheduled = scheduled;
+		}
+		if scheduled {
+			return Ok(())
+		}
+
+		if !self.config.policy.should_order(ready, since_inclusion) ||
+			!self.config.policy.cooldown_passed(self.last_order, number)
+		{
+			return Ok(())
+		}
+
+		self.place_order(relay_parent).await?;
+		self.last_order = Some(number);
+
+		log::info!(
+			target: LOG_TARGET,
+			"Placed an on-demand order for parachain {} at relay block #{number} ({ready} ready \
+			transactions, {}s since the last inclusion)",
+			self.para_id,
+			since_inclusion.as_secs(),
+		);
+
+		Ok(())
+	}
+
+	/// Track the included head of the parachain, returning the time since it changed.
+	async fn track_inclusion(&mut self, relay_parent: PHash) -> RelayChainResult<Duration> {
+		let head = self
+			.relay_chain_interface
+			.persisted_validation_data(relay_parent, self.para_id, OccupiedCoreAssumption::TimedOut)
+			.await?
+			.ok_or_else(|| {
+				RelayChainError::GenericError(format!("Parachain {} is unknown", self.para_id))
+			})?
+			.parent_head;
+
+		match &self.last_head {
+			Some((last, since)) if *last == head => Ok(since.elapsed()),
+			_ => {
+				self.last_head = Some((head, Instant::now()));
+				Ok(Duration::ZERO)
+			},
+		}
+	}
+
+	async fn place_order(&mut self, relay_parent: PHash) -> RelayChainResult<()> {
+		let version = self.relay_chain_interface.version(relay_parent).await?;
+		let builder = match self.builder.take() {
+			Some(builder) if builder.spec_version == version.spec_version => builder,
+			_ => {
+				let metadata: Option<OpaqueMetadata> = call_runtime_api(
+					&self.relay_chain_interface,
+					"Metadata_metadata_at_version",
+					relay_parent,
+					METADATA_VERSION,
+				)
+				.await?;
+				let metadata = metadata.ok_or_else(|| {
+					RelayChainError::GenericError(format!(
+						"Relay chain does not provide metadata V{METADATA_VERSION}"
+					))
+				})?;
+				let metadata = subxt::Metadata::decode(&mut &metadata[..])?;
+
+				let genesis = self
+					.relay_chain_interface
+					.header(BlockId::Number(0))
+					.await?
+					.ok_or_else(|| {
+						RelayChainError::GenericError("Relay chain genesis is unknown".into())
+					})?
+					.hash();
+				let runtime_version = SubxtRuntimeVersion {
+					spec_version: version.spec_version,
+					transaction_version: version.transaction_version,
+				};
+
+				OrderBuilder {
+					client: OfflineClient::new(
+						H256::from(genesis.to_fixed_bytes()),
+						runtime_version,
+						metadata,
+					),
+					spec_version: version.spec_version,
+				}
+			},
+		};
+
+		let nonce: u32 = call_runtime_api(
+			&self.relay_chain_interface,
+			"AccountNonceApi_account_nonce",
+			relay_parent,
+			self.config.signer.public(),
+		)
+		.await?;
+		let call = subxt::dynamic::tx(
+			self.config.pallet_name.as_str(),
+			self.config.payment.call_name(),
+			vec![
+				Value::u128(self.config.max_spot_price),
+				Value::unnamed_composite([Value::u128(u32::from(self.para_id).into())]),
+			],
+		);
+		let params = PolkadotExtrinsicParamsBuilder::<PolkadotConfig>::new()
+			.nonce(nonce.into())
+			.build();
+		let extrinsic = builder
+			.client
+			.tx()
+			.create_signed_offline(&call, &OrderSigner(&self.config.signer), params)
+			.map_err(|err| RelayChainError::GenericError(err.to_string()))?
+			.into_encoded();
+		self.builder = Some(builder);
+
+		self.relay_chain_interface.submit_extrinsic(extrinsic).await
+	}
+}
+
+/// Whether `para_id` has a core in the claim queue.
+fn is_scheduled(claim_queue: &BTreeMap<CoreIndex, VecDeque<ParaId>>, para_id: ParaId) -> bool {
+	claim_queue.values().any(|claims| claims.contains(&para_id))
+}
+
+/// Signs orders with the configured relay chain account.
+struct OrderSigner<'a>(&'a sr25519::Pair);
+
+impl Signer<PolkadotConfig> for OrderSigner<'_> {
+	fn account_id(&self) -> AccountId32 {
+		AccountId32(self.0.public().0)
+	}
+
+	fn address(&self) -> MultiAddress<AccountId32, ()> {
+		MultiAddress::Id(self.account_id())
+	}
+
+	fn sign(&self, signer_payload: &[u8]) -> MultiSignature {
+		MultiSignature::Sr25519(self.0.sign(signer_payload).0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use async_trait::async_trait;
+	use cumulus_relay_chain_interface::{
+		CommittedCandidateReceipt, CoreState, InboundDownwardMessage, InboundHrmpMessage,
+		OverseerHandle, PHeader, PersistedValidationData, SessionIndex, StorageValue,
+		ValidationCodeHash, ValidatorId,
+	};
+	use futures::{executor::block_on, Stream};
+	use polkadot_runtime_parachains::on_demand as parachains_on_demand;
+	use polkadot_test_runtime::{Runtime, RuntimeCall, UncheckedExtrinsic};
+	use sc_client_api::StorageProof;
+	use sp_core::Encode;
+	use sp_runtime::generic::Preamble;
+	use sp_version::RuntimeVersion;
+	use std::{pin::Pin, sync::Mutex};
+
+	const PARA_ID: u32 = 1000;
+	const NONCE: u32 = 7;
+	const MAX_SPOT_PRICE: u128 = 1_000_000;
+
+	/// A relay chain that serves the metadata of the test runtime and records the submitted
+	/// extrinsics.
+	#[derive(Default)]
+	struct Relaychain {
+		head: HeadData,
+		claim_queue: BTreeMap<CoreIndex, VecDeque<ParaId>>,
+		submitted: Mutex<Vec<Vec<u8>>>,
+	}
+
+	impl Relaychain {
+		fn orders(&self) -> Vec<UncheckedExtrinsic> {
+			self.submitted
+				.lock()
+				.unwrap()
+				.iter()
+				.map(|extrinsic| UncheckedExtrinsic::decode(&mut &extrinsic[..]).unwrap())
+				.collect()
+		}
+	}
+
+	#[async_trait]
+	impl RelayChainInterface for Relaychain {
+		async fn validators(&self, _: PHash) -> RelayChainResult<Vec<ValidatorId>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn best_block_hash(&self) -> RelayChainResult<PHash> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn finalized_block_hash(&self) -> RelayChainResult<PHash> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn retrieve_dmq_contents(
+			&self,
+			_: ParaId,
+			_: PHash,
+		) -> RelayChainResult<Vec<InboundDownwardMessage>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn retrieve_all_inbound_hrmp_channel_contents(
+			&self,
+			_: ParaId,
+			_: PHash,
+		) -> RelayChainResult<BTreeMap<ParaId, Vec<InboundHrmpMessage>>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn persisted_validation_data(
+			&self,
+			_: PHash,
+			_: ParaId,
+			_: OccupiedCoreAssumption,
+		) -> RelayChainResult<Option<PersistedValidationData>> {
+			Ok(Some(PersistedValidationData {
+				parent_head: self.head.clone(),
+				..Default::default()
+			}))
+		}
+
+		async fn validation_code_hash(
+			&self,
+			_: PHash,
+			_: ParaId,
+			_: OccupiedCoreAssumption,
+		) -> RelayChainResult<Option<ValidationCodeHash>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn candidate_pending_availability(
+			&self,
+			_: PHash,
+			_: ParaId,
+		) -> RelayChainResult<Option<CommittedCandidateReceipt>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn candidates_pending_availability(
+			&self,
+			_: PHash,
+			_: ParaId,
+		) -> RelayChainResult<Vec<CommittedCandidateReceipt>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn session_index_for_child(&self, _: PHash) -> RelayChainResult<SessionIndex> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn import_notification_stream(
+			&self,
+		) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn finality_notification_stream(
+			&self,
+		) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn new_best_notification_stream(
+			&self,
+		) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn is_major_syncing(&self) -> RelayChainResult<bool> {
+			unimplemented!("Not needed for test")
+		}
+
+		fn overseer_handle(&self) -> RelayChainResult<OverseerHandle> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn get_storage_by_key(
+			&self,
+			_: PHash,
+			_: &[u8],
+		) -> RelayChainResult<Option<StorageValue>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn prove_read(&self, _: PHash, _: &Vec<Vec<u8>>) -> RelayChainResult<StorageProof> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn wait_for_block(&self, _: PHash) -> RelayChainResult<()> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn header(&self, _: BlockId) -> RelayChainResult<Option<PHeader>> {
+			Ok(Some(PHeader::new(
+				0,
+				Default::default(),
+				Default::default(),
+				Default::default(),
+				Default::default(),
+			)))
+		}
+
+		async fn call_runtime_api(
+			&self,
+			method_name: &'static str,
+			_: PHash,
+			payload: &[u8],
+		) -> RelayChainResult<Vec<u8>> {
+			Ok(match method_name {
+				"Metadata_metadata_at_version" =>
+					Runtime::metadata_at_version(u32::decode(&mut &payload[..])?).encode(),
+				"AccountNonceApi_account_nonce" => NONCE.encode(),
+				_ => unimplemented!("Not needed for test"),
+			})
+		}
+
+		async fn version(&self, _: PHash) -> RelayChainResult<RuntimeVersion> {
+			Ok(polkadot_test_runtime::VERSION)
+		}
+
+		async fn availability_cores(
+			&self,
+			_: PHash,
+		) -> RelayChainResult<Vec<CoreState<PHash, BlockNumber>>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn claim_queue(
+			&self,
+			_: PHash,
+		) -> RelayChainResult<BTreeMap<CoreIndex, VecDeque<ParaId>>> {
+			Ok(self.claim_queue.clone())
+		}
+
+		async fn scheduling_lookahead(&self, _: PHash) -> RelayChainResult<u32> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn submit_extrinsic(&self, extrinsic: Vec<u8>) -> RelayChainResult<()> {
+			self.submitted.lock().unwrap().push(extrinsic);
+			Ok(())
+		}
+	}
+
+	fn alice() -> sr25519::Pair {
+		sr25519::Pair::from_string("//Alice", None).unwrap()
+	}
+
+	fn service(policy: OrderPolicy) -> OnDemandOrders<(), Relaychain> {
+		OnDemandOrders {
+			para_id: PARA_ID.into(),
+			transaction_pool: Arc::new(()),
+			relay_chain_interface: Relaychain::default(),
+			config: OnDemandOrderConfig {
+				signer: alice(),
+				max_spot_price: MAX_SPOT_PRICE,
+				payment: OrderPayment::KeepAlive,
+				policy,
+				pallet_name: DEFAULT_ON_DEMAND_PALLET.into(),
+			},
+			builder: None,
+			last_head: None,
+			last_order: None,
+			scheduled: false,
+		}
+	}
+
+	#[test]
+	fn order_is_signed_and_submitted() {
+		let mut service = service(OrderPolicy::default());
+
+		block_on(service.on_relay_block(PHash::repeat_byte(1), 1, 1)).unwrap();
+
+		let orders = service.relay_chain_interface.orders();
+		assert_eq!(orders.len(), 1);
+		assert_eq!(
+			orders[0].function,
+			RuntimeCall::OnDemandAssignmentProvider(
+				parachains_on_demand::Call::place_order_keep_alive {
+					max_amount: MAX_SPOT_PRICE,
+					para_id: PARA_ID.into(),
+				}
+			)
+		);
+		let Preamble::Signed(address, _, extension) = &orders[0].preamble else {
+			panic!("Orders are signed");
+		};
+		assert_eq!(*address, sp_runtime::MultiAddress::Id(alice().public().0.into()));
+		assert_eq!(extension.5 .0, NONCE);
+	}
+
+	#[test]
+	fn orders_wait_for_cooldown() {
+		let mut service = service(OrderPolicy::default());
+
+		for number in 1..=5 {
+			block_on(service.on_relay_block(PHash::repeat_byte(number as u8), number, 1)).unwrap();
+		}
+
+		// Ordered at #1 and #5.
+		assert_eq!(service.relay_chain_interface.orders().len(), 2);
+		assert_eq!(service.last_order, Some(5));
+	}
+
+	#[test]
+	fn no_orders_without_ready_transactions() {
+		let mut service = service(OrderPolicy::default());
+
+		block_on(service.on_relay_block(PHash::repeat_byte(1), 1, 0)).unwrap();
+
+		assert!(service.relay_chain_interface.orders().is_empty());
+		assert_eq!(service.last_order, None);
+	}
+
+	#[test]
+	fn no_orders_while_scheduled() {
+		let mut service = service(OrderPolicy { order_cooldown: 0, ..Default::default() });
+		service.relay_chain_interface.claim_queue =
+			BTreeMap::from([(CoreIndex(0), VecDeque::from([ParaId::from(PARA_ID)]))]);
+
+		block_on(service.on_relay_block(PHash::repeat_byte(1), 1, 10)).unwrap();
+		assert!(service.scheduled);
+		assert!(service.relay_chain_interface.orders().is_empty());
+
+		service.relay_chain_interface.claim_queue.clear();
+
+		block_on(service.on_relay_block(PHash::repeat_byte(2), 2, 10)).unwrap();
+		assert!(!service.scheduled);
+		assert_eq!(service.relay_chain_interface.orders().len(), 1);
+	}
+
+	#[test]
+	fn orders_when_inclusion_stalls() {
+		let mut service = service(OrderPolicy {
+			min_ready_transactions: None,
+			max_inclusion_interval: Some(Duration::from_secs(30)),
+			order_cooldown: 0,
+		});
+		service.relay_chain_interface.head = HeadData(vec![1]);
+		service.last_head = Some((HeadData(vec![1]), Instant::now() - Duration::from_secs(60)));
+
+		block_on(service.on_relay_block(PHash::repeat_byte(1), 1, 0)).unwrap();
+		assert_eq!(service.relay_chain_interface.orders().len(), 1);
+
+		// A new head was included, no order is needed.
+		service.relay_chain_interface.head = HeadData(vec![2]);
+
+		block_on(service.on_relay_block(PHash::repeat_byte(2), 2, 0)).unwrap();
+		assert_eq!(service.relay_chain_interface.orders().len(), 1);
+	}
+
+	#[test]
+	fn policy_orders_on_ready_transactions() {
+		let policy = OrderPolicy::default();
+
+		assert!(!policy.should_order(0, Duration::from_secs(600)));
+		assert!(policy.should_order(1, Duration::ZERO));
+	}
+
+	#[test]
+	fn policy_orders_after_inclusion_interval() {
+		let policy = OrderPolicy {
+			min_ready_transactions: None,
+			max_inclusion_interval: Some(Duration::from_secs(60)),
+			order_cooldown: 0,
+		};
+
+		assert!(!policy.should_order(100, Duration::from_secs(59)));
+		assert!(policy.should_order(0, Duration::from_secs(60)));
+	}
+
+	#[test]
+	fn policy_waits_for_cooldown() {
+		let policy = OrderPolicy::default();
+
+		assert!(policy.cooldown_passed(None, 0));
+		assert!(!policy.cooldown_passed(Some(10), 13));
+		assert!(policy.cooldown_passed(Some(10), 14));
+	}
+
+	#[test]
+	fn scheduled_para_is_detected() {
+		let claim_queue = BTreeMap::from([
+			(CoreIndex(0), VecDeque::from([ParaId::from(1000), ParaId::from(1000)])),
+			(CoreIndex(1), VecDeque::from([ParaId::from(2000)])),
+		]);
+
+		assert!(is_scheduled(&claim_queue, 2000.into()));
+		assert!(!is_scheduled(&claim_queue, 3000.into()));
+		assert!(!is_scheduled(&BTreeMap::new(), 1000.into()));
+	}
+}
@@ -174,6 +174,20 @@ impl CheckAssociatedRelayNumber for RelayNumberMonotonicallyIncreases {
 	}
 }
 
+/// Returns the size of the storage proof recorded so far in the current block.
+///
+/// Returns `None` if the node does not record a storage proof, e.g. when the runtime is executed
+/// outside of block production or validation. This is the proof size measurement expected by
+/// `frame_executive::Executive::try_runtime_upgrade_with_report`.
+pub fn storage_proof_size() -> Option<u64> {
+	use cumulus_primitives_proof_size_hostfunction::{
+		storage_proof_size, PROOF_RECORDING_DISABLED,
+	};
+
+	let proof_size = storage_proof_size::storage_proof_size();
+	(proof_size != PROOF_RECORDING_DISABLED).then_some(proof_size)
+}
+
 /// The max length of a DMP message.
 pub type MaxDmpMessageLenOf<T> = <<T as Config>::DmpQueue as HandleMessage>::MaxMessageLen;
 
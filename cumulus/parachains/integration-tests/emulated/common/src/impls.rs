@@ -325,6 +325,42 @@ macro_rules! impl_hrmp_channels_helpers_for_relay_chain {
 					.into()
 				}
 
+				/// Request changing the limits of an open channel the Parachain participates in
+				pub fn request_channel_resize_call(
+					sender: $crate::impls::ParaId,
+					recipient: $crate::impls::ParaId,
+					max_capacity: u32,
+					max_message_size: u32,
+				) -> $crate::impls::DoubleEncoded<()> {
+					use $crate::impls::Encode;
+
+					<Self as $crate::impls::Chain>::RuntimeCall::Hrmp($crate::impls::hrmp::Call::<
+						<Self as $crate::impls::Chain>::Runtime,
+					>::hrmp_request_channel_resize {
+						channel_id: $crate::impls::HrmpChannelId { sender, recipient },
+						proposed_max_capacity: max_capacity,
+						proposed_max_message_size: max_message_size,
+					})
+					.encode()
+					.into()
+				}
+
+				/// Counterparty Parachain accept the resize request of an open channel
+				pub fn accept_channel_resize_call(
+					sender: $crate::impls::ParaId,
+					recipient: $crate::impls::ParaId,
+				) -> $crate::impls::DoubleEncoded<()> {
+					use $crate::impls::Encode;
+
+					<Self as $crate::impls::Chain>::RuntimeCall::Hrmp($crate::impls::hrmp::Call::<
+						<Self as $crate::impls::Chain>::Runtime,
+					>::hrmp_accept_channel_resize {
+						channel_id: $crate::impls::HrmpChannelId { sender, recipient },
+					})
+					.encode()
+					.into()
+				}
+
 				/// A root origin force to open a channel between two Parachains
 				pub fn force_process_hrmp_open(sender: $crate::impls::ParaId, recipient: $crate::impls::ParaId) {
 					use $crate::impls::Chain;
@@ -223,6 +223,40 @@ fn teleport_from_and_to_relay() {
 	);
 }
 
+/// The journey of a teleport from the Relay Chain to the System Parachain can be traced from the
+/// Relay Chain to its execution on the System Parachain
+#[test]
+fn teleport_from_relay_to_system_para_is_traced() {
+	use emulated_integration_tests_common::xcm_emulator::tracer::{self, Endpoint, HopKind};
+	type Network = <Westend as Chain>::Network;
+
+	let amount = WESTEND_ED * 100;
+	let beneficiary: Location =
+		AccountId32Junction { network: None, id: AssetHubWestendReceiver::get().into() }.into();
+
+	Westend::execute_with(|| {
+		Dmp::make_parachain_reachable(AssetHubWestend::para_id());
+
+		assert_ok!(<Westend as WestendPallet>::XcmPallet::limited_teleport_assets(
+			<Westend as Chain>::RuntimeOrigin::signed(WestendSender::get()),
+			bx!(Westend::child_location_of(AssetHubWestend::para_id()).into()),
+			bx!(beneficiary.into()),
+			bx!((Here, amount).into()),
+			0,
+			WeightLimit::Unlimited,
+		));
+	});
+
+	let journey = tracer::last_journey::<Network>().expect("the teleport was traced");
+	let hops = journey.hops();
+	assert_eq!(hops.len(), 1, "{journey}");
+	assert_eq!(hops[0].kind, HopKind::Dmp);
+	assert_eq!(hops[0].from, Endpoint::Relay);
+	assert_eq!(hops[0].to, Endpoint::Para(AssetHubWestend::para_id().into()));
+	assert!(hops[0].instructions.contains(&"ReceiveTeleportedAsset".to_string()));
+	assert!(journey.is_complete(), "{journey}");
+}
+
 /// Limited Teleport of native asset from System Parachain to Relay Chain
 /// shouldn't work when there is not enough balance in Relay Chain's `CheckAccount`
 #[test]
@@ -818,6 +818,7 @@ impl pallet_message_queue::Config for Runtime {
 		AggregateMessageOrigin,
 		xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
 		RuntimeCall,
+		PolkadotXcm,
 	>;
 	type Size = u32;
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
@@ -1829,13 +1830,35 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_migrations::runtime_api::MigrationsApi<Block> for Runtime {
+		fn migration_status() -> pallet_migrations::MigrationStatus {
+			MultiBlockMigrations::migration_status()
+		}
+
+		fn last_failure() -> Option<pallet_migrations::MigrationProgress> {
+			MultiBlockMigrations::last_failure()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
+	#[api_version(2)]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
 			let weight = Executive::try_runtime_upgrade(checks).unwrap();
 			(weight, RuntimeBlockWeights::get().max_block)
 		}
 
+		fn on_runtime_upgrade_with_report(
+			checks: frame_try_runtime::UpgradeCheckSelect,
+		) -> frame_try_runtime::UpgradeReport {
+			// NOTE: intentional unwrap, to fail loudly if the upgrade checks fail.
+			Executive::try_runtime_upgrade_with_report(
+				checks,
+				cumulus_pallet_parachain_system::storage_proof_size,
+			)
+			.unwrap()
+		}
+
 		fn execute_block(
 			block: Block,
 			state_root_check: bool,
@@ -1848,6 +1871,15 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryDecodeState<Block> for Runtime {
+		fn decode_entire_state(
+			select: frame_try_runtime::DecodeSelect,
+		) -> frame_try_runtime::DecodeEntireStateReport {
+			Executive::try_decode_entire_state(select)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -2218,6 +2250,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::outcomes::XcmOutcomesApi<Block, BlockNumber> for Runtime {
+		fn recent_xcm_outcomes(
+			filter: xcm_runtime_apis::outcomes::XcmOutcomesFilter,
+			cursor: Option<u64>,
+			limit: u32,
+		) -> Result<xcm_runtime_apis::outcomes::XcmOutcomesPage<BlockNumber>, xcm_runtime_apis::outcomes::Error> {
+			PolkadotXcm::recent_xcm_outcomes(filter, cursor, limit)
+		}
+	}
+
 	impl pallet_revive::ReviveApi<Block, AccountId, Balance, Nonce, BlockNumber> for Runtime
 	{
 		fn balance(address: H160) -> U256 {
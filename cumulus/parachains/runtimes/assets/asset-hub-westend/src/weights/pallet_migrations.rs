@@ -221,4 +221,20 @@ impl<T: frame_system::Config> pallet_migrations::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 39).saturating_mul(n.into()))
 	}
+	/// Storage: `MultiBlockMigrations::Cursor` (r:1 w:1)
+	/// Proof: `MultiBlockMigrations::Cursor` (`max_values`: Some(1), `max_size`: Some(65550), added: 66045, mode: `MaxEncodedLen`)
+	/// Storage: `MultiBlockMigrations::LastFailure` (r:1 w:1)
+	/// Proof: `MultiBlockMigrations::LastFailure` (`max_values`: Some(1), `max_size`: Some(65807), added: 66302, mode: `MaxEncodedLen`)
+	/// Storage: `MultiBlockMigrations::Steps` (r:0 w:1)
+	/// Proof: `MultiBlockMigrations::Steps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn retry_failed_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `333`
+		//  Estimated: `132347`
+		// Minimum execution time: 9_512_000 picoseconds.
+		Weight::from_parts(9_874_000, 0)
+			.saturating_add(Weight::from_parts(0, 132347))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
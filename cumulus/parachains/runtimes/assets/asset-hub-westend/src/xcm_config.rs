@@ -467,6 +467,7 @@ impl xcm_executor::Config for XcmConfig {
 	type HrmpNewChannelOpenRequestHandler = ();
 	type HrmpChannelAcceptedHandler = ();
 	type HrmpChannelClosingHandler = ();
+	type HrmpChannelResizingHandler = ();
 	type XcmRecorder = PolkadotXcm;
 }
 
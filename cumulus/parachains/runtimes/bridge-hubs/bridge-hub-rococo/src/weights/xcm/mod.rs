@@ -108,6 +108,16 @@ impl<Call> XcmWeightInfo<Call> for BridgeHubRococoXcmWeight<Call> {
 		// XCM Executor does not currently support HRMP channel operations
 		Weight::MAX
 	}
+	fn hrmp_channel_resizing(
+		_initiator: &u32,
+		_sender: &u32,
+		_recipient: &u32,
+		_max_message_size: &u32,
+		_max_capacity: &u32,
+	) -> Weight {
+		// XCM Executor does not currently support HRMP channel operations
+		Weight::MAX
+	}
 	fn clear_origin() -> Weight {
 		XcmGeneric::<Runtime>::clear_origin()
 	}
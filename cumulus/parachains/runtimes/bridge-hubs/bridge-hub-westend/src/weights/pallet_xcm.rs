@@ -351,4 +351,10 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Placeholder until the benchmark is run for this runtime: reads the history size, clears a
+	// full history and writes the new size.
+	fn set_xcm_outcome_history_size() -> Weight {
+		T::DbWeight::get()
+			.reads_writes(1, u64::from(pallet_xcm::MAX_XCM_OUTCOME_HISTORY_SIZE).saturating_add(1))
+	}
 }
@@ -1045,12 +1045,24 @@ impl_runtime_apis! {
 	}
 
 	#[cfg(feature = "try-runtime")]
+	#[api_version(2)]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
 			let weight = Executive::try_runtime_upgrade(checks).unwrap();
 			(weight, RuntimeBlockWeights::get().max_block)
 		}
 
+		fn on_runtime_upgrade_with_report(
+			checks: frame_try_runtime::UpgradeCheckSelect,
+		) -> frame_try_runtime::UpgradeReport {
+			// NOTE: intentional unwrap, to fail loudly if the upgrade checks fail.
+			Executive::try_runtime_upgrade_with_report(
+				checks,
+				cumulus_pallet_parachain_system::storage_proof_size,
+			)
+			.unwrap()
+		}
+
 		fn execute_block(
 			block: Block,
 			state_root_check: bool,
@@ -1063,6 +1075,15 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryDecodeState<Block> for Runtime {
+		fn decode_entire_state(
+			select: frame_try_runtime::DecodeSelect,
+		) -> frame_try_runtime::DecodeEntireStateReport {
+			Executive::try_decode_entire_state(select)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -640,4 +640,25 @@ impl<T: frame_system::Config> pallet_broker::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// The auto-pool weights below are placeholders until their benchmarks are run. Each of them
+	// is composed of benchmarked calls accessing a superset of the storage that it accesses.
+	fn enable_auto_pool() -> Weight {
+		// Reads the Region like `transfer` and the `MaxAutoPoolsPerCore` (10) records of its core
+		// like `disable_auto_pool`.
+		Self::transfer().saturating_add(Self::disable_auto_pool().saturating_mul(10))
+	}
+	fn disable_auto_pool() -> Weight {
+		// Removes a record, which is smaller than the `Workplan` entry of `remove_assignment`.
+		Self::remove_assignment()
+	}
+	fn notify_core_utilization(n: u32, ) -> Weight {
+		// Reads the status, then removes each record and pools its Region like `pool`, dropping
+		// the potential renewal of the Region like `drop_renewal`.
+		T::DbWeight::get().reads(1).saturating_add(
+			Self::disable_auto_pool()
+				.saturating_add(Self::pool())
+				.saturating_add(Self::drop_renewal())
+				.saturating_mul(n.into()),
+		)
+	}
 }
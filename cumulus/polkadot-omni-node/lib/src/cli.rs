@@ -150,6 +150,14 @@ pub struct Cli<Config: CliConfig> {
 	#[arg(long)]
 	pub experimental_use_slot_based: bool,
 
+	/// EXPERIMENTAL: When all cores assigned to the parachain are already claimed at the best
+	/// relay chain block, make the slot-based collator wait for the next relay chain block and
+	/// build on top of it instead of skipping the slot.
+	///
+	/// Only has an effect together with `--experimental-use-slot-based`.
+	#[arg(long, requires = "experimental_use_slot_based")]
+	pub experimental_relay_parent_lookahead: bool,
+
 	/// Disable automatic hardware benchmarks.
 	///
 	/// By default these benchmarks are automatically ran at startup and measure
@@ -179,6 +187,7 @@ impl<Config: CliConfig> Cli<Config> {
 	pub(crate) fn node_extra_args(&self) -> NodeExtraArgs {
 		NodeExtraArgs {
 			use_slot_based_consensus: self.experimental_use_slot_based,
+			relay_parent_lookahead: self.experimental_relay_parent_lookahead,
 			export_pov: self.export_pov_to_path.clone(),
 		}
 	}
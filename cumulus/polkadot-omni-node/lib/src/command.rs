@@ -26,7 +26,7 @@ use crate::{
 		NodeBlock, NodeExtraArgs,
 	},
 	fake_runtime_api,
-	nodes::DynNodeSpecExt,
+	nodes::{DynNodeSpecExt, DynSolochainNodeSpec, OmniNodeSpec},
 	runtime::BlockNumber,
 };
 #[cfg(feature = "runtime-benchmarks")]
@@ -35,6 +35,7 @@ use cumulus_primitives_core::ParaId;
 use frame_benchmarking_cli::{BenchmarkCmd, SUBSTRATE_REFERENCE_HARDWARE};
 use log::info;
 use sc_cli::{CliConfiguration, Result, SubstrateCli};
+use sc_sysinfo::HwBench;
 use sp_runtime::traits::AccountIdConversion;
 #[cfg(feature = "runtime-benchmarks")]
 use sp_runtime::traits::HashingFor;
@@ -81,21 +82,58 @@ where
 	}
 }
 
+pub fn new_aura_grandpa_node_spec<Block>(aura_id: AuraConsensusId) -> Box<dyn DynSolochainNodeSpec>
+where
+	Block: NodeBlock,
+{
+	match aura_id {
+		AuraConsensusId::Sr25519 => crate::nodes::aura_grandpa::new_aura_grandpa_node_spec::<
+			Block,
+			fake_runtime_api::aura_sr25519::RuntimeApi,
+			sp_consensus_aura::sr25519::AuthorityId,
+		>(),
+		AuraConsensusId::Ed25519 => crate::nodes::aura_grandpa::new_aura_grandpa_node_spec::<
+			Block,
+			fake_runtime_api::aura_ed25519::RuntimeApi,
+			sp_consensus_aura::ed25519::AuthorityId,
+		>(),
+	}
+}
+
 fn new_node_spec(
 	config: &sc_service::Configuration,
 	runtime_resolver: &Box<dyn RuntimeResolverT>,
 	extra_args: &NodeExtraArgs,
-) -> std::result::Result<Box<dyn DynNodeSpecExt>, sc_cli::Error> {
+) -> std::result::Result<Box<OmniNodeSpec>, sc_cli::Error> {
 	let runtime = runtime_resolver.runtime(config.chain_spec.as_ref())?;
 
-	Ok(match runtime {
+	Ok(Box::new(match runtime {
 		Runtime::Omni(block_number, consensus) => match (block_number, consensus) {
 			(BlockNumber::U32, Consensus::Aura(aura_id)) =>
-				new_aura_node_spec::<Block<u32>>(aura_id, extra_args),
+				OmniNodeSpec::Parachain(new_aura_node_spec::<Block<u32>>(aura_id, extra_args)),
 			(BlockNumber::U64, Consensus::Aura(aura_id)) =>
-				new_aura_node_spec::<Block<u64>>(aura_id, extra_args),
+				OmniNodeSpec::Parachain(new_aura_node_spec::<Block<u64>>(aura_id, extra_args)),
+			(BlockNumber::U32, Consensus::AuraGrandpa(aura_id)) =>
+				OmniNodeSpec::Solochain(new_aura_grandpa_node_spec::<Block<u32>>(aura_id)),
+			(BlockNumber::U64, Consensus::AuraGrandpa(aura_id)) =>
+				OmniNodeSpec::Solochain(new_aura_grandpa_node_spec::<Block<u64>>(aura_id)),
 		},
-	})
+	}))
+}
+
+/// Gather the hardware benchmark of the machine, unless disabled on the command line.
+fn gather_hwbench(
+	config: &sc_service::Configuration,
+	no_hardware_benchmarks: bool,
+) -> Option<HwBench> {
+	(!no_hardware_benchmarks)
+		.then(|| {
+			config.database.path().map(|database_path| {
+				let _ = std::fs::create_dir_all(database_path);
+				sc_sysinfo::gather_hwbench(Some(database_path), &SUBSTRATE_REFERENCE_HARDWARE)
+			})
+		})
+		.flatten()
 }
 
 /// Parse command line arguments into service configuration.
@@ -212,6 +250,7 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 				}),
 				BenchmarkCmd::Machine(cmd) =>
 					runner.sync_run(|config| cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone())),
+				BenchmarkCmd::Compare(cmd) => cmd.run(),
 				#[allow(unreachable_patterns)]
 				_ => Err("Benchmarking sub-command unsupported or compilation feature missing. \
 					Make sure to compile with --features=runtime-benchmarks \
@@ -227,8 +266,18 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 			let collator_options = cli.run.collator_options();
 
 			runner.run_node_until_exit(|config| async move {
-				let node_spec =
-					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?;
+				let node_spec = match *new_node_spec(
+					&config,
+					&cmd_config.runtime_resolver,
+					&cli.node_extra_args(),
+				)? {
+					OmniNodeSpec::Parachain(node_spec) => node_spec,
+					OmniNodeSpec::Solochain(node_spec) => {
+						info!("⛓️ Running a standalone chain, no relay chain is needed");
+						let hwbench = gather_hwbench(&config, cli.no_hardware_benchmarks);
+						return node_spec.start_node(config, hwbench).await.map_err(Into::into)
+					},
+				};
 				let para_id = ParaId::from(
 					Extensions::try_get(&*config.chain_spec)
 						.map(|e| e.para_id)
@@ -289,17 +338,7 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 					}
 				}
 
-				let hwbench = (!cli.no_hardware_benchmarks)
-					.then(|| {
-						config.database.path().map(|database_path| {
-							let _ = std::fs::create_dir_all(database_path);
-							sc_sysinfo::gather_hwbench(
-								Some(database_path),
-								&SUBSTRATE_REFERENCE_HARDWARE,
-							)
-						})
-					})
-					.flatten();
+				let hwbench = gather_hwbench(&config, cli.no_hardware_benchmarks);
 
 				let parachain_account =
 					AccountIdConversion::<polkadot_primitives::AccountId>::into_account_truncating(
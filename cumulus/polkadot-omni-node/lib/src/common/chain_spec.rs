@@ -28,11 +28,18 @@ pub trait LoadSpec {
 }
 
 /// Default implementation for `LoadSpec` that just reads a chain spec from the disk.
+///
+/// Chain specs without the parachain [`Extensions`] are loaded as [`SolochainChainSpec`].
 pub struct DiskChainSpecLoader;
 
 impl LoadSpec for DiskChainSpecLoader {
 	fn load_spec(&self, path: &str) -> Result<Box<dyn ChainSpec>, String> {
-		Ok(Box::new(GenericChainSpec::from_json_file(path.into())?))
+		match GenericChainSpec::from_json_file(path.into()) {
+			Ok(spec) => Ok(Box::new(spec)),
+			Err(err) => SolochainChainSpec::from_json_file(path.into())
+				.map(|spec| Box::new(spec) as Box<_>)
+				.map_err(|_| err),
+		}
 	}
 }
 
@@ -57,6 +64,9 @@ impl Extensions {
 /// Generic chain spec for all polkadot-parachain runtimes
 pub type GenericChainSpec = sc_service::GenericChainSpec<Extensions>;
 
+/// Generic chain spec for standalone chains, which have no parachain extensions.
+pub type SolochainChainSpec = sc_service::GenericChainSpec;
+
 #[cfg(test)]
 mod tests {
 	use super::*;
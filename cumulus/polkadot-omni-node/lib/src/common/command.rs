@@ -23,9 +23,9 @@ use sc_cli::{CheckBlockCmd, ExportBlocksCmd, ExportStateCmd, ImportBlocksCmd, Re
 use sc_service::{Configuration, TaskManager};
 use std::{future::Future, pin::Pin};
 
-type SyncCmdResult = sc_cli::Result<()>;
+pub(crate) type SyncCmdResult = sc_cli::Result<()>;
 
-type AsyncCmdResult<'a> =
+pub(crate) type AsyncCmdResult<'a> =
 	sc_cli::Result<(Pin<Box<dyn Future<Output = SyncCmdResult> + 'a>>, TaskManager)>;
 
 pub trait NodeCommandRunner {
@@ -108,4 +108,7 @@ pub struct NodeExtraArgs {
 
 	/// If set, each `PoV` build by the node will be exported to this folder.
 	pub export_pov: Option<PathBuf>,
+
+	/// Whether the slot-based collator waits for the next relay parent when all cores are claimed.
+	pub relay_parent_lookahead: bool,
 }
@@ -29,6 +29,8 @@ use subxt_metadata::{Metadata, StorageEntryType};
 pub const DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME: &str = "ParachainSystem";
 /// Expected frame system pallet runtime type name.
 pub const DEFAULT_FRAME_SYSTEM_PALLET_NAME: &str = "System";
+/// Expected GRANDPA pallet runtime type name.
+pub const DEFAULT_GRANDPA_PALLET_NAME: &str = "Grandpa";
 
 /// The Aura ID used by the Aura consensus
 #[derive(PartialEq)]
@@ -39,11 +41,13 @@ pub enum AuraConsensusId {
 	Sr25519,
 }
 
-/// The choice of consensus for the parachain omni-node.
+/// The choice of consensus for the omni-node.
 #[derive(PartialEq)]
 pub enum Consensus {
-	/// Aura consensus.
+	/// Aura consensus, for a parachain.
 	Aura(AuraConsensusId),
+	/// Aura block authoring and GRANDPA finality, for a standalone chain.
+	AuraGrandpa(AuraConsensusId),
 }
 
 /// The choice of block number for the parachain omni-node.
@@ -121,15 +125,7 @@ impl RuntimeResolver for DefaultRuntimeResolver {
 			},
 		};
 
-		if !metadata_inspector.pallet_exists(DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME) {
-			log::warn!(
-				r#"⚠️  The parachain system pallet (https://docs.rs/crate/cumulus-pallet-parachain-system/latest) is
-			   missing from the runtime’s metadata. Please check Omni Node docs for runtime conventions:
-			   https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/reference_docs/omni_node/index.html#runtime-conventions."#
-			);
-		}
-
-		Ok(Runtime::Omni(block_number, Consensus::Aura(AuraConsensusId::Sr25519)))
+		Ok(Runtime::Omni(block_number, metadata_inspector.consensus()))
 	}
 }
 
@@ -144,6 +140,25 @@ impl MetadataInspector {
 		self.0.pallet_by_name(name).is_some()
 	}
 
+	/// Runtimes without the parachain system pallet but with the GRANDPA pallet are standalone
+	/// chains, everything else is run as a parachain.
+	fn consensus(&self) -> Consensus {
+		if !self.pallet_exists(DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME) {
+			if self.pallet_exists(DEFAULT_GRANDPA_PALLET_NAME) {
+				log::info!("The runtime contains the GRANDPA pallet but no parachain system pallet, running it as a standalone chain.");
+				return Consensus::AuraGrandpa(AuraConsensusId::Sr25519)
+			}
+
+			log::warn!(
+				r#"⚠️  The parachain system pallet (https://docs.rs/crate/cumulus-pallet-parachain-system/latest) is
+			   missing from the runtime’s metadata. Please check Omni Node docs for runtime conventions:
+			   https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/reference_docs/omni_node/index.html#runtime-conventions."#
+			);
+		}
+
+		Consensus::Aura(AuraConsensusId::Sr25519)
+	}
+
 	fn block_number(&self) -> Option<BlockNumber> {
 		let pallet_metadata = self.0.pallet_by_name(DEFAULT_FRAME_SYSTEM_PALLET_NAME);
 		pallet_metadata
@@ -178,7 +193,8 @@ impl MetadataInspector {
 #[cfg(test)]
 mod tests {
 	use crate::runtime::{
-		BlockNumber, MetadataInspector, DEFAULT_FRAME_SYSTEM_PALLET_NAME,
+		AuraConsensusId, BlockNumber, Consensus, MetadataInspector,
+		DEFAULT_FRAME_SYSTEM_PALLET_NAME, DEFAULT_GRANDPA_PALLET_NAME,
 		DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME,
 	};
 	use codec::Decode;
@@ -186,23 +202,28 @@ mod tests {
 	use sc_executor::WasmExecutor;
 	use sc_runtime_utilities::fetch_latest_metadata_from_code_blob;
 
-	fn cumulus_test_runtime_metadata() -> subxt_metadata::Metadata {
+	fn runtime_metadata(code: &[u8]) -> subxt_metadata::Metadata {
 		let opaque_metadata = fetch_latest_metadata_from_code_blob(
 			&WasmExecutor::<ParachainHostFunctions>::builder()
 				.with_allow_missing_host_functions(true)
 				.build(),
-			sp_runtime::Cow::Borrowed(cumulus_test_runtime::WASM_BINARY.unwrap()),
+			sp_runtime::Cow::Borrowed(code),
 		)
 		.unwrap();
 
 		subxt_metadata::Metadata::decode(&mut (*opaque_metadata).as_slice()).unwrap()
 	}
 
+	fn cumulus_test_runtime_metadata() -> subxt_metadata::Metadata {
+		runtime_metadata(cumulus_test_runtime::WASM_BINARY.unwrap())
+	}
+
 	#[test]
 	fn test_pallet_exists() {
 		let metadata_inspector = MetadataInspector(cumulus_test_runtime_metadata());
 		assert!(metadata_inspector.pallet_exists(DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME));
 		assert!(metadata_inspector.pallet_exists(DEFAULT_FRAME_SYSTEM_PALLET_NAME));
+		assert!(!metadata_inspector.pallet_exists(DEFAULT_GRANDPA_PALLET_NAME));
 	}
 
 	#[test]
@@ -210,4 +231,16 @@ mod tests {
 		let metadata_inspector = MetadataInspector(cumulus_test_runtime_metadata());
 		assert_eq!(metadata_inspector.block_number().unwrap(), BlockNumber::U32);
 	}
+
+	#[test]
+	fn test_runtime_consensus() {
+		let parachain = MetadataInspector(cumulus_test_runtime_metadata());
+		assert!(parachain.consensus() == Consensus::Aura(AuraConsensusId::Sr25519));
+
+		let solochain =
+			MetadataInspector(runtime_metadata(solochain_template_runtime::WASM_BINARY.unwrap()));
+		assert!(!solochain.pallet_exists(DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME));
+		assert!(solochain.pallet_exists(DEFAULT_GRANDPA_PALLET_NAME));
+		assert!(solochain.consensus() == Consensus::AuraGrandpa(AuraConsensusId::Sr25519));
+	}
 }
@@ -24,6 +24,10 @@ use crate::common::{
 	ConstructNodeRuntimeApi, NodeBlock, NodeExtraArgs,
 };
 use cumulus_client_cli::CollatorOptions;
+use cumulus_client_consensus_aura::collators::slot_based::{
+	rpc::{SlotBasedCollator, SlotBasedCollatorApiServer},
+	DecisionLog, DEFAULT_DECISION_LOG_CAPACITY,
+};
 use cumulus_client_service::{
 	build_network, build_relay_chain_interface, prepare_node_config, start_relay_chain_tasks,
 	BuildNetworkParams, CollatorSybilResistance, DARecoveryProfile, StartRelayChainTasksParams,
@@ -83,6 +87,7 @@ where
 		backend: Arc<ParachainBackend<Block>>,
 		node_extra_args: NodeExtraArgs,
 		block_import_extra_return_value: BIAuxiliaryData,
+		slot_based_decision_log: Option<DecisionLog<Block>>,
 	) -> Result<(), sc_service::Error>;
 }
 
@@ -327,17 +332,31 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 				);
 			}
 
+			let slot_based_decision_log = (validator && node_extra_args.use_slot_based_consensus)
+				.then(|| {
+					DecisionLog::new(DEFAULT_DECISION_LOG_CAPACITY, prometheus_registry.as_ref())
+				});
+
 			let rpc_builder = {
 				let client = client.clone();
 				let transaction_pool = transaction_pool.clone();
 				let backend_for_rpc = backend.clone();
+				let decision_log = slot_based_decision_log.clone();
 
 				Box::new(move |_| {
-					Self::BuildRpcExtensions::build_rpc_extensions(
+					let mut module = Self::BuildRpcExtensions::build_rpc_extensions(
 						client.clone(),
 						backend_for_rpc.clone(),
 						transaction_pool.clone(),
-					)
+					)?;
+
+					if let Some(decision_log) = decision_log.clone() {
+						module
+							.merge(SlotBasedCollator::new(decision_log).into_rpc())
+							.map_err(|e| sc_service::Error::Application(Box::new(e)))?;
+					}
+
+					Ok(module)
 				})
 			};
 
@@ -418,6 +437,7 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 					backend.clone(),
 					node_extra_args,
 					block_import_auxiliary_data,
+					slot_based_decision_log,
 				)?;
 			}
 
@@ -78,6 +78,33 @@ macro_rules! impl_node_runtime_apis {
 				}
 			}
 
+			impl sp_consensus_grandpa::GrandpaApi<$block> for $runtime {
+				fn grandpa_authorities() -> sp_consensus_grandpa::AuthorityList {
+					unimplemented!()
+				}
+
+				fn current_set_id() -> sp_consensus_grandpa::SetId {
+					unimplemented!()
+				}
+
+				fn submit_report_equivocation_unsigned_extrinsic(
+					_: sp_consensus_grandpa::EquivocationProof<
+						<$block as BlockT>::Hash,
+						sp_runtime::traits::NumberFor<$block>,
+					>,
+					_: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+				) -> Option<()> {
+					unimplemented!()
+				}
+
+				fn generate_key_ownership_proof(
+					_: sp_consensus_grandpa::SetId,
+					_: sp_consensus_grandpa::AuthorityId,
+				) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
+					unimplemented!()
+				}
+			}
+
 			impl sp_block_builder::BlockBuilder<$block> for $runtime {
 				fn apply_extrinsic(_: <$block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
 					unimplemented!()
@@ -40,7 +40,7 @@ use cumulus_client_consensus_aura::collators::slot_based::{
 use cumulus_client_consensus_aura::{
 	collators::{
 		lookahead::{self as aura, Params as AuraParams},
-		slot_based::{SlotBasedBlockImport, SlotBasedBlockImportHandle},
+		slot_based::{DecisionLog, SlotBasedBlockImport, SlotBasedBlockImportHandle},
 	},
 	equivocation_import_queue::Verifier as EquivocationVerifier,
 };
@@ -323,6 +323,7 @@ where
 		backend: Arc<ParachainBackend<Block>>,
 		node_extra_args: NodeExtraArgs,
 		block_import_handle: SlotBasedBlockImportHandle<Block>,
+		decision_log: Option<DecisionLog<Block>>,
 	) -> Result<(), Error> {
 		let proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
 			task_manager.spawn_handle(),
@@ -361,6 +362,8 @@ where
 			block_import_handle,
 			spawner: task_manager.spawn_handle(),
 			export_pov: node_extra_args.export_pov,
+			decision_log,
+			relay_parent_lookahead: node_extra_args.relay_parent_lookahead,
 		};
 
 		// We have a separate function only to be able to use `docify::export` on this piece of
@@ -446,6 +449,7 @@ where
 		backend: Arc<ParachainBackend<Block>>,
 		node_extra_args: NodeExtraArgs,
 		_: (),
+		_: Option<DecisionLog<Block>>,
 	) -> Result<(), Error> {
 		let proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
 			task_manager.spawn_handle(),
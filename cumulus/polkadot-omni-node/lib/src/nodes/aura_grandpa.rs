@@ -0,0 +1,591 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Node for standalone chains (solochains) using Aura for block authoring and GRANDPA for
+//! finality.
+//!
+//! Only a runtime and a chain spec are needed to run such a chain: the node sets up the keystore,
+//! the Aura authoring task, the GRANDPA voter and the RPC server on its own.
+
+use crate::{
+	common::{
+		aura::{AuraIdT, AuraRuntimeApi},
+		command::{AsyncCmdResult, NodeCommandRunner, SyncCmdResult},
+		rpc::{BuildParachainRpcExtensions, BuildRpcExtensions},
+		types::{
+			AccountId, Balance, Hash, Nonce, ParachainBackend, ParachainClient,
+			ParachainHostFunctions,
+		},
+		ConstructNodeRuntimeApi, NodeBlock,
+	},
+	nodes::DynSolochainNodeSpec,
+};
+use cumulus_client_cli::ExportGenesisHeadCommand;
+use frame_benchmarking_cli::BlockCmd;
+#[cfg(any(feature = "runtime-benchmarks"))]
+use frame_benchmarking_cli::StorageCmd;
+use futures::FutureExt;
+use sc_cli::{CheckBlockCmd, ExportBlocksCmd, ExportStateCmd, ImportBlocksCmd, RevertCmd};
+use sc_client_api::{Backend, BlockBackend};
+use sc_consensus::{DefaultImportQueue, LongestChain};
+use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
+use sc_consensus_grandpa::{FinalityProofProvider, GrandpaBlockImport, LinkHalf, SharedVoterState};
+use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
+use sc_executor::{HeapAllocStrategy, DEFAULT_HEAP_ALLOC_STRATEGY};
+use sc_network::{config::FullNetworkConfiguration, NetworkBackend};
+use sc_service::{Configuration, PartialComponents, TaskManager, WarpSyncConfig};
+use sc_sysinfo::HwBench;
+use sc_telemetry::{Telemetry, TelemetryWorker};
+use sc_transaction_pool::TransactionPoolHandle;
+use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_consensus_aura::SlotDuration;
+use sp_runtime::{app_crypto::AppCrypto, traits::Zero};
+use std::{future::Future, marker::PhantomData, pin::Pin, sync::Arc, time::Duration};
+
+/// The minimum period of blocks on which justifications will be imported and generated.
+const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
+
+type SelectChain<Block> = LongestChain<ParachainBackend<Block>, Block>;
+
+type SolochainBlockImport<Block, RuntimeApi> = GrandpaBlockImport<
+	ParachainBackend<Block>,
+	Block,
+	ParachainClient<Block, RuntimeApi>,
+	SelectChain<Block>,
+>;
+
+type SolochainService<Block, RuntimeApi> = PartialComponents<
+	ParachainClient<Block, RuntimeApi>,
+	ParachainBackend<Block>,
+	SelectChain<Block>,
+	DefaultImportQueue<Block>,
+	TransactionPoolHandle<Block, ParachainClient<Block, RuntimeApi>>,
+	(
+		SolochainBlockImport<Block, RuntimeApi>,
+		LinkHalf<Block, ParachainClient<Block, RuntimeApi>, SelectChain<Block>>,
+		Option<Telemetry>,
+	),
+>;
+
+/// Inherent data providers for an Aura authored block.
+fn aura_inherent_data_providers(
+	slot_duration: SlotDuration,
+) -> (sp_consensus_aura::inherents::InherentDataProvider, sp_timestamp::InherentDataProvider) {
+	let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+	let slot = sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+		*timestamp,
+		slot_duration,
+	);
+
+	(slot, timestamp)
+}
+
+/// Start a standalone node with Aura block authoring and GRANDPA finality.
+pub(crate) struct AuraGrandpaNode<Block, RuntimeApi, AuraId>(
+	PhantomData<(Block, RuntimeApi, AuraId)>,
+);
+
+impl<Block, RuntimeApi, AuraId> Default for AuraGrandpaNode<Block, RuntimeApi, AuraId> {
+	fn default() -> Self {
+		Self(Default::default())
+	}
+}
+
+impl<Block, RuntimeApi, AuraId> AuraGrandpaNode<Block, RuntimeApi, AuraId>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>
+		+ sp_consensus_grandpa::GrandpaApi<Block>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	AuraId: AuraIdT + Sync,
+{
+	/// Build the components of the node, with the GRANDPA block import in front of the client.
+	///
+	/// The chain operation commands use the same components as the full node, so that blocks
+	/// imported by `import-blocks` go through the GRANDPA block import as well.
+	fn new_solochain_partial(
+		config: &Configuration,
+	) -> sc_service::error::Result<SolochainService<Block, RuntimeApi>> {
+		let telemetry = config
+			.telemetry_endpoints
+			.clone()
+			.filter(|x| !x.is_empty())
+			.map(|endpoints| -> Result<_, sc_telemetry::Error> {
+				let worker = TelemetryWorker::new(16)?;
+				let telemetry = worker.handle().new_telemetry(endpoints);
+				Ok((worker, telemetry))
+			})
+			.transpose()?;
+
+		let heap_pages =
+			config.executor.default_heap_pages.map_or(DEFAULT_HEAP_ALLOC_STRATEGY, |h| {
+				HeapAllocStrategy::Static { extra_pages: h as _ }
+			});
+
+		let executor = sc_executor::WasmExecutor::<ParachainHostFunctions>::builder()
+			.with_execution_method(config.executor.wasm_method)
+			.with_max_runtime_instances(config.executor.max_runtime_instances)
+			.with_runtime_cache_size(config.executor.runtime_cache_size)
+			.with_onchain_heap_alloc_strategy(heap_pages)
+			.with_offchain_heap_alloc_strategy(heap_pages)
+			.build();
+
+		let (client, backend, keystore_container, task_manager) =
+			sc_service::new_full_parts::<Block, RuntimeApi, _>(
+				config,
+				telemetry.as_ref().map(|(_, telemetry)| telemetry.handle()),
+				executor,
+			)?;
+		let client = Arc::new(client);
+
+		let telemetry = telemetry.map(|(worker, telemetry)| {
+			task_manager.spawn_handle().spawn("telemetry", None, worker.run());
+			telemetry
+		});
+
+		let select_chain = LongestChain::new(backend.clone());
+
+		let transaction_pool = Arc::from(
+			sc_transaction_pool::Builder::new(
+				task_manager.spawn_essential_handle(),
+				client.clone(),
+				config.role.is_authority().into(),
+			)
+			.with_options(config.transaction_pool.clone())
+			.with_prometheus(config.prometheus_registry())
+			.build(),
+		);
+
+		let (grandpa_block_import, grandpa_link) = sc_consensus_grandpa::block_import(
+			client.clone(),
+			GRANDPA_JUSTIFICATION_PERIOD,
+			&client,
+			select_chain.clone(),
+			telemetry.as_ref().map(|x| x.handle()),
+		)?;
+
+		let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
+		let import_queue =
+			sc_consensus_aura::import_queue::<<AuraId as AppCrypto>::Pair, _, _, _, _, _>(
+				ImportQueueParams {
+					block_import: grandpa_block_import.clone(),
+					justification_import: Some(Box::new(grandpa_block_import.clone())),
+					client: client.clone(),
+					create_inherent_data_providers: move |_, _| async move {
+						Ok(aura_inherent_data_providers(slot_duration))
+					},
+					spawner: &task_manager.spawn_essential_handle(),
+					registry: config.prometheus_registry(),
+					check_for_equivocation: Default::default(),
+					telemetry: telemetry.as_ref().map(|x| x.handle()),
+					compatibility_mode: Default::default(),
+				},
+			)?;
+
+		Ok(PartialComponents {
+			client,
+			backend,
+			task_manager,
+			import_queue,
+			keystore_container,
+			select_chain,
+			transaction_pool,
+			other: (grandpa_block_import, grandpa_link, telemetry),
+		})
+	}
+
+	fn start_solochain_node<Net>(
+		config: Configuration,
+		hwbench: Option<HwBench>,
+	) -> sc_service::error::Result<TaskManager>
+	where
+		Net: NetworkBackend<Block, Hash>,
+	{
+		let PartialComponents {
+			client,
+			backend,
+			mut task_manager,
+			import_queue,
+			keystore_container,
+			select_chain,
+			transaction_pool,
+			other: (block_import, grandpa_link, mut telemetry),
+		} = Self::new_solochain_partial(&config)?;
+
+		let mut net_config = FullNetworkConfiguration::<_, _, Net>::new(
+			&config.network,
+			config.prometheus_registry().cloned(),
+		);
+		let metrics = Net::register_notification_metrics(config.prometheus_registry());
+
+		let peer_store_handle = net_config.peer_store_handle();
+		let grandpa_protocol_name = sc_consensus_grandpa::protocol_standard_name(
+			&client
+				.block_hash(Zero::zero())
+				.ok()
+				.flatten()
+				.expect("Genesis block exists; qed"),
+			&config.chain_spec,
+		);
+		let (grandpa_protocol_config, grandpa_notification_service) =
+			sc_consensus_grandpa::grandpa_peers_set_config::<_, Net>(
+				grandpa_protocol_name.clone(),
+				metrics.clone(),
+				peer_store_handle,
+			);
+		net_config.add_notification_protocol(grandpa_protocol_config);
+
+		let warp_sync = Arc::new(sc_consensus_grandpa::warp_proof::NetworkProvider::new(
+			backend.clone(),
+			grandpa_link.shared_authority_set().clone(),
+			Vec::default(),
+		));
+
+		let (network, system_rpc_tx, tx_handler_controller, sync_service) =
+			sc_service::build_network(sc_service::BuildNetworkParams {
+				config: &config,
+				net_config,
+				client: client.clone(),
+				transaction_pool: transaction_pool.clone(),
+				spawn_handle: task_manager.spawn_handle(),
+				import_queue,
+				block_announce_validator_builder: None,
+				warp_sync_config: Some(WarpSyncConfig::WithProvider(warp_sync)),
+				block_relay: None,
+				metrics,
+			})?;
+
+		if config.offchain_worker.enabled {
+			let offchain_workers =
+				sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
+					runtime_api_provider: client.clone(),
+					keystore: Some(keystore_container.keystore()),
+					offchain_db: backend.offchain_storage(),
+					transaction_pool: Some(OffchainTransactionPoolFactory::new(
+						transaction_pool.clone(),
+					)),
+					network_provider: Arc::new(network.clone()),
+					is_validator: config.role.is_authority(),
+					enable_http_requests: false,
+					custom_extensions: move |_| vec![],
+				})?;
+			task_manager.spawn_handle().spawn(
+				"offchain-workers-runner",
+				"offchain-work",
+				offchain_workers.run(client.clone(), task_manager.spawn_handle()).boxed(),
+			);
+		}
+
+		let role = config.role;
+		let force_authoring = config.force_authoring;
+		let name = config.network.node_name.clone();
+		let enable_grandpa = !config.disable_grandpa;
+		let prometheus_registry = config.prometheus_registry().cloned();
+		let shared_voter_state = SharedVoterState::empty();
+
+		let rpc_builder = {
+			let client = client.clone();
+			let transaction_pool = transaction_pool.clone();
+			let backend_for_rpc = backend.clone();
+			let shared_voter_state = shared_voter_state.clone();
+			let shared_authority_set = grandpa_link.shared_authority_set().clone();
+			let justification_stream = grandpa_link.justification_stream();
+
+			Box::new(move |subscription_executor| {
+				let mut module =
+					BuildParachainRpcExtensions::<Block, RuntimeApi>::build_rpc_extensions(
+						client.clone(),
+						backend_for_rpc.clone(),
+						transaction_pool.clone(),
+					)?;
+
+				let finality_proof_provider = FinalityProofProvider::new_for_service(
+					backend_for_rpc.clone(),
+					Some(shared_authority_set.clone()),
+				);
+				module
+					.merge(
+						Grandpa::new(
+							subscription_executor,
+							shared_authority_set.clone(),
+							shared_voter_state.clone(),
+							justification_stream.clone(),
+							finality_proof_provider,
+						)
+						.into_rpc(),
+					)
+					.map_err(|e| sc_service::Error::Application(e.into()))?;
+
+				Ok(module)
+			})
+		};
+
+		sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+			rpc_builder,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			task_manager: &mut task_manager,
+			config,
+			keystore: keystore_container.keystore(),
+			backend,
+			network: network.clone(),
+			sync_service: sync_service.clone(),
+			system_rpc_tx,
+			tx_handler_controller,
+			telemetry: telemetry.as_mut(),
+		})?;
+
+		if let Some(hwbench) = hwbench {
+			sc_sysinfo::print_hwbench(&hwbench);
+
+			if let Some(ref mut telemetry) = telemetry {
+				let telemetry_handle = telemetry.handle();
+				task_manager.spawn_handle().spawn(
+					"telemetry_hwbench",
+					None,
+					sc_sysinfo::initialize_hwbench_telemetry(telemetry_handle, hwbench),
+				);
+			}
+		}
+
+		if role.is_authority() {
+			let proposer_factory = sc_basic_authorship::ProposerFactory::new(
+				task_manager.spawn_handle(),
+				client.clone(),
+				transaction_pool.clone(),
+				prometheus_registry.as_ref(),
+				telemetry.as_ref().map(|x| x.handle()),
+			);
+
+			let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
+
+			let aura = sc_consensus_aura::start_aura::<
+				<AuraId as AppCrypto>::Pair,
+				_,
+				_,
+				_,
+				_,
+				_,
+				_,
+				_,
+				_,
+				_,
+				_,
+			>(StartAuraParams {
+				slot_duration,
+				client,
+				select_chain,
+				block_import,
+				proposer_factory,
+				create_inherent_data_providers: move |_, ()| async move {
+					Ok(aura_inherent_data_providers(slot_duration))
+				},
+				force_authoring,
+				backoff_authoring_blocks: Option::<()>::None,
+				keystore: keystore_container.keystore(),
+				sync_oracle: sync_service.clone(),
+				justification_sync_link: sync_service.clone(),
+				block_proposal_slot_portion: SlotProportion::new(2f32 / 3f32),
+				max_block_proposal_slot_portion: None,
+				telemetry: telemetry.as_ref().map(|x| x.handle()),
+				compatibility_mode: Default::default(),
+			})?;
+
+			// The Aura authoring task is considered essential, i.e. if it fails we take down the
+			// service with it.
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"aura",
+				Some("block-authoring"),
+				aura,
+			);
+		}
+
+		if enable_grandpa {
+			// If the node isn't actively participating in consensus then it doesn't need a
+			// keystore.
+			let keystore = role.is_authority().then(|| keystore_container.keystore());
+
+			let grandpa_config = sc_consensus_grandpa::Config {
+				gossip_duration: Duration::from_millis(333),
+				justification_generation_period: GRANDPA_JUSTIFICATION_PERIOD,
+				name: Some(name),
+				observer_enabled: false,
+				keystore,
+				local_role: role,
+				telemetry: telemetry.as_ref().map(|x| x.handle()),
+				protocol_name: grandpa_protocol_name,
+			};
+
+			let grandpa_params = sc_consensus_grandpa::GrandpaParams {
+				config: grandpa_config,
+				link: grandpa_link,
+				network,
+				sync: Arc::new(sync_service),
+				notification_service: grandpa_notification_service,
+				voting_rule: sc_consensus_grandpa::VotingRulesBuilder::default().build(),
+				prometheus_registry,
+				shared_voter_state,
+				telemetry: telemetry.as_ref().map(|x| x.handle()),
+				offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(transaction_pool),
+			};
+
+			// The GRANDPA voter task is considered infallible, i.e. if it fails we take down the
+			// service with it.
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"grandpa-voter",
+				None,
+				sc_consensus_grandpa::run_grandpa_voter(grandpa_params)?,
+			);
+		}
+
+		Ok(task_manager)
+	}
+}
+
+impl<Block, RuntimeApi, AuraId> NodeCommandRunner for AuraGrandpaNode<Block, RuntimeApi, AuraId>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>
+		+ sp_consensus_grandpa::GrandpaApi<Block>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	AuraId: AuraIdT + Sync,
+{
+	fn prepare_check_block_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &CheckBlockCmd,
+	) -> AsyncCmdResult<'_> {
+		let partial = Self::new_solochain_partial(&config).map_err(sc_cli::Error::Service)?;
+		Ok((Box::pin(cmd.run(partial.client, partial.import_queue)), partial.task_manager))
+	}
+
+	fn prepare_export_blocks_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ExportBlocksCmd,
+	) -> AsyncCmdResult<'_> {
+		let partial = Self::new_solochain_partial(&config).map_err(sc_cli::Error::Service)?;
+		Ok((Box::pin(cmd.run(partial.client, config.database)), partial.task_manager))
+	}
+
+	fn prepare_export_state_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ExportStateCmd,
+	) -> AsyncCmdResult<'_> {
+		let partial = Self::new_solochain_partial(&config).map_err(sc_cli::Error::Service)?;
+		Ok((Box::pin(cmd.run(partial.client, config.chain_spec)), partial.task_manager))
+	}
+
+	fn prepare_import_blocks_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ImportBlocksCmd,
+	) -> AsyncCmdResult<'_> {
+		let partial = Self::new_solochain_partial(&config).map_err(sc_cli::Error::Service)?;
+		Ok((Box::pin(cmd.run(partial.client, partial.import_queue)), partial.task_manager))
+	}
+
+	fn prepare_revert_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &RevertCmd,
+	) -> AsyncCmdResult<'_> {
+		let partial = Self::new_solochain_partial(&config).map_err(sc_cli::Error::Service)?;
+		let aux_revert = Box::new(|client, _, blocks| {
+			sc_consensus_grandpa::revert(client, blocks)?;
+			Ok(())
+		});
+		Ok((
+			Box::pin(cmd.run(partial.client, partial.backend, Some(aux_revert))),
+			partial.task_manager,
+		))
+	}
+
+	fn run_export_genesis_head_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ExportGenesisHeadCommand,
+	) -> SyncCmdResult {
+		let partial = Self::new_solochain_partial(&config).map_err(sc_cli::Error::Service)?;
+		cmd.run(partial.client)
+	}
+
+	fn run_benchmark_block_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &BlockCmd,
+	) -> SyncCmdResult {
+		let partial = Self::new_solochain_partial(&config).map_err(sc_cli::Error::Service)?;
+		cmd.run(partial.client)
+	}
+
+	#[cfg(any(feature = "runtime-benchmarks"))]
+	fn run_benchmark_storage_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &StorageCmd,
+	) -> SyncCmdResult {
+		let partial = Self::new_solochain_partial(&config).map_err(sc_cli::Error::Service)?;
+		let db = partial.backend.expose_db();
+		let storage = partial.backend.expose_storage();
+
+		cmd.run(config, partial.client, db, storage)
+	}
+}
+
+impl<Block, RuntimeApi, AuraId> DynSolochainNodeSpec for AuraGrandpaNode<Block, RuntimeApi, AuraId>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>
+		+ sp_consensus_grandpa::GrandpaApi<Block>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	AuraId: AuraIdT + Sync,
+{
+	fn start_node(
+		self: Box<Self>,
+		config: Configuration,
+		hwbench: Option<HwBench>,
+	) -> Pin<Box<dyn Future<Output = sc_service::error::Result<TaskManager>>>> {
+		let result = match config.network.network_backend {
+			sc_network::config::NetworkBackendType::Libp2p =>
+				Self::start_solochain_node::<sc_network::NetworkWorker<_, _>>(config, hwbench),
+			sc_network::config::NetworkBackendType::Litep2p =>
+				Self::start_solochain_node::<sc_network::Litep2pNetworkBackend>(config, hwbench),
+		};
+
+		Box::pin(async move { result })
+	}
+}
+
+pub fn new_aura_grandpa_node_spec<Block, RuntimeApi, AuraId>() -> Box<dyn DynSolochainNodeSpec>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>
+		+ sp_consensus_grandpa::GrandpaApi<Block>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	AuraId: AuraIdT + Sync,
+{
+	Box::new(AuraGrandpaNode::<Block, RuntimeApi, AuraId>::default())
+}
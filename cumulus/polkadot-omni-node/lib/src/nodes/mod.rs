@@ -15,12 +15,23 @@
 // limitations under the License.
 
 pub mod aura;
+pub mod aura_grandpa;
 mod manual_seal;
 
-use crate::common::spec::{DynNodeSpec, NodeSpec as NodeSpecT};
+use crate::common::{
+	command::{AsyncCmdResult, NodeCommandRunner, SyncCmdResult},
+	spec::{DynNodeSpec, NodeSpec as NodeSpecT},
+};
+use cumulus_client_cli::ExportGenesisHeadCommand;
 use cumulus_primitives_core::ParaId;
+use frame_benchmarking_cli::BlockCmd;
+#[cfg(any(feature = "runtime-benchmarks"))]
+use frame_benchmarking_cli::StorageCmd;
 use manual_seal::ManualSealNode;
+use sc_cli::{CheckBlockCmd, ExportBlocksCmd, ExportStateCmd, ImportBlocksCmd, RevertCmd};
 use sc_service::{Configuration, TaskManager};
+use sc_sysinfo::HwBench;
+use std::{future::Future, pin::Pin};
 
 /// The current node version for cumulus official binaries, which takes the basic
 /// SemVer form `<major>.<minor>.<patch>`. It should correspond to the latest
@@ -60,3 +71,112 @@ where
 		}
 	}
 }
+
+/// Trait for nodes of standalone chains, i.e. chains that are not parachains and hence don't
+/// need a relay chain.
+pub trait DynSolochainNodeSpec: NodeCommandRunner {
+	fn start_node(
+		self: Box<Self>,
+		config: Configuration,
+		hwbench: Option<HwBench>,
+	) -> Pin<Box<dyn Future<Output = sc_service::error::Result<TaskManager>>>>;
+}
+
+/// The node spec selected for a runtime.
+pub enum OmniNodeSpec {
+	/// The runtime is a parachain runtime.
+	Parachain(Box<dyn DynNodeSpecExt>),
+	/// The runtime is a standalone chain runtime.
+	Solochain(Box<dyn DynSolochainNodeSpec>),
+}
+
+impl NodeCommandRunner for OmniNodeSpec {
+	fn prepare_check_block_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &CheckBlockCmd,
+	) -> AsyncCmdResult<'_> {
+		match *self {
+			Self::Parachain(node) => node.prepare_check_block_cmd(config, cmd),
+			Self::Solochain(node) => node.prepare_check_block_cmd(config, cmd),
+		}
+	}
+
+	fn prepare_export_blocks_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ExportBlocksCmd,
+	) -> AsyncCmdResult<'_> {
+		match *self {
+			Self::Parachain(node) => node.prepare_export_blocks_cmd(config, cmd),
+			Self::Solochain(node) => node.prepare_export_blocks_cmd(config, cmd),
+		}
+	}
+
+	fn prepare_export_state_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ExportStateCmd,
+	) -> AsyncCmdResult<'_> {
+		match *self {
+			Self::Parachain(node) => node.prepare_export_state_cmd(config, cmd),
+			Self::Solochain(node) => node.prepare_export_state_cmd(config, cmd),
+		}
+	}
+
+	fn prepare_import_blocks_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ImportBlocksCmd,
+	) -> AsyncCmdResult<'_> {
+		match *self {
+			Self::Parachain(node) => node.prepare_import_blocks_cmd(config, cmd),
+			Self::Solochain(node) => node.prepare_import_blocks_cmd(config, cmd),
+		}
+	}
+
+	fn prepare_revert_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &RevertCmd,
+	) -> AsyncCmdResult<'_> {
+		match *self {
+			Self::Parachain(node) => node.prepare_revert_cmd(config, cmd),
+			Self::Solochain(node) => node.prepare_revert_cmd(config, cmd),
+		}
+	}
+
+	fn run_export_genesis_head_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ExportGenesisHeadCommand,
+	) -> SyncCmdResult {
+		match *self {
+			Self::Parachain(node) => node.run_export_genesis_head_cmd(config, cmd),
+			Self::Solochain(node) => node.run_export_genesis_head_cmd(config, cmd),
+		}
+	}
+
+	fn run_benchmark_block_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &BlockCmd,
+	) -> SyncCmdResult {
+		match *self {
+			Self::Parachain(node) => node.run_benchmark_block_cmd(config, cmd),
+			Self::Solochain(node) => node.run_benchmark_block_cmd(config, cmd),
+		}
+	}
+
+	#[cfg(any(feature = "runtime-benchmarks"))]
+	fn run_benchmark_storage_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &StorageCmd,
+	) -> SyncCmdResult {
+		match *self {
+			Self::Parachain(node) => node.run_benchmark_storage_cmd(config, cmd),
+			Self::Solochain(node) => node.run_benchmark_storage_cmd(config, cmd),
+		}
+	}
+}
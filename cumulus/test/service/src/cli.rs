@@ -56,6 +56,12 @@ pub struct TestCollatorCli {
 	/// Use with care, this flag is unstable and subject to change.
 	#[arg(long)]
 	pub experimental_use_slot_based: bool,
+
+	/// Place on-demand coretime orders for the parachain, paid by Alice on the relay chain.
+	///
+	/// The value is the maximal spot price that is paid for a core.
+	#[arg(long, value_name = "MAX_SPOT_PRICE")]
+	pub on_demand_orders: Option<u128>,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -56,8 +56,10 @@ use cumulus_client_pov_recovery::{RecoveryDelayRange, RecoveryHandle};
 #[allow(deprecated)]
 use cumulus_client_service::old_consensus;
 use cumulus_client_service::{
-	build_network, prepare_node_config, start_relay_chain_tasks, BuildNetworkParams,
-	CollatorSybilResistance, DARecoveryProfile, StartRelayChainTasksParams,
+	build_network,
+	on_demand::{start_on_demand_orders, OnDemandOrderConfig, StartOnDemandOrdersParams},
+	prepare_node_config, start_relay_chain_tasks, BuildNetworkParams, CollatorSybilResistance,
+	DARecoveryProfile, StartRelayChainTasksParams,
 };
 use cumulus_primitives_core::{relay_chain::ValidationCode, ParaId};
 use cumulus_relay_chain_inprocess_interface::RelayChainInProcessInterface;
@@ -315,6 +317,7 @@ async fn build_relay_chain_interface(
 		relay_chain_node.overseer_handle.ok_or(RelayChainError::GenericError(
 			"Overseer should be running in full node.".to_string(),
 		))?,
+		relay_chain_node.offchain_tx_pool_factory,
 	)))
 }
 
@@ -334,6 +337,7 @@ pub async fn start_node_impl<RB, Net: NetworkBackend<Block, Hash>>(
 	collator_options: CollatorOptions,
 	proof_recording_during_import: bool,
 	use_slot_based_collator: bool,
+	on_demand_orders: Option<OnDemandOrderConfig>,
 ) -> sc_service::error::Result<(
 	TaskManager,
 	Arc<Client>,
@@ -449,6 +453,16 @@ where
 		sync_service: sync_service.clone(),
 	})?;
 
+	if let Some(config) = on_demand_orders {
+		start_on_demand_orders(StartOnDemandOrdersParams {
+			para_id,
+			transaction_pool: transaction_pool.clone(),
+			relay_chain_interface: relay_chain_interface.clone(),
+			task_manager: &task_manager,
+			config,
+		});
+	}
+
 	if let Some(collator_key) = collator_key {
 		if let Consensus::Null = consensus {
 			#[allow(deprecated)]
@@ -507,6 +521,8 @@ where
 					block_import_handle: slot_based_handle,
 					spawner: task_manager.spawn_handle(),
 					export_pov: None,
+					decision_log: None,
+					relay_parent_lookahead: false,
 				};
 
 				slot_based::run::<Block, AuthorityPair, _, _, _, _, _, _, _, _, _>(params);
@@ -587,6 +603,8 @@ pub struct TestNodeBuilder {
 	relay_chain_mode: RelayChainMode,
 	endowed_accounts: Vec<AccountId>,
 	record_proof_during_import: bool,
+	runtime_code: Option<&'static [u8]>,
+	use_slot_based_collator: bool,
 }
 
 impl TestNodeBuilder {
@@ -612,6 +630,8 @@ impl TestNodeBuilder {
 			endowed_accounts: Default::default(),
 			relay_chain_mode: RelayChainMode::Embedded,
 			record_proof_during_import: true,
+			runtime_code: None,
+			use_slot_based_collator: false,
 		}
 	}
 
@@ -730,6 +750,23 @@ impl TestNodeBuilder {
 		self
 	}
 
+	/// Use the given runtime code for the parachain genesis instead of the default test runtime.
+	///
+	/// For example [`cumulus_test_runtime::elastic_scaling::WASM_BINARY`] to build blocks on
+	/// multiple cores.
+	pub fn runtime_code(mut self, code: &'static [u8]) -> TestNodeBuilder {
+		self.runtime_code = Some(code);
+		self
+	}
+
+	/// Author blocks with the slot-based collator instead of the lookahead collator.
+	///
+	/// The slot-based collator builds on all cores assigned to the parachain in the claim queue.
+	pub fn use_slot_based_collator(mut self) -> TestNodeBuilder {
+		self.use_slot_based_collator = true;
+		self
+	}
+
 	/// Build the [`TestNode`].
 	pub async fn build(self) -> TestNode {
 		let parachain_config = node_config(
@@ -741,6 +778,10 @@ impl TestNodeBuilder {
 			self.para_id,
 			self.collator_key.is_some(),
 			self.endowed_accounts,
+			self.runtime_code.unwrap_or_else(|| {
+				cumulus_test_runtime::WASM_BINARY
+					.expect("WASM binary was not built, please build it!")
+			}),
 		)
 		.expect("could not generate Configuration");
 
@@ -772,7 +813,8 @@ impl TestNodeBuilder {
 						self.consensus,
 						collator_options,
 						self.record_proof_during_import,
-						false,
+						self.use_slot_based_collator,
+						None,
 					)
 					.await
 					.expect("could not create Cumulus test service"),
@@ -788,7 +830,8 @@ impl TestNodeBuilder {
 						self.consensus,
 						collator_options,
 						self.record_proof_during_import,
-						false,
+						self.use_slot_based_collator,
+						None,
 					)
 					.await
 					.expect("could not create Cumulus test service"),
@@ -805,7 +848,7 @@ impl TestNodeBuilder {
 /// By default an in-memory socket will be used, therefore you need to provide nodes if you want the
 /// node to be connected to other nodes. If `nodes_exclusive` is `true`, the node will only connect
 /// to the given `nodes` and not to any other node. The `storage_update_func` can be used to make
-/// adjustments to the runtime genesis.
+/// adjustments to the runtime genesis, which is built from the given runtime `code`.
 pub fn node_config(
 	storage_update_func: impl Fn(),
 	tokio_handle: tokio::runtime::Handle,
@@ -815,6 +858,7 @@ pub fn node_config(
 	para_id: ParaId,
 	is_collator: bool,
 	endowed_accounts: Vec<AccountId>,
+	code: &[u8],
 ) -> Result<Configuration, ServiceError> {
 	let base_path = BasePath::new_temp_dir()?;
 	let root = base_path.path().join(format!("cumulus_test_service_{}", key));
@@ -823,7 +867,7 @@ pub fn node_config(
 	let mut spec = Box::new(chain_spec::get_chain_spec_with_extra_endowed(
 		Some(para_id),
 		endowed_accounts,
-		cumulus_test_runtime::WASM_BINARY.expect("WASM binary was not built, please build it!"),
+		code,
 	));
 
 	let mut storage = spec.as_storage_builder().build_storage().expect("could not build storage");
@@ -889,6 +933,7 @@ pub fn node_config(
 			rate_limit: None,
 			rate_limit_whitelisted_ips: Default::default(),
 			rate_limit_trust_proxy_headers: Default::default(),
+			policy_file: None,
 		},
 		prometheus_config: None,
 		telemetry_endpoints: None,
@@ -19,10 +19,14 @@ mod cli;
 use std::sync::Arc;
 
 use cli::{RelayChainCli, Subcommand, TestCollatorCli};
+use cumulus_client_service::on_demand::{
+	OnDemandOrderConfig, OrderPayment, DEFAULT_ON_DEMAND_PALLET,
+};
 use cumulus_primitives_core::relay_chain::CollatorPair;
 use cumulus_test_service::{chain_spec, new_partial, AnnounceBlockFn};
 use sc_cli::{CliConfiguration, SubstrateCli};
 use sp_core::Pair;
+use sp_keyring::Sr25519Keyring;
 
 pub fn wrap_announce_block() -> Box<dyn FnOnce(AnnounceBlockFn) -> AnnounceBlockFn> {
 	tracing::info!("Block announcements disabled.");
@@ -103,6 +107,17 @@ fn main() -> Result<(), sc_cli::Error> {
 				})
 				.unwrap_or(cumulus_test_service::Consensus::Aura);
 
+			let on_demand_orders = cli.on_demand_orders.map(|max_spot_price| {
+				tracing::info!("Placing on-demand orders up to a spot price of {max_spot_price}.");
+				OnDemandOrderConfig {
+					signer: Sr25519Keyring::Alice.pair(),
+					max_spot_price,
+					payment: OrderPayment::KeepAlive,
+					policy: Default::default(),
+					pallet_name: DEFAULT_ON_DEMAND_PALLET.into(),
+				}
+			});
+
 			let (mut task_manager, _, _, _, _, _) = tokio_runtime
 				.block_on(async move {
 					match relay_chain_config.network.network_backend {
@@ -122,6 +137,7 @@ fn main() -> Result<(), sc_cli::Error> {
 								collator_options,
 								true,
 								cli.experimental_use_slot_based,
+								on_demand_orders,
 							)
 							.await,
 						sc_network::config::NetworkBackendType::Litep2p =>
@@ -140,6 +156,7 @@ fn main() -> Result<(), sc_cli::Error> {
 								collator_options,
 								true,
 								cli.experimental_use_slot_based,
+								on_demand_orders,
 							)
 							.await,
 					}
@@ -0,0 +1,151 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use codec::Encode;
+use cumulus_primitives_core::{
+	relay_chain::{AsyncBackingParams, HeadData, ValidationCode},
+	ParaId,
+};
+use cumulus_test_service::{run_relay_chain_validator_node, Keyring::*, TestNodeBuilder};
+use futures::{pin_mut, select, FutureExt, StreamExt};
+use sc_client_api::BlockchainEvents;
+use sp_blockchain::HeaderBackend;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_runtime::traits::Header as _;
+
+const PARA_ID: u32 = 2100;
+
+/// Prepares the relay chain genesis for a parachain building on two cores.
+///
+/// Every core is backed by a group of a single validator. One core is assigned to the parachain
+/// here, the second one when the parachain gets registered.
+fn relay_chain_genesis() {
+	use polkadot_runtime_parachains::{
+		assigner_coretime, configuration::ActiveConfig, paras::AssignCoretime,
+	};
+	use polkadot_test_runtime::Runtime;
+
+	ActiveConfig::<Runtime>::mutate(|config| {
+		config.minimum_backing_votes = 1;
+		config.async_backing_params =
+			AsyncBackingParams { max_candidate_depth: 6, allowed_ancestry_len: 2 };
+		config.scheduler_params.lookahead = 3;
+	});
+
+	assigner_coretime::Pallet::<Runtime>::assign_coretime(PARA_ID.into())
+		.expect("Assigning a core at genesis works");
+}
+
+/// Makes Charlie the only Aura authority of the parachain, so that it authors every slot.
+fn parachain_genesis() {
+	let authorities: Vec<AuraId> = vec![Charlie.public().into()];
+	for pallet in ["Aura", "AuraExt"] {
+		let key =
+			[sp_core::twox_128(pallet.as_bytes()), sp_core::twox_128(b"Authorities")].concat();
+		sp_io::storage::set(&key, &authorities.encode());
+	}
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn elastic_scaling_builds_on_all_assigned_cores() {
+	let mut builder = sc_cli::LoggerBuilder::new("");
+	builder.with_colors(false);
+	let _ = builder.init();
+
+	let tokio_handle = tokio::runtime::Handle::current();
+	let para_id = ParaId::from(PARA_ID);
+
+	let mut alice = run_relay_chain_validator_node(
+		tokio_handle.clone(),
+		Alice,
+		relay_chain_genesis,
+		Vec::new(),
+		None,
+	);
+	let mut bob = run_relay_chain_validator_node(
+		tokio_handle.clone(),
+		Bob,
+		relay_chain_genesis,
+		vec![alice.addr.clone()],
+		None,
+	);
+
+	let code = cumulus_test_runtime::elastic_scaling::WASM_BINARY
+		.expect("WASM binary was not built, please build it!");
+	let mut charlie = TestNodeBuilder::new(para_id, tokio_handle, Charlie)
+		.enable_collator()
+		.use_slot_based_collator()
+		.runtime_code(code)
+		.update_storage_parachain(parachain_genesis)
+		.update_storage_relay_chain(relay_chain_genesis)
+		.connect_to_relay_chain_nodes(vec![&alice, &bob])
+		.build()
+		.await;
+
+	let genesis_head = charlie
+		.client
+		.header(charlie.client.info().genesis_hash)
+		.expect("Genesis header is available")
+		.expect("Genesis header exists");
+	alice
+		.register_parachain(para_id, ValidationCode(code.to_vec()), HeadData(genesis_head.encode()))
+		.await
+		.expect("Registers the parachain");
+
+	let para_client = charlie.client.clone();
+	let relay_client = alice.client.clone();
+	let blocks = async move {
+		let mut finalized = para_client.finality_notification_stream();
+		let mut start = None;
+
+		while let Some(notification) = finalized.next().await {
+			let para_number = *notification.header.number();
+			let relay_number = relay_client.info().finalized_number;
+
+			// Give block production a few blocks to settle before measuring.
+			let Some((para_start, relay_start)) = start else {
+				if para_number >= 5 {
+					start = Some((para_number, relay_number));
+				}
+				continue
+			};
+
+			if para_number >= para_start + 20 {
+				return (para_number - para_start, relay_number - relay_start)
+			}
+		}
+
+		panic!("Finality notification stream ended")
+	}
+	.fuse();
+	let alice_failed = alice.task_manager.future().fuse();
+	let bob_failed = bob.task_manager.future().fuse();
+	let charlie_failed = charlie.task_manager.future().fuse();
+	pin_mut!(blocks, alice_failed, bob_failed, charlie_failed);
+
+	let (para_blocks, relay_blocks) = select! {
+		blocks = blocks => blocks,
+		_ = alice_failed => panic!("service Alice failed"),
+		_ = bob_failed => panic!("service Bob failed"),
+		_ = charlie_failed => panic!("service Charlie failed"),
+	};
+
+	// With a single core at most one parachain block gets included per relay chain block.
+	assert!(
+		u64::from(para_blocks) * 2 > u64::from(relay_blocks) * 3,
+		"{para_blocks} parachain blocks were finalized in {relay_blocks} relay chain blocks",
+	);
+}
@@ -16,6 +16,8 @@
 
 extern crate alloc;
 
+pub mod tracer;
+
 pub use array_bytes;
 pub use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
 pub use log;
@@ -543,6 +545,9 @@ macro_rules! __impl_test_ext_for_relay_chain {
 				$local_ext.with(|v| {
 					v.borrow_mut().execute_with(|| {
 						use $crate::polkadot_primitives::runtime_api::runtime_decl_for_parachain_host::$api_version;
+						use $crate::tracer::{Endpoint, Hop, HopKind};
+
+						let sent_events = $crate::tracer::traced_events::<Self>(&Self::events());
 
 						//TODO: mark sent count & filter out sent msg
 						for para_id in <$network>::para_ids() {
@@ -553,6 +558,13 @@ macro_rules! __impl_test_ext_for_relay_chain {
 							if downward_messages.len() == 0 {
 								continue;
 							}
+							let downward_messages = downward_messages.collect::<Vec<_>>();
+							for (_, msg) in &downward_messages {
+								$crate::tracer::note_sent::<$network>(
+									Hop::new(HopKind::Dmp, Endpoint::Relay, Endpoint::Para(para_id), msg.clone())
+										.with_sent_events(&sent_events),
+								);
+							}
 							<$network>::send_downward_messages(para_id, downward_messages.into_iter());
 
 							// Note: no need to handle horizontal messages, as the
@@ -858,14 +870,27 @@ macro_rules! __impl_test_ext_for_parachain {
 
 						let collation_info = <Self as Parachain>::ParachainSystem::collect_collation_info(&mock_header);
 
+						use $crate::tracer::{Endpoint, Hop, HopKind};
+						let sent_events = $crate::tracer::traced_events::<Self>(&<Self as $crate::Chain>::events());
+
 						// send upward messages
 						let relay_block_number = <$network>::relay_block_number();
 						for msg in collation_info.upward_messages.clone() {
+							$crate::tracer::note_sent::<$network>(
+								Hop::new(HopKind::Ump, Endpoint::Para(para_id), Endpoint::Relay, msg.clone())
+									.with_sent_events(&sent_events),
+							);
 							<$network>::send_upward_message(para_id, msg);
 						}
 
 						// send horizontal messages
 						for msg in collation_info.horizontal_messages {
+							for xcm in $crate::tracer::split_xcmp_messages(&msg.data) {
+								$crate::tracer::note_sent::<$network>(
+									Hop::new(HopKind::Hrmp, Endpoint::Para(para_id), Endpoint::Para(msg.recipient.into()), xcm)
+										.with_sent_events(&sent_events),
+								);
+							}
 							<$network>::send_horizontal_messages(
 								msg.recipient.into(),
 								vec![(para_id.into(), relay_block_number, msg.data)].into_iter(),
@@ -947,6 +972,7 @@ macro_rules! decl_test_networks {
 					$crate::HORIZONTAL_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
 					$crate::BRIDGED_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
 					$crate::LAST_HEAD.with(|b| b.borrow_mut().remove(Self::name()));
+					$crate::tracer::reset::<Self>();
 
 					<$relay_chain<Self>>::reset_ext();
 					$( <$parachain<Self>>::reset_ext(); )*
@@ -966,6 +992,14 @@ macro_rules! decl_test_networks {
 
 						<$relay_chain<Self> as $crate::RelayChain>::init();
 						$( <$parachain<Self> as $crate::Parachain>::init(); )*
+
+						$crate::tracer::register_chain::<Self>($crate::tracer::Endpoint::Relay, stringify!($relay_chain));
+						$(
+							$crate::tracer::register_chain::<Self>(
+								$crate::tracer::Endpoint::Para(<$parachain<Self> as $crate::Parachain>::para_id().into()),
+								stringify!($parachain),
+							);
+						)*
 					}
 				}
 
@@ -1004,7 +1038,7 @@ macro_rules! decl_test_networks {
 				}
 
 				fn process_downward_messages() {
-					use $crate::{DmpMessageHandler, Bounded, Parachain, RelayChainBlockNumber, TestExt, Encode};
+					use $crate::{DmpMessageHandler, Bounded, Parachain, RelayChainBlockNumber, TestExt};
 
 					while let Some((to_para_id, messages))
 						= $crate::DOWNWARD_MESSAGES.with(|b| b.borrow_mut().get_mut(Self::name()).unwrap().pop_front()) {
@@ -1028,14 +1062,23 @@ macro_rules! decl_test_networks {
 								use $crate::{ProcessMessage, CumulusAggregateMessageOrigin, BoundedSlice, WeightMeter};
 								for (block, msg) in msgs.clone().into_iter() {
 									let mut weight_meter = WeightMeter::new();
-									<$parachain<Self>>::ext_wrapper(|| {
+									let events = <$parachain<Self>>::ext_wrapper(|| {
+										let before = <$parachain<Self> as $crate::Chain>::events().len();
 										let _ =  <$parachain<Self> as Parachain>::MessageProcessor::process_message(
 											&msg[..],
 											$crate::CumulusAggregateMessageOrigin::Parent.into(),
 											&mut weight_meter,
-											&mut msg.using_encoded($crate::blake2_256),
+											&mut $crate::tracer::message_id(&msg),
 										);
+										$crate::tracer::traced_events::<$parachain<Self>>(&<$parachain<Self> as $crate::Chain>::events()[before..])
 									});
+									$crate::tracer::note_processed::<Self>(
+										$crate::tracer::HopKind::Dmp,
+										$crate::tracer::Endpoint::Relay,
+										$crate::tracer::Endpoint::Para(to_para_id),
+										&msg,
+										events,
+									);
 									let messages = msgs.clone().iter().map(|(block, message)| {
 										(*block, $crate::array_bytes::bytes2hex("0x", message))
 									}).collect::<Vec<_>>();
@@ -1057,11 +1100,24 @@ macro_rules! decl_test_networks {
 							let para_id: u32 = <$parachain<Self>>::para_id().into();
 
 							if $crate::PARA_IDS.with(|b| b.borrow_mut().get_mut(Self::name()).unwrap().contains(&to_para_id)) && para_id == to_para_id {
-								<$parachain<Self>>::ext_wrapper(|| {
+								let events = <$parachain<Self>>::ext_wrapper(|| {
+									let before = <$parachain<Self> as $crate::Chain>::events().len();
 									<$parachain<Self> as Parachain>::XcmpMessageHandler::handle_xcmp_messages(iter.clone(), $crate::Weight::MAX);
 									// Nudge the MQ pallet to process immediately instead of in the next block.
 									let _ =  <$parachain<Self> as Parachain>::MessageProcessor::service_queues($crate::Weight::MAX);
+									$crate::tracer::traced_events::<$parachain<Self>>(&<$parachain<Self> as $crate::Chain>::events()[before..])
 								});
+								for (from_para_id, _, message) in &messages {
+									for xcm in $crate::tracer::split_xcmp_messages(message) {
+										$crate::tracer::note_processed::<Self>(
+											$crate::tracer::HopKind::Hrmp,
+											$crate::tracer::Endpoint::Para((*from_para_id).into()),
+											$crate::tracer::Endpoint::Para(to_para_id),
+											&xcm,
+											events.clone(),
+										);
+									}
+								}
 								let messages = messages.clone().iter().map(|(para_id, relay_block_number, message)| {
 									(*para_id, *relay_block_number, $crate::array_bytes::bytes2hex("0x", message))
 								}).collect::<Vec<_>>();
@@ -1072,18 +1128,27 @@ macro_rules! decl_test_networks {
 				}
 
 				fn process_upward_messages() {
-					use $crate::{Encode, ProcessMessage, TestExt, WeightMeter};
+					use $crate::{ProcessMessage, TestExt, WeightMeter};
 
 					while let Some((from_para_id, msg)) = $crate::UPWARD_MESSAGES.with(|b| b.borrow_mut().get_mut(Self::name()).unwrap().pop_front()) {
 						let mut weight_meter = WeightMeter::new();
-						<$relay_chain<Self>>::ext_wrapper(|| {
+						let events = <$relay_chain<Self>>::ext_wrapper(|| {
+							let before = <$relay_chain<Self> as $crate::Chain>::events().len();
 							let _ =  <$relay_chain<Self> as $crate::RelayChain>::MessageProcessor::process_message(
 								&msg[..],
 								from_para_id.into(),
 								&mut weight_meter,
-								&mut msg.using_encoded($crate::blake2_256),
+								&mut $crate::tracer::message_id(&msg),
 							);
+							$crate::tracer::traced_events::<$relay_chain<Self>>(&<$relay_chain<Self> as $crate::Chain>::events()[before..])
 						});
+						$crate::tracer::note_processed::<Self>(
+							$crate::tracer::HopKind::Ump,
+							$crate::tracer::Endpoint::Para(from_para_id),
+							$crate::tracer::Endpoint::Relay,
+							&msg,
+							events,
+						);
 						let message = $crate::array_bytes::bytes2hex("0x", msg.clone());
 						$crate::log::info!(target: concat!("xcm::ump::", stringify!($name)) , "Upward message processed from para_id {:?}: {:?}", &from_para_id, &message);
 					}
@@ -1328,6 +1393,10 @@ macro_rules! assert_expected_events {
 			<$chain as $crate::Chain>::events().iter().for_each(|event| {
 				$crate::log::info!(target: concat!("events::", stringify!($chain)), "{:?}", event);
 			});
+			// Show where the last message travelled, to help locating where value got lost
+			if let Some(journey) = $crate::tracer::last_journey::<<$chain as $crate::Chain>::Network>() {
+				message.push(format!("\n\n{}", journey));
+			}
 			panic!("{}", message.concat())
 		}
 	}
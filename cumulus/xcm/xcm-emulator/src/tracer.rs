@@ -0,0 +1,691 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracing of XCM messages as they travel through an emulated [`Network`].
+//!
+//! Every message moved by the emulator between chains is recorded as a [`Hop`], together with
+//! the events emitted on the sending chain for this message (e.g. delivery fees) and on the
+//! receiving chain while the message was processed (e.g. trapped assets, message queue outcome).
+//! Hops sharing the same message id, which is the `SetTopic` id whenever the message carries one,
+//! form an [`XcmJourney`] that can be inspected by tests or pretty-printed when an assertion fails.
+
+use crate::{blake2_256, Chain, Network, ProcessMessageError, RefCell, XcmHash};
+use codec::{Decode, Encode};
+use cumulus_primitives_core::XcmpMessageFormat;
+use std::{collections::HashMap, fmt};
+use xcm::{latest::prelude::*, VersionedAssets, VersionedXcm};
+
+const LOG_TARGET: &str = "xcm::emulator::tracer";
+
+thread_local! {
+	/// Recorded hops of every `Network`, in the order they were sent.
+	pub static XCM_TRACES: RefCell<HashMap<String, Vec<Hop>>> = RefCell::new(HashMap::new());
+	/// Human readable names of the chains of every `Network`.
+	pub static CHAIN_NAMES: RefCell<HashMap<String, HashMap<Endpoint, String>>>
+		= RefCell::new(HashMap::new());
+}
+
+/// The transport used to move a message between two chains.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HopKind {
+	/// Relay chain to parachain.
+	Dmp,
+	/// Parachain to relay chain.
+	Ump,
+	/// Parachain to parachain.
+	Hrmp,
+}
+
+/// One end of a [`Hop`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+	/// The relay chain of the network.
+	Relay,
+	/// A parachain of the network.
+	Para(u32),
+}
+
+/// What happened to a message once it reached its destination.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HopOutcome {
+	/// The message has been sent but not processed yet.
+	Pending,
+	/// The message was executed completely.
+	Complete,
+	/// The execution of the message stopped at some instruction.
+	Incomplete,
+	/// The message was refused before execution, e.g. by the barrier.
+	Rejected(String),
+	/// The message did not fit the available weight and was put aside.
+	Overweight,
+	/// The message was processed but no outcome could be observed.
+	Unknown,
+}
+
+/// The events the tracer needs to follow a message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TracedEventKind {
+	/// The message queue processed the message `id`.
+	Processed { id: XcmHash, success: bool },
+	/// The message queue failed to process the message `id`.
+	ProcessingFailed { id: XcmHash, error: ProcessMessageError },
+	/// The message `id` did not fit the available weight.
+	OverweightEnqueued { id: XcmHash },
+	/// The XCM pallet sent the message `message_id`.
+	Sent { message_id: XcmHash },
+	/// The XCM pallet charged fees for delivering a message.
+	FeesPaid { fees: Assets },
+	/// The XCM pallet trapped assets.
+	AssetsTrapped { assets: VersionedAssets },
+	/// Any other event of the XCM pallet.
+	Xcm,
+	/// Any other event of the message queue.
+	MessageQueue,
+	/// Balances were minted, issued, deposited or burned, e.g. by executing a message.
+	Balances,
+	/// Any other event.
+	Other,
+}
+
+impl TracedEventKind {
+	/// The id of the message the message queue event is about, if any.
+	fn processed_id(&self) -> Option<XcmHash> {
+		match self {
+			Self::Processed { id, .. } |
+			Self::ProcessingFailed { id, .. } |
+			Self::OverweightEnqueued { id } => Some(*id),
+			_ => None,
+		}
+	}
+
+	/// Whether the event is related to sending or executing messages.
+	fn is_xcm_related(&self) -> bool {
+		!matches!(self, Self::Other)
+	}
+}
+
+/// An event emitted on one of the chains of the network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TracedEvent {
+	/// The `Debug` representation of the event, used for display only.
+	pub description: String,
+	/// The event as understood by the tracer.
+	pub kind: TracedEventKind,
+}
+
+/// Converts `events` emitted on the chain `C` for the tracer.
+pub fn traced_events<C>(events: &[C::RuntimeEvent]) -> Vec<TracedEvent>
+where
+	C: Chain,
+	C::Runtime: pallet_balances::Config + pallet_message_queue::Config + pallet_xcm::Config,
+	C::RuntimeEvent: Clone
+		+ fmt::Debug
+		+ TryInto<pallet_balances::Event<C::Runtime>>
+		+ TryInto<pallet_message_queue::Event<C::Runtime>>
+		+ TryInto<pallet_xcm::Event<C::Runtime>>,
+{
+	events
+		.iter()
+		.map(|event| {
+			let message_queue =
+				TryInto::<pallet_message_queue::Event<C::Runtime>>::try_into(event.clone()).ok();
+			let xcm = TryInto::<pallet_xcm::Event<C::Runtime>>::try_into(event.clone()).ok();
+			let balances =
+				TryInto::<pallet_balances::Event<C::Runtime>>::try_into(event.clone()).ok();
+			let kind = match (message_queue, xcm, balances) {
+				(Some(pallet_message_queue::Event::Processed { id, success, .. }), _, _) =>
+					TracedEventKind::Processed { id: id.into(), success },
+				(Some(pallet_message_queue::Event::ProcessingFailed { id, error, .. }), _, _) =>
+					TracedEventKind::ProcessingFailed { id: id.into(), error },
+				(Some(pallet_message_queue::Event::OverweightEnqueued { id, .. }), _, _) =>
+					TracedEventKind::OverweightEnqueued { id },
+				(Some(_), _, _) => TracedEventKind::MessageQueue,
+				(_, Some(pallet_xcm::Event::Sent { message_id, .. }), _) =>
+					TracedEventKind::Sent { message_id },
+				(_, Some(pallet_xcm::Event::FeesPaid { fees, .. }), _) =>
+					TracedEventKind::FeesPaid { fees },
+				(_, Some(pallet_xcm::Event::AssetsTrapped { assets, .. }), _) =>
+					TracedEventKind::AssetsTrapped { assets },
+				(_, Some(_), _) => TracedEventKind::Xcm,
+				(
+					_,
+					_,
+					Some(
+						pallet_balances::Event::Minted { .. } |
+						pallet_balances::Event::Issued { .. } |
+						pallet_balances::Event::Deposit { .. } |
+						pallet_balances::Event::Burned { .. },
+					),
+				) => TracedEventKind::Balances,
+				_ => TracedEventKind::Other,
+			};
+			TracedEvent { description: format!("{event:?}"), kind }
+		})
+		.collect()
+}
+
+/// The id of `message` if it doesn't carry a `SetTopic` id.
+///
+/// This is the id the message queue assigns to a message, the emulator uses the same id when it
+/// hands messages to the message processors directly.
+pub fn message_id(message: &[u8]) -> XcmHash {
+	blake2_256(message)
+}
+
+/// A message moved by the emulator from one chain to another.
+#[derive(Clone, Debug)]
+pub struct Hop {
+	/// Id of the message: its `SetTopic` id if any, otherwise [`message_id`].
+	pub id: XcmHash,
+	/// Transport used for the message.
+	pub kind: HopKind,
+	/// Chain which sent the message.
+	pub from: Endpoint,
+	/// Chain which received the message.
+	pub to: Endpoint,
+	/// The encoded message.
+	pub message: Vec<u8>,
+	/// The instructions of the message, in order.
+	pub instructions: Vec<String>,
+	/// Delivery fees paid on the sending chain.
+	pub delivery_fees: Vec<String>,
+	/// Assets trapped on the receiving chain.
+	pub trapped_assets: Vec<String>,
+	/// XCM related events emitted on the sending chain while the message was sent.
+	pub sent_events: Vec<String>,
+	/// Events emitted on the receiving chain while the message was processed.
+	pub processed_events: Vec<TracedEvent>,
+	/// Outcome of the message on the receiving chain.
+	pub outcome: HopOutcome,
+}
+
+impl Hop {
+	/// Creates a pending hop for `message`, deriving its id and instructions.
+	pub fn new(kind: HopKind, from: Endpoint, to: Endpoint, message: Vec<u8>) -> Self {
+		let decoded = decode_message(&message);
+		let id = decoded
+			.as_ref()
+			.and_then(|xcm| match xcm.last() {
+				Some(SetTopic(topic)) => Some(*topic),
+				_ => None,
+			})
+			.unwrap_or_else(|| message_id(&message));
+		let instructions = decoded
+			.map(|xcm| xcm.0.iter().map(instruction_name).collect())
+			.unwrap_or_else(|| vec!["<undecodable message>".into()]);
+
+		Hop {
+			id,
+			kind,
+			from,
+			to,
+			message,
+			instructions,
+			delivery_fees: Vec::new(),
+			trapped_assets: Vec::new(),
+			sent_events: Vec::new(),
+			processed_events: Vec::new(),
+			outcome: HopOutcome::Pending,
+		}
+	}
+
+	/// Records the events emitted on the sending chain that belong to this message.
+	///
+	/// These are the events following the `Sent` event of the previous message up to the `Sent`
+	/// event of this one, which is where the XCM pallet charges the delivery fees. Nothing is
+	/// recorded if the message was not sent by the XCM pallet.
+	pub fn with_sent_events(mut self, events: &[TracedEvent]) -> Self {
+		let is_sent = |event: &TracedEvent| matches!(event.kind, TracedEventKind::Sent { .. });
+		let Some(end) = events.iter().position(
+			|event| matches!(event.kind, TracedEventKind::Sent { message_id } if message_id == self.id),
+		) else {
+			return self
+		};
+		let start = events[..end].iter().rposition(is_sent).map_or(0, |index| index + 1);
+		let own_events = &events[start..=end];
+
+		self.delivery_fees = own_events
+			.iter()
+			.filter_map(|event| match &event.kind {
+				TracedEventKind::FeesPaid { fees } => Some(format!("{fees:?}")),
+				_ => None,
+			})
+			.collect();
+		self.sent_events = own_events
+			.iter()
+			.filter(|event| event.kind.is_xcm_related())
+			.map(|event| event.description.clone())
+			.collect();
+		self
+	}
+
+	/// Records the events emitted on the receiving chain and derives the outcome from them.
+	///
+	/// When several messages are processed together, the message queue events about the id of
+	/// this hop take precedence to determine its outcome.
+	pub fn note_processed(&mut self, events: Vec<TracedEvent>) {
+		let own_events: Vec<&TracedEvent> = events
+			.iter()
+			.filter(|event| event.kind.processed_id() == Some(self.id))
+			.collect();
+		self.outcome = match outcome_from_events(own_events) {
+			HopOutcome::Unknown => outcome_from_events(&events),
+			outcome => outcome,
+		};
+		self.trapped_assets = events
+			.iter()
+			.filter_map(|event| match &event.kind {
+				TracedEventKind::AssetsTrapped { assets } => Some(format!("{assets:?}")),
+				_ => None,
+			})
+			.collect();
+		self.processed_events = events;
+	}
+
+	/// Whether the message reached its destination and was executed completely.
+	pub fn is_complete(&self) -> bool {
+		self.outcome == HopOutcome::Complete
+	}
+}
+
+/// A node of an [`XcmJourney`].
+#[derive(Clone, Debug)]
+pub struct JourneyNode {
+	pub hop: Hop,
+	/// Hops sent by the destination of `hop` after it was received.
+	pub children: Vec<JourneyNode>,
+}
+
+/// All the hops of a message id, arranged as a tree following the order they were sent in.
+#[derive(Clone, Debug)]
+pub struct XcmJourney {
+	pub id: XcmHash,
+	pub roots: Vec<JourneyNode>,
+	names: HashMap<Endpoint, String>,
+}
+
+impl XcmJourney {
+	/// Builds the journey of `id` out of `hops`, which must be ordered by sending time.
+	///
+	/// A hop is considered to be caused by the latest previous hop that delivered a message to
+	/// its sender.
+	pub fn from_hops(id: XcmHash, hops: &[Hop], names: HashMap<Endpoint, String>) -> Self {
+		let hops: Vec<&Hop> = hops.iter().filter(|h| h.id == id).collect();
+		let parents: Vec<Option<usize>> = hops
+			.iter()
+			.enumerate()
+			.map(|(index, hop)| hops[..index].iter().rposition(|prev| prev.to == hop.from))
+			.collect();
+
+		fn build(index: usize, hops: &[&Hop], parents: &[Option<usize>]) -> JourneyNode {
+			JourneyNode {
+				hop: hops[index].clone(),
+				children: (0..hops.len())
+					.filter(|child| parents[*child] == Some(index))
+					.map(|child| build(child, hops, parents))
+					.collect(),
+			}
+		}
+
+		let roots = (0..hops.len())
+			.filter(|index| parents[*index].is_none())
+			.map(|index| build(index, &hops, &parents))
+			.collect();
+
+		XcmJourney { id, roots, names }
+	}
+
+	/// All hops of the journey, depth first.
+	pub fn hops(&self) -> Vec<&Hop> {
+		fn collect<'a>(node: &'a JourneyNode, hops: &mut Vec<&'a Hop>) {
+			hops.push(&node.hop);
+			node.children.iter().for_each(|child| collect(child, hops));
+		}
+		let mut hops = Vec::new();
+		self.roots.iter().for_each(|root| collect(root, &mut hops));
+		hops
+	}
+
+	/// Whether every hop of the journey was executed completely without trapping assets.
+	pub fn is_complete(&self) -> bool {
+		self.hops().iter().all(|hop| hop.is_complete() && hop.trapped_assets.is_empty())
+	}
+
+	/// The first hop, depth first, that was not executed completely or that trapped assets.
+	pub fn first_failure(&self) -> Option<&Hop> {
+		self.hops()
+			.into_iter()
+			.find(|hop| !hop.is_complete() || !hop.trapped_assets.is_empty())
+	}
+
+	fn name_of(&self, endpoint: &Endpoint) -> String {
+		self.names.get(endpoint).cloned().unwrap_or_else(|| match endpoint {
+			Endpoint::Relay => "Relay".into(),
+			Endpoint::Para(id) => format!("Para({id})"),
+		})
+	}
+
+	fn fmt_node(
+		&self,
+		f: &mut fmt::Formatter<'_>,
+		node: &JourneyNode,
+		depth: usize,
+	) -> fmt::Result {
+		let indent = "  ".repeat(depth);
+		let hop = &node.hop;
+		writeln!(
+			f,
+			"{indent}└─ {:?} {} -> {}: {:?}",
+			hop.kind,
+			self.name_of(&hop.from),
+			self.name_of(&hop.to),
+			hop.outcome
+		)?;
+		writeln!(f, "{indent}   instructions: [{}]", hop.instructions.join(", "))?;
+		for fee in &hop.delivery_fees {
+			writeln!(f, "{indent}   delivery fees: {fee}")?;
+		}
+		if let HopOutcome::Rejected(reason) = &hop.outcome {
+			writeln!(f, "{indent}   rejected: {reason}")?;
+		}
+		for trapped in &hop.trapped_assets {
+			writeln!(f, "{indent}   trapped: {trapped}")?;
+		}
+		for event in hop.processed_events.iter().filter(|e| e.kind.is_xcm_related()) {
+			writeln!(f, "{indent}   event: {}", event.description)?;
+		}
+		node.children.iter().try_for_each(|child| self.fmt_node(f, child, depth + 1))
+	}
+}
+
+impl fmt::Display for XcmJourney {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "XCM journey of {}", array_bytes::bytes2hex("0x", self.id))?;
+		if self.roots.is_empty() {
+			return writeln!(f, "  <no hops recorded>")
+		}
+		self.roots.iter().try_for_each(|root| self.fmt_node(f, root, 0))
+	}
+}
+
+/// Registers the name used to display `endpoint` of the network `N`.
+pub fn register_chain<N: Network>(endpoint: Endpoint, name: &str) {
+	CHAIN_NAMES.with(|b| {
+		b.borrow_mut()
+			.entry(N::name().to_string())
+			.or_default()
+			.insert(endpoint, name.to_string())
+	});
+}
+
+/// Records a message sent on the network `N`.
+///
+/// Messages that were already recorded for the same destination are ignored, as the relay chain
+/// keeps handing out its downward message queue until the messages are processed.
+pub fn note_sent<N: Network>(hop: Hop) {
+	XCM_TRACES.with(|b| {
+		let mut traces = b.borrow_mut();
+		let hops = traces.entry(N::name().to_string()).or_default();
+		if hop.kind == HopKind::Dmp &&
+			hops.iter().any(|h| h.to == hop.to && h.message == hop.message)
+		{
+			return
+		}
+		log::trace!(
+			target: LOG_TARGET,
+			"{:?} message {:?} sent from {:?} to {:?}",
+			hop.kind, hop.id, hop.from, hop.to,
+		);
+		hops.push(hop);
+	});
+}
+
+/// Records the events emitted while `message` was processed by `to` on the network `N`.
+///
+/// If no pending hop was recorded for the message, a new one is created.
+pub fn note_processed<N: Network>(
+	kind: HopKind,
+	from: Endpoint,
+	to: Endpoint,
+	message: &[u8],
+	events: Vec<TracedEvent>,
+) {
+	XCM_TRACES.with(|b| {
+		let mut traces = b.borrow_mut();
+		let hops = traces.entry(N::name().to_string()).or_default();
+		let index = hops
+			.iter()
+			.position(|h| {
+				h.outcome == HopOutcome::Pending && h.to == to && h.message.as_slice() == message
+			})
+			.unwrap_or_else(|| {
+				hops.push(Hop::new(kind, from, to, message.to_vec()));
+				hops.len() - 1
+			});
+		hops[index].note_processed(events);
+		log::trace!(
+			target: LOG_TARGET,
+			"message {:?} processed: {:?}",
+			hops[index].id, hops[index].outcome,
+		);
+	});
+}
+
+/// All hops recorded on the network `N`, in the order they were sent.
+pub fn hops<N: Network>() -> Vec<Hop> {
+	XCM_TRACES.with(|b| b.borrow().get(N::name()).cloned().unwrap_or_default())
+}
+
+/// The journey of the message `id` on the network `N`.
+pub fn journey<N: Network>(id: XcmHash) -> XcmJourney {
+	let names = CHAIN_NAMES.with(|b| b.borrow().get(N::name()).cloned().unwrap_or_default());
+	XcmJourney::from_hops(id, &hops::<N>(), names)
+}
+
+/// The journey of the last message sent on the network `N`, if any.
+pub fn last_journey<N: Network>() -> Option<XcmJourney> {
+	hops::<N>().last().map(|hop| journey::<N>(hop.id))
+}
+
+/// Clears the recorded hops of the network `N`.
+pub fn reset<N: Network>() {
+	XCM_TRACES.with(|b| b.borrow_mut().remove(N::name()));
+}
+
+/// Splits an XCMP blob, as sent over HRMP, into the encoded messages it contains.
+pub fn split_xcmp_messages(data: &[u8]) -> Vec<Vec<u8>> {
+	let mut data = data;
+	match XcmpMessageFormat::decode(&mut data) {
+		Ok(XcmpMessageFormat::ConcatenatedVersionedXcm) => {
+			let mut messages = Vec::new();
+			while !data.is_empty() {
+				let Ok(message) = VersionedXcm::<()>::decode(&mut data) else { break };
+				messages.push(message.encode());
+			}
+			messages
+		},
+		_ => vec![data.to_vec()],
+	}
+}
+
+fn decode_message(message: &[u8]) -> Option<Xcm<()>> {
+	VersionedXcm::<()>::decode(&mut &message[..]).ok()?.try_into().ok()
+}
+
+fn instruction_name(instruction: &Instruction<()>) -> String {
+	let debug = format!("{instruction:?}");
+	debug
+		.split(|c: char| c == ' ' || c == '(' || c == '{')
+		.next()
+		.unwrap_or_default()
+		.to_string()
+}
+
+fn outcome_from_events<'a>(events: impl IntoIterator<Item = &'a TracedEvent>) -> HopOutcome {
+	let outcome = events.into_iter().filter_map(|event| match &event.kind {
+		TracedEventKind::ProcessingFailed { error, .. } => {
+			let reason = match error {
+				ProcessMessageError::Unsupported => "barrier or executor refused the message",
+				ProcessMessageError::Corrupt => "message could not be decoded",
+				_ => "processing failed",
+			};
+			Some(HopOutcome::Rejected(reason.into()))
+		},
+		TracedEventKind::OverweightEnqueued { .. } => Some(HopOutcome::Overweight),
+		TracedEventKind::Processed { success: true, .. } => Some(HopOutcome::Complete),
+		TracedEventKind::Processed { success: false, .. } => Some(HopOutcome::Incomplete),
+		_ => None,
+	});
+	outcome.last().unwrap_or(HopOutcome::Unknown)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn message(topic: XcmHash) -> Vec<u8> {
+		VersionedXcm::<()>::from(Xcm::<()>(vec![ClearOrigin, SetTopic(topic)])).encode()
+	}
+
+	fn event(kind: TracedEventKind) -> TracedEvent {
+		TracedEvent { description: format!("{kind:?}"), kind }
+	}
+
+	fn fees(amount: u128) -> TracedEvent {
+		event(TracedEventKind::FeesPaid { fees: (Here, amount).into() })
+	}
+
+	#[test]
+	fn hops_are_identified_by_topic() {
+		let hop = Hop::new(HopKind::Ump, Endpoint::Para(1000), Endpoint::Relay, message([1; 32]));
+		assert_eq!(hop.id, [1; 32]);
+		assert_eq!(hop.instructions, vec!["ClearOrigin".to_string(), "SetTopic".to_string()]);
+		assert_eq!(hop.outcome, HopOutcome::Pending);
+	}
+
+	#[test]
+	fn hops_without_topic_use_the_message_queue_id() {
+		let message = VersionedXcm::<()>::from(Xcm::<()>(vec![ClearOrigin])).encode();
+		let hop = Hop::new(HopKind::Ump, Endpoint::Para(1000), Endpoint::Relay, message.clone());
+		// The message queue hashes the message itself, not its encoding.
+		assert_eq!(hop.id, blake2_256(&message));
+		assert_eq!(hop.id, message_id(&message));
+	}
+
+	#[test]
+	fn only_xcm_related_events_are_displayed() {
+		let topic = [3; 32];
+		let mut hop = Hop::new(HopKind::Ump, Endpoint::Para(1000), Endpoint::Relay, message(topic));
+		hop.note_processed(vec![
+			TracedEvent { description: "System::XcmLike".into(), kind: TracedEventKind::Other },
+			TracedEvent { description: "Balances::Minted".into(), kind: TracedEventKind::Balances },
+			event(TracedEventKind::Processed { id: topic, success: true }),
+		]);
+
+		let journey = XcmJourney::from_hops(topic, &[hop], Default::default());
+		let displayed = journey.to_string();
+		assert!(displayed.contains("Balances::Minted"));
+		assert!(!displayed.contains("System::XcmLike"));
+	}
+
+	#[test]
+	fn journey_follows_hops_between_chains() {
+		let topic = [7; 32];
+		let mut hops = vec![
+			Hop::new(HopKind::Ump, Endpoint::Para(1000), Endpoint::Relay, message(topic)),
+			Hop::new(HopKind::Dmp, Endpoint::Relay, Endpoint::Para(2000), message(topic)),
+			Hop::new(HopKind::Ump, Endpoint::Para(1000), Endpoint::Relay, message([8; 32])),
+		];
+		hops[0]
+			.note_processed(vec![event(TracedEventKind::Processed { id: topic, success: true })]);
+		hops[1].note_processed(vec![
+			event(TracedEventKind::AssetsTrapped { assets: (Here, 1u128).into() }),
+			event(TracedEventKind::Processed { id: topic, success: true }),
+		]);
+
+		let journey = XcmJourney::from_hops(topic, &hops, Default::default());
+		assert_eq!(journey.roots.len(), 1);
+		assert_eq!(journey.roots[0].children.len(), 1);
+		assert_eq!(journey.hops().len(), 2);
+		assert!(!journey.is_complete());
+		assert_eq!(journey.first_failure().unwrap().to, Endpoint::Para(2000));
+		assert!(journey.to_string().contains("trapped"));
+	}
+
+	#[test]
+	fn rejected_messages_are_reported() {
+		let mut hop =
+			Hop::new(HopKind::Hrmp, Endpoint::Para(1), Endpoint::Para(2), message([0; 32]));
+		hop.note_processed(vec![event(TracedEventKind::ProcessingFailed {
+			id: [0; 32],
+			error: ProcessMessageError::Unsupported,
+		})]);
+		assert!(matches!(hop.outcome, HopOutcome::Rejected(_)));
+	}
+
+	#[test]
+	fn outcome_is_taken_from_events_of_the_message() {
+		let mut hop =
+			Hop::new(HopKind::Hrmp, Endpoint::Para(1), Endpoint::Para(2), message([1; 32]));
+		hop.note_processed(vec![
+			event(TracedEventKind::Processed { id: [1; 32], success: true }),
+			event(TracedEventKind::Processed { id: [2; 32], success: false }),
+		]);
+		assert_eq!(hop.outcome, HopOutcome::Complete);
+
+		// Events without a description matching the outcome are not misinterpreted.
+		let mut hop =
+			Hop::new(HopKind::Hrmp, Endpoint::Para(1), Endpoint::Para(2), message([1; 32]));
+		hop.note_processed(vec![TracedEvent {
+			description: "Processed { success: true }".into(),
+			kind: TracedEventKind::Other,
+		}]);
+		assert_eq!(hop.outcome, HopOutcome::Unknown);
+	}
+
+	#[test]
+	fn fees_are_attributed_to_their_message() {
+		let events = vec![
+			fees(1),
+			event(TracedEventKind::Sent { message_id: [1; 32] }),
+			fees(2),
+			event(TracedEventKind::Sent { message_id: [2; 32] }),
+			fees(3),
+		];
+
+		let first = Hop::new(HopKind::Ump, Endpoint::Para(1), Endpoint::Relay, message([1; 32]))
+			.with_sent_events(&events);
+		assert_eq!(first.delivery_fees, vec![format!("{:?}", Assets::from((Here, 1u128)))]);
+
+		let second = Hop::new(HopKind::Ump, Endpoint::Para(1), Endpoint::Relay, message([2; 32]))
+			.with_sent_events(&events);
+		assert_eq!(second.delivery_fees, vec![format!("{:?}", Assets::from((Here, 2u128)))]);
+
+		// Messages not sent by the XCM pallet get no events attributed.
+		let other = Hop::new(HopKind::Ump, Endpoint::Para(1), Endpoint::Relay, message([3; 32]))
+			.with_sent_events(&events);
+		assert!(other.delivery_fees.is_empty());
+		assert!(other.sent_events.is_empty());
+	}
+
+	#[test]
+	fn xcmp_blobs_are_split_into_messages() {
+		let mut blob = XcmpMessageFormat::ConcatenatedVersionedXcm.encode();
+		blob.extend(message([1; 32]));
+		blob.extend(message([2; 32]));
+		assert_eq!(split_xcmp_messages(&blob), vec![message([1; 32]), message([2; 32])]);
+	}
+}
@@ -56,6 +56,128 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Export the available data and chunks stored for a candidate.
+	ExportAvailability(ExportAvailabilityCmd),
+
+	/// Inspect the disputes recorded by the dispute coordinator.
+	InspectDisputes(InspectDisputesCmd),
+}
+
+/// The `export-availability` command.
+///
+/// Reads the availability store of a stopped node and writes everything stored for a candidate to
+/// a directory.
+#[derive(Debug, Clone, Parser)]
+pub struct ExportAvailabilityCmd {
+	/// The hash of the candidate.
+	#[arg(value_name = "CANDIDATE_HASH")]
+	pub candidate_hash: sp_core::H256,
+
+	/// The directory to write the available data and the chunks to.
+	#[arg(long, value_name = "PATH")]
+	pub output: PathBuf,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for ExportAvailabilityCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// The `inspect-disputes` command.
+///
+/// Lists the disputes in the database of a stopped node, with the votes cast in them, the node's
+/// own vote and the spam slots they occupy. Given the validation code and the PoV of a disputed
+/// candidate, participation in its dispute is re-run locally instead.
+#[derive(Debug, Clone, Parser)]
+pub struct InspectDisputesCmd {
+	/// Only show disputes of this session.
+	#[arg(long, value_name = "SESSION")]
+	pub session: Option<u32>,
+
+	/// Only show the dispute of this candidate.
+	#[arg(long, value_name = "CANDIDATE_HASH")]
+	pub candidate: Option<sp_core::H256>,
+
+	/// Re-run participation for the candidate using the validation code in this file.
+	///
+	/// Requires `--session`, `--candidate` and the candidate's PoV and persisted validation data,
+	/// either from `--available-data` or from `--pov` and `--persisted-validation-data`.
+	#[arg(long, value_name = "PATH", requires_all = ["session", "candidate"])]
+	pub validation_code: Option<PathBuf>,
+
+	/// SCALE-encoded available data of the candidate, as written by `export-availability`.
+	#[arg(
+		long,
+		value_name = "PATH",
+		requires = "validation_code",
+		conflicts_with_all = ["pov", "persisted_validation_data"]
+	)]
+	pub available_data: Option<PathBuf>,
+
+	/// SCALE-encoded PoV of the candidate, e.g. from the PoV archive.
+	#[arg(
+		long,
+		value_name = "PATH",
+		requires_all = ["validation_code", "persisted_validation_data"]
+	)]
+	pub pov: Option<PathBuf>,
+
+	/// SCALE-encoded persisted validation data of the candidate.
+	#[arg(long, value_name = "PATH", requires = "pov")]
+	pub persisted_validation_data: Option<PathBuf>,
+
+	/// SCALE-encoded executor parameters to validate the candidate with.
+	///
+	/// Defaults to the default executor parameters, which might differ from the ones of the
+	/// dispute's session.
+	#[arg(long, value_name = "PATH", requires = "validation_code")]
+	pub executor_params: Option<PathBuf>,
+
+	/// Path to the directory where auxiliary worker binaries reside.
+	///
+	/// See `--workers-path` of the `run` command.
+	#[arg(long, value_name = "PATH")]
+	pub workers_path: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub keystore_params: sc_cli::KeystoreParams,
+}
+
+impl sc_cli::CliConfiguration for InspectDisputesCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+
+	fn keystore_params(&self) -> Option<&sc_cli::KeystoreParams> {
+		Some(&self.keystore_params)
+	}
 }
 
 #[allow(missing_docs)]
@@ -154,10 +276,21 @@ pub struct RunCmd {
 	pub enable_approval_voting_parallel: bool,
 
 	/// How long finalized data should be kept in the availability store (in hours).
-	/// Only used for testnets. If not specified, set to 1 hour. Always set to 25 hours for live
-	/// networks.
+	/// If not specified, set to 1 hour for testnets and 25 hours for live networks. Live networks
+	/// never keep the data for less than 25 hours.
 	#[arg(long)]
 	pub keep_finalized_for: Option<u32>,
+
+	/// Archive the PoVs of the included candidates of the given parachains to
+	/// `--pov-archive-path`.
+	///
+	/// Only PoVs that the node stored in full, e.g. because it backed the candidate, are archived.
+	#[arg(long, value_name = "PARA_ID", requires = "pov_archive_path")]
+	pub archive_povs_of: Vec<u32>,
+
+	/// The directory to archive PoVs to, with one sub-directory per parachain.
+	#[arg(long, value_name = "PATH", requires = "archive_povs_of")]
+	pub pov_archive_path: Option<PathBuf>,
 }
 
 #[allow(missing_docs)]
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::cli::{Cli, Subcommand, NODE_VERSION};
+use crate::cli::{Cli, InspectDisputesCmd, Subcommand, NODE_VERSION};
 use frame_benchmarking_cli::{
 	BenchmarkCmd, ExtrinsicFactory, SubstrateRemarkBuilder, SUBSTRATE_REFERENCE_HARDWARE,
 };
@@ -236,6 +236,17 @@ where
 				prepare_workers_soft_max_num: cli.run.prepare_workers_soft_max_num,
 				enable_approval_voting_parallel: cli.run.enable_approval_voting_parallel,
 				keep_finalized_for: cli.run.keep_finalized_for,
+				pov_archive: cli.run.pov_archive_path.map(|path| {
+					polkadot_service::PovArchiveConfig {
+						path,
+						para_ids: cli
+							.run
+							.archive_povs_of
+							.iter()
+							.map(|&para_id| para_id.into())
+							.collect(),
+					}
+				}),
 			},
 		)
 		.map(|full| full.task_manager)?;
@@ -252,6 +263,117 @@ where
 	})
 }
 
+/// Print the disputes known to the dispute coordinator, for the `inspect-disputes` command.
+fn print_disputes(cmd: &InspectDisputesCmd, config: &sc_service::Configuration) -> Result<()> {
+	use polkadot_service::dispute_inspect;
+
+	let dispute_db = polkadot_service::inspect_disputes(&config.database)?;
+	let own_keys = polkadot_service::local_validator_keys(&config.keystore)?;
+	let disputes = dispute_db.disputes().map_err(polkadot_service::Error::from)?;
+	let in_session = |session: u32| cmd.session.map_or(true, |s| s == session);
+
+	for dispute in &disputes {
+		if !in_session(dispute.session) ||
+			cmd.candidate.map_or(false, |c| c != dispute.candidate_hash.0)
+		{
+			continue
+		}
+
+		println!(
+			"Session {}, candidate {:?}: {:?}",
+			dispute.session, dispute.candidate_hash.0, dispute.status
+		);
+		let Some(votes) = &dispute.votes else {
+			println!("  votes have been pruned");
+			continue
+		};
+		let valid: Vec<_> = votes
+			.valid
+			.raw()
+			.iter()
+			.map(|(index, (kind, _))| format!("{} ({:?})", index.0, kind))
+			.collect();
+		let invalid: Vec<_> = votes
+			.invalid
+			.iter()
+			.map(|(index, (kind, _))| format!("{} ({:?})", index.0, kind))
+			.collect();
+		println!("  valid:   {}", valid.join(", "));
+		println!("  invalid: {}", invalid.join(", "));
+		match dispute.own_vote(&own_keys) {
+			Some((index, statement)) =>
+				println!("  own vote: {:?}, as validator {}", statement, index.0),
+			None => println!("  own vote: none"),
+		}
+	}
+
+	// Spam slots are shared by all disputes of a session, so they are not filtered by candidate.
+	println!(
+		"Spam slots (upper bound, at most {} per validator and session):",
+		dispute_inspect::MAX_SPAM_VOTES
+	);
+	for ((session, validator), count) in dispute_inspect::spam_slot_usage(&disputes) {
+		if in_session(session) {
+			println!("  session {}, validator {}: {}", session, validator.0, count);
+		}
+	}
+
+	Ok(())
+}
+
+/// Re-run participation for a disputed candidate, for the `inspect-disputes` command.
+async fn revalidate_disputed_candidate(
+	cmd: InspectDisputesCmd,
+	database: sc_service::config::DatabaseSource,
+) -> Result<()> {
+	use polkadot_node_primitives::ValidationResult;
+	use polkadot_service::CandidateDataFiles;
+
+	let (Some(session), Some(candidate_hash), Some(validation_code)) =
+		(cmd.session, cmd.candidate, cmd.validation_code)
+	else {
+		return Err(Error::Other(
+			"`--validation-code` requires `--session` and `--candidate`".into(),
+		))
+	};
+	let candidate_data = match (cmd.available_data, cmd.pov, cmd.persisted_validation_data) {
+		(Some(available_data), _, _) => CandidateDataFiles::AvailableData(available_data),
+		(None, Some(pov), Some(persisted_validation_data)) =>
+			CandidateDataFiles::Separate { pov, persisted_validation_data },
+		_ =>
+			return Err(Error::Other(
+				"`--validation-code` requires either `--available-data` or `--pov` and \
+				 `--persisted-validation-data`"
+					.into(),
+			)),
+	};
+
+	let result = polkadot_service::revalidate_disputed_candidate(
+		&database,
+		session,
+		candidate_hash,
+		candidate_data,
+		&validation_code,
+		cmd.executor_params.as_deref(),
+		cmd.workers_path,
+		Some(NODE_VERSION.to_string()),
+	)
+	.await?;
+
+	match result {
+		Some(ValidationResult::Valid(..)) => info!("Candidate {:?} is valid", candidate_hash),
+		Some(ValidationResult::Invalid(reason)) =>
+			info!("Candidate {:?} is invalid: {:?}", candidate_hash, reason),
+		None =>
+			return Err(Error::Other(format!(
+				"No votes for candidate {:?} in session {} in the dispute coordinator's database",
+				candidate_hash, session
+			))),
+	}
+
+	Ok(())
+}
+
 /// Parses polkadot specific CLI arguments and run the service.
 pub fn run() -> Result<()> {
 	let cli: Cli = Cli::from_args();
@@ -451,6 +573,7 @@ pub fn run() -> Result<()> {
 					cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone())
 						.map_err(Error::SubstrateCli)
 				}),
+				BenchmarkCmd::Compare(cmd) => cmd.run().map_err(Error::SubstrateCli),
 				// NOTE: this allows the Polkadot client to leniently implement
 				// new benchmark commands.
 				#[allow(unreachable_patterns)]
@@ -462,6 +585,39 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			Ok(runner.sync_run(|config| cmd.run::<polkadot_service::Block>(&config))?)
 		},
+		Some(Subcommand::ExportAvailability(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			Ok(runner.sync_run(|config| {
+				if polkadot_service::export_availability(
+					&config.database,
+					cmd.candidate_hash,
+					&cmd.output,
+				)? {
+					info!("Exported candidate {:?} to {:?}", cmd.candidate_hash, cmd.output);
+					Ok(())
+				} else {
+					Err(Error::Other(format!(
+						"Candidate {:?} is not in the availability store",
+						cmd.candidate_hash
+					)))
+				}
+			})?)
+		},
+		Some(Subcommand::InspectDisputes(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+
+			if cmd.validation_code.is_some() {
+				let cmd = cmd.clone();
+				Ok(runner.async_run(|config| {
+					let task_manager =
+						sc_service::TaskManager::new(config.tokio_handle.clone(), None)
+							.map_err(sc_service::Error::from)?;
+					Ok((revalidate_disputed_candidate(cmd, config.database), task_manager))
+				})?)
+			} else {
+				Ok(runner.sync_run(|config| print_disputes(cmd, &config))?)
+			}
+		},
 	}?;
 
 	#[cfg(feature = "pyroscope")]
@@ -53,6 +53,26 @@ fn construct_and_reconstruct_5mb_pov(c: &mut Criterion) {
 	}
 	group.finish();
 
+	let mut group = c.benchmark_group("construct_streaming");
+	for n_validators in N_VALIDATORS {
+		let expected_root = erasure_root(n_validators, &pov);
+
+		group.throughput(Throughput::Bytes(pov.len() as u64));
+		group.bench_with_input(
+			BenchmarkId::from_parameter(n_validators),
+			&n_validators,
+			|b, &n| {
+				b.iter(|| {
+					let chunks =
+						polkadot_erasure_coding::streaming::obtain_chunks(n, &pov).unwrap();
+					let root = polkadot_erasure_coding::branches(&chunks).root();
+					assert_eq!(root, expected_root);
+				});
+			},
+		);
+	}
+	group.finish();
+
 	let mut group = c.benchmark_group("reconstruct_regular");
 	for n_validators in N_VALIDATORS {
 		let all_chunks = chunks(n_validators, &pov);
@@ -78,6 +98,32 @@ fn construct_and_reconstruct_5mb_pov(c: &mut Criterion) {
 	}
 	group.finish();
 
+	let mut group = c.benchmark_group("reconstruct_streaming");
+	for n_validators in N_VALIDATORS {
+		let all_chunks = chunks(n_validators, &pov);
+
+		let chunks: Vec<_> = all_chunks
+			.into_iter()
+			.enumerate()
+			.rev()
+			.take(polkadot_erasure_coding::recovery_threshold(n_validators).unwrap())
+			.map(|(i, c)| (c, i))
+			.collect();
+
+		group.throughput(Throughput::Bytes(pov.len() as u64));
+		group.bench_with_input(
+			BenchmarkId::from_parameter(n_validators),
+			&n_validators,
+			|b, &n| {
+				b.iter(|| {
+					let _pov: Vec<u8> =
+						polkadot_erasure_coding::streaming::reconstruct(n, chunks.clone()).unwrap();
+				});
+			},
+		);
+	}
+	group.finish();
+
 	let mut group = c.benchmark_group("reconstruct_systematic");
 	for n_validators in N_VALIDATORS {
 		let all_chunks = chunks(n_validators, &pov);
@@ -0,0 +1,55 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use honggfuzz::fuzz;
+use polkadot_erasure_coding::*;
+use polkadot_node_primitives::{AvailableData, BlockData, PoV};
+use polkadot_primitives::PersistedValidationData;
+use std::sync::Arc;
+
+fn main() {
+	loop {
+		fuzz!(|data: (u16, Vec<u8>)| {
+			let (n_validators, pov) = data;
+			let n_validators = (n_validators as usize).max(2);
+
+			let available_data = AvailableData {
+				pov: Arc::new(PoV { block_data: BlockData(pov) }),
+				validation_data: PersistedValidationData::default(),
+			};
+			let chunks = obtain_chunks_v1(n_validators, &available_data).unwrap();
+
+			// The streaming encoder must produce the same chunks.
+			assert_eq!(streaming::obtain_chunks_v1(n_validators, &available_data).unwrap(), chunks);
+
+			// Both must reconstruct the data from the last chunks, which are not systematic.
+			let threshold = recovery_threshold(n_validators).unwrap();
+			let regular = chunks.iter().enumerate().rev().take(threshold);
+
+			let reconstructed: AvailableData =
+				reconstruct_v1(n_validators, regular.clone().map(|(i, chunk)| (&chunk[..], i)))
+					.unwrap();
+			let streamed: AvailableData = streaming::reconstruct_v1(
+				n_validators,
+				regular.map(|(i, chunk)| (chunk.clone(), i)),
+			)
+			.unwrap();
+
+			assert_eq!(reconstructed, available_data);
+			assert_eq!(streamed, available_data);
+		});
+	}
+}
@@ -36,6 +36,8 @@ use thiserror::Error;
 
 use novelpoly::{CodeParams, WrappedShard};
 
+pub mod streaming;
+
 // we are limited to the field order of GF(2^16), which is 65536
 const MAX_VALIDATORS: usize = novelpoly::f2e16::FIELD_SIZE;
 
@@ -0,0 +1,471 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Streaming erasure coding.
+//!
+//! Every chunk is a sequence of 2-byte symbols, and the `i`-th symbol of all the chunks only
+//! depends on the `i`-th row of `k` symbols of the payload, where `k` is the
+//! [`systematic_recovery_threshold`](crate::systematic_recovery_threshold). The payload can
+//! therefore be encoded while it is being produced and reconstructed one segment of rows at a time,
+//! without holding the whole payload next to all the chunks and the intermediate buffers of the
+//! codec.
+//!
+//! The chunks and payloads are identical to the ones of [`obtain_chunks`](crate::obtain_chunks)
+//! and [`reconstruct`](crate::reconstruct).
+
+use crate::{code_params, Error};
+use codec::{Decode, Encode, Input, Output};
+use novelpoly::{CodeParams, ReedSolomon, WrappedShard};
+use polkadot_node_primitives::AvailableData;
+
+/// The approximate amount of payload bytes that are encoded or reconstructed at once.
+pub const SEGMENT_SIZE: usize = 256 * 1024;
+
+/// The amount of payload bytes in a segment, rounded down to a whole number of rows of `k`
+/// symbols.
+fn segment_size(k: usize) -> usize {
+	let row_size = 2 * k;
+	(SEGMENT_SIZE / row_size).max(1) * row_size
+}
+
+/// The length of every chunk of a payload with the given size.
+fn chunk_len(k: usize, payload_len: usize) -> usize {
+	payload_len.div_ceil(2).div_ceil(k) * 2
+}
+
+/// Erasure-codes a payload that is provided in pieces.
+///
+/// Implements [`Output`], so that any value can be encoded straight into it without allocating
+/// its encoding first.
+pub struct StreamingEncoder {
+	params: CodeParams,
+	segment_size: usize,
+	pending: Vec<u8>,
+	payload_len: usize,
+	chunks: Vec<Vec<u8>>,
+}
+
+impl StreamingEncoder {
+	/// Create an encoder producing one chunk for each validator.
+	///
+	/// Works only up to 65536 validators, and `n_validators` must be non-zero.
+	pub fn new(n_validators: usize) -> Result<Self, Error> {
+		let params = code_params(n_validators)?;
+		let segment_size = segment_size(params.k());
+
+		Ok(Self {
+			params,
+			segment_size,
+			pending: Vec::with_capacity(segment_size),
+			payload_len: 0,
+			chunks: vec![Vec::new(); n_validators],
+		})
+	}
+
+	/// Reserve the space for the chunks of a payload of the given total size.
+	pub fn reserve(&mut self, payload_len: usize) {
+		let chunk_len = chunk_len(self.params.k(), payload_len);
+		for chunk in &mut self.chunks {
+			chunk.reserve(chunk_len.saturating_sub(chunk.len()));
+		}
+	}
+
+	/// Append the given bytes to the payload, encoding all complete segments.
+	pub fn update(&mut self, mut bytes: &[u8]) {
+		self.payload_len += bytes.len();
+
+		while !bytes.is_empty() {
+			// Encode whole segments in place when nothing is buffered.
+			if self.pending.is_empty() && bytes.len() >= self.segment_size {
+				let (segment, rest) = bytes.split_at(self.segment_size);
+				encode_segment(&self.params, segment, &mut self.chunks);
+				bytes = rest;
+				continue
+			}
+
+			let take = (self.segment_size - self.pending.len()).min(bytes.len());
+			self.pending.extend_from_slice(&bytes[..take]);
+			bytes = &bytes[take..];
+
+			if self.pending.len() == self.segment_size {
+				encode_segment(&self.params, &self.pending, &mut self.chunks);
+				self.pending.clear();
+			}
+		}
+	}
+
+	/// Encode the rest of the payload and return the chunks, one for each validator.
+	pub fn finish(mut self) -> Result<Vec<Vec<u8>>, Error> {
+		if self.payload_len == 0 {
+			return Err(Error::BadPayload)
+		}
+
+		if !self.pending.is_empty() {
+			encode_segment(&self.params, &self.pending, &mut self.chunks);
+		}
+
+		Ok(self.chunks)
+	}
+}
+
+impl Output for StreamingEncoder {
+	fn write(&mut self, bytes: &[u8]) {
+		self.update(bytes)
+	}
+}
+
+fn encode_segment(params: &CodeParams, segment: &[u8], chunks: &mut [Vec<u8>]) {
+	let shards = params
+		.make_encoder()
+		.encode::<WrappedShard>(segment)
+		.expect("Segment non-empty, shard sizes are uniform, and validator numbers checked; qed");
+
+	for (chunk, shard) in chunks.iter_mut().zip(shards) {
+		chunk.extend_from_slice(&shard.into_inner());
+	}
+}
+
+/// Obtain erasure-coded chunks for v1 `AvailableData`, one for each validator.
+///
+/// Same as [`obtain_chunks_v1`](crate::obtain_chunks_v1), but without allocating the encoding of
+/// the data.
+pub fn obtain_chunks_v1(n_validators: usize, data: &AvailableData) -> Result<Vec<Vec<u8>>, Error> {
+	obtain_chunks(n_validators, data)
+}
+
+/// Obtain erasure-coded chunks, one for each validator.
+///
+/// Same as [`obtain_chunks`](crate::obtain_chunks), but without allocating the encoding of the
+/// data.
+pub fn obtain_chunks<T: Encode>(n_validators: usize, data: &T) -> Result<Vec<Vec<u8>>, Error> {
+	let mut encoder = StreamingEncoder::new(n_validators)?;
+	encoder.reserve(data.size_hint());
+	data.encode_to(&mut encoder);
+	encoder.finish()
+}
+
+/// Reconstructs a payload from chunks that are provided one at a time.
+///
+/// Every chunk is checked when it is added. Once enough chunks are present, the payload is
+/// decoded while it is reconstructed one segment at a time, or simply reassembled if all the
+/// systematic chunks are present.
+pub struct StreamingReconstructor {
+	params: CodeParams,
+	chunks: Vec<Option<Vec<u8>>>,
+	chunk_len: Option<usize>,
+	received: usize,
+}
+
+impl StreamingReconstructor {
+	/// Create a reconstructor for the chunks of the given number of validators.
+	///
+	/// Works only up to 65536 validators, and `n_validators` must be non-zero.
+	pub fn new(n_validators: usize) -> Result<Self, Error> {
+		let params = code_params(n_validators)?;
+		Ok(Self { params, chunks: vec![None; n_validators], chunk_len: None, received: 0 })
+	}
+
+	/// Add the chunk with the given index. Chunks which were already added are ignored.
+	///
+	/// Returns whether enough chunks are present to reconstruct the payload.
+	pub fn push(&mut self, chunk_index: usize, chunk: Vec<u8>) -> Result<bool, Error> {
+		let n_validators = self.chunks.len();
+		if chunk_index >= n_validators {
+			return Err(Error::ChunkIndexOutOfBounds { chunk_index, n_validators })
+		}
+		if chunk.len() % 2 != 0 {
+			return Err(Error::UnevenLength)
+		}
+		if chunk.is_empty() || self.chunk_len.map_or(false, |len| len != chunk.len()) {
+			return Err(Error::NonUniformChunks)
+		}
+
+		self.chunk_len = Some(chunk.len());
+		let slot = &mut self.chunks[chunk_index];
+		if slot.is_none() {
+			*slot = Some(chunk);
+			self.received += 1;
+		}
+
+		Ok(self.is_complete())
+	}
+
+	/// The number of distinct chunks added so far.
+	pub fn received(&self) -> usize {
+		self.received
+	}
+
+	/// Whether enough chunks are present to reconstruct the payload.
+	pub fn is_complete(&self) -> bool {
+		self.received >= self.params.k()
+	}
+
+	/// Reconstruct the payload and decode it.
+	///
+	/// The payload is decoded while it is being reconstructed, so that it is never held as a whole
+	/// next to the decoded value.
+	pub fn reconstruct<T: Decode>(&self) -> Result<T, Error> {
+		let mut reader = self.reader()?;
+		let decoded = T::decode(&mut reader);
+		match reader.error {
+			Some(err) => Err(err),
+			None => decoded.map_err(|_| Error::BadPayload),
+		}
+	}
+
+	/// Reconstruct the whole payload, including the padding of the last row.
+	pub fn reconstruct_payload(&self) -> Result<Vec<u8>, Error> {
+		let mut reader = self.reader()?;
+		let mut payload = vec![0; reader.remaining];
+		let read = reader.read(&mut payload);
+		match reader.error {
+			Some(err) => Err(err),
+			None => read.map(|_| payload).map_err(|_| Error::BadPayload),
+		}
+	}
+
+	fn reader(&self) -> Result<PayloadReader<'_>, Error> {
+		let k = self.params.k();
+		let chunk_len = match self.chunk_len {
+			Some(chunk_len) if self.is_complete() => chunk_len,
+			_ => return Err(Error::NotEnoughChunks),
+		};
+
+		Ok(PayloadReader {
+			encoder: self.params.make_encoder(),
+			k,
+			chunks: &self.chunks,
+			systematic: self.chunks[..k].iter().all(Option::is_some),
+			chunk_len,
+			segment_len: segment_size(k) / k,
+			next: 0,
+			segment: Vec::new(),
+			pos: 0,
+			remaining: chunk_len * k,
+			error: None,
+		})
+	}
+}
+
+/// Reads the payload of a [`StreamingReconstructor`], reconstructing one segment at a time when
+/// the previous one was consumed.
+struct PayloadReader<'a> {
+	encoder: ReedSolomon,
+	k: usize,
+	chunks: &'a [Option<Vec<u8>>],
+	/// Whether all the systematic chunks are present, such that the segments only need to be
+	/// reassembled.
+	systematic: bool,
+	chunk_len: usize,
+	/// The amount of bytes of every chunk that make up a segment.
+	segment_len: usize,
+	/// The offset within the chunks of the next segment.
+	next: usize,
+	segment: Vec<u8>,
+	/// The offset within `segment` of the next byte to read.
+	pos: usize,
+	/// The amount of payload bytes that were not read yet.
+	remaining: usize,
+	/// The reconstruction error, which [`Input`] can't carry.
+	error: Option<Error>,
+}
+
+impl PayloadReader<'_> {
+	fn next_segment(&mut self) -> Result<(), Error> {
+		let start = self.next;
+		let end = (start + self.segment_len).min(self.chunk_len);
+		let shard = |chunk: &Vec<u8>| WrappedShard::new(chunk[start..end].to_vec());
+
+		self.segment = if self.systematic {
+			let systematic = self.chunks[..self.k].iter().flatten().map(shard).collect();
+			self.encoder.reconstruct_from_systematic(systematic)?
+		} else {
+			let shards = self.chunks.iter().map(|chunk| chunk.as_ref().map(shard)).collect();
+			self.encoder.reconstruct(shards)?
+		};
+		self.next = end;
+		self.pos = 0;
+
+		Ok(())
+	}
+}
+
+impl Input for PayloadReader<'_> {
+	fn remaining_len(&mut self) -> Result<Option<usize>, codec::Error> {
+		Ok(Some(self.remaining))
+	}
+
+	fn read(&mut self, mut into: &mut [u8]) -> Result<(), codec::Error> {
+		if into.len() > self.remaining {
+			return Err("Not enough data to fill buffer".into())
+		}
+
+		while !into.is_empty() {
+			if self.pos == self.segment.len() {
+				if let Err(err) = self.next_segment() {
+					self.error = Some(err);
+					return Err("Failed to reconstruct the payload".into())
+				}
+			}
+
+			let take = (self.segment.len() - self.pos).min(into.len());
+			into[..take].copy_from_slice(&self.segment[self.pos..self.pos + take]);
+			self.pos += take;
+			self.remaining -= take;
+			into = &mut into[take..];
+		}
+
+		Ok(())
+	}
+}
+
+/// Reconstruct the v1 available data from a set of chunks.
+///
+/// Same as [`reconstruct_v1`](crate::reconstruct_v1), but takes ownership of the chunks and
+/// reconstructs the data one segment at a time.
+pub fn reconstruct_v1<I>(n_validators: usize, chunks: I) -> Result<AvailableData, Error>
+where
+	I: IntoIterator<Item = (Vec<u8>, usize)>,
+{
+	reconstruct(n_validators, chunks)
+}
+
+/// Reconstruct decodable data from a set of chunks.
+///
+/// Same as [`reconstruct`](crate::reconstruct), but takes ownership of the chunks and reconstructs
+/// the data one segment at a time.
+pub fn reconstruct<I, T: Decode>(n_validators: usize, chunks: I) -> Result<T, Error>
+where
+	I: IntoIterator<Item = (Vec<u8>, usize)>,
+{
+	let mut reconstructor = StreamingReconstructor::new(n_validators)?;
+	for (chunk, chunk_index) in chunks {
+		if reconstructor.push(chunk_index, chunk)? {
+			break
+		}
+	}
+
+	reconstructor.reconstruct()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_primitives::{BlockData, PoV};
+	use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+	fn available_data(pov_len: usize) -> AvailableData {
+		let pov = PoV { block_data: BlockData((0..pov_len).map(|i| i as u8).collect()) };
+		AvailableData { pov: pov.into(), validation_data: Default::default() }
+	}
+
+	#[test]
+	fn chunks_match_whole_buffer_encoding() {
+		fn property(pov_len: u32, n_validators: u16) {
+			let n_validators = (n_validators as usize).max(2);
+			let data = available_data(pov_len as usize % (4 * SEGMENT_SIZE));
+
+			assert_eq!(
+				obtain_chunks_v1(n_validators, &data).unwrap(),
+				crate::obtain_chunks_v1(n_validators, &data).unwrap(),
+			);
+		}
+
+		QuickCheck::new().tests(20).quickcheck(property as fn(u32, u16))
+	}
+
+	#[test]
+	fn chunks_do_not_depend_on_the_input_pieces() {
+		let data = available_data(3 * SEGMENT_SIZE + 17);
+		let payload = data.encode();
+
+		let mut g = Gen::new(SEGMENT_SIZE);
+		let mut encoder = StreamingEncoder::new(10).unwrap();
+		let mut rest = &payload[..];
+		while !rest.is_empty() {
+			let (piece, tail) =
+				rest.split_at((usize::arbitrary(&mut g) % SEGMENT_SIZE + 1).min(rest.len()));
+			encoder.update(piece);
+			rest = tail;
+		}
+
+		assert_eq!(encoder.finish().unwrap(), crate::obtain_chunks_v1(10, &data).unwrap());
+	}
+
+	#[test]
+	fn reconstruct_works() {
+		let data = available_data(2 * SEGMENT_SIZE + 5);
+		let chunks = obtain_chunks_v1(100, &data).unwrap();
+		let threshold = crate::systematic_recovery_threshold(100).unwrap();
+
+		// Regular chunks.
+		let regular = chunks.iter().cloned().enumerate().rev().take(threshold);
+		let reconstructed: AvailableData =
+			reconstruct(100, regular.map(|(index, chunk)| (chunk, index))).unwrap();
+		assert_eq!(reconstructed, data);
+
+		// Systematic chunks.
+		let systematic = chunks.iter().cloned().enumerate().take(threshold);
+		let reconstructed: AvailableData =
+			reconstruct(100, systematic.map(|(index, chunk)| (chunk, index))).unwrap();
+		assert_eq!(reconstructed, data);
+	}
+
+	#[test]
+	fn reconstructor_checks_chunks() {
+		let mut reconstructor = StreamingReconstructor::new(10).unwrap();
+		assert_eq!(
+			reconstructor.push(10, vec![0; 2]),
+			Err(Error::ChunkIndexOutOfBounds { chunk_index: 10, n_validators: 10 })
+		);
+		assert_eq!(reconstructor.push(0, vec![0; 3]), Err(Error::UnevenLength));
+		assert_eq!(reconstructor.push(0, vec![]), Err(Error::NonUniformChunks));
+		assert_eq!(reconstructor.push(0, vec![0; 2]), Ok(false));
+		assert_eq!(reconstructor.push(1, vec![0; 4]), Err(Error::NonUniformChunks));
+
+		// Duplicates are not counted.
+		assert_eq!(reconstructor.push(0, vec![0; 2]), Ok(false));
+		assert_eq!(reconstructor.received(), 1);
+		assert_eq!(reconstructor.reconstruct_payload(), Err(Error::NotEnoughChunks));
+	}
+
+	#[test]
+	fn reconstructor_decodes_while_reconstructing() {
+		let data = available_data(3 * SEGMENT_SIZE + 11);
+		let payload = data.encode();
+		let chunks = obtain_chunks_v1(50, &data).unwrap();
+
+		let mut reconstructor = StreamingReconstructor::new(50).unwrap();
+		for (index, chunk) in chunks.into_iter().enumerate().skip(10) {
+			if reconstructor.push(index, chunk).unwrap() {
+				break
+			}
+		}
+
+		// The reconstructor can be used more than once.
+		assert_eq!(reconstructor.reconstruct::<AvailableData>().unwrap(), data);
+		let reconstructed = reconstructor.reconstruct_payload().unwrap();
+		assert_eq!(&reconstructed[..payload.len()], &payload[..]);
+		assert!(reconstructed[payload.len()..].iter().all(|byte| *byte == 0));
+
+		// Decoding more than the payload fails.
+		assert_eq!(reconstructor.reconstruct::<[u8; 32]>().map(|_| ()), Ok(()));
+		assert_eq!(
+			reconstructor.reconstruct::<(AvailableData, [u8; 4096])>(),
+			Err(Error::BadPayload)
+		);
+	}
+}
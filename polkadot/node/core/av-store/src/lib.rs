@@ -22,6 +22,7 @@
 use std::{
 	collections::{BTreeSet, HashMap, HashSet},
 	io,
+	path::PathBuf,
 	sync::Arc,
 	time::{Duration, SystemTime, SystemTimeError, UNIX_EPOCH},
 };
@@ -48,7 +49,8 @@ use polkadot_node_subsystem::{
 use polkadot_node_subsystem_util as util;
 use polkadot_primitives::{
 	vstaging::{CandidateEvent, CandidateReceiptV2 as CandidateReceipt},
-	BlockNumber, CandidateHash, ChunkIndex, CoreIndex, Hash, Header, NodeFeatures, ValidatorIndex,
+	BlockNumber, CandidateHash, ChunkIndex, CoreIndex, Hash, Header, Id as ParaId, NodeFeatures,
+	ValidatorIndex,
 };
 use util::availability_chunks::availability_chunk_indices;
 
@@ -421,7 +423,7 @@ struct PruningConfig {
 }
 
 /// Configuration for the availability store.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Config {
 	/// The column family for availability data and chunks.
 	pub col_data: u32,
@@ -429,6 +431,21 @@ pub struct Config {
 	pub col_meta: u32,
 	/// How long finalized data should be kept (in hours).
 	pub keep_finalized_for: u32,
+	/// Archival of the PoVs of some parachains, disabled if `None`.
+	pub pov_archive: Option<PovArchiveConfig>,
+}
+
+/// Configuration for archiving the PoVs of some parachains to a local directory.
+///
+/// The PoV of an included candidate of one of these parachains is written to
+/// `<path>/<para id>/<candidate hash>.pov`, provided that the full available data was stored
+/// locally, e.g. because the node backed the candidate.
+#[derive(Debug, Clone)]
+pub struct PovArchiveConfig {
+	/// The directory to write the PoVs to.
+	pub path: PathBuf,
+	/// The parachains whose PoVs are archived.
+	pub para_ids: HashSet<ParaId>,
 }
 
 trait Clock: Send + Sync {
@@ -656,7 +673,7 @@ async fn start_prune_all<Context>(
 ) -> Result<(), Error> {
 	let metrics = subsystem.metrics.clone();
 	let db = subsystem.db.clone();
-	let config = subsystem.config;
+	let config = subsystem.config.clone();
 	let time_now = subsystem.clock.now()?;
 
 	ctx.spawn_blocking(
@@ -746,6 +763,7 @@ async fn process_new_head<Context>(
 	let n_validators =
 		util::request_validators(header.parent_hash, ctx.sender()).await.await??.len();
 
+	let mut to_archive = Vec::new();
 	for event in candidate_events {
 		match event {
 			CandidateEvent::CandidateBacked(receipt, _head, _core_index, _group_index) => {
@@ -767,15 +785,38 @@ async fn process_new_head<Context>(
 					pruning_config,
 					(header.number, hash),
 					receipt,
+					&mut to_archive,
 				)?;
 			},
 			_ => {},
 		}
 	}
 
+	if !to_archive.is_empty() {
+		start_archive_povs(ctx, db, config, to_archive);
+	}
+
 	Ok(())
 }
 
+// Archive PoVs on a separate thread, so that slow disk writes don't block the subsystem.
+#[overseer::contextbounds(AvailabilityStore, prefix = self::overseer)]
+fn start_archive_povs<Context>(
+	ctx: &mut Context,
+	db: &Arc<dyn Database>,
+	config: &Config,
+	candidates: Vec<(ParaId, CandidateHash)>,
+) {
+	let db = db.clone();
+	let config = config.clone();
+	if let Err(err) = ctx.spawn_blocking(
+		"av-store-pov-archival",
+		Box::pin(async move { archive_povs(&db, &config, candidates) }),
+	) {
+		gum::warn!(target: LOG_TARGET, ?err, "Failed to spawn PoV archival");
+	}
+}
+
 fn note_block_backed(
 	db: &Arc<dyn Database>,
 	db_transaction: &mut DBTransaction,
@@ -812,6 +853,7 @@ fn note_block_included(
 	pruning_config: &PruningConfig,
 	block: (BlockNumber, Hash),
 	candidate: CandidateReceipt,
+	to_archive: &mut Vec<(ParaId, CandidateHash)>,
 ) -> Result<(), Error> {
 	let candidate_hash = candidate.hash();
 
@@ -836,6 +878,15 @@ fn note_block_included(
 					let prune_at = at_d + pruning_config.keep_unavailable_for;
 					delete_pruning_key(db_transaction, config, prune_at, &candidate_hash);
 
+					let para_id = candidate.descriptor.para_id();
+					let archived = config
+						.pov_archive
+						.as_ref()
+						.map_or(false, |archive| archive.para_ids.contains(&para_id));
+					if meta.data_available && archived {
+						to_archive.push((para_id, candidate_hash));
+					}
+
 					State::Unfinalized(at, vec![be_block])
 				},
 				State::Unfinalized(at, mut within) => {
@@ -867,6 +918,34 @@ fn note_block_included(
 	Ok(())
 }
 
+/// Write the PoVs of the given candidates to the archive.
+///
+/// Failures are logged, but don't affect the availability store.
+fn archive_povs(db: &Arc<dyn Database>, config: &Config, candidates: Vec<(ParaId, CandidateHash)>) {
+	let Some(ref archive) = config.pov_archive else { return };
+
+	for (para_id, candidate_hash) in candidates {
+		let available_data = match load_available_data(db, config, &candidate_hash) {
+			Ok(Some(available_data)) => available_data,
+			Ok(None) => continue,
+			Err(err) => {
+				gum::warn!(target: LOG_TARGET, ?candidate_hash, ?err, "Failed to load PoV to archive");
+				continue
+			},
+		};
+
+		let dir = archive.path.join(u32::from(para_id).to_string());
+		let path = dir.join(format!("{:?}.pov", candidate_hash.0));
+		if let Err(err) = std::fs::create_dir_all(&dir)
+			.and_then(|_| std::fs::write(&path, available_data.pov.encode()))
+		{
+			gum::warn!(target: LOG_TARGET, ?candidate_hash, ?path, ?err, "Failed to archive PoV");
+		} else {
+			gum::debug!(target: LOG_TARGET, ?candidate_hash, ?path, "Archived PoV");
+		}
+	}
+}
+
 macro_rules! peek_num {
 	($iter:ident) => {
 		match $iter.peek() {
@@ -1311,7 +1390,8 @@ fn store_available_data(
 
 	// Important note: This check below is critical for consensus and the `backing` subsystem relies
 	// on it to ensure candidate validity.
-	let chunks = polkadot_erasure_coding::obtain_chunks_v1(n_validators, &available_data)?;
+	let chunks =
+		polkadot_erasure_coding::streaming::obtain_chunks_v1(n_validators, &available_data)?;
 	let branches = polkadot_erasure_coding::branches(chunks.as_ref());
 
 	if branches.root() != expected_erasure_root {
@@ -1405,3 +1485,47 @@ fn prune_all(db: &Arc<dyn Database>, config: &Config, now: Duration) -> Result<(
 	db.write(tx)?;
 	Ok(())
 }
+
+/// Everything stored for a candidate in the availability store.
+#[derive(Debug, Clone)]
+pub struct StoredCandidateData {
+	/// The full available data, if it is stored.
+	pub available_data: Option<AvailableData>,
+	/// The stored chunks along with the index of the validator they were stored for.
+	pub chunks: Vec<(ValidatorIndex, ErasureChunk)>,
+}
+
+/// Load everything stored for a candidate, e.g. to export it from the database of a stopped node.
+///
+/// Returns `None` if the candidate is unknown to the availability store.
+pub fn load_candidate_data(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	candidate_hash: &CandidateHash,
+) -> Result<Option<StoredCandidateData>, Error> {
+	let meta = match load_meta(db, config, candidate_hash)? {
+		Some(meta) => meta,
+		None => return Ok(None),
+	};
+
+	let available_data =
+		if meta.data_available { load_available_data(db, config, candidate_hash)? } else { None };
+
+	let mut chunks = Vec::new();
+	for validator_index in meta.chunks_stored.iter_ones() {
+		let validator_index = ValidatorIndex(validator_index as _);
+		match load_chunk(db, config, candidate_hash, validator_index)? {
+			Some(chunk) => chunks.push((validator_index, chunk)),
+			None => {
+				gum::warn!(
+					target: LOG_TARGET,
+					?candidate_hash,
+					?validator_index,
+					"No chunk found for set bit in meta"
+				);
+			},
+		}
+	}
+
+	Ok(Some(StoredCandidateData { available_data, chunks }))
+}
@@ -43,8 +43,12 @@ mod columns {
 	pub const NUM_COLUMNS: u32 = 2;
 }
 
-const TEST_CONFIG: Config =
-	Config { col_data: columns::DATA, col_meta: columns::META, keep_finalized_for: 1 };
+const TEST_CONFIG: Config = Config {
+	col_data: columns::DATA,
+	col_meta: columns::META,
+	keep_finalized_for: 1,
+	pov_archive: None,
+};
 
 type VirtualOverseer =
 	polkadot_node_subsystem_test_helpers::TestSubsystemContextHandle<AvailabilityStoreMessage>;
@@ -1391,3 +1395,165 @@ fn query_chunk_size_works() {
 		virtual_overseer
 	});
 }
+
+#[test]
+fn load_candidate_data_works() {
+	let store = test_store();
+	let candidate_hash = CandidateHash(Hash::repeat_byte(1));
+	let n_validators = 10;
+
+	let available_data = AvailableData {
+		pov: Arc::new(PoV { block_data: BlockData(vec![4, 5, 6]) }),
+		validation_data: TestState::default().persisted_validation_data,
+	};
+	let chunk = ErasureChunk {
+		chunk: vec![1, 2, 3],
+		index: ChunkIndex(3),
+		proof: Proof::try_from(vec![vec![3, 4, 5]]).unwrap(),
+	};
+
+	assert!(load_candidate_data(&store, &TEST_CONFIG, &candidate_hash).unwrap().is_none());
+
+	with_tx(&store, |tx| {
+		let mut chunks_stored = bitvec::bitvec![u8, BitOrderLsb0; 0; n_validators];
+		chunks_stored.set(2, true);
+
+		write_meta(
+			tx,
+			&TEST_CONFIG,
+			&candidate_hash,
+			&CandidateMeta {
+				data_available: true,
+				chunks_stored,
+				state: State::Unavailable(BETimestamp(0)),
+			},
+		);
+		write_available_data(tx, &TEST_CONFIG, &candidate_hash, &available_data);
+		write_chunk(tx, &TEST_CONFIG, &candidate_hash, ValidatorIndex(2), &chunk);
+	});
+
+	let stored = load_candidate_data(&store, &TEST_CONFIG, &candidate_hash).unwrap().unwrap();
+	assert_eq!(stored.available_data, Some(available_data));
+	assert_eq!(stored.chunks, vec![(ValidatorIndex(2), chunk)]);
+}
+
+#[test]
+fn included_pov_is_archived() {
+	let store = test_store();
+	let archive_dir = tempfile::tempdir().unwrap();
+	let config = Config {
+		pov_archive: Some(PovArchiveConfig {
+			path: archive_dir.path().to_owned(),
+			para_ids: [ParaId::from(1)].into_iter().collect(),
+		}),
+		..TEST_CONFIG
+	};
+	let pruning_config = TestState::default().pruning_config;
+	let pov = PoV { block_data: BlockData(vec![4, 5, 6]) };
+
+	let archived_path = |candidate: &CandidateReceipt| {
+		archive_dir
+			.path()
+			.join(u32::from(candidate.descriptor.para_id()).to_string())
+			.join(format!("{:?}.pov", candidate.hash().0))
+	};
+
+	for para_id in [1, 2] {
+		let candidate = TestCandidateBuilder {
+			para_id: para_id.into(),
+			pov_hash: pov.hash(),
+			..Default::default()
+		}
+		.build();
+		let candidate_hash = candidate.hash();
+
+		with_tx(&store, |tx| {
+			write_meta(
+				tx,
+				&config,
+				&candidate_hash,
+				&CandidateMeta {
+					data_available: true,
+					chunks_stored: bitvec::bitvec![u8, BitOrderLsb0; 0; 10],
+					state: State::Unavailable(BETimestamp(0)),
+				},
+			);
+			write_available_data(
+				tx,
+				&config,
+				&candidate_hash,
+				&AvailableData {
+					pov: Arc::new(pov.clone()),
+					validation_data: TestState::default().persisted_validation_data,
+				},
+			);
+		});
+
+		let path = archived_path(&candidate);
+		let mut to_archive = Vec::new();
+		with_tx(&store, |tx| {
+			note_block_included(
+				&store,
+				tx,
+				&config,
+				&pruning_config,
+				(1, Hash::repeat_byte(1)),
+				candidate,
+				&mut to_archive,
+			)
+			.unwrap();
+		});
+
+		// Only the candidates of archived parachains are scheduled for archival.
+		if para_id == 1 {
+			assert_eq!(to_archive, vec![(para_id.into(), candidate_hash)]);
+		} else {
+			assert!(to_archive.is_empty());
+		}
+
+		archive_povs(&store, &config, to_archive);
+		if para_id == 1 {
+			assert_eq!(std::fs::read(path).unwrap(), pov.encode());
+		} else {
+			assert!(!path.exists());
+		}
+	}
+}
+
+#[test]
+fn failing_pov_archival_is_ignored() {
+	let store = test_store();
+	let archive_dir = tempfile::tempdir().unwrap();
+	// The archive directory can't be created, as a file with the same name exists.
+	let path = archive_dir.path().join("archive");
+	std::fs::write(&path, b"").unwrap();
+	let config = Config {
+		pov_archive: Some(PovArchiveConfig {
+			path,
+			para_ids: [ParaId::from(1)].into_iter().collect(),
+		}),
+		..TEST_CONFIG
+	};
+	let pov = PoV { block_data: BlockData(vec![4, 5, 6]) };
+	let candidate_hash = CandidateHash(Hash::repeat_byte(1));
+
+	with_tx(&store, |tx| {
+		write_available_data(
+			tx,
+			&config,
+			&candidate_hash,
+			&AvailableData {
+				pov: Arc::new(pov),
+				validation_data: TestState::default().persisted_validation_data,
+			},
+		);
+	});
+
+	// Neither the failing write nor the missing data of the second candidate stop the archival.
+	archive_povs(
+		&store,
+		&config,
+		vec![(1.into(), candidate_hash), (1.into(), CandidateHash(Hash::repeat_byte(2)))],
+	);
+	assert!(load_available_data(&store, &config, &candidate_hash).unwrap().is_some());
+}
@@ -24,8 +24,9 @@
 #![warn(missing_docs)]
 
 use polkadot_node_core_pvf::{
-	InternalValidationError, InvalidCandidate as WasmInvalidCandidate, PossiblyInvalidError,
-	PrepareError, PrepareJobKind, PvfPrepData, ValidationError, ValidationHost,
+	ExecutionOutcome, InternalValidationError, InvalidCandidate as WasmInvalidCandidate,
+	PossiblyInvalidError, PrepareError, PrepareJobKind, PvfPrepData, ValidationError,
+	ValidationHost,
 };
 use polkadot_node_primitives::{InvalidCandidate, PoV, ValidationResult};
 use polkadot_node_subsystem::{
@@ -42,7 +43,6 @@ use polkadot_node_subsystem_util::{
 	runtime::{fetch_scheduling_lookahead, ClaimQueueSnapshot},
 };
 use polkadot_overseer::{ActivatedLeaf, ActiveLeavesUpdate};
-use polkadot_parachain_primitives::primitives::ValidationResult as WasmValidationResult;
 use polkadot_primitives::{
 	executor_params::{
 		DEFAULT_APPROVAL_EXECUTION_TIMEOUT, DEFAULT_BACKING_EXECUTION_TIMEOUT,
@@ -318,6 +318,55 @@ async fn run<Context>(
 	}
 }
 
+/// Validate a candidate outside of a running node, the way the node would when participating in a
+/// dispute.
+///
+/// A PVF validation host is started for the duration of the call, so `config` needs to point at
+/// working worker binaries. Checks that depend on chain state, such as the claim queue, are not
+/// performed.
+pub async fn validate_candidate_offline(
+	config: Config,
+	persisted_validation_data: PersistedValidationData,
+	validation_code: ValidationCode,
+	candidate_receipt: CandidateReceipt,
+	pov: Arc<PoV>,
+	executor_params: ExecutorParams,
+) -> Result<ValidationResult, ValidationFailed> {
+	let (validation_host, task) = polkadot_node_core_pvf::start(
+		polkadot_node_core_pvf::Config::new(
+			config.artifacts_cache_path,
+			config.node_version,
+			config.secure_validator_mode,
+			config.prep_worker_path,
+			config.exec_worker_path,
+			config.pvf_execute_workers_max_num,
+			config.pvf_prepare_workers_soft_max_num,
+			config.pvf_prepare_workers_hard_max_num,
+		),
+		polkadot_node_core_pvf::Metrics::default(),
+	)
+	.await
+	.map_err(|e| ValidationFailed(e.to_string()))?;
+
+	let validation = validate_candidate_exhaustive(
+		None,
+		validation_host,
+		persisted_validation_data,
+		validation_code,
+		candidate_receipt,
+		pov,
+		executor_params,
+		PvfExecKind::Dispute,
+		&Metrics::default(),
+		None,
+	);
+
+	futures::select! {
+		result = validation.fuse() => result,
+		_ = task.fuse() => Err(ValidationFailed("PVF validation host exited".into())),
+	}
+}
+
 struct PrepareValidationState {
 	session_index: Option<SessionIndex>,
 	is_next_session_authority: bool,
@@ -850,6 +899,18 @@ async fn validate_candidate_exhaustive(
 	if let Err(ref error) = result {
 		gum::info!(target: LOG_TARGET, ?para_id, ?error, "Failed to validate candidate");
 	}
+	let result = result.map(|outcome| {
+		if let Some(fuel_consumed) = outcome.fuel_consumed {
+			gum::debug!(
+				target: LOG_TARGET,
+				?para_id,
+				fuel_consumed,
+				"Metered PVF execution finished",
+			);
+			metrics.observe_execution_fuel(fuel_consumed);
+		}
+		outcome.result
+	});
 
 	match result {
 		Err(ValidationError::Internal(e)) => {
@@ -983,7 +1044,7 @@ trait ValidationBackend {
 		prepare_priority: polkadot_node_core_pvf::Priority,
 		// The kind for the execution job.
 		exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError>;
+	) -> Result<ExecutionOutcome, ValidationError>;
 
 	/// Tries executing a PVF. Will retry once if an error is encountered that may have
 	/// been transient.
@@ -1005,7 +1066,7 @@ trait ValidationBackend {
 		prepare_priority: polkadot_node_core_pvf::Priority,
 		// The kind for the execution job.
 		exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError> {
+	) -> Result<ExecutionOutcome, ValidationError> {
 		let prep_timeout = pvf_prep_timeout(&executor_params, PvfPrepKind::Prepare);
 		// Construct the PVF a single time, since it is an expensive operation. Cloning it is cheap.
 		let pvf = PvfPrepData::from_code(
@@ -1145,7 +1206,7 @@ impl ValidationBackend for ValidationHost {
 		prepare_priority: polkadot_node_core_pvf::Priority,
 		// The kind for the execution job.
 		exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError> {
+	) -> Result<ExecutionOutcome, ValidationError> {
 		let (tx, rx) = oneshot::channel();
 		if let Err(err) = self
 			.execute_pvf(pvf, exec_timeout, pvd, pov, prepare_priority, exec_kind, tx)
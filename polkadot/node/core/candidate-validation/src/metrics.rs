@@ -22,6 +22,7 @@ pub(crate) struct MetricsInner {
 	pub(crate) validation_requests: prometheus::CounterVec<prometheus::U64>,
 	pub(crate) validate_from_exhaustive: prometheus::Histogram,
 	pub(crate) validate_candidate_exhaustive: prometheus::Histogram,
+	pub(crate) execution_fuel: prometheus::Histogram,
 }
 
 /// Candidate validation metrics.
@@ -60,6 +61,13 @@ impl Metrics {
 			.as_ref()
 			.map(|metrics| metrics.validate_candidate_exhaustive.start_timer())
 	}
+
+	/// Observe the amount of fuel consumed by a metered PVF execution.
+	pub fn observe_execution_fuel(&self, fuel_consumed: u64) {
+		if let Some(metrics) = &self.0 {
+			metrics.execution_fuel.observe(fuel_consumed as f64);
+		}
+	}
 }
 
 impl metrics::Metrics for Metrics {
@@ -89,6 +97,19 @@ impl metrics::Metrics for Metrics {
 				))?,
 				registry,
 			)?,
+			execution_fuel: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_candidate_validation_execution_fuel",
+						"Units of fuel consumed by metered PVF executions",
+					)
+					.buckets(
+						prometheus::exponential_buckets(1_000_000.0, 4.0, 12)
+							.expect("arguments are always valid; qed"),
+					),
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
@@ -24,11 +24,13 @@ use crate::PvfExecKind;
 use assert_matches::assert_matches;
 use futures::executor;
 use polkadot_node_core_pvf::PrepareError;
+use polkadot_node_metrics::metrics::{prometheus, Metrics as _};
 use polkadot_node_primitives::{BlockData, VALIDATION_CODE_BOMB_LIMIT};
 use polkadot_node_subsystem::messages::AllMessages;
 use polkadot_node_subsystem_test_helpers::{make_subsystem_context, TestSubsystemContextHandle};
 use polkadot_node_subsystem_util::reexports::SubsystemContext;
 use polkadot_overseer::ActivatedLeaf;
+use polkadot_parachain_primitives::primitives::ValidationResult as WasmValidationResult;
 use polkadot_primitives::{
 	vstaging::{
 		CandidateDescriptorV2, CandidateDescriptorVersion, ClaimQueueOffset, CoreSelector,
@@ -419,17 +421,22 @@ fn check_does_not_match() {
 struct MockValidateCandidateBackend {
 	result_list: Vec<Result<WasmValidationResult, ValidationError>>,
 	num_times_called: usize,
+	fuel_consumed: Option<u64>,
 }
 
 impl MockValidateCandidateBackend {
 	fn with_hardcoded_result(result: Result<WasmValidationResult, ValidationError>) -> Self {
-		Self { result_list: vec![result], num_times_called: 0 }
+		Self { result_list: vec![result], num_times_called: 0, fuel_consumed: None }
 	}
 
 	fn with_hardcoded_result_list(
 		result_list: Vec<Result<WasmValidationResult, ValidationError>>,
 	) -> Self {
-		Self { result_list, num_times_called: 0 }
+		Self { result_list, num_times_called: 0, fuel_consumed: None }
+	}
+
+	fn with_fuel_consumed(self, fuel_consumed: u64) -> Self {
+		Self { fuel_consumed: Some(fuel_consumed), ..self }
 	}
 }
 
@@ -443,13 +450,13 @@ impl ValidationBackend for MockValidateCandidateBackend {
 		_pov: Arc<PoV>,
 		_prepare_priority: polkadot_node_core_pvf::Priority,
 		_exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError> {
+	) -> Result<ExecutionOutcome, ValidationError> {
 		// This is expected to panic if called more times than expected, indicating an error in the
 		// test.
 		let result = self.result_list[self.num_times_called].clone();
 		self.num_times_called += 1;
 
-		result
+		result.map(|result| ExecutionOutcome { result, fuel_consumed: self.fuel_consumed })
 	}
 
 	async fn precheck_pvf(&mut self, _pvf: PvfPrepData) -> Result<(), PrepareError> {
@@ -1010,6 +1017,75 @@ fn candidate_validation_bad_return_is_invalid() {
 	assert_matches!(v, ValidationResult::Invalid(InvalidCandidate::Timeout));
 }
 
+#[test]
+fn candidate_validation_reports_consumed_fuel() {
+	let validation_data = PersistedValidationData { max_pov_size: 1024, ..Default::default() };
+
+	let pov = PoV { block_data: BlockData(vec![1; 32]) };
+	let head_data = HeadData(vec![1, 1, 1]);
+	let validation_code = ValidationCode(vec![2; 16]);
+
+	let descriptor = make_valid_candidate_descriptor(
+		ParaId::from(1_u32),
+		dummy_hash(),
+		validation_data.hash(),
+		pov.hash(),
+		validation_code.hash(),
+		head_data.hash(),
+		dummy_hash(),
+		Sr25519Keyring::Alice,
+	)
+	.into();
+
+	let validation_result = WasmValidationResult {
+		head_data,
+		new_validation_code: None,
+		upward_messages: Default::default(),
+		horizontal_messages: Default::default(),
+		processed_downward_messages: 0,
+		hrmp_watermark: 0,
+	};
+	let commitments = CandidateCommitments {
+		head_data: validation_result.head_data.clone(),
+		upward_messages: validation_result.upward_messages.clone(),
+		horizontal_messages: validation_result.horizontal_messages.clone(),
+		new_validation_code: None,
+		processed_downward_messages: 0,
+		hrmp_watermark: 0,
+	};
+	let candidate_receipt = CandidateReceipt { descriptor, commitments_hash: commitments.hash() };
+
+	let registry = prometheus::Registry::new();
+	let metrics = Metrics::try_register(&registry).unwrap();
+
+	let v = executor::block_on(validate_candidate_exhaustive(
+		Some(1),
+		MockValidateCandidateBackend::with_hardcoded_result(Ok(validation_result))
+			.with_fuel_consumed(42_000),
+		validation_data,
+		validation_code,
+		candidate_receipt,
+		Arc::new(pov),
+		ExecutorParams::default(),
+		PvfExecKind::Approval,
+		&metrics,
+		Default::default(),
+	))
+	.unwrap();
+	assert_matches!(v, ValidationResult::Valid(..));
+
+	let fuel = registry
+		.gather()
+		.into_iter()
+		.find(|family| {
+			family.get_name() == "polkadot_parachain_candidate_validation_execution_fuel"
+		})
+		.expect("The fuel metric is registered; qed");
+	let histogram = fuel.get_metric()[0].get_histogram();
+	assert_eq!(histogram.get_sample_count(), 1);
+	assert_eq!(histogram.get_sample_sum(), 42_000.0);
+}
+
 fn perform_basic_checks_on_valid_candidate(
 	pov: &PoV,
 	validation_code: &ValidationCode,
@@ -1479,7 +1555,7 @@ impl ValidationBackend for MockPreCheckBackend {
 		_pov: Arc<PoV>,
 		_prepare_priority: polkadot_node_core_pvf::Priority,
 		_exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError> {
+	) -> Result<ExecutionOutcome, ValidationError> {
 		unreachable!()
 	}
 
@@ -1636,7 +1712,7 @@ impl ValidationBackend for MockHeadsUp {
 		_pov: Arc<PoV>,
 		_prepare_priority: polkadot_node_core_pvf::Priority,
 		_exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError> {
+	) -> Result<ExecutionOutcome, ValidationError> {
 		unreachable!()
 	}
 
@@ -208,6 +208,41 @@ fn candidate_votes_session_prefix(session: SessionIndex) -> [u8; 15 + 4] {
 	buf
 }
 
+/// Read-only access to the dispute data, for inspecting a database outside of a running node.
+pub struct DbReader {
+	inner: Arc<dyn Database>,
+	config: ColumnConfiguration,
+}
+
+impl DbReader {
+	pub fn new(db: Arc<dyn Database>, config: ColumnConfiguration) -> Self {
+		Self { inner: db, config }
+	}
+
+	/// Load the earliest session, if any.
+	pub fn load_earliest_session(&self) -> Result<Option<SessionIndex>> {
+		load_decode(&*self.inner, self.config.col_dispute_data, EARLIEST_SESSION_KEY)
+	}
+
+	/// Load the recent disputes, if any.
+	pub fn load_recent_disputes(&self) -> Result<Option<RecentDisputes>> {
+		load_decode(&*self.inner, self.config.col_dispute_data, RECENT_DISPUTES_KEY)
+	}
+
+	/// Load the candidate votes for the specific session-candidate pair, if any.
+	pub fn load_candidate_votes(
+		&self,
+		session: SessionIndex,
+		candidate_hash: &CandidateHash,
+	) -> Result<Option<CandidateVotes>> {
+		load_decode(
+			&*self.inner,
+			self.config.col_dispute_data,
+			&candidate_votes_key(session, candidate_hash),
+		)
+	}
+}
+
 /// Column configuration information for the DB.
 #[derive(Debug, Clone)]
 pub struct ColumnConfiguration {
@@ -0,0 +1,268 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Offline inspection of the dispute coordinator's database.
+//!
+//! Nothing in here writes to the database, so it is safe to point it at the database of a node
+//! that has been stopped, e.g. to investigate why it participated in a dispute the way it did.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use polkadot_node_primitives::{CandidateVotes, DisputeStatus};
+use polkadot_node_subsystem_util::database::Database;
+use polkadot_primitives::{
+	CandidateHash, DisputeStatement, SessionIndex, ValidatorId, ValidatorIndex,
+};
+
+use crate::{db::v1::DbReader, spam_slots::SpamSlots, Config};
+
+pub use crate::{
+	db::v1::{Error, Result},
+	spam_slots::MAX_SPAM_VOTES,
+};
+
+/// A dispute known to the dispute coordinator.
+#[derive(Debug, Clone)]
+pub struct DisputeRecord {
+	/// The session the disputed candidate was backed in.
+	pub session: SessionIndex,
+	/// The disputed candidate.
+	pub candidate_hash: CandidateHash,
+	/// Status of the dispute as last recorded by the node.
+	pub status: DisputeStatus,
+	/// All votes imported for the candidate.
+	///
+	/// `None` if the votes have already been pruned from the database.
+	pub votes: Option<CandidateVotes>,
+}
+
+impl DisputeRecord {
+	/// Find the vote cast with one of the given keys, if any.
+	///
+	/// The database does not know which validator index belongs to the node, so this checks the
+	/// signature of every vote against the given keys.
+	pub fn own_vote(&self, keys: &[ValidatorId]) -> Option<(ValidatorIndex, DisputeStatement)> {
+		let votes = self.votes.as_ref()?;
+		let valid = votes
+			.valid
+			.raw()
+			.iter()
+			.map(|(index, (kind, sig))| (*index, DisputeStatement::Valid(kind.clone()), sig));
+		let invalid = votes
+			.invalid
+			.iter()
+			.map(|(index, (kind, sig))| (*index, DisputeStatement::Invalid(*kind), sig));
+
+		valid.chain(invalid).find_map(|(index, statement, sig)| {
+			keys.iter()
+				.any(|key| {
+					statement.check_signature(key, self.candidate_hash, self.session, sig).is_ok()
+				})
+				.then_some((index, statement))
+		})
+	}
+}
+
+/// Read-only view on the dispute data of a parachains database.
+pub struct DisputeDb {
+	reader: DbReader,
+}
+
+impl DisputeDb {
+	/// Create a view on the dispute data stored in the given database.
+	pub fn new(db: Arc<dyn Database>, config: Config) -> Self {
+		Self { reader: DbReader::new(db, config.column_config()) }
+	}
+
+	/// The oldest session votes are still kept for.
+	pub fn earliest_session(&self) -> Result<Option<SessionIndex>> {
+		self.reader.load_earliest_session()
+	}
+
+	/// All recent disputes, ordered by session and candidate hash.
+	pub fn disputes(&self) -> Result<Vec<DisputeRecord>> {
+		let recent = self.reader.load_recent_disputes()?.unwrap_or_default();
+		recent
+			.into_iter()
+			.map(|((session, candidate_hash), status)| self.record(session, candidate_hash, status))
+			.collect()
+	}
+
+	/// A single dispute, if it is still known.
+	pub fn dispute(
+		&self,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+	) -> Result<Option<DisputeRecord>> {
+		let recent = self.reader.load_recent_disputes()?.unwrap_or_default();
+		recent
+			.get(&(session, candidate_hash))
+			.map(|status| self.record(session, candidate_hash, *status))
+			.transpose()
+	}
+
+	fn record(
+		&self,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		status: DisputeStatus,
+	) -> Result<DisputeRecord> {
+		let votes = self.reader.load_candidate_votes(session, &candidate_hash)?.map(Into::into);
+		Ok(DisputeRecord { session, candidate_hash, status, votes })
+	}
+}
+
+/// Spam slots occupied by the given disputes, per session and validator.
+///
+/// Every dispute which is neither confirmed nor concluded is counted, the same way the dispute
+/// coordinator recovers its spam slots on startup. Disputes for candidates the node has seen
+/// backed or included on chain do not occupy spam slots, which can't be known offline, so the
+/// result is an upper bound. A count above `MAX_SPAM_VOTES` means the node dropped further votes
+/// of that validator.
+pub fn spam_slot_usage(
+	disputes: &[DisputeRecord],
+) -> BTreeMap<(SessionIndex, ValidatorIndex), u32> {
+	let unconfirmed = disputes
+		.iter()
+		.filter(|dispute| !dispute.status.is_confirmed_concluded())
+		.filter_map(|dispute| {
+			let votes = dispute.votes.as_ref()?;
+			Some(((dispute.session, dispute.candidate_hash), votes.voted_indices()))
+		})
+		.collect();
+
+	SpamSlots::recover_from_state(unconfirmed).occupied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use polkadot_node_primitives::SignedDisputeStatement;
+	use polkadot_primitives::{
+		Hash, InvalidDisputeStatementKind, ValidDisputeStatementKind, PARACHAIN_KEY_TYPE_ID,
+	};
+	use polkadot_primitives_test_helpers::{dummy_candidate_receipt_v2, dummy_hash};
+	use sc_keystore::LocalKeystore;
+	use sp_keyring::Sr25519Keyring;
+	use sp_keystore::{Keystore, KeystorePtr};
+
+	use crate::{
+		backend::{Backend, OverlayedBackend},
+		db::v1::{CandidateVotes as DbCandidateVotes, DbBackend},
+		metrics::Metrics,
+	};
+
+	const COL_DISPUTE_DATA: u32 = 0;
+
+	fn make_db() -> Arc<dyn Database> {
+		let db = kvdb_memorydb::create(1);
+		let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(
+			db,
+			&[COL_DISPUTE_DATA],
+		);
+		Arc::new(db)
+	}
+
+	fn explicit_vote(
+		keystore: &KeystorePtr,
+		public: ValidatorId,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		valid: bool,
+	) -> SignedDisputeStatement {
+		SignedDisputeStatement::sign_explicit(keystore, valid, candidate_hash, session, public)
+			.unwrap()
+			.unwrap()
+	}
+
+	#[test]
+	fn disputes_and_own_votes_are_reported() {
+		let store = make_db();
+		let config = Config { col_dispute_data: COL_DISPUTE_DATA };
+		let keystore: KeystorePtr = Arc::new(LocalKeystore::in_memory());
+		let mut keys = Vec::new();
+		for seed in [Sr25519Keyring::Alice, Sr25519Keyring::Bob, Sr25519Keyring::Charlie] {
+			let key = keystore
+				.sr25519_generate_new(PARACHAIN_KEY_TYPE_ID, Some(&seed.to_seed()))
+				.unwrap();
+			keys.push(ValidatorId::from(key));
+		}
+
+		let session = 3;
+		let receipt = dummy_candidate_receipt_v2(dummy_hash());
+		let disputed = receipt.hash();
+		let concluded = CandidateHash(Hash::repeat_byte(1));
+
+		let valid = explicit_vote(&keystore, keys[0].clone(), session, disputed, true);
+		let invalid = explicit_vote(&keystore, keys[1].clone(), session, disputed, false);
+
+		let mut backend = DbBackend::new(store.clone(), config.column_config(), Metrics::default());
+		let mut overlay_db = OverlayedBackend::new(&backend);
+		overlay_db.write_earliest_session(session);
+		overlay_db.write_recent_disputes(
+			vec![
+				((session, disputed), DisputeStatus::Active),
+				((session, concluded), DisputeStatus::ConcludedFor(0)),
+			]
+			.into_iter()
+			.collect(),
+		);
+		overlay_db.write_candidate_votes(
+			session,
+			disputed,
+			DbCandidateVotes {
+				candidate_receipt: receipt,
+				valid: vec![(
+					ValidDisputeStatementKind::Explicit,
+					ValidatorIndex(0),
+					valid.validator_signature().clone(),
+				)],
+				invalid: vec![(
+					InvalidDisputeStatementKind::Explicit,
+					ValidatorIndex(4),
+					invalid.validator_signature().clone(),
+				)],
+			},
+		);
+		let write_ops = overlay_db.into_write_ops();
+		backend.write(write_ops).unwrap();
+
+		let inspect = DisputeDb::new(store, config);
+		assert_eq!(inspect.earliest_session().unwrap(), Some(session));
+
+		let disputes = inspect.disputes().unwrap();
+		assert_eq!(disputes.len(), 2);
+		let record = inspect.dispute(session, disputed).unwrap().unwrap();
+		assert_eq!(record.status, DisputeStatus::Active);
+		assert!(inspect.dispute(session + 1, disputed).unwrap().is_none());
+		assert!(disputes.iter().any(|d| d.candidate_hash == concluded && d.votes.is_none()));
+
+		assert_eq!(
+			record.own_vote(&keys[..1]),
+			Some((ValidatorIndex(0), DisputeStatement::Valid(ValidDisputeStatementKind::Explicit)))
+		);
+		assert_eq!(record.own_vote(&keys[1..2]).map(|(index, _)| index), Some(ValidatorIndex(4)));
+		assert_eq!(record.own_vote(&keys[2..]), None);
+
+		// Only the active dispute occupies spam slots.
+		let usage = spam_slot_usage(&disputes);
+		assert_eq!(
+			usage.into_iter().collect::<Vec<_>>(),
+			vec![((session, ValidatorIndex(0)), 1), ((session, ValidatorIndex(4)), 1)]
+		);
+	}
+}
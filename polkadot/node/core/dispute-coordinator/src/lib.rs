@@ -109,6 +109,9 @@ mod metrics;
 /// Status tracking of disputes (`DisputeStatus`).
 mod status;
 
+/// Read-only access to the dispute data, for investigating a node's disputes offline.
+pub mod inspect;
+
 use crate::status::Clock;
 
 #[cfg(test)]
@@ -38,9 +38,9 @@ type SpamCount = u32;
 /// candidate would not have been available in the first place and could not have been included.)
 /// So this is really just a fallback mechanism if things go terribly wrong.
 #[cfg(not(test))]
-const MAX_SPAM_VOTES: SpamCount = 50;
+pub const MAX_SPAM_VOTES: SpamCount = 50;
 #[cfg(test)]
-const MAX_SPAM_VOTES: SpamCount = 1;
+pub const MAX_SPAM_VOTES: SpamCount = 1;
 
 /// Spam slots for raised disputes concerning unknown candidates.
 pub struct SpamSlots {
@@ -81,6 +81,13 @@ impl SpamSlots {
 		Self { slots, unconfirmed: unconfirmed_disputes }
 	}
 
+	/// Number of occupied spam slots per session and validator.
+	pub fn occupied(
+		&self,
+	) -> impl Iterator<Item = ((SessionIndex, ValidatorIndex), SpamCount)> + '_ {
+		self.slots.iter().map(|(key, count)| (*key, *count))
+	}
+
 	/// Increase a "voting invalid" validator's spam slot.
 	///
 	/// This function should get called for any validator's invalidity vote for any not yet
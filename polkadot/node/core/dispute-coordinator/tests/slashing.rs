@@ -0,0 +1,370 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A dispute about an invalid candidate, from the dispute coordinator concluding it to the
+//! backers being slashed by the test runtime.
+
+use std::{
+	collections::HashMap,
+	future::Future,
+	sync::{Arc, Mutex, MutexGuard},
+	time::Duration,
+};
+
+use assert_matches::assert_matches;
+use futures::{channel::oneshot, future, pin_mut, select, FutureExt};
+use futures_timer::Delay;
+
+use polkadot_node_core_dispute_coordinator::{Config, DisputeCoordinatorSubsystem};
+use polkadot_node_primitives::{
+	AvailableData, BlockData, CandidateVotes, DisputeStatus, InvalidCandidate, PoV,
+	ValidationResult,
+};
+use polkadot_node_subsystem::{
+	messages::{
+		AllMessages, ApprovalVotingMessage, AvailabilityRecoveryMessage,
+		CandidateValidationMessage, ChainApiMessage, DisputeCoordinatorMessage,
+		ImportStatementsResult, RuntimeApiMessage,
+	},
+	ActiveLeavesUpdate, FromOrchestra, OverseerSignal, SpawnedSubsystem, Subsystem,
+};
+use polkadot_node_subsystem_test_helpers::{
+	disputes::{
+		answer_runtime_api_request, dispute_statement_set, SimulatedRuntime, TestValidators,
+	},
+	make_buffered_subsystem_context,
+	mock::new_leaf,
+	TestSubsystemContextHandle,
+};
+use polkadot_primitives::{
+	slashing,
+	vstaging::{CandidateEvent, MutateDescriptorV2, ScrapedOnChainVotes},
+	BlockNumber, CandidateHash, Hash, SessionIndex, SessionInfo, ValidationCode,
+	ValidationCodeHash, ValidatorId, ValidatorIndex, PARACHAIN_KEY_TYPE_ID,
+};
+use polkadot_primitives_test_helpers::{
+	dummy_candidate_receipt_v2, dummy_head_data, dummy_pvd, dummy_validation_code,
+};
+use polkadot_test_runtime::simulation::Simulation;
+use sc_keystore::LocalKeystore;
+use sp_core::testing::TaskExecutor;
+use sp_keyring::Sr25519Keyring;
+use sp_keystore::Keystore;
+
+type VirtualOverseer = TestSubsystemContextHandle<DisputeCoordinatorMessage>;
+
+/// The validators of the simulated chain. Alice runs the dispute coordinator under test.
+const VALIDATORS: [Sr25519Keyring; 7] = [
+	Sr25519Keyring::Alice,
+	Sr25519Keyring::Bob,
+	Sr25519Keyring::Charlie,
+	Sr25519Keyring::Dave,
+	Sr25519Keyring::Eve,
+	Sr25519Keyring::Ferdie,
+	Sr25519Keyring::One,
+];
+
+const SLASHING_OFFENCE: [u8; 16] = *b"disputes:slashin";
+
+/// The test runtime, as seen by the dispute coordinator.
+struct Chain {
+	sim: Mutex<Simulation>,
+	code: ValidationCode,
+}
+
+impl Chain {
+	fn new() -> Self {
+		Self { sim: Mutex::new(Simulation::new(&VALIDATORS)), code: dummy_validation_code() }
+	}
+
+	fn sim(&self) -> MutexGuard<'_, Simulation> {
+		self.sim.lock().unwrap()
+	}
+}
+
+impl SimulatedRuntime for Chain {
+	fn session_index_for_child(&self) -> SessionIndex {
+		self.sim().session_index_for_child()
+	}
+
+	fn session_info(&self, session: SessionIndex) -> Option<SessionInfo> {
+		self.sim().session_info(session)
+	}
+
+	fn candidate_events(&self) -> Vec<CandidateEvent> {
+		self.sim().candidate_events()
+	}
+
+	fn on_chain_votes(&self) -> Option<ScrapedOnChainVotes> {
+		self.sim().on_chain_votes()
+	}
+
+	fn disabled_validators(&self) -> Vec<ValidatorIndex> {
+		self.sim().disabled_validators()
+	}
+
+	fn unapplied_slashes(&self) -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)> {
+		self.sim().unapplied_slashes()
+	}
+
+	fn key_ownership_proof(
+		&self,
+		validator_id: ValidatorId,
+	) -> Option<slashing::OpaqueKeyOwnershipProof> {
+		self.sim().key_ownership_proof(validator_id)
+	}
+
+	fn submit_report_dispute_lost(
+		&self,
+		dispute_proof: slashing::DisputeProof,
+		key_ownership_proof: slashing::OpaqueKeyOwnershipProof,
+	) -> Option<()> {
+		self.sim().submit_report_dispute_lost(dispute_proof, key_ownership_proof)
+	}
+
+	fn validation_code_by_hash(&self, hash: ValidationCodeHash) -> Option<ValidationCode> {
+		(hash == self.code.hash()).then(|| self.code.clone())
+	}
+}
+
+fn block_hash(number: BlockNumber) -> Hash {
+	Hash::repeat_byte(number as u8)
+}
+
+fn block_number(hash: Hash) -> BlockNumber {
+	hash.as_bytes()[0] as BlockNumber
+}
+
+/// Answer a request of the dispute coordinator. Every candidate fails validation.
+fn answer(chain: &Chain, msg: AllMessages) {
+	match msg {
+		AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, request)) => {
+			if let Err(request) = answer_runtime_api_request(chain, request) {
+				panic!("Unexpected runtime API request: {:?}", request)
+			}
+		},
+		AllMessages::ChainApi(ChainApiMessage::BlockNumber(hash, tx)) => {
+			let _ = tx.send(Ok(Some(block_number(hash))));
+		},
+		AllMessages::ChainApi(ChainApiMessage::FinalizedBlockNumber(tx)) => {
+			let _ = tx.send(Ok(0));
+		},
+		AllMessages::ChainApi(ChainApiMessage::Ancestors { hash, k, response_channel }) => {
+			let number = block_number(hash);
+			let ancestors = (number.saturating_sub(k as BlockNumber)..number)
+				.rev()
+				.map(block_hash)
+				.collect();
+			let _ = response_channel.send(Ok(ancestors));
+		},
+		AllMessages::AvailabilityRecovery(AvailabilityRecoveryMessage::RecoverAvailableData(
+			_,
+			_,
+			_,
+			_,
+			tx,
+		)) => {
+			let available_data = AvailableData {
+				pov: Arc::new(PoV { block_data: BlockData(Vec::new()) }),
+				validation_data: dummy_pvd(dummy_head_data(), 0),
+			};
+			let _ = tx.send(Ok(available_data));
+		},
+		AllMessages::CandidateValidation(CandidateValidationMessage::ValidateFromExhaustive {
+			response_sender,
+			..
+		}) => {
+			let _ = response_sender
+				.send(Ok(ValidationResult::Invalid(InvalidCandidate::InvalidOutputs)));
+		},
+		AllMessages::ApprovalVoting(ApprovalVotingMessage::GetApprovalSignaturesForCandidate(
+			_,
+			tx,
+		)) => {
+			let _ = tx.send(HashMap::new());
+		},
+		AllMessages::DisputeDistribution(_) | AllMessages::ChainSelection(_) => {},
+		msg => panic!("Unexpected message: {:?}", msg),
+	}
+}
+
+/// Answer the requests of the dispute coordinator until `done` resolves.
+async fn serve<T>(
+	virtual_overseer: &mut VirtualOverseer,
+	chain: &Chain,
+	done: impl Future<Output = T>,
+) -> T {
+	let done = done.fuse();
+	pin_mut!(done);
+	loop {
+		select! {
+			result = done => return result,
+			msg = virtual_overseer.recv().fuse() => answer(chain, msg),
+		}
+	}
+}
+
+async fn request<T>(
+	virtual_overseer: &mut VirtualOverseer,
+	chain: &Chain,
+	msg: impl FnOnce(oneshot::Sender<T>) -> DisputeCoordinatorMessage,
+) -> T {
+	let (tx, rx) = oneshot::channel();
+	virtual_overseer.send(FromOrchestra::Communication { msg: msg(tx) }).await;
+	serve(virtual_overseer, chain, rx).await.unwrap()
+}
+
+/// Activate the current block of the simulation and wait for the coordinator to process it.
+async fn activate_current_block(virtual_overseer: &mut VirtualOverseer, chain: &Chain) {
+	let number = chain.sim().block_number();
+	virtual_overseer
+		.send(FromOrchestra::Signal(OverseerSignal::ActiveLeaves(ActiveLeavesUpdate::start_work(
+			new_leaf(block_hash(number), number),
+		))))
+		.await;
+	// Messages are only handled once the leaf has been processed.
+	request(virtual_overseer, chain, DisputeCoordinatorMessage::RecentDisputes).await;
+}
+
+async fn candidate_votes(
+	virtual_overseer: &mut VirtualOverseer,
+	chain: &Chain,
+	session: SessionIndex,
+	candidate_hash: CandidateHash,
+) -> CandidateVotes {
+	let mut votes = request(virtual_overseer, chain, |tx| {
+		DisputeCoordinatorMessage::QueryCandidateVotes(vec![(session, candidate_hash)], tx)
+	})
+	.await;
+	assert_eq!(votes.len(), 1);
+	votes.pop().unwrap().2
+}
+
+#[test]
+fn backers_of_invalid_candidate_are_slashed() {
+	sp_tracing::try_init_simple();
+
+	let chain = Chain::new();
+	let keystore = LocalKeystore::in_memory();
+	keystore
+		.sr25519_generate_new(PARACHAIN_KEY_TYPE_ID, Some(&Sr25519Keyring::Alice.to_seed()))
+		.unwrap();
+	let db = kvdb_memorydb::create(1);
+	let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(db, &[0]);
+	let subsystem = DisputeCoordinatorSubsystem::new(
+		Arc::new(db),
+		Config { col_dispute_data: 0 },
+		Arc::new(keystore),
+		Default::default(),
+		false,
+	);
+	// Leave room for a signal and a message while the coordinator waits for answers.
+	let (ctx, mut virtual_overseer) = make_buffered_subsystem_context(TaskExecutor::new(), 2);
+	let SpawnedSubsystem { future: subsystem, .. } = subsystem.start(ctx);
+
+	let test = async move {
+		let virtual_overseer = &mut virtual_overseer;
+		let chain = &chain;
+
+		// The coordinator starts up in session 0 and learns about session 1, in which the invalid
+		// candidate gets backed.
+		activate_current_block(virtual_overseer, chain).await;
+		chain.sim().new_session();
+		activate_current_block(virtual_overseer, chain).await;
+
+		let session = chain.sim().session_index_for_child();
+		let session_info = chain.sim().session_info(session).unwrap();
+		let validators = TestValidators::new(VALIDATORS, session_info.validators.to_vec());
+		let index = |keyring| validators.index_of(keyring);
+
+		let mut candidate_receipt = dummy_candidate_receipt_v2(block_hash(1));
+		candidate_receipt.descriptor.set_validation_code_hash(chain.code.hash());
+		let candidate_hash = candidate_receipt.hash();
+
+		// Bob and Charlie back the candidate, four others dispute it.
+		let mut statements = Vec::new();
+		for backer in [Sr25519Keyring::Bob, Sr25519Keyring::Charlie] {
+			statements.push(validators.backing_vote(
+				index(backer),
+				candidate_hash,
+				session,
+				block_hash(1),
+			));
+		}
+		for disputer in &VALIDATORS[3..] {
+			statements.push(validators.explicit_vote(
+				index(*disputer),
+				candidate_hash,
+				session,
+				false,
+			));
+		}
+		let result =
+			request(virtual_overseer, chain, |tx| DisputeCoordinatorMessage::ImportStatements {
+				candidate_receipt,
+				session,
+				statements,
+				pending_confirmation: Some(tx),
+			})
+			.await;
+		assert_eq!(result, ImportStatementsResult::ValidImport);
+
+		// Alice participates, fails to validate the candidate and concludes the dispute.
+		let alice = index(Sr25519Keyring::Alice);
+		let mut votes = candidate_votes(virtual_overseer, chain, session, candidate_hash).await;
+		for _ in 0..100 {
+			if votes.invalid.contains_key(&alice) {
+				break
+			}
+			serve(virtual_overseer, chain, Delay::new(Duration::from_millis(50))).await;
+			votes = candidate_votes(virtual_overseer, chain, session, candidate_hash).await;
+		}
+		assert!(votes.invalid.contains_key(&alice), "Alice did not participate");
+		let disputes =
+			request(virtual_overseer, chain, DisputeCoordinatorMessage::RecentDisputes).await;
+		assert_matches!(
+			disputes.as_slice(),
+			[(s, h, DisputeStatus::ConcludedAgainst(_))] if *s == session && *h == candidate_hash
+		);
+
+		// The votes reach the chain after the session ended, so the runtime can't slash right
+		// away and waits for the nodes to report the backers.
+		chain.sim().new_session();
+		let initiated = chain.sim().submit_disputes(dispute_statement_set(session, &votes));
+		assert_eq!(initiated, Ok(vec![(session, candidate_hash)]));
+		assert_matches!(
+			chain.sim().unapplied_slashes().as_slice(),
+			[(s, h, pending)] if *s == session && *h == candidate_hash && pending.keys.len() == 2
+		);
+
+		activate_current_block(virtual_overseer, chain).await;
+
+		assert!(chain.sim().unapplied_slashes().is_empty());
+		let mut offences = chain.sim().offences();
+		offences.sort();
+		let mut expected = vec![
+			(SLASHING_OFFENCE, Sr25519Keyring::Bob.to_account_id()),
+			(SLASHING_OFFENCE, Sr25519Keyring::Charlie.to_account_id()),
+		];
+		expected.sort();
+		assert_eq!(offences, expected);
+
+		virtual_overseer.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+	};
+
+	let (result, ()) = futures::executor::block_on(future::join(subsystem, test));
+	result.unwrap();
+}
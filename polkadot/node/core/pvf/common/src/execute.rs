@@ -73,6 +73,8 @@ pub enum JobResponse {
 	Ok {
 		/// The result of parachain validation.
 		result_descriptor: ValidationResult,
+		/// The amount of fuel consumed, if the execution was metered.
+		fuel_consumed: Option<u64>,
 	},
 	/// A possibly transient runtime instantiation error happened during the execution; may be
 	/// retried with re-preparation
@@ -24,7 +24,7 @@ use polkadot_primitives::{
 use sc_executor_common::{
 	error::WasmError,
 	runtime_blob::RuntimeBlob,
-	wasm_runtime::{HeapAllocStrategy, WasmModule as _},
+	wasm_runtime::{HeapAllocStrategy, WasmInstance as _, WasmModule as _},
 };
 use sc_executor_wasmtime::{Config, DeterministicStackLimit, Semantics, WasmtimeRuntime};
 use sp_core::storage::{ChildInfo, TrackedStorageKey};
@@ -93,6 +93,9 @@ pub const DEFAULT_CONFIG: Config = Config {
 		wasm_simd: false,
 		wasm_bulk_memory: false,
 		wasm_multi_value: false,
+
+		// Deterministic metering is only enabled by the `PvfExecFuel` executor parameter.
+		consume_fuel: false,
 	},
 };
 
@@ -111,6 +114,25 @@ pub unsafe fn execute_artifact(
 	executor_params: &ExecutorParams,
 	params: &[u8],
 ) -> Result<Vec<u8>, ExecuteError> {
+	execute_artifact_metered(compiled_artifact_blob, executor_params, params, None).0
+}
+
+/// Executes the given PVF in the form of a compiled artifact, limiting the execution to
+/// `fuel_limit` units of fuel if given.
+///
+/// Returns the result of execution together with the amount of fuel consumed, which is only
+/// available if the executor parameters enable metering (see [`ExecutorParam::PvfExecFuel`]).
+/// Running out of fuel results in a trap.
+///
+/// # Safety
+///
+/// The same requirements as for [`execute_artifact`] apply.
+pub unsafe fn execute_artifact_metered(
+	compiled_artifact_blob: &[u8],
+	executor_params: &ExecutorParams,
+	params: &[u8],
+	fuel_limit: Option<u64>,
+) -> (Result<Vec<u8>, ExecuteError>, Option<u64>) {
 	let mut extensions = sp_externalities::Extensions::new();
 
 	extensions.register(sp_core::traits::ReadRuntimeVersionExt::new(ReadRuntimeVersion));
@@ -118,11 +140,26 @@ pub unsafe fn execute_artifact(
 	let mut ext = ValidationExternalities(extensions);
 
 	match sc_executor::with_externalities_safe(&mut ext, || {
-		let runtime = create_runtime_from_artifact_bytes(compiled_artifact_blob, executor_params)?;
-		runtime.new_instance()?.call("validate_block", params)
+		let runtime =
+			match create_runtime_from_artifact_bytes(compiled_artifact_blob, executor_params) {
+				Ok(runtime) => runtime,
+				Err(err) => return (Err(err.into()), None),
+			};
+		if !executor_params.pvf_exec_metered() {
+			let result = runtime.new_instance().and_then(|mut i| i.call("validate_block", params));
+			return (result, None)
+		}
+
+		match runtime.new_metered_instance(fuel_limit.unwrap_or(u64::MAX)) {
+			Ok(mut instance) => {
+				let result = instance.call("validate_block", params);
+				(result, instance.fuel_consumed())
+			},
+			Err(err) => (Err(err), None),
+		}
 	}) {
-		Ok(Ok(ok)) => Ok(ok),
-		Ok(Err(err)) | Err(err) => Err(err),
+		Ok(result) => result,
+		Err(err) => (Err(err), None),
 	}
 }
 
@@ -167,6 +204,7 @@ pub fn params_to_wasmtime_semantics(par: &ExecutorParams) -> (Semantics, Determi
 			ExecutorParam::StackLogicalMax(slm) => stack_limit.logical_max = *slm,
 			ExecutorParam::StackNativeMax(snm) => stack_limit.native_stack_max = *snm,
 			ExecutorParam::WasmExtBulkMemory => sem.wasm_bulk_memory = true,
+			ExecutorParam::PvfExecFuel(_, _) => sem.consume_fuel = true,
 			ExecutorParam::PrecheckingMaxMemory(_) |
 			ExecutorParam::PvfPrepTimeout(_, _) |
 			ExecutorParam::PvfExecTimeout(_, _) => (), /* Not used here */
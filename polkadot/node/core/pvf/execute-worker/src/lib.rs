@@ -20,7 +20,8 @@
 #![warn(missing_docs)]
 
 pub use polkadot_node_core_pvf_common::{
-	error::ExecuteError, executor_interface::execute_artifact,
+	error::ExecuteError,
+	executor_interface::{execute_artifact, execute_artifact_metered},
 };
 use polkadot_parachain_primitives::primitives::ValidationParams;
 
@@ -87,7 +88,9 @@ fn recv_execute_handshake(stream: &mut UnixStream) -> io::Result<Handshake> {
 	Ok(handshake)
 }
 
-fn recv_request(stream: &mut UnixStream) -> io::Result<(PersistedValidationData, PoV, Duration)> {
+fn recv_request(
+	stream: &mut UnixStream,
+) -> io::Result<(PersistedValidationData, PoV, Duration, Option<u64>)> {
 	let pvd = framed_recv_blocking(stream)?;
 	let pvd = PersistedValidationData::decode(&mut &pvd[..]).map_err(|_| {
 		io::Error::new(
@@ -111,7 +114,15 @@ fn recv_request(stream: &mut UnixStream) -> io::Result<(PersistedValidationData,
 			"execute pvf recv_request: failed to decode duration".to_string(),
 		)
 	})?;
-	Ok((pvd, pov, execution_timeout))
+
+	let fuel_limit = framed_recv_blocking(stream)?;
+	let fuel_limit = Option::<u64>::decode(&mut &fuel_limit[..]).map_err(|_| {
+		io::Error::new(
+			io::ErrorKind::Other,
+			"execute pvf recv_request: failed to decode fuel limit".to_string(),
+		)
+	})?;
+	Ok((pvd, pov, execution_timeout, fuel_limit))
 }
 
 /// Sends an error to the host and returns the original error wrapped in `io::Error`.
@@ -166,14 +177,15 @@ pub fn worker_entrypoint(
 			let execute_thread_stack_size = max_stack_size(&executor_params);
 
 			loop {
-				let (pvd, pov, execution_timeout) = recv_request(&mut stream).map_err(|e| {
-					map_and_send_err!(
-						e,
-						InternalValidationError::HostCommunication,
-						&mut stream,
-						worker_info
-					)
-				})?;
+				let (pvd, pov, execution_timeout, fuel_limit) =
+					recv_request(&mut stream).map_err(|e| {
+						map_and_send_err!(
+							e,
+							InternalValidationError::HostCommunication,
+							&mut stream,
+							worker_info
+						)
+					})?;
 				gum::debug!(
 					target: LOG_TARGET,
 					?worker_info,
@@ -253,6 +265,7 @@ pub fn worker_entrypoint(
 								&executor_params,
 								&params,
 								execution_timeout,
+								fuel_limit,
 								execute_thread_stack_size,
 								worker_info,
 								security_status.can_unshare_user_namespace_and_change_root,
@@ -269,6 +282,7 @@ pub fn worker_entrypoint(
 								&executor_params,
 								&params,
 								execution_timeout,
+								fuel_limit,
 								execute_thread_stack_size,
 								worker_info,
 								usage_before,
@@ -284,6 +298,7 @@ pub fn worker_entrypoint(
 							&executor_params,
 							&params,
 							execution_timeout,
+							fuel_limit,
 							execute_thread_stack_size,
 							worker_info,
 							usage_before,
@@ -308,15 +323,23 @@ fn validate_using_artifact(
 	compiled_artifact_blob: &[u8],
 	executor_params: &ExecutorParams,
 	params: &[u8],
+	fuel_limit: Option<u64>,
 ) -> JobResponse {
-	let descriptor_bytes = match unsafe {
+	let (result, fuel_consumed) = unsafe {
 		// SAFETY: this should be safe since the compiled artifact passed here comes from the
 		//         file created by the prepare workers. These files are obtained by calling
 		//         [`executor_interface::prepare`].
-		execute_artifact(compiled_artifact_blob, executor_params, params)
-	} {
+		execute_artifact_metered(compiled_artifact_blob, executor_params, params, fuel_limit)
+	};
+	let fuel_exhausted = matches!(
+		(fuel_limit, fuel_consumed),
+		(Some(limit), Some(consumed)) if consumed >= limit
+	);
+
+	let descriptor_bytes = match result {
 		Err(ExecuteError::RuntimeConstruction(wasmerr)) =>
 			return JobResponse::runtime_construction("execute", &wasmerr.to_string()),
+		Err(_) if fuel_exhausted => return JobResponse::format_invalid("execute", "out of fuel"),
 		Err(err) => return JobResponse::format_invalid("execute", &err.to_string()),
 		Ok(d) => d,
 	};
@@ -330,7 +353,7 @@ fn validate_using_artifact(
 		Ok(r) => r,
 	};
 
-	JobResponse::Ok { result_descriptor }
+	JobResponse::Ok { result_descriptor, fuel_consumed }
 }
 
 #[cfg(target_os = "linux")]
@@ -342,6 +365,7 @@ fn handle_clone(
 	executor_params: &Arc<ExecutorParams>,
 	params: &Arc<Vec<u8>>,
 	execution_timeout: Duration,
+	fuel_limit: Option<u64>,
 	execute_stack_size: usize,
 	worker_info: &WorkerInfo,
 	have_unshare_newuser: bool,
@@ -365,6 +389,7 @@ fn handle_clone(
 					Arc::clone(executor_params),
 					Arc::clone(params),
 					execution_timeout,
+					fuel_limit,
 					execute_stack_size,
 				)
 			}),
@@ -392,6 +417,7 @@ fn handle_fork(
 	executor_params: &Arc<ExecutorParams>,
 	params: &Arc<Vec<u8>>,
 	execution_timeout: Duration,
+	fuel_limit: Option<u64>,
 	execute_worker_stack_size: usize,
 	worker_info: &WorkerInfo,
 	usage_before: Usage,
@@ -408,6 +434,7 @@ fn handle_fork(
 			Arc::clone(executor_params),
 			Arc::clone(params),
 			execution_timeout,
+			fuel_limit,
 			execute_worker_stack_size,
 		),
 		Ok(ForkResult::Parent { child }) => handle_parent_process(
@@ -437,6 +464,7 @@ fn handle_child_process(
 	executor_params: Arc<ExecutorParams>,
 	params: Arc<Vec<u8>>,
 	execution_timeout: Duration,
+	fuel_limit: Option<u64>,
 	execute_thread_stack_size: usize,
 ) -> ! {
 	// SAFETY: this is an open and owned file descriptor at this point.
@@ -479,7 +507,9 @@ fn handle_child_process(
 
 	let execute_thread = thread::spawn_worker_thread_with_stack_size(
 		"execute thread",
-		move || validate_using_artifact(&compiled_artifact_blob, &executor_params, &params),
+		move || {
+			validate_using_artifact(&compiled_artifact_blob, &executor_params, &params, fuel_limit)
+		},
 		Arc::clone(&condvar),
 		WaitOutcome::Finished,
 		execute_thread_stack_size,
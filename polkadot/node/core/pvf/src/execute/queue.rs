@@ -19,7 +19,7 @@
 use super::worker_interface::{Error as WorkerInterfaceError, Response as WorkerInterfaceResponse};
 use crate::{
 	artifacts::{ArtifactId, ArtifactPathId},
-	host::ResultSender,
+	host::{ExecutionOutcome, ResultSender},
 	metrics::Metrics,
 	worker_interface::{IdleWorker, WorkerHandle},
 	InvalidCandidate, PossiblyInvalidError, ValidationError, LOG_TARGET,
@@ -447,7 +447,7 @@ async fn handle_job_finish(
 		Ok(WorkerInterfaceResponse {
 			worker_response:
 				WorkerResponse {
-					job_response: JobResponse::Ok { result_descriptor },
+					job_response: JobResponse::Ok { result_descriptor, fuel_consumed },
 					duration,
 					pov_size,
 				},
@@ -455,7 +455,13 @@ async fn handle_job_finish(
 		}) => {
 			// TODO: propagate the soft timeout
 
-			(Some(idle_worker), Ok(result_descriptor), Some(duration), None, Some(pov_size))
+			(
+				Some(idle_worker),
+				Ok(ExecutionOutcome { result: result_descriptor, fuel_consumed }),
+				Some(duration),
+				None,
+				Some(pov_size),
+			)
 		},
 		Ok(WorkerInterfaceResponse {
 			worker_response: WorkerResponse { job_response: JobResponse::InvalidCandidate(err), .. },
@@ -680,6 +686,7 @@ fn assign(queue: &mut Queue, worker: Worker, job: ExecuteJob) {
 		.metrics
 		.observe_execution_queued_time(job.waiting_since.elapsed().as_millis() as u32);
 	let execution_timer = queue.metrics.time_execution();
+	let fuel_limit = job.executor_params.pvf_exec_fuel(job.exec_kind.into());
 	queue.mux.push(
 		async move {
 			let _timer = execution_timer;
@@ -687,6 +694,7 @@ fn assign(queue: &mut Queue, worker: Worker, job: ExecuteJob) {
 				idle,
 				job.artifact.clone(),
 				job.exec_timeout,
+				fuel_limit,
 				job.pvd,
 				job.pov,
 			)
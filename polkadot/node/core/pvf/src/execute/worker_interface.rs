@@ -118,12 +118,16 @@ pub enum Error {
 /// Given the idle token of a worker and parameters of work, communicates with the worker and
 /// returns the outcome.
 ///
+/// If the executor parameters enable metering, `fuel_limit` bounds the execution in addition to
+/// `execution_timeout`.
+///
 /// NOTE: Not returning the idle worker token in `Outcome` will trigger the child process being
 /// killed, if it's still alive.
 pub async fn start_work(
 	worker: IdleWorker,
 	artifact: ArtifactPathId,
 	execution_timeout: Duration,
+	fuel_limit: Option<u64>,
 	pvd: Arc<PersistedValidationData>,
 	pov: Arc<PoV>,
 ) -> Result<Response, Error> {
@@ -139,16 +143,18 @@ pub async fn start_work(
 	);
 
 	with_worker_dir_setup(worker_dir, pid, &artifact.path, |worker_dir| async move {
-		send_request(&mut stream, pvd, pov, execution_timeout).await.map_err(|error| {
-			gum::warn!(
-				target: LOG_TARGET,
-				worker_pid = %pid,
-				validation_code_hash = ?artifact.id.code_hash,
-				"failed to send an execute request: {}",
-				error,
-			);
-			Error::InternalError(InternalValidationError::HostCommunication(error.to_string()))
-		})?;
+		send_request(&mut stream, pvd, pov, execution_timeout, fuel_limit)
+			.await
+			.map_err(|error| {
+				gum::warn!(
+					target: LOG_TARGET,
+					worker_pid = %pid,
+					validation_code_hash = ?artifact.id.code_hash,
+					"failed to send an execute request: {}",
+					error,
+				);
+				Error::InternalError(InternalValidationError::HostCommunication(error.to_string()))
+			})?;
 
 		// We use a generous timeout here. This is in addition to the one in the child process, in
 		// case the child stalls. We have a wall clock timeout here in the host, but a CPU timeout
@@ -291,10 +297,12 @@ async fn send_request(
 	pvd: Arc<PersistedValidationData>,
 	pov: Arc<PoV>,
 	execution_timeout: Duration,
+	fuel_limit: Option<u64>,
 ) -> io::Result<()> {
 	framed_send(stream, &pvd.encode()).await?;
 	framed_send(stream, &pov.encode()).await?;
-	framed_send(stream, &execution_timeout.encode()).await
+	framed_send(stream, &execution_timeout.encode()).await?;
+	framed_send(stream, &fuel_limit.encode()).await
 }
 
 async fn recv_result(stream: &mut UnixStream) -> io::Result<Result<WorkerResponse, WorkerError>> {
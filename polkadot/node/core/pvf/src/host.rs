@@ -68,8 +68,17 @@ pub const EXECUTE_BINARY_NAME: &str = "polkadot-execute-worker";
 /// The size of incoming message queue
 pub const HOST_MESSAGE_QUEUE_SIZE: usize = 10;
 
+/// The outcome of a successful PVF execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionOutcome {
+	/// The result returned by the PVF.
+	pub result: ValidationResult,
+	/// The amount of fuel consumed, if the execution was metered.
+	pub fuel_consumed: Option<u64>,
+}
+
 /// An alias to not spell the type for the oneshot sender for the PVF execution result.
-pub(crate) type ResultSender = oneshot::Sender<Result<ValidationResult, ValidationError>>;
+pub(crate) type ResultSender = oneshot::Sender<Result<ExecutionOutcome, ValidationError>>;
 
 /// Transmission end used for sending the PVF preparation result.
 pub(crate) type PrecheckResultSender = oneshot::Sender<PrecheckResult>;
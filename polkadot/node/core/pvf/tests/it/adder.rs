@@ -22,7 +22,9 @@ use polkadot_node_primitives::PoV;
 use polkadot_parachain_primitives::primitives::{
 	BlockData as GenericBlockData, HeadData as GenericHeadData,
 };
-use polkadot_primitives::PersistedValidationData;
+use polkadot_primitives::{
+	ExecutorParam, ExecutorParams, PersistedValidationData, PvfExecKind as RuntimePvfExecKind,
+};
 use sp_core::H256;
 use test_parachain_adder::{hash_state, BlockData, HeadData};
 
@@ -58,6 +60,64 @@ async fn execute_good_block_on_parent() {
 	assert_eq!(new_head.post_state, hash_state(512));
 }
 
+#[tokio::test]
+async fn execute_good_block_reports_consumed_fuel() {
+	let parent_head = HeadData { number: 0, parent_hash: [0; 32], post_state: hash_state(0) };
+	let block_data = BlockData { state: 0, add: 512 };
+	let pvd = PersistedValidationData {
+		parent_head: GenericHeadData(parent_head.encode()),
+		relay_parent_number: 1u32,
+		relay_parent_storage_root: H256::default(),
+		max_pov_size: 4096 * 1024,
+	};
+	let pov = PoV { block_data: GenericBlockData(block_data.encode()) };
+
+	let host = TestHost::new().await;
+
+	// Without metering no fuel is reported.
+	let unmetered = host
+		.execute_candidate(
+			test_parachain_adder::wasm_binary_unwrap(),
+			pvd.clone(),
+			pov.clone(),
+			Default::default(),
+			H256::default(),
+		)
+		.await
+		.unwrap();
+	assert_eq!(unmetered.fuel_consumed, None);
+
+	let executor_params = ExecutorParams::from(
+		&[ExecutorParam::PvfExecFuel(RuntimePvfExecKind::Backing, 10_000_000_000)][..],
+	);
+	let metered = host
+		.execute_candidate(
+			test_parachain_adder::wasm_binary_unwrap(),
+			pvd.clone(),
+			pov.clone(),
+			executor_params.clone(),
+			H256::default(),
+		)
+		.await
+		.unwrap();
+	assert_eq!(metered.result, unmetered.result);
+	let fuel_consumed = metered.fuel_consumed.expect("Execution is metered; qed");
+	assert!(fuel_consumed > 0);
+
+	// The consumed fuel is deterministic.
+	let again = host
+		.execute_candidate(
+			test_parachain_adder::wasm_binary_unwrap(),
+			pvd,
+			pov,
+			executor_params,
+			H256::default(),
+		)
+		.await
+		.unwrap();
+	assert_eq!(again.fuel_consumed, Some(fuel_consumed));
+}
+
 #[tokio::test]
 async fn execute_good_chain_on_parent() {
 	let mut parent_hash = [0; 32];
@@ -20,8 +20,8 @@ use assert_matches::assert_matches;
 #[cfg(all(feature = "ci-only-tests", target_os = "linux"))]
 use polkadot_node_core_pvf::SecurityStatus;
 use polkadot_node_core_pvf::{
-	start, testing::build_workers_and_get_paths, Config, InvalidCandidate, Metrics,
-	PossiblyInvalidError, PrepareError, PrepareJobKind, PvfPrepData, ValidationError,
+	start, testing::build_workers_and_get_paths, Config, ExecutionOutcome, InvalidCandidate,
+	Metrics, PossiblyInvalidError, PrepareError, PrepareJobKind, PvfPrepData, ValidationError,
 	ValidationHost, JOB_TIMEOUT_WALL_CLOCK_FACTOR,
 };
 use polkadot_node_primitives::{PoV, POV_BOMB_LIMIT, VALIDATION_CODE_BOMB_LIMIT};
@@ -110,6 +110,19 @@ impl TestHost {
 		executor_params: ExecutorParams,
 		relay_parent: Hash,
 	) -> Result<ValidationResult, ValidationError> {
+		self.execute_candidate(code, pvd, pov, executor_params, relay_parent)
+			.await
+			.map(|outcome| outcome.result)
+	}
+
+	async fn execute_candidate(
+		&self,
+		code: &[u8],
+		pvd: PersistedValidationData,
+		pov: PoV,
+		executor_params: ExecutorParams,
+		relay_parent: Hash,
+	) -> Result<ExecutionOutcome, ValidationError> {
 		let (result_tx, result_rx) = futures::channel::oneshot::channel();
 
 		self.host
@@ -191,6 +204,40 @@ async fn execute_job_terminates_on_timeout() {
 	assert!(duration < TEST_EXECUTION_TIMEOUT * JOB_TIMEOUT_WALL_CLOCK_FACTOR);
 }
 
+#[tokio::test]
+async fn execute_job_runs_out_of_fuel() {
+	let host = TestHost::new().await;
+	let pvd = PersistedValidationData {
+		parent_head: Default::default(),
+		relay_parent_number: 1u32,
+		relay_parent_storage_root: H256::default(),
+		max_pov_size: 4096 * 1024,
+	};
+	let pov = PoV { block_data: BlockData(Vec::new()) };
+	let executor_params = ExecutorParams::from(
+		&[ExecutorParam::PvfExecFuel(RuntimePvfExecKind::Backing, 1_000_000)][..],
+	);
+
+	let start = std::time::Instant::now();
+	let result = host
+		.validate_candidate(
+			test_parachain_halt::wasm_binary_unwrap(),
+			pvd,
+			pov,
+			executor_params,
+			H256::default(),
+		)
+		.await;
+
+	// The limit is hit deterministically, long before the wall-clock timeout.
+	match result {
+		Err(ValidationError::Invalid(InvalidCandidate::WorkerReportedInvalid(err))) =>
+			assert!(err.contains("out of fuel"), "{}", err),
+		r => panic!("{:?}", r),
+	}
+	assert!(std::time::Instant::now().duration_since(start) < TEST_EXECUTION_TIMEOUT);
+}
+
 #[cfg(feature = "ci-only-tests")]
 #[tokio::test]
 async fn ensure_parallel_execution() {
@@ -18,12 +18,7 @@
 
 #![warn(missing_docs)]
 
-use std::{
-	collections::{BTreeMap, VecDeque},
-	iter::Iterator,
-	num::NonZeroUsize,
-	pin::Pin,
-};
+use std::{collections::VecDeque, iter::Iterator, num::NonZeroUsize, pin::Pin};
 
 use futures::{
 	channel::oneshot,
@@ -42,8 +37,9 @@ use task::{
 };
 
 use polkadot_erasure_coding::{
-	branches, obtain_chunks_v1, recovery_threshold, systematic_recovery_threshold,
-	Error as ErasureEncodingError,
+	branches, recovery_threshold,
+	streaming::{self, StreamingReconstructor},
+	systematic_recovery_threshold, Error as ErasureEncodingError,
 };
 use task::{RecoveryParams, RecoveryStrategy, RecoveryTask};
 
@@ -67,7 +63,7 @@ use polkadot_node_subsystem_util::{
 };
 use polkadot_primitives::{
 	node_features, vstaging::CandidateReceiptV2 as CandidateReceipt, BlockNumber, CandidateHash,
-	ChunkIndex, CoreIndex, GroupIndex, Hash, SessionIndex, ValidatorIndex,
+	CoreIndex, GroupIndex, Hash, SessionIndex, ValidatorIndex,
 };
 
 mod error;
@@ -151,10 +147,9 @@ enum PostRecoveryCheck {
 
 /// Expensive erasure coding computations that we want to run on a blocking thread.
 enum ErasureTask {
-	/// Reconstructs `AvailableData` from chunks given `n_validators`.
+	/// Reconstructs `AvailableData` from the chunks that were fed into the reconstructor.
 	Reconstruct(
-		usize,
-		BTreeMap<ChunkIndex, Vec<u8>>,
+		StreamingReconstructor,
 		oneshot::Sender<std::result::Result<AvailableData, ErasureEncodingError>>,
 	),
 	/// Re-encode `AvailableData` into erasure chunks in order to verify the provided root hash of
@@ -185,7 +180,7 @@ fn reconstructed_data_matches_root(
 ) -> bool {
 	let _timer = metrics.time_reencode_chunks();
 
-	let chunks = match obtain_chunks_v1(n_validators, data) {
+	let chunks = match streaming::obtain_chunks_v1(n_validators, data) {
 		Ok(chunks) => chunks,
 		Err(e) => {
 			gum::debug!(
@@ -880,17 +875,8 @@ async fn erasure_task_thread(
 ) {
 	loop {
 		match ingress.next().await {
-			Some(ErasureTask::Reconstruct(n_validators, chunks, sender)) => {
-				let _ = sender.send(polkadot_erasure_coding::reconstruct_v1(
-					n_validators,
-					chunks.iter().map(|(c_index, chunk)| {
-						(
-							&chunk[..],
-							usize::try_from(c_index.0)
-								.expect("usize is at least u32 bytes on all modern targets."),
-						)
-					}),
-				));
+			Some(ErasureTask::Reconstruct(reconstructor, sender)) => {
+				let _ = sender.send(reconstructor.reconstruct());
 			},
 			Some(ErasureTask::Reencode(n_validators, root, available_data, sender)) => {
 				let metrics = metrics.clone();
@@ -115,18 +115,12 @@ impl FetchChunks {
 		// Send request to reconstruct available data from chunks.
 		let (avilable_data_tx, available_data_rx) = oneshot::channel();
 
+		// Safe to leave no chunks in place, as we're stopping the recovery process if this
+		// reconstruct fails.
+		let reconstructor = state.take_reconstructor().ok_or(RecoveryError::Invalid)?;
 		let mut erasure_task_tx = common_params.erasure_task_tx.clone();
 		erasure_task_tx
-			.send(ErasureTask::Reconstruct(
-				common_params.n_validators,
-				// Safe to leave an empty vec in place, as we're stopping the recovery process if
-				// this reconstruct fails.
-				std::mem::take(&mut state.received_chunks)
-					.into_iter()
-					.map(|(c_index, chunk)| (c_index, chunk.chunk))
-					.collect(),
-				avilable_data_tx,
-			))
+			.send(ErasureTask::Reconstruct(reconstructor, avilable_data_tx))
 			.await
 			.map_err(|_| RecoveryError::ChannelClosed)?;
 
@@ -191,7 +185,7 @@ impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender>
 		// No need to query the validators that have the chunks we already received or that we know
 		// don't have the data from previous strategies.
 		self.validators.retain(|v_index| {
-			!state.received_chunks.values().any(|c| v_index == &c.validator_index) &&
+			!state.received_chunks.values().any(|received| v_index == received) &&
 				state.can_retry_request(
 					&(common_params.validator_authority_keys[v_index.0 as usize].clone(), *v_index),
 					REGULAR_CHUNKS_REQ_RETRY_LIMIT,
@@ -31,7 +31,7 @@ use crate::{
 
 use codec::Decode;
 use futures::{channel::oneshot, SinkExt};
-use polkadot_erasure_coding::branch_hash;
+use polkadot_erasure_coding::{branch_hash, streaming::StreamingReconstructor};
 #[cfg(not(test))]
 use polkadot_node_network_protocol::request_response::CHUNK_REQUEST_TIMEOUT;
 use polkadot_node_network_protocol::request_response::{
@@ -190,24 +190,17 @@ enum ErrorRecord {
 	Fatal,
 }
 
-/// Helper struct used for the `received_chunks` mapping.
-/// Compared to `ErasureChunk`, it doesn't need to hold the `ChunkIndex` (because it's the key used
-/// for the map) and proof, but needs to hold the `ValidatorIndex` instead.
-struct Chunk {
-	/// The erasure-encoded chunk of data belonging to the candidate block.
-	chunk: Vec<u8>,
-	/// The validator index that corresponds to this chunk. Not always the same as the chunk index.
-	validator_index: ValidatorIndex,
-}
-
 /// Intermediate/common data that must be passed between `RecoveryStrategy`s belonging to the
 /// same `RecoveryTask`.
 pub struct State {
-	/// Chunks received so far.
-	/// This MUST be a `BTreeMap` in order for systematic recovery to work (the algorithm assumes
-	/// that chunks are ordered by their index). If we ever switch this to some non-ordered
-	/// collection, we need to add a sort step to the systematic recovery.
-	received_chunks: BTreeMap<ChunkIndex, Chunk>,
+	/// The indices of the chunks received so far, together with the validator index that
+	/// corresponds to each of them. Not always the same as the chunk index.
+	/// This is a `BTreeMap`, so that the systematic chunks can be counted as a range.
+	received_chunks: BTreeMap<ChunkIndex, ValidatorIndex>,
+
+	/// The chunks received so far, which are fed into the reconstructor as they arrive. Created
+	/// with the first chunk.
+	reconstructor: Option<StreamingReconstructor>,
 
 	/// A record of errors returned when requesting a chunk from a validator.
 	recorded_errors: HashMap<(AuthorityDiscoveryId, ValidatorIndex), ErrorRecord>,
@@ -215,11 +208,55 @@ pub struct State {
 
 impl State {
 	pub fn new() -> Self {
-		Self { received_chunks: BTreeMap::new(), recorded_errors: HashMap::new() }
+		Self {
+			received_chunks: BTreeMap::new(),
+			reconstructor: None,
+			recorded_errors: HashMap::new(),
+		}
+	}
+
+	fn insert_chunk(
+		&mut self,
+		params: &RecoveryParams,
+		chunk: ErasureChunk,
+		validator_index: ValidatorIndex,
+	) {
+		if self.reconstructor.is_none() {
+			match StreamingReconstructor::new(params.n_validators) {
+				Ok(reconstructor) => self.reconstructor = Some(reconstructor),
+				Err(err) => {
+					gum::debug!(
+						target: LOG_TARGET,
+						candidate_hash = ?params.candidate_hash,
+						?err,
+						"Failed to create the reconstructor",
+					);
+					return
+				},
+			}
+		}
+		let Some(reconstructor) = self.reconstructor.as_mut() else { return };
+
+		let c_index = usize::try_from(chunk.index.0)
+			.expect("usize is at least u32 bytes on all modern targets.");
+		match reconstructor.push(c_index, chunk.chunk) {
+			Ok(_) => {
+				self.received_chunks.insert(chunk.index, validator_index);
+			},
+			Err(err) => gum::debug!(
+				target: LOG_TARGET,
+				candidate_hash = ?params.candidate_hash,
+				chunk_index = ?chunk.index,
+				?err,
+				"Chunk does not fit the ones received before",
+			),
+		}
 	}
 
-	fn insert_chunk(&mut self, chunk_index: ChunkIndex, chunk: Chunk) {
-		self.received_chunks.insert(chunk_index, chunk);
+	/// Take the reconstructor holding all the chunks received so far, forgetting about them.
+	fn take_reconstructor(&mut self) -> Option<StreamingReconstructor> {
+		self.received_chunks.clear();
+		self.reconstructor.take()
 	}
 
 	fn chunk_count(&self) -> usize {
@@ -298,10 +335,7 @@ impl State {
 							chunk_index = ?chunk.index,
 							"Found valid chunk on disk"
 						);
-						self.insert_chunk(
-							chunk.index,
-							Chunk { chunk: chunk.chunk, validator_index },
-						);
+						self.insert_chunk(params, chunk, validator_index);
 					} else {
 						gum::error!(
 							target: LOG_TARGET,
@@ -502,10 +536,7 @@ impl State {
 									?validator_index,
 									"Received valid chunk",
 								);
-								self.insert_chunk(
-									chunk.index,
-									Chunk { chunk: chunk.chunk, validator_index },
-								);
+								self.insert_chunk(params, chunk, validator_index);
 							} else {
 								metrics.on_chunk_request_invalid(strategy_type);
 								error_count += 1;
@@ -101,22 +101,12 @@ impl FetchSystematicChunks {
 		let strategy_type = RecoveryStrategy::<Sender>::strategy_type(self);
 		let recovery_duration = common_params.metrics.time_erasure_recovery(strategy_type);
 		let reconstruct_duration = common_params.metrics.time_erasure_reconstruct(strategy_type);
-		let chunks = state
-			.received_chunks
-			.range(
-				ChunkIndex(0)..
-					ChunkIndex(
-						u32::try_from(self.threshold)
-							.expect("validator count should not exceed u32"),
-					),
-			)
-			.map(|(_, chunk)| chunk.chunk.clone())
-			.collect::<Vec<_>>();
-
-		let available_data = polkadot_erasure_coding::reconstruct_from_systematic_v1(
-			common_params.n_validators,
-			chunks,
-		);
+		// All the systematic chunks are present, hence the reconstructor only reassembles them.
+		// The chunks are kept in place for the next strategies.
+		let available_data = match state.reconstructor.as_ref() {
+			Some(reconstructor) => reconstructor.reconstruct(),
+			None => Err(polkadot_erasure_coding::Error::NotEnoughChunks),
+		};
 
 		match available_data {
 			Ok(data) => {
@@ -44,6 +44,10 @@ mod error;
 mod collator_side;
 mod validator_side;
 
+pub mod rpc;
+
+pub use validator_side::{CollatorScore, ReputationConfig, ReputationDb};
+
 const LOG_TARGET: &'static str = "parachain::collator-protocol";
 
 /// A collator eviction policy - how fast to evict collators which are inactive.
@@ -74,6 +78,8 @@ pub enum ProtocolSide {
 		eviction_policy: CollatorEvictionPolicy,
 		/// Prometheus metrics for validators.
 		metrics: validator_side::Metrics,
+		/// Storage of the collator reputation.
+		reputation_db: ReputationDb,
 	},
 	/// Collators operate on a parachain.
 	Collator {
@@ -110,8 +116,8 @@ impl CollatorProtocolSubsystem {
 impl<Context> CollatorProtocolSubsystem {
 	fn start(self, ctx: Context) -> SpawnedSubsystem {
 		let future = match self.protocol_side {
-			ProtocolSide::Validator { keystore, eviction_policy, metrics } =>
-				validator_side::run(ctx, keystore, eviction_policy, metrics)
+			ProtocolSide::Validator { keystore, eviction_policy, metrics, reputation_db } =>
+				validator_side::run(ctx, keystore, eviction_policy, metrics, reputation_db)
 					.map_err(|e| SubsystemError::with_origin("collator-protocol", e))
 					.boxed(),
 			ProtocolSide::Collator { peer_id, collator_pair, request_receiver_v2, metrics } =>
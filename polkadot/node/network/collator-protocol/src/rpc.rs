@@ -0,0 +1,95 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC API for inspecting the collator reputation of a validator.
+//!
+//! The answers reflect the reputation as last written to the database, which happens
+//! periodically while the node is running.
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use polkadot_primitives::{CollatorId, Id as ParaId};
+use serde::{Deserialize, Serialize};
+
+use crate::{CollatorScore, ReputationDb};
+
+/// Error code returned when reading the database failed.
+const DATABASE_ERROR: i32 = 9000;
+
+/// Reputation of a single collator of a para.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreboardEntry {
+	/// The collator.
+	pub collator: CollatorId,
+	/// The score fetches from this collator are prioritized by.
+	pub score: i32,
+	/// Number of collations by this collator which got seconded.
+	pub seconded: u32,
+	/// Number of fetches from this collator which failed because of the collator.
+	pub failed_fetches: u32,
+	/// Number of collations by this collator which were invalid.
+	pub invalid: u32,
+}
+
+impl From<(CollatorId, CollatorScore)> for ScoreboardEntry {
+	fn from((collator, score): (CollatorId, CollatorScore)) -> Self {
+		let CollatorScore { score, seconded, failed_fetches, invalid } = score;
+		Self { collator, score, seconded, failed_fetches, invalid }
+	}
+}
+
+/// Provides RPC methods for inspecting the collator reputation.
+#[rpc(client, server)]
+pub trait CollatorReputationApi {
+	/// Returns the paras collator reputation is known for.
+	#[method(name = "collatorReputation_paras")]
+	fn paras(&self) -> RpcResult<Vec<ParaId>>;
+
+	/// Returns the reputation of the collators of a para, best collators first.
+	#[method(name = "collatorReputation_scoreboard")]
+	fn scoreboard(&self, para_id: ParaId) -> RpcResult<Vec<ScoreboardEntry>>;
+}
+
+/// Implements the [`CollatorReputationApiServer`] RPC trait.
+pub struct CollatorReputation {
+	db: ReputationDb,
+}
+
+impl CollatorReputation {
+	/// Create a new instance serving the reputation stored in `db`.
+	pub fn new(db: ReputationDb) -> Self {
+		Self { db }
+	}
+}
+
+impl CollatorReputationApiServer for CollatorReputation {
+	fn paras(&self) -> RpcResult<Vec<ParaId>> {
+		self.db.paras().map_err(database_error)
+	}
+
+	fn scoreboard(&self, para_id: ParaId) -> RpcResult<Vec<ScoreboardEntry>> {
+		let scores = self.db.scoreboard(para_id).map_err(database_error)?;
+		Ok(scores.into_iter().map(Into::into).collect())
+	}
+}
+
+fn database_error(err: std::io::Error) -> ErrorObjectOwned {
+	ErrorObject::owned(DATABASE_ERROR, err.to_string(), None::<()>)
+}
@@ -35,6 +35,7 @@
 //!    └─▶Waiting ─▶ Fetching ─▶ WaitingOnValidation
 
 use std::{
+	cmp::Reverse,
 	collections::{BTreeMap, VecDeque},
 	future::Future,
 	pin::Pin,
@@ -287,9 +288,14 @@ impl Collations {
 	///
 	/// `unfulfilled_claim_queue_entries` represents all claim queue entries which are still not
 	/// fulfilled.
+	///
+	/// Among the advertisements for the same para, the one from the collator with the highest
+	/// `collator_score` is picked. Advertisements from equally scored collators are picked in the
+	/// order they were received.
 	pub(super) fn pick_a_collation_to_fetch(
 		&mut self,
 		unfulfilled_claim_queue_entries: Vec<ParaId>,
+		collator_score: impl Fn(ParaId, &CollatorId) -> i32,
 	) -> Option<(PendingCollation, CollatorId)> {
 		gum::trace!(
 			target: LOG_TARGET,
@@ -300,11 +306,15 @@ impl Collations {
 
 		for assignment in unfulfilled_claim_queue_entries {
 			// if there is an unfulfilled assignment - return it
-			if let Some(collation) = self
-				.waiting_queue
-				.get_mut(&assignment)
-				.and_then(|collations| collations.pop_front())
-			{
+			let Some(collations) = self.waiting_queue.get_mut(&assignment) else { continue };
+			let best = collations
+				.iter()
+				.enumerate()
+				.max_by_key(|(index, (_, collator_id))| {
+					(collator_score(assignment, collator_id), Reverse(*index))
+				})
+				.map(|(index, _)| index);
+			if let Some(collation) = best.and_then(|index| collations.remove(index)) {
 				return Some(collation)
 			}
 		}
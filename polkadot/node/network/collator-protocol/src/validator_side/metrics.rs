@@ -15,6 +15,7 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use polkadot_node_subsystem_util::metrics::{self, prometheus};
+use polkadot_primitives::Id as ParaId;
 
 #[derive(Clone, Default)]
 pub struct Metrics(Option<MetricsInner>);
@@ -65,6 +66,27 @@ impl Metrics {
 			.as_ref()
 			.map(|metrics| metrics.request_unblocked_collations.start_timer())
 	}
+
+	/// Note the number of collators of a para by their standing in the collator reputation.
+	pub fn note_collator_reputation(
+		&self,
+		para_id: ParaId,
+		reliable: usize,
+		neutral: usize,
+		unreliable: usize,
+	) {
+		if let Some(metrics) = &self.0 {
+			let para_id = para_id.to_string();
+			for (standing, count) in
+				[("reliable", reliable), ("neutral", neutral), ("unreliable", unreliable)]
+			{
+				metrics
+					.collator_reputation
+					.with_label_values(&[&para_id, standing])
+					.set(count as u64);
+			}
+		}
+	}
 }
 
 #[derive(Clone)]
@@ -75,6 +97,7 @@ struct MetricsInner {
 	collator_peer_count: prometheus::Gauge<prometheus::U64>,
 	collation_request_duration: prometheus::Histogram,
 	request_unblocked_collations: prometheus::Histogram,
+	collator_reputation: prometheus::GaugeVec<prometheus::U64>,
 }
 
 impl metrics::Metrics for Metrics {
@@ -135,6 +158,16 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			collator_reputation: prometheus::register(
+				prometheus::GaugeVec::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_collator_protocol_validator_collator_reputation",
+						"Number of collators known per para, by their standing in the collator reputation",
+					),
+					&["para_id", "standing"],
+				)?,
+				registry,
+			)?,
 		};
 
 		Ok(Metrics(Some(metrics)))
@@ -63,13 +63,14 @@ use crate::error::{Error, FetchError, Result, SecondingError};
 
 use self::collation::BlockedCollationId;
 
-use self::claim_queue_state::ClaimQueueState;
+use self::{claim_queue_state::ClaimQueueState, reputation::CollatorReputation};
 
 use super::{modify_reputation, tick_stream, LOG_TARGET};
 
 mod claim_queue_state;
 mod collation;
 mod metrics;
+mod reputation;
 
 use collation::{
 	fetched_collation_sanity_check, CollationEvent, CollationFetchError, CollationFetchRequest,
@@ -81,6 +82,7 @@ use collation::{
 mod tests;
 
 pub use metrics::Metrics;
+pub use reputation::{CollatorScore, ReputationConfig, ReputationDb};
 
 const COST_UNEXPECTED_MESSAGE: Rep = Rep::CostMinor("An unexpected message");
 /// Message could not be decoded properly.
@@ -409,6 +411,9 @@ struct State {
 
 	/// Aggregated reputation change
 	reputation: ReputationAggregator,
+
+	/// Reputation of collators per para, kept across restarts.
+	collator_reputation: CollatorReputation,
 }
 
 impl State {
@@ -1236,6 +1241,9 @@ where
 			parent_head_data_hash,
 		});
 
+	// Needed to prioritize the advertisement in case it has to wait.
+	state.collator_reputation.load_para(para_id);
+
 	let collations = &mut per_relay_parent.collations;
 	let pending_collation =
 		PendingCollation::new(relay_parent, para_id, &peer_id, prospective_candidate);
@@ -1521,6 +1529,7 @@ async fn process_msg<Context>(
 					collator_id.clone(),
 				)
 				.await;
+				state.collator_reputation.note_seconded(para_id, &collator_id);
 				if let Some(peer_data) = state.peer_data.get(&peer_id) {
 					notify_collation_seconded(
 						ctx.sender(),
@@ -1592,6 +1601,9 @@ async fn process_msg<Context>(
 
 			report_collator(&mut state.reputation, ctx.sender(), &state.peer_data, id.clone())
 				.await;
+			state
+				.collator_reputation
+				.note_invalid(candidate_receipt.descriptor.para_id(), &id);
 
 			dequeue_next_collation_and_fetch(ctx, state, parent, (id, Some(candidate_hash))).await;
 		},
@@ -1605,6 +1617,7 @@ pub(crate) async fn run<Context>(
 	keystore: KeystorePtr,
 	eviction_policy: crate::CollatorEvictionPolicy,
 	metrics: Metrics,
+	reputation_db: ReputationDb,
 ) -> std::result::Result<(), crate::error::FatalError> {
 	run_inner(
 		ctx,
@@ -1613,6 +1626,7 @@ pub(crate) async fn run<Context>(
 		metrics,
 		ReputationAggregator::default(),
 		REPUTATION_CHANGE_INTERVAL,
+		CollatorReputation::new(reputation_db),
 	)
 	.await
 }
@@ -1625,11 +1639,12 @@ async fn run_inner<Context>(
 	metrics: Metrics,
 	reputation: ReputationAggregator,
 	reputation_interval: Duration,
+	collator_reputation: CollatorReputation,
 ) -> std::result::Result<(), crate::error::FatalError> {
 	let new_reputation_delay = || futures_timer::Delay::new(reputation_interval).fuse();
 	let mut reputation_delay = new_reputation_delay();
 
-	let mut state = State { metrics, reputation, ..Default::default() };
+	let mut state = State { metrics, reputation, collator_reputation, ..Default::default() };
 
 	let next_inactivity_stream = tick_stream(ACTIVITY_POLL);
 	futures::pin_mut!(next_inactivity_stream);
@@ -1641,6 +1656,7 @@ async fn run_inner<Context>(
 		select! {
 			_ = reputation_delay => {
 				state.reputation.send(ctx.sender()).await;
+				state.collator_reputation.flush(&state.metrics);
 				reputation_delay = new_reputation_delay();
 			},
 			res = ctx.recv().fuse() => {
@@ -1748,6 +1764,8 @@ async fn run_inner<Context>(
 		}
 	}
 
+	state.collator_reputation.flush(&state.metrics);
+
 	Ok(())
 }
 
@@ -1989,6 +2007,7 @@ async fn handle_collation_fetch_response(
 		response;
 	// Remove the cancellation handle, as the future already completed.
 	state.collation_requests_cancel_handles.remove(&pending_collation);
+	let (para_id, fetched_from) = (pending_collation.para_id, collator_id.clone());
 
 	let response = match response {
 		Err(CollationFetchError::Cancelled) => {
@@ -2019,6 +2038,7 @@ async fn handle_collation_fetch_response(
 				err = ?err,
 				"Collator provided response that could not be decoded"
 			);
+			state.collator_reputation.note_failed_fetch(para_id, &fetched_from);
 			Err(Some((pending_collation.peer_id, COST_CORRUPTED_MESSAGE)))
 		},
 		Err(err) if err.is_timed_out() => {
@@ -2048,6 +2068,8 @@ async fn handle_collation_fetch_response(
 			// sensible. In theory this could be exploited, by DoSing this node,
 			// which would result in reduced reputation for proper nodes, but the
 			// same can happen for penalties on timeouts, which we also have.
+			// For the same reason, network failures are not recorded in the
+			// persistent score of the collator.
 			Err(Some((pending_collation.peer_id, COST_NETWORK_ERROR)))
 		},
 		Err(RequestError::Canceled(err)) => {
@@ -2077,6 +2099,7 @@ async fn handle_collation_fetch_response(
 				"Got wrong para ID for requested collation."
 			);
 
+			state.collator_reputation.note_failed_fetch(para_id, &fetched_from);
 			Err(Some((pending_collation.peer_id, COST_WRONG_PARA)))
 		},
 		Ok(request_v1::CollationFetchingResponse::Collation(candidate_receipt, pov)) => {
@@ -2227,7 +2250,12 @@ fn get_next_collation_to_fetch(
 		}
 	}
 	rp_state.collations.status.back_to_waiting();
-	rp_state.collations.pick_a_collation_to_fetch(unfulfilled_entries)
+	let collator_reputation = &state.collator_reputation;
+	rp_state
+		.collations
+		.pick_a_collation_to_fetch(unfulfilled_entries, |para_id, collator_id| {
+			collator_reputation.score(para_id, collator_id)
+		})
 }
 
 // Sanity check the candidate descriptor version.
@@ -0,0 +1,365 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Collator reputation kept across restarts.
+//!
+//! Peer reputation as tracked by the network is bound to a `PeerId` and lost on restart. In
+//! addition to that, every collator gets a score per para, based on the outcome of the collations
+//! we fetched from it. The scores of the paras we are assigned to are cached in memory and
+//! written to the parachains database periodically.
+//!
+//! When several advertisements for the same para are waiting to be fetched, the one from the
+//! collator with the highest score is fetched first.
+
+use std::{
+	collections::{HashMap, HashSet},
+	io,
+	sync::Arc,
+};
+
+use codec::{Decode, Encode};
+use polkadot_node_subsystem_util::database::{DBTransaction, Database};
+use polkadot_primitives::{CollatorId, Id as ParaId};
+
+use super::{Metrics, LOG_TARGET};
+
+const REPUTATION_PREFIX: &[u8; 19] = b"collator_reputation";
+
+/// Scores are kept within `[-MAX_SCORE, MAX_SCORE]`, so a collator can recover from past
+/// misbehavior and a good track record does not cover up for misbehavior forever.
+const MAX_SCORE: i32 = 1_000;
+/// Score gained for a collation which got seconded.
+const BENEFIT_SECONDED: i32 = 10;
+/// Score lost for a fetch which failed because of the collator.
+const COST_FAILED_FETCH: i32 = 20;
+/// Score lost for an invalid collation.
+const COST_INVALID: i32 = 100;
+
+/// Maximum number of collators tracked per para.
+///
+/// When exceeded, the collator with the score closest to neutral is forgotten, as it is the one
+/// we know least about.
+const MAX_COLLATORS_PER_PARA: usize = 100;
+
+/// Configuration of the collator reputation storage.
+#[derive(Debug, Clone, Copy)]
+pub struct ReputationConfig {
+	/// The column of the parachains database holding collator reputation.
+	pub col_reputation_data: u32,
+}
+
+/// Track record of a collator for a single para.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
+pub struct CollatorScore {
+	/// The score fetches are prioritized by. Positive for reliable collators, negative for
+	/// collators which misbehaved.
+	pub score: i32,
+	/// Number of collations by this collator which got seconded.
+	pub seconded: u32,
+	/// Number of fetches from this collator which failed because of the collator.
+	pub failed_fetches: u32,
+	/// Number of collations by this collator which were invalid.
+	pub invalid: u32,
+}
+
+impl CollatorScore {
+	fn adjust(&mut self, delta: i32) {
+		self.score = self.score.saturating_add(delta).clamp(-MAX_SCORE, MAX_SCORE);
+	}
+}
+
+/// Access to the collator reputation stored in the parachains database.
+#[derive(Clone)]
+pub struct ReputationDb {
+	db: Arc<dyn Database>,
+	config: ReputationConfig,
+}
+
+impl ReputationDb {
+	/// Create a new instance using the given database and configuration.
+	pub fn new(db: Arc<dyn Database>, config: ReputationConfig) -> Self {
+		Self { db, config }
+	}
+
+	/// All paras with stored collator reputation, in ascending order.
+	pub fn paras(&self) -> io::Result<Vec<ParaId>> {
+		self.db
+			.iter_with_prefix(self.config.col_reputation_data, REPUTATION_PREFIX)
+			.map(|entry| {
+				let (key, _) = entry?;
+				ParaId::decode(&mut &key[REPUTATION_PREFIX.len()..]).map_err(invalid_data)
+			})
+			.collect()
+	}
+
+	/// The stored scoreboard of a para, best collators first.
+	pub fn scoreboard(&self, para_id: ParaId) -> io::Result<Vec<(CollatorId, CollatorScore)>> {
+		let mut scores: Vec<_> = self.load(para_id)?.into_iter().collect();
+		scores.sort_by(|(a_id, a), (b_id, b)| b.score.cmp(&a.score).then_with(|| a_id.cmp(b_id)));
+		Ok(scores)
+	}
+
+	fn load(&self, para_id: ParaId) -> io::Result<HashMap<CollatorId, CollatorScore>> {
+		match self.db.get(self.config.col_reputation_data, &reputation_key(para_id))? {
+			None => Ok(HashMap::new()),
+			Some(raw) => Vec::<(CollatorId, CollatorScore)>::decode(&mut &raw[..])
+				.map(|scores| scores.into_iter().collect())
+				.map_err(invalid_data),
+		}
+	}
+
+	fn write<'a>(
+		&self,
+		paras: impl IntoIterator<Item = (ParaId, &'a HashMap<CollatorId, CollatorScore>)>,
+	) -> io::Result<()> {
+		let mut tx = DBTransaction::new();
+		for (para_id, scores) in paras {
+			let scores: Vec<_> = scores.iter().collect();
+			tx.put_vec(self.config.col_reputation_data, &reputation_key(para_id), scores.encode());
+		}
+		self.db.write(tx)
+	}
+}
+
+fn reputation_key(para_id: ParaId) -> Vec<u8> {
+	(REPUTATION_PREFIX, para_id).encode()
+}
+
+fn invalid_data(err: codec::Error) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Collator reputation of the validator side, backed by the database if one was given.
+#[derive(Default)]
+pub(super) struct CollatorReputation {
+	db: Option<ReputationDb>,
+	scores: HashMap<ParaId, HashMap<CollatorId, CollatorScore>>,
+	/// Paras with changes which were not written to the database yet.
+	dirty: HashSet<ParaId>,
+}
+
+impl CollatorReputation {
+	pub(super) fn new(db: ReputationDb) -> Self {
+		Self { db: Some(db), ..Default::default() }
+	}
+
+	/// Make sure the scores of a para are available, loading them from the database if needed.
+	///
+	/// Scores of paras which were not loaded are treated as neutral.
+	pub(super) fn load_para(&mut self, para_id: ParaId) {
+		if self.scores.contains_key(&para_id) {
+			return
+		}
+
+		let scores = match self.db.as_ref().map(|db| db.load(para_id)).transpose() {
+			Ok(scores) => scores.unwrap_or_default(),
+			Err(err) => {
+				gum::warn!(
+					target: LOG_TARGET,
+					?para_id,
+					?err,
+					"Failed to load collator reputation, starting from scratch",
+				);
+				HashMap::new()
+			},
+		};
+		self.scores.insert(para_id, scores);
+	}
+
+	/// Current score of a collator for the given para.
+	pub(super) fn score(&self, para_id: ParaId, collator_id: &CollatorId) -> i32 {
+		self.scores
+			.get(&para_id)
+			.and_then(|scores| scores.get(collator_id))
+			.map_or(0, |score| score.score)
+	}
+
+	/// A collation of the collator got seconded.
+	pub(super) fn note_seconded(&mut self, para_id: ParaId, collator_id: &CollatorId) {
+		self.update(para_id, collator_id, |score| {
+			score.seconded = score.seconded.saturating_add(1);
+			score.adjust(BENEFIT_SECONDED);
+		})
+	}
+
+	/// Fetching a collation from the collator failed because of the collator.
+	pub(super) fn note_failed_fetch(&mut self, para_id: ParaId, collator_id: &CollatorId) {
+		self.update(para_id, collator_id, |score| {
+			score.failed_fetches = score.failed_fetches.saturating_add(1);
+			score.adjust(-COST_FAILED_FETCH);
+		})
+	}
+
+	/// A collation of the collator turned out to be invalid.
+	pub(super) fn note_invalid(&mut self, para_id: ParaId, collator_id: &CollatorId) {
+		self.update(para_id, collator_id, |score| {
+			score.invalid = score.invalid.saturating_add(1);
+			score.adjust(-COST_INVALID);
+		})
+	}
+
+	fn update(
+		&mut self,
+		para_id: ParaId,
+		collator_id: &CollatorId,
+		f: impl FnOnce(&mut CollatorScore),
+	) {
+		self.load_para(para_id);
+		let scores = self.scores.entry(para_id).or_default();
+		f(scores.entry(collator_id.clone()).or_default());
+
+		if scores.len() > MAX_COLLATORS_PER_PARA {
+			let least_known = scores
+				.iter()
+				.filter(|(id, _)| *id != collator_id)
+				.min_by_key(|(_, score)| score.score.abs())
+				.map(|(id, _)| id.clone());
+			if let Some(id) = least_known {
+				scores.remove(&id);
+			}
+		}
+
+		self.dirty.insert(para_id);
+	}
+
+	/// Write all changed scores to the database and update the metrics of the changed paras.
+	pub(super) fn flush(&mut self, metrics: &Metrics) {
+		if self.dirty.is_empty() {
+			return
+		}
+
+		for para_id in &self.dirty {
+			if let Some(scores) = self.scores.get(para_id) {
+				let reliable = scores.values().filter(|s| s.score > 0).count();
+				let unreliable = scores.values().filter(|s| s.score < 0).count();
+				let neutral = scores.len() - reliable - unreliable;
+				metrics.note_collator_reputation(*para_id, reliable, neutral, unreliable);
+			}
+		}
+
+		if let Some(db) = &self.db {
+			let changed = self
+				.dirty
+				.iter()
+				.filter_map(|para_id| self.scores.get(para_id).map(|scores| (*para_id, scores)));
+			if let Err(err) = db.write(changed) {
+				gum::warn!(target: LOG_TARGET, ?err, "Failed to persist collator reputation");
+				return
+			}
+		}
+
+		self.dirty.clear();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter;
+	use sp_core::crypto::Pair;
+
+	const COL_REPUTATION: u32 = 0;
+
+	fn make_db() -> ReputationDb {
+		let db = DbAdapter::new(kvdb_memorydb::create(1), &[COL_REPUTATION]);
+		ReputationDb::new(Arc::new(db), ReputationConfig { col_reputation_data: COL_REPUTATION })
+	}
+
+	fn collator() -> CollatorId {
+		polkadot_primitives::CollatorPair::generate().0.public()
+	}
+
+	#[test]
+	fn scores_survive_restart() {
+		let db = make_db();
+		let (para_a, para_b) = (ParaId::from(1), ParaId::from(2));
+		let (good, bad) = (collator(), collator());
+
+		let mut reputation = CollatorReputation::new(db.clone());
+		reputation.note_seconded(para_a, &good);
+		reputation.note_seconded(para_a, &good);
+		reputation.note_failed_fetch(para_a, &bad);
+		reputation.note_invalid(para_b, &bad);
+		reputation.flush(&Metrics::default());
+
+		assert_eq!(db.paras().unwrap(), vec![para_a, para_b]);
+		assert_eq!(
+			db.scoreboard(para_a).unwrap(),
+			vec![
+				(
+					good.clone(),
+					CollatorScore {
+						score: 2 * BENEFIT_SECONDED,
+						seconded: 2,
+						..Default::default()
+					}
+				),
+				(
+					bad.clone(),
+					CollatorScore {
+						score: -COST_FAILED_FETCH,
+						failed_fetches: 1,
+						..Default::default()
+					}
+				),
+			]
+		);
+
+		// Scores are only known after the para has been loaded.
+		let mut reputation = CollatorReputation::new(db);
+		assert_eq!(reputation.score(para_b, &bad), 0);
+		reputation.load_para(para_b);
+		assert_eq!(reputation.score(para_b, &bad), -COST_INVALID);
+		assert_eq!(reputation.score(para_b, &good), 0);
+	}
+
+	#[test]
+	fn scores_are_bounded() {
+		let para_id = ParaId::from(1);
+		let collator = collator();
+		let mut reputation = CollatorReputation::default();
+
+		for _ in 0..2 * MAX_SCORE / COST_INVALID {
+			reputation.note_invalid(para_id, &collator);
+		}
+		assert_eq!(reputation.score(para_id, &collator), -MAX_SCORE);
+
+		reputation.note_seconded(para_id, &collator);
+		assert_eq!(reputation.score(para_id, &collator), -MAX_SCORE + BENEFIT_SECONDED);
+	}
+
+	#[test]
+	fn least_known_collator_is_forgotten() {
+		let para_id = ParaId::from(1);
+		let mut reputation = CollatorReputation::default();
+
+		let bad = collator();
+		reputation.note_invalid(para_id, &bad);
+		let neutral = collator();
+		reputation.note_seconded(para_id, &neutral);
+		reputation.note_seconded(para_id, &neutral);
+		reputation.note_failed_fetch(para_id, &neutral);
+		for _ in 0..MAX_COLLATORS_PER_PARA - 2 {
+			reputation.note_seconded(para_id, &collator());
+		}
+		assert_eq!(reputation.scores[&para_id].len(), MAX_COLLATORS_PER_PARA);
+
+		reputation.note_seconded(para_id, &collator());
+		assert_eq!(reputation.scores[&para_id].len(), MAX_COLLATORS_PER_PARA);
+		assert!(!reputation.scores[&para_id].contains_key(&neutral));
+		assert_eq!(reputation.score(para_id, &bad), -COST_INVALID);
+	}
+}
@@ -206,6 +206,14 @@ struct TestHarness {
 fn test_harness<T: Future<Output = VirtualOverseer>>(
 	reputation: ReputationAggregator,
 	test: impl FnOnce(TestHarness) -> T,
+) {
+	test_harness_with_collator_reputation(reputation, CollatorReputation::default(), test)
+}
+
+fn test_harness_with_collator_reputation<T: Future<Output = VirtualOverseer>>(
+	reputation: ReputationAggregator,
+	collator_reputation: CollatorReputation,
+	test: impl FnOnce(TestHarness) -> T,
 ) {
 	sp_tracing::init_for_tests();
 
@@ -232,6 +240,7 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(
 		Metrics::default(),
 		reputation,
 		REPUTATION_CHANGE_TEST_INTERVAL,
+		collator_reputation,
 	);
 
 	let test_fut = test(TestHarness { virtual_overseer, keystore });
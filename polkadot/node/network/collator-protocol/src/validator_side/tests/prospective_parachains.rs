@@ -2462,3 +2462,117 @@ fn claim_fills_last_free_slot() {
 		virtual_overseer
 	});
 }
+
+#[test]
+fn collation_fetching_prefers_reliable_collators() {
+	let mut test_state = TestState::with_one_scheduled_para();
+
+	let pair_a = CollatorPair::generate().0;
+	let collator_a = PeerId::random();
+	let pair_b = CollatorPair::generate().0;
+	let collator_b = PeerId::random();
+	let para_id = test_state.chain_ids[0];
+
+	// `pair_b` got two collations seconded before the restart.
+	let db = kvdb_memorydb::create(1);
+	let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(db, &[0]);
+	let reputation_db =
+		ReputationDb::new(Arc::new(db), ReputationConfig { col_reputation_data: 0 });
+	let mut collator_reputation = CollatorReputation::new(reputation_db.clone());
+	collator_reputation.note_seconded(para_id, &pair_b.public());
+	collator_reputation.note_seconded(para_id, &pair_b.public());
+	collator_reputation.flush(&Metrics::default());
+
+	test_harness_with_collator_reputation(
+		ReputationAggregator::new(|_| true),
+		CollatorReputation::new(reputation_db),
+		|test_harness| async move {
+			let TestHarness { mut virtual_overseer, keystore } = test_harness;
+
+			let head = Hash::from_low_u64_be(128);
+			let head_num: u32 = 2;
+
+			update_view(&mut virtual_overseer, &mut test_state, vec![(head, head_num)]).await;
+
+			for (peer, pair) in [(collator_a, pair_a.clone()), (collator_b, pair_b.clone())] {
+				connect_and_declare_collator(
+					&mut virtual_overseer,
+					peer,
+					pair,
+					para_id,
+					CollationVersion::V2,
+				)
+				.await;
+			}
+
+			let (candidate_a1, commitments_a1) =
+				create_dummy_candidate_and_commitments(para_id, HeadData(vec![0u8]), head);
+			let (candidate_a2, _) =
+				create_dummy_candidate_and_commitments(para_id, HeadData(vec![1u8]), head);
+			let (candidate_b1, _) =
+				create_dummy_candidate_and_commitments(para_id, HeadData(vec![2u8]), head);
+
+			// The first advertisement is fetched right away.
+			assert_advertise_collation(
+				&mut virtual_overseer,
+				collator_a,
+				head,
+				para_id,
+				(candidate_a1.hash(), HeadData(vec![0u8]).hash()),
+			)
+			.await;
+			let response_channel_a1 = assert_fetch_collation_request(
+				&mut virtual_overseer,
+				head,
+				para_id,
+				Some(candidate_a1.hash()),
+			)
+			.await;
+
+			// Both of these have to wait for the pending fetch.
+			assert_advertise_collation(
+				&mut virtual_overseer,
+				collator_a,
+				head,
+				para_id,
+				(candidate_a2.hash(), HeadData(vec![1u8]).hash()),
+			)
+			.await;
+			assert_advertise_collation(
+				&mut virtual_overseer,
+				collator_b,
+				head,
+				para_id,
+				(candidate_b1.hash(), HeadData(vec![2u8]).hash()),
+			)
+			.await;
+			test_helpers::Yield::new().await;
+			assert_matches!(virtual_overseer.recv().now_or_never(), None);
+
+			send_collation_and_assert_processing(
+				&mut virtual_overseer,
+				keystore.clone(),
+				head,
+				para_id,
+				collator_a,
+				response_channel_a1,
+				candidate_a1,
+				commitments_a1,
+				PoV { block_data: BlockData(vec![1]) },
+			)
+			.await;
+
+			// Even though `pair_a` just got a collation seconded, `pair_b` has the better track
+			// record and its collation is fetched first.
+			assert_fetch_collation_request(
+				&mut virtual_overseer,
+				head,
+				para_id,
+				Some(candidate_b1.hash()),
+			)
+			.await;
+
+			virtual_overseer
+		},
+	);
+}
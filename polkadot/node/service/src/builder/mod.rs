@@ -34,8 +34,12 @@ use frame_benchmarking_cli::SUBSTRATE_REFERENCE_HARDWARE;
 use gum::info;
 use mmr_gadget::MmrGadget;
 use polkadot_availability_recovery::FETCH_CHUNKS_THRESHOLD;
+use polkadot_collator_protocol::{
+	rpc::{CollatorReputation, CollatorReputationApiServer},
+	ReputationConfig as CollatorReputationConfig, ReputationDb as CollatorReputationDb,
+};
 use polkadot_node_core_approval_voting::Config as ApprovalVotingConfig;
-use polkadot_node_core_av_store::Config as AvailabilityConfig;
+use polkadot_node_core_av_store::{Config as AvailabilityConfig, PovArchiveConfig};
 use polkadot_node_core_candidate_validation::Config as CandidateValidationConfig;
 use polkadot_node_core_chain_selection::{
 	self as chain_selection_subsystem, Config as ChainSelectionConfig,
@@ -85,6 +89,8 @@ pub struct NewFullParams<OverseerGenerator: OverseerGen> {
 	pub prepare_workers_hard_max_num: Option<usize>,
 	/// How long finalized data should be kept in the availability store (in hours)
 	pub keep_finalized_for: Option<u32>,
+	/// Archival of the PoVs of some parachains by the availability store.
+	pub pov_archive: Option<PovArchiveConfig>,
 	pub overseer_gen: OverseerGenerator,
 	pub overseer_message_channel_capacity_override: Option<usize>,
 	#[allow(dead_code)]
@@ -103,6 +109,8 @@ pub struct NewFull {
 	pub sync_service: Arc<sc_network_sync::SyncingService<Block>>,
 	pub rpc_handlers: RpcHandlers,
 	pub backend: Arc<FullBackend>,
+	/// Submits transactions to the transaction pool of the node.
+	pub offchain_tx_pool_factory: OffchainTransactionPoolFactory<Block>,
 }
 
 pub struct PolkadotServiceBuilder<OverseerGenerator, Network>
@@ -202,6 +210,7 @@ where
 					prepare_workers_soft_max_num,
 					prepare_workers_hard_max_num,
 					keep_finalized_for,
+					pov_archive,
 					enable_approval_voting_parallel,
 				},
 			overseer_connector,
@@ -418,6 +427,9 @@ where
 				stagnant_check_interval: Default::default(),
 				stagnant_check_mode: chain_selection_subsystem::StagnantCheckMode::PruneOnly,
 			};
+			let collator_reputation_config = CollatorReputationConfig {
+				col_reputation_data: parachains_db::REAL_COLUMNS.col_collator_reputation_data,
+			};
 
 			// Kusama + testnets get a higher threshold, we are conservative on Polkadot for now.
 			let fetch_chunks_threshold =
@@ -429,8 +441,12 @@ where
 				keep_finalized_for: if matches!(config.chain_spec.identify_chain(), Chain::Rococo) {
 					keep_finalized_for.unwrap_or(1)
 				} else {
-					KEEP_FINALIZED_FOR_LIVE_NETWORKS
+					// Live networks may keep data for longer, but never for less than the default.
+					keep_finalized_for.map_or(KEEP_FINALIZED_FOR_LIVE_NETWORKS, |hours| {
+						hours.max(KEEP_FINALIZED_FOR_LIVE_NETWORKS)
+					})
 				},
+				pov_archive,
 			};
 
 			Some(ExtendedOverseerGenArgs {
@@ -447,11 +463,26 @@ where
 				dispute_req_receiver,
 				dispute_coordinator_config,
 				chain_selection_config,
+				collator_reputation_config,
 				fetch_chunks_threshold,
 				enable_approval_voting_parallel,
 			})
 		};
 
+		let collator_reputation_db = ext_overseer_args.as_ref().map(|args| {
+			CollatorReputationDb::new(args.parachains_db.clone(), args.collator_reputation_config)
+		});
+		let rpc_extensions_builder =
+			move |subscription_executor: polkadot_rpc::SubscriptionTaskExecutor|
+			      -> Result<polkadot_rpc::RpcExtension, sc_service::Error> {
+				let mut io = rpc_extensions_builder(subscription_executor)?;
+				if let Some(db) = collator_reputation_db.clone() {
+					io.merge(CollatorReputation::new(db).into_rpc())
+						.map_err(|e| sc_service::Error::Application(e.into()))?;
+				}
+				Ok(io)
+			};
+
 		let (network, system_rpc_tx, tx_handler_controller, sync_service) =
 			sc_service::build_network(sc_service::BuildNetworkParams {
 				config: &config,
@@ -845,6 +876,7 @@ where
 			sync_service,
 			rpc_handlers,
 			backend,
+			offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(transaction_pool),
 		})
 	}
 }
@@ -59,6 +59,9 @@ use polkadot_overseer::SpawnGlue;
 
 #[cfg(feature = "full-node")]
 pub use {
+	polkadot_node_core_av_store::PovArchiveConfig,
+	polkadot_node_core_dispute_coordinator::inspect as dispute_inspect,
+	polkadot_node_subsystem::messages::ValidationFailed,
 	polkadot_overseer::{Handle, Overseer, OverseerConnector, OverseerHandle},
 	polkadot_primitives::runtime_api::ParachainHost,
 	relay_chain_selection::SelectRelayChain,
@@ -209,6 +212,22 @@ pub enum Error {
 	#[error(transparent)]
 	Availability(#[from] AvailabilityError),
 
+	#[cfg(feature = "full-node")]
+	#[error(transparent)]
+	DisputeDb(#[from] dispute_inspect::Error),
+
+	#[cfg(feature = "full-node")]
+	#[error(transparent)]
+	Keystore(#[from] sc_keystore::Error),
+
+	#[cfg(feature = "full-node")]
+	#[error(transparent)]
+	Validation(#[from] ValidationFailed),
+
+	#[cfg(feature = "full-node")]
+	#[error("Failed to decode {path:?}: {error}")]
+	InputDecoding { path: PathBuf, error: codec::Error },
+
 	#[error("Authorities require the real overseer implementation")]
 	AuthoritiesRequireRealOverseer,
 
@@ -345,6 +364,33 @@ pub fn open_database(db_source: &DatabaseSource) -> Result<Arc<dyn Database>, Er
 	Ok(parachains_db)
 }
 
+/// Open the parachains database without writing to it.
+///
+/// Unlike [`open_database`], this neither creates nor migrates the database.
+#[cfg(feature = "full-node")]
+pub fn open_database_read_only(db_source: &DatabaseSource) -> Result<Arc<dyn Database>, Error> {
+	let parachains_db = match db_source {
+		DatabaseSource::RocksDb { path, .. } =>
+			parachains_db::open_read_only_rocksdb(path.clone())?,
+		DatabaseSource::ParityDb { path, .. } => parachains_db::open_read_only_paritydb(
+			path.parent().ok_or(Error::DatabasePathRequired)?.into(),
+		)?,
+		DatabaseSource::Auto { paritydb_path, rocksdb_path, .. } => {
+			if paritydb_path.is_dir() && paritydb_path.exists() {
+				parachains_db::open_read_only_paritydb(
+					paritydb_path.parent().ok_or(Error::DatabasePathRequired)?.into(),
+				)?
+			} else {
+				parachains_db::open_read_only_rocksdb(rocksdb_path.clone())?
+			}
+		},
+		DatabaseSource::Custom { .. } => {
+			unimplemented!("No polkadot subsystem db for custom source.");
+		},
+	};
+	Ok(parachains_db)
+}
+
 /// Is this node running as in-process node for a parachain node?
 #[cfg(feature = "full-node")]
 #[derive(Clone)]
@@ -493,6 +539,175 @@ pub fn revert_backend(
 	Ok(())
 }
 
+/// Export everything the availability store holds for a candidate to the `output` directory.
+///
+/// The available data is written to `available_data.scale` and every chunk to
+/// `chunk_<validator index>.scale`, SCALE-encoded. Returns `false` if the candidate is unknown to
+/// the availability store.
+///
+/// The database is opened read-only, but directly, so the node must not be running.
+#[cfg(feature = "full-node")]
+pub fn export_availability(
+	database: &DatabaseSource,
+	candidate_hash: Hash,
+	output: &std::path::Path,
+) -> Result<bool, Error> {
+	use codec::Encode;
+
+	let parachains_db = open_database_read_only(database)?;
+	let config = polkadot_node_core_av_store::Config {
+		col_data: parachains_db::REAL_COLUMNS.col_availability_data,
+		col_meta: parachains_db::REAL_COLUMNS.col_availability_meta,
+		keep_finalized_for: KEEP_FINALIZED_FOR_LIVE_NETWORKS,
+		pov_archive: None,
+	};
+
+	let stored = match polkadot_node_core_av_store::load_candidate_data(
+		&parachains_db,
+		&config,
+		&polkadot_primitives::CandidateHash(candidate_hash),
+	)? {
+		Some(stored) => stored,
+		None => return Ok(false),
+	};
+
+	std::fs::create_dir_all(output)?;
+	if let Some(available_data) = stored.available_data {
+		std::fs::write(output.join("available_data.scale"), available_data.encode())?;
+	}
+	for (validator_index, chunk) in stored.chunks {
+		let path = output.join(format!("chunk_{}.scale", validator_index.0));
+		std::fs::write(path, chunk.encode())?;
+	}
+
+	Ok(true)
+}
+
+/// Open the dispute coordinator's data for inspection.
+///
+/// The database is opened read-only, but directly, so the node must not be running.
+#[cfg(feature = "full-node")]
+pub fn inspect_disputes(database: &DatabaseSource) -> Result<dispute_inspect::DisputeDb, Error> {
+	let parachains_db = open_database_read_only(database)?;
+	let config = polkadot_node_core_dispute_coordinator::Config {
+		col_dispute_data: parachains_db::REAL_COLUMNS.col_dispute_coordinator_data,
+	};
+
+	Ok(dispute_inspect::DisputeDb::new(parachains_db, config))
+}
+
+/// The parachain validator keys stored in the given keystore.
+///
+/// Used to recognize the node's own votes when inspecting disputes.
+#[cfg(feature = "full-node")]
+pub fn local_validator_keys(
+	keystore: &sc_service::config::KeystoreConfig,
+) -> Result<Vec<polkadot_primitives::ValidatorId>, Error> {
+	use sc_keystore::Keystore;
+
+	let keystore = match keystore {
+		sc_service::config::KeystoreConfig::Path { path, password } =>
+			sc_keystore::LocalKeystore::open(path.clone(), password.clone())?,
+		sc_service::config::KeystoreConfig::InMemory => return Ok(Vec::new()),
+	};
+
+	Ok(keystore
+		.sr25519_public_keys(polkadot_primitives::PARACHAIN_KEY_TYPE_ID)
+		.into_iter()
+		.map(Into::into)
+		.collect())
+}
+
+/// Files holding the inputs needed to validate a candidate.
+#[cfg(feature = "full-node")]
+#[derive(Debug, Clone)]
+pub enum CandidateDataFiles {
+	/// SCALE-encoded `AvailableData`, as written by [`export_availability`].
+	AvailableData(PathBuf),
+	/// SCALE-encoded `PoV`, e.g. from the PoV archive, and `PersistedValidationData`.
+	Separate { pov: PathBuf, persisted_validation_data: PathBuf },
+}
+
+/// Re-run dispute participation for a candidate the dispute coordinator knows about.
+///
+/// The candidate receipt is taken from the dispute coordinator's database, everything else has to
+/// be provided: `validation_code` is the raw, possibly compressed, validation code and
+/// `executor_params` an optional file with SCALE-encoded `ExecutorParams`. The executor parameters
+/// of the dispute's session are not stored locally, so the defaults are used if none are given.
+///
+/// PVF artifacts are compiled to a separate directory next to the database, so the node must not
+/// be running. Returns `None` if no votes are known for the candidate.
+#[cfg(feature = "full-node")]
+pub async fn revalidate_disputed_candidate(
+	database: &DatabaseSource,
+	session: polkadot_primitives::SessionIndex,
+	candidate_hash: Hash,
+	candidate_data: CandidateDataFiles,
+	validation_code: &std::path::Path,
+	executor_params: Option<&std::path::Path>,
+	workers_path: Option<PathBuf>,
+	node_version: Option<String>,
+) -> Result<Option<polkadot_node_primitives::ValidationResult>, Error> {
+	use polkadot_node_primitives::{AvailableData, PoV};
+	use polkadot_primitives::{ExecutorParams, PersistedValidationData, ValidationCode};
+
+	fn decode_file<T: codec::Decode>(path: &std::path::Path) -> Result<T, Error> {
+		let raw = std::fs::read(path)?;
+		T::decode(&mut &raw[..])
+			.map_err(|error| Error::InputDecoding { path: path.to_owned(), error })
+	}
+
+	let dispute = inspect_disputes(database)?
+		.dispute(session, polkadot_primitives::CandidateHash(candidate_hash))?;
+	let Some(votes) = dispute.and_then(|dispute| dispute.votes) else { return Ok(None) };
+
+	let (pov, persisted_validation_data) = match candidate_data {
+		CandidateDataFiles::AvailableData(path) => {
+			let available_data: AvailableData = decode_file(&path)?;
+			(available_data.pov, available_data.validation_data)
+		},
+		CandidateDataFiles::Separate { pov, persisted_validation_data } => (
+			Arc::new(decode_file::<PoV>(&pov)?),
+			decode_file::<PersistedValidationData>(&persisted_validation_data)?,
+		),
+	};
+	let validation_code = ValidationCode(std::fs::read(validation_code)?);
+	let executor_params = match executor_params {
+		Some(path) => decode_file::<ExecutorParams>(path)?,
+		None => ExecutorParams::default(),
+	};
+
+	let (prep_worker_path, exec_worker_path) =
+		workers::determine_workers_paths(workers_path, None, node_version.clone())?;
+	let config = polkadot_node_core_candidate_validation::Config {
+		artifacts_cache_path: database
+			.path()
+			.ok_or(Error::DatabasePathRequired)?
+			.join("pvf-artifacts-offline"),
+		node_version,
+		// Revalidation is run on whatever machine the database was copied to, which need not
+		// support the security features of a validator.
+		secure_validator_mode: false,
+		prep_worker_path,
+		exec_worker_path,
+		pvf_execute_workers_max_num: 1,
+		pvf_prepare_workers_soft_max_num: 1,
+		pvf_prepare_workers_hard_max_num: 1,
+	};
+
+	let result = polkadot_node_core_candidate_validation::validate_candidate_offline(
+		config,
+		persisted_validation_data,
+		validation_code,
+		votes.candidate_receipt,
+		pov,
+		executor_params,
+	)
+	.await?;
+
+	Ok(Some(result))
+}
+
 fn revert_chain_selection(db: Arc<dyn Database>, hash: Hash) -> sp_blockchain::Result<()> {
 	let config = chain_selection_subsystem::Config {
 		col_data: parachains_db::REAL_COLUMNS.col_chain_selection_data,
@@ -20,6 +20,9 @@ use polkadot_overseer::{DummySubsystem, InitializedOverseerBuilder, SubsystemErr
 use sp_core::traits::SpawnNamed;
 
 use polkadot_availability_distribution::IncomingRequestReceivers;
+use polkadot_collator_protocol::{
+	ReputationConfig as CollatorReputationConfig, ReputationDb as CollatorReputationDb,
+};
 use polkadot_node_core_approval_voting::{Config as ApprovalVotingConfig, RealAssignmentCriteria};
 use polkadot_node_core_av_store::Config as AvailabilityConfig;
 use polkadot_node_core_candidate_validation::Config as CandidateValidationConfig;
@@ -138,6 +141,8 @@ pub struct ExtendedOverseerGenArgs {
 	pub dispute_coordinator_config: DisputeCoordinatorConfig,
 	/// Configuration for the chain selection subsystem.
 	pub chain_selection_config: ChainSelectionConfig,
+	/// Configuration for the collator reputation of the collator protocol.
+	pub collator_reputation_config: CollatorReputationConfig,
 	/// Optional availability recovery fetch chunks threshold. If PoV size size is lower
 	/// than the value put in here we always try to recovery availability from backers.
 	/// The presence of this parameter here is needed to have different values per chain.
@@ -183,6 +188,7 @@ pub fn validator_overseer_builder<Spawner, RuntimeClient>(
 		dispute_req_receiver,
 		dispute_coordinator_config,
 		chain_selection_config,
+		collator_reputation_config,
 		fetch_chunks_threshold,
 		enable_approval_voting_parallel,
 	}: ExtendedOverseerGenArgs,
@@ -307,6 +313,10 @@ where
 					keystore: keystore.clone(),
 					eviction_policy: Default::default(),
 					metrics: Metrics::register(registry)?,
+					reputation_db: CollatorReputationDb::new(
+						parachains_db.clone(),
+						collator_reputation_config,
+					),
 				},
 			};
 			CollatorProtocolSubsystem::new(side)
@@ -408,6 +418,7 @@ pub fn validator_with_parallel_overseer_builder<Spawner, RuntimeClient>(
 		dispute_req_receiver,
 		dispute_coordinator_config,
 		chain_selection_config,
+		collator_reputation_config,
 		fetch_chunks_threshold,
 		enable_approval_voting_parallel,
 	}: ExtendedOverseerGenArgs,
@@ -531,6 +542,10 @@ where
 					keystore: keystore.clone(),
 					eviction_policy: Default::default(),
 					metrics: Metrics::register(registry)?,
+					reputation_db: CollatorReputationDb::new(
+						parachains_db.clone(),
+						collator_reputation_config,
+					),
 				},
 			};
 			CollatorProtocolSubsystem::new(side)
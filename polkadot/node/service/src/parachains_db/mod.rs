@@ -57,6 +57,28 @@ pub(crate) mod columns {
 		pub const ORDERED_COL: &[u32] =
 			&[COL_AVAILABILITY_META, COL_CHAIN_SELECTION_DATA, COL_DISPUTE_COORDINATOR_DATA];
 	}
+
+	// Version 5 only changed structures in approval voting, so we can re-export the v4 definitions.
+	pub mod v5 {
+		pub use super::v4::{NUM_COLUMNS, ORDERED_COL};
+	}
+
+	pub mod v6 {
+		pub use super::v4::{
+			COL_APPROVAL_DATA, COL_AVAILABILITY_DATA, COL_AVAILABILITY_META,
+			COL_CHAIN_SELECTION_DATA, COL_DISPUTE_COORDINATOR_DATA,
+		};
+
+		pub const NUM_COLUMNS: u32 = 6;
+		pub const COL_COLLATOR_REPUTATION_DATA: u32 = 5;
+
+		pub const ORDERED_COL: &[u32] = &[
+			COL_AVAILABILITY_META,
+			COL_CHAIN_SELECTION_DATA,
+			COL_DISPUTE_COORDINATOR_DATA,
+			COL_COLLATOR_REPUTATION_DATA,
+		];
+	}
 }
 
 /// Columns used by different subsystems.
@@ -73,16 +95,19 @@ pub struct ColumnsConfig {
 	pub col_chain_selection_data: u32,
 	/// The column used by dispute coordinator for data.
 	pub col_dispute_coordinator_data: u32,
+	/// The column used by the collator protocol for collator reputation.
+	pub col_collator_reputation_data: u32,
 }
 
 /// The real columns used by the parachains DB.
 #[cfg(any(test, feature = "full-node"))]
 pub const REAL_COLUMNS: ColumnsConfig = ColumnsConfig {
-	col_availability_data: columns::v4::COL_AVAILABILITY_DATA,
-	col_availability_meta: columns::v4::COL_AVAILABILITY_META,
-	col_approval_data: columns::v4::COL_APPROVAL_DATA,
-	col_chain_selection_data: columns::v4::COL_CHAIN_SELECTION_DATA,
-	col_dispute_coordinator_data: columns::v4::COL_DISPUTE_COORDINATOR_DATA,
+	col_availability_data: columns::v6::COL_AVAILABILITY_DATA,
+	col_availability_meta: columns::v6::COL_AVAILABILITY_META,
+	col_approval_data: columns::v6::COL_APPROVAL_DATA,
+	col_chain_selection_data: columns::v6::COL_CHAIN_SELECTION_DATA,
+	col_dispute_coordinator_data: columns::v6::COL_DISPUTE_COORDINATOR_DATA,
+	col_collator_reputation_data: columns::v6::COL_COLLATOR_REPUTATION_DATA,
 };
 
 #[derive(PartialEq, Copy, Clone)]
@@ -123,17 +148,17 @@ pub fn open_creating_rocksdb(
 
 	let path = root.join("parachains").join("db");
 
-	let mut db_config = DatabaseConfig::with_columns(columns::v4::NUM_COLUMNS);
+	let mut db_config = DatabaseConfig::with_columns(columns::v6::NUM_COLUMNS);
 
 	let _ = db_config
 		.memory_budget
-		.insert(columns::v4::COL_AVAILABILITY_DATA, cache_sizes.availability_data);
+		.insert(columns::v6::COL_AVAILABILITY_DATA, cache_sizes.availability_data);
 	let _ = db_config
 		.memory_budget
-		.insert(columns::v4::COL_AVAILABILITY_META, cache_sizes.availability_meta);
+		.insert(columns::v6::COL_AVAILABILITY_META, cache_sizes.availability_meta);
 	let _ = db_config
 		.memory_budget
-		.insert(columns::v4::COL_APPROVAL_DATA, cache_sizes.approval_data);
+		.insert(columns::v6::COL_APPROVAL_DATA, cache_sizes.approval_data);
 
 	let path_str = path
 		.to_str()
@@ -144,12 +169,106 @@ pub fn open_creating_rocksdb(
 	let db = Database::open(&db_config, &path_str)?;
 	let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(
 		db,
-		columns::v4::ORDERED_COL,
+		columns::v6::ORDERED_COL,
 	);
 
 	Ok(Arc::new(db))
 }
 
+/// Open an existing database on disk for reading only.
+///
+/// The database is opened as a `RocksDB` secondary instance. Secondary instances never write to
+/// the database and do not take its lock, so the node may keep running. They see the database as
+/// it was when they were opened. The log files of the secondary instance are written to a
+/// temporary directory, which is removed once the database is dropped.
+///
+/// Fails if the database does not exist or needs to be migrated.
+#[cfg(feature = "full-node")]
+pub fn open_read_only_rocksdb(root: PathBuf) -> io::Result<Arc<dyn Database>> {
+	use kvdb_rocksdb::{Database, DatabaseConfig};
+
+	let path = root.join("parachains").join("db");
+	let path_str = path
+		.to_str()
+		.ok_or_else(|| other_io_error(format!("Bad database path: {:?}", path)))?;
+
+	if !path.is_dir() {
+		return Err(other_io_error(format!("No parachains database found at {:?}", path)))
+	}
+	upgrade::ensure_current_version(&path, DatabaseKind::RocksDB)?;
+
+	let secondary = tempfile::Builder::new().prefix("parachains-db-secondary").tempdir()?;
+	let mut db_config = DatabaseConfig::with_columns(columns::v6::NUM_COLUMNS);
+	db_config.create_if_missing = false;
+	db_config.secondary = Some(secondary.path().to_path_buf());
+	let db = Database::open(&db_config, &path_str)?;
+	let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(
+		ReadOnly { db, _secondary: secondary },
+		columns::v6::ORDERED_COL,
+	);
+
+	Ok(Arc::new(db))
+}
+
+/// Open an existing parity db database for reading only.
+///
+/// Fails if the database does not exist or needs to be migrated.
+#[cfg(feature = "full-node")]
+pub fn open_read_only_paritydb(root: PathBuf) -> io::Result<Arc<dyn Database>> {
+	let path = root.join("parachains");
+
+	if !path.is_dir() {
+		return Err(other_io_error(format!("No parachains database found at {:?}", path)))
+	}
+	upgrade::ensure_current_version(&path, DatabaseKind::ParityDB)?;
+
+	let db = parity_db::Db::open_read_only(&upgrade::paritydb_version_6_config(&path))
+		.map_err(|err| io::Error::new(io::ErrorKind::Other, format!("{:?}", err)))?;
+
+	let db = polkadot_node_subsystem_util::database::paritydb_impl::DbAdapter::new(
+		db,
+		columns::v6::ORDERED_COL,
+	);
+	Ok(Arc::new(db))
+}
+
+/// A `RocksDB` secondary instance, rejecting all writes.
+///
+/// `RocksDB` fails writes to secondary instances as well, this just reports them clearly. Keeps the
+/// directory of the secondary instance alive while the database is open.
+#[cfg(feature = "full-node")]
+struct ReadOnly<D> {
+	db: D,
+	_secondary: tempfile::TempDir,
+}
+
+#[cfg(feature = "full-node")]
+impl<D: kvdb::KeyValueDB> kvdb::KeyValueDB for ReadOnly<D> {
+	fn get(&self, col: u32, key: &[u8]) -> io::Result<Option<kvdb::DBValue>> {
+		self.db.get(col, key)
+	}
+
+	fn get_by_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<Option<kvdb::DBValue>> {
+		self.db.get_by_prefix(col, prefix)
+	}
+
+	fn write(&self, _transaction: kvdb::DBTransaction) -> io::Result<()> {
+		Err(other_io_error("Database has been opened read-only".into()))
+	}
+
+	fn iter<'a>(&'a self, col: u32) -> Box<dyn Iterator<Item = io::Result<kvdb::DBKeyValue>> + 'a> {
+		self.db.iter(col)
+	}
+
+	fn iter_with_prefix<'a>(
+		&'a self,
+		col: u32,
+		prefix: &'a [u8],
+	) -> Box<dyn Iterator<Item = io::Result<kvdb::DBKeyValue>> + 'a> {
+		self.db.iter_with_prefix(col, prefix)
+	}
+}
+
 /// Open a parity db database.
 #[cfg(feature = "full-node")]
 pub fn open_creating_paritydb(
@@ -164,12 +283,68 @@ pub fn open_creating_paritydb(
 	std::fs::create_dir_all(&path_str)?;
 	upgrade::try_upgrade_db(&path, DatabaseKind::ParityDB, upgrade::CURRENT_VERSION)?;
 
-	let db = parity_db::Db::open_or_create(&upgrade::paritydb_version_3_config(&path))
+	let db = parity_db::Db::open_or_create(&upgrade::paritydb_version_6_config(&path))
 		.map_err(|err| io::Error::new(io::ErrorKind::Other, format!("{:?}", err)))?;
 
 	let db = polkadot_node_subsystem_util::database::paritydb_impl::DbAdapter::new(
 		db,
-		columns::v4::ORDERED_COL,
+		columns::v6::ORDERED_COL,
 	);
 	Ok(Arc::new(db))
 }
+
+#[cfg(all(test, feature = "full-node"))]
+mod tests {
+	use super::*;
+	use kvdb::{DBTransaction, KeyValueDB};
+	use std::{collections::BTreeMap, time::SystemTime};
+
+	const COL: u32 = REAL_COLUMNS.col_dispute_coordinator_data;
+
+	fn create_rocksdb(root: &std::path::Path) {
+		let db = open_creating_rocksdb(root.into(), CacheSizes::default()).unwrap();
+		let mut tx = DBTransaction::new();
+		tx.put(COL, b"key", b"value");
+		db.write(tx).unwrap();
+	}
+
+	/// The size and modification time of every file in `dir`.
+	fn snapshot(dir: &std::path::Path) -> BTreeMap<PathBuf, (u64, SystemTime)> {
+		std::fs::read_dir(dir)
+			.unwrap()
+			.map(|entry| {
+				let entry = entry.unwrap();
+				let metadata = entry.metadata().unwrap();
+				(entry.path(), (metadata.len(), metadata.modified().unwrap()))
+			})
+			.collect()
+	}
+
+	#[test]
+	fn read_only_rocksdb_does_not_modify_the_database() {
+		let root = tempfile::tempdir().unwrap();
+		create_rocksdb(root.path());
+		let before = snapshot(&root.path().join("parachains").join("db"));
+
+		let db = open_read_only_rocksdb(root.path().into()).unwrap();
+		assert_eq!(db.get(COL, b"key").unwrap(), Some(b"value".to_vec()));
+
+		let mut tx = DBTransaction::new();
+		tx.put(COL, b"other", b"value");
+		assert!(db.write(tx).is_err());
+		drop(db);
+
+		assert_eq!(snapshot(&root.path().join("parachains").join("db")), before);
+	}
+
+	#[test]
+	fn read_only_rocksdb_opens_while_the_node_is_running() {
+		let root = tempfile::tempdir().unwrap();
+		create_rocksdb(root.path());
+
+		let _node = open_creating_rocksdb(root.path().into(), CacheSizes::default()).unwrap();
+		let db = open_read_only_rocksdb(root.path().into()).unwrap();
+
+		assert_eq!(db.get(COL, b"key").unwrap(), Some(b"value".to_vec()));
+	}
+}
@@ -0,0 +1,254 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Helpers for simulating disputes end to end.
+//!
+//! Subsystems under test talk to the runtime through [`RuntimeApiRequest`]s. A
+//! [`SimulatedRuntime`] answers those requests from a single in-process runtime state, e.g. the
+//! `simulation` module of `polkadot-test-runtime`, so disputes raised by the node can be carried
+//! on chain and slashing can be observed. [`TestValidators`] signs the statements of the other
+//! validators of the session.
+
+use std::sync::Arc;
+
+use polkadot_node_primitives::{
+	CandidateVotes, SignedDisputeStatement, SignedFullStatement, Statement,
+};
+use polkadot_node_subsystem::messages::RuntimeApiRequest;
+use polkadot_primitives::{
+	slashing,
+	vstaging::{CandidateEvent, ScrapedOnChainVotes},
+	CandidateHash, DisputeStatement, DisputeStatementSet, ExecutorParams, Hash, NodeFeatures,
+	SessionIndex, SessionInfo, SigningContext, ValidationCode, ValidationCodeHash, ValidatorId,
+	ValidatorIndex, PARACHAIN_KEY_TYPE_ID,
+};
+use sc_keystore::LocalKeystore;
+use sp_keyring::Sr25519Keyring;
+use sp_keystore::{Keystore, KeystorePtr};
+
+/// A runtime answering the runtime API requests involved in disputes and slashing.
+///
+/// The state is the same for every relay parent: requests are answered from whatever block the
+/// simulation is currently at.
+pub trait SimulatedRuntime {
+	/// Index of the session the next block will be in.
+	fn session_index_for_child(&self) -> SessionIndex;
+
+	/// Information about a past or the current session.
+	fn session_info(&self, session: SessionIndex) -> Option<SessionInfo>;
+
+	/// Executor parameters of the given session.
+	fn session_executor_params(&self, _session: SessionIndex) -> Option<ExecutorParams> {
+		Some(ExecutorParams::default())
+	}
+
+	/// Node features enabled in the given session.
+	fn node_features(&self, _session: SessionIndex) -> NodeFeatures {
+		NodeFeatures::EMPTY
+	}
+
+	/// Candidate events of the current block.
+	fn candidate_events(&self) -> Vec<CandidateEvent>;
+
+	/// Votes the current block put on chain.
+	fn on_chain_votes(&self) -> Option<ScrapedOnChainVotes>;
+
+	/// Validators currently disabled.
+	fn disabled_validators(&self) -> Vec<ValidatorIndex>;
+
+	/// Validators that lost a past session dispute and still need to be slashed.
+	fn unapplied_slashes(&self) -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>;
+
+	/// Proof that the given key is part of the current validator set.
+	fn key_ownership_proof(
+		&self,
+		validator_id: ValidatorId,
+	) -> Option<slashing::OpaqueKeyOwnershipProof>;
+
+	/// Report a validator that lost a past session dispute.
+	fn submit_report_dispute_lost(
+		&self,
+		dispute_proof: slashing::DisputeProof,
+		key_ownership_proof: slashing::OpaqueKeyOwnershipProof,
+	) -> Option<()>;
+
+	/// Validation code with the given hash.
+	fn validation_code_by_hash(&self, _hash: ValidationCodeHash) -> Option<ValidationCode> {
+		None
+	}
+
+	/// Version of the `ParachainHost` API the runtime claims to implement.
+	fn api_version(&self) -> u32 {
+		RuntimeApiRequest::NODE_FEATURES_RUNTIME_REQUIREMENT
+	}
+}
+
+/// Answer a runtime API request from the given runtime.
+///
+/// Requests the simulation has no answer for are handed back, so the caller can answer them or
+/// fail the test.
+pub fn answer_runtime_api_request(
+	runtime: &impl SimulatedRuntime,
+	request: RuntimeApiRequest,
+) -> Result<(), RuntimeApiRequest> {
+	// The subsystem might have given up on the answer already, which is fine.
+	match request {
+		RuntimeApiRequest::Version(tx) => {
+			let _ = tx.send(Ok(runtime.api_version()));
+		},
+		RuntimeApiRequest::SessionIndexForChild(tx) => {
+			let _ = tx.send(Ok(runtime.session_index_for_child()));
+		},
+		RuntimeApiRequest::SessionInfo(session, tx) => {
+			let _ = tx.send(Ok(runtime.session_info(session)));
+		},
+		RuntimeApiRequest::SessionExecutorParams(session, tx) => {
+			let _ = tx.send(Ok(runtime.session_executor_params(session)));
+		},
+		RuntimeApiRequest::NodeFeatures(session, tx) => {
+			let _ = tx.send(Ok(runtime.node_features(session)));
+		},
+		RuntimeApiRequest::CandidateEvents(tx) => {
+			let _ = tx.send(Ok(runtime.candidate_events()));
+		},
+		RuntimeApiRequest::FetchOnChainVotes(tx) => {
+			let _ = tx.send(Ok(runtime.on_chain_votes()));
+		},
+		RuntimeApiRequest::DisabledValidators(tx) => {
+			let _ = tx.send(Ok(runtime.disabled_validators()));
+		},
+		RuntimeApiRequest::UnappliedSlashes(tx) => {
+			let _ = tx.send(Ok(runtime.unapplied_slashes()));
+		},
+		RuntimeApiRequest::KeyOwnershipProof(validator_id, tx) => {
+			let _ = tx.send(Ok(runtime.key_ownership_proof(validator_id)));
+		},
+		RuntimeApiRequest::SubmitReportDisputeLost(dispute_proof, key_ownership_proof, tx) => {
+			let _ =
+				tx.send(Ok(runtime.submit_report_dispute_lost(dispute_proof, key_ownership_proof)));
+		},
+		RuntimeApiRequest::ValidationCodeByHash(hash, tx) => {
+			let _ = tx.send(Ok(runtime.validation_code_by_hash(hash)));
+		},
+		request => return Err(request),
+	}
+	Ok(())
+}
+
+/// The validators of a session, with all their keys in one keystore.
+pub struct TestValidators {
+	keystore: KeystorePtr,
+	validators: Vec<ValidatorId>,
+}
+
+impl TestValidators {
+	/// Create the keys of `keyrings` for a session with the given validators.
+	///
+	/// `validators` is the validator set as ordered by the runtime, and may only contain keys of
+	/// the given keyrings.
+	pub fn new(
+		keyrings: impl IntoIterator<Item = Sr25519Keyring>,
+		validators: Vec<ValidatorId>,
+	) -> Self {
+		let keystore: KeystorePtr = Arc::new(LocalKeystore::in_memory());
+		for keyring in keyrings {
+			keystore
+				.sr25519_generate_new(PARACHAIN_KEY_TYPE_ID, Some(&keyring.to_seed()))
+				.expect("Insert key into keystore");
+		}
+		Self { keystore, validators }
+	}
+
+	/// The validators in session order.
+	pub fn validators(&self) -> &[ValidatorId] {
+		&self.validators
+	}
+
+	/// Index of the given validator in the session.
+	///
+	/// Panics if the validator is not part of the session.
+	pub fn index_of(&self, keyring: Sr25519Keyring) -> ValidatorIndex {
+		let public = ValidatorId::from(keyring.public());
+		let index = self
+			.validators
+			.iter()
+			.position(|validator| validator == &public)
+			.expect("Validator is not part of the session");
+		ValidatorIndex(index as _)
+	}
+
+	/// Explicit dispute statement of the validator at `index`.
+	pub fn explicit_vote(
+		&self,
+		index: ValidatorIndex,
+		candidate_hash: CandidateHash,
+		session: SessionIndex,
+		valid: bool,
+	) -> (SignedDisputeStatement, ValidatorIndex) {
+		let public = self.validators[index.0 as usize].clone();
+		let statement = SignedDisputeStatement::sign_explicit(
+			&self.keystore,
+			valid,
+			candidate_hash,
+			session,
+			public,
+		)
+		.expect("Signing does not fail")
+		.expect("Key is in the keystore");
+		(statement, index)
+	}
+
+	/// Backing vote of the validator at `index`, cast at the given relay parent.
+	pub fn backing_vote(
+		&self,
+		index: ValidatorIndex,
+		candidate_hash: CandidateHash,
+		session: SessionIndex,
+		relay_parent: Hash,
+	) -> (SignedDisputeStatement, ValidatorIndex) {
+		let public = self.validators[index.0 as usize].clone();
+		let context = SigningContext { session_index: session, parent_hash: relay_parent };
+		let statement = SignedFullStatement::sign(
+			&self.keystore,
+			Statement::Valid(candidate_hash),
+			&context,
+			index,
+			&public,
+		)
+		.expect("Signing does not fail")
+		.expect("Key is in the keystore")
+		.into_unchecked();
+		let statement = SignedDisputeStatement::from_backing_statement(&statement, context, public)
+			.expect("Statement was signed by the given key");
+		(statement, index)
+	}
+}
+
+/// The votes a node gathered for a dispute, as they would be put on chain.
+pub fn dispute_statement_set(session: SessionIndex, votes: &CandidateVotes) -> DisputeStatementSet {
+	let valid = votes.valid.raw().iter().map(|(index, (kind, signature))| {
+		(DisputeStatement::Valid(kind.clone()), *index, signature.clone())
+	});
+	let invalid = votes.invalid.iter().map(|(index, (kind, signature))| {
+		(DisputeStatement::Invalid(*kind), *index, signature.clone())
+	});
+
+	DisputeStatementSet {
+		candidate_hash: votes.candidate_receipt.hash(),
+		session,
+		statements: valid.chain(invalid).collect(),
+	}
+}
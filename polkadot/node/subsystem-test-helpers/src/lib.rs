@@ -44,6 +44,9 @@ use std::{
 /// Generally useful mock data providers for unit tests.
 pub mod mock;
 
+/// Runtime and validator helpers for simulating disputes.
+pub mod disputes;
+
 enum SinkState<T> {
 	Empty { read_waker: Option<Waker> },
 	Item { item: T, ready_waker: Option<Waker>, flush_waker: Option<Waker> },
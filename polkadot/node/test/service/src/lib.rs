@@ -102,6 +102,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 					prepare_workers_soft_max_num: None,
 					enable_approval_voting_parallel: false,
 					keep_finalized_for: None,
+					pov_archive: None,
 				},
 			),
 		sc_network::config::NetworkBackendType::Litep2p =>
@@ -125,6 +126,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 					prepare_workers_soft_max_num: None,
 					enable_approval_voting_parallel: false,
 					keep_finalized_for: None,
+					pov_archive: None,
 				},
 			),
 	}
@@ -225,6 +227,7 @@ pub fn node_config(
 			rate_limit: None,
 			rate_limit_whitelisted_ips: Default::default(),
 			rate_limit_trust_proxy_headers: Default::default(),
+			policy_file: None,
 		},
 		prometheus_config: None,
 		telemetry_endpoints: None,
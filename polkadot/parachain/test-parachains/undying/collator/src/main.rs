@@ -101,6 +101,7 @@ fn main() -> Result<()> {
 						prepare_workers_soft_max_num: None,
 						enable_approval_voting_parallel: false,
 						keep_finalized_for: None,
+						pov_archive: None,
 					},
 				)
 				.map_err(|e| e.to_string())?;
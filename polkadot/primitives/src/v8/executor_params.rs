@@ -120,6 +120,13 @@ pub enum ExecutorParam {
 	/// Enables WASM bulk memory proposal
 	#[codec(index = 7)]
 	WasmExtBulkMemory,
+	/// PVF execution limits, in units of fuel consumed by the executed code.
+	/// Presence of this parameter enables deterministic metering of the execution, the
+	/// `PvfExecTimeout` still applies as a wall-clock safety net.
+	/// Always ensure that `backing_fuel` < `approval_fuel`.
+	/// When absent for the given kind, the execution is metered but not limited.
+	#[codec(index = 8)]
+	PvfExecFuel(PvfExecKind, u64),
 }
 
 /// Possible inconsistencies of executor params.
@@ -242,9 +249,15 @@ impl ExecutorParams {
 				PvfPrepTimeout(..) => Some(param),
 				PvfExecTimeout(..) => None,
 				WasmExtBulkMemory => Some(param),
+				PvfExecFuel(..) => None,
 			})
 			.for_each(|p| enc.extend(p.encode()));
 
+		// Metering is compiled into the artifact, while the limits are only enforced on execution.
+		if self.0.iter().any(|param| matches!(param, PvfExecFuel(..))) {
+			enc.extend(b"fuel");
+		}
+
 		ExecutorParamsPrepHash(BlakeTwo256::hash(&enc))
 	}
 
@@ -272,6 +285,23 @@ impl ExecutorParams {
 		None
 	}
 
+	/// Returns a PVF execution fuel limit, if any
+	pub fn pvf_exec_fuel(&self, kind: PvfExecKind) -> Option<u64> {
+		for param in &self.0 {
+			if let ExecutorParam::PvfExecFuel(k, fuel) = param {
+				if kind == *k {
+					return Some(*fuel)
+				}
+			}
+		}
+		None
+	}
+
+	/// Returns `true` if PVF execution should be deterministically metered
+	pub fn pvf_exec_metered(&self) -> bool {
+		self.0.iter().any(|param| matches!(param, ExecutorParam::PvfExecFuel(..)))
+	}
+
 	/// Returns pre-checking memory limit, if any
 	pub fn prechecking_max_memory(&self) -> Option<u64> {
 		for param in &self.0 {
@@ -325,6 +355,10 @@ impl ExecutorParams {
 					PvfExecKind::Approval => "PvfExecKind::Approval",
 				},
 				WasmExtBulkMemory => "WasmExtBulkMemory",
+				PvfExecFuel(kind, _) => match kind {
+					PvfExecKind::Backing => "PvfExecFuel::Backing",
+					PvfExecKind::Approval => "PvfExecFuel::Approval",
+				},
 			};
 
 			match *param {
@@ -359,6 +393,10 @@ impl ExecutorParams {
 				WasmExtBulkMemory => {
 					check!(param_ident, 1);
 				},
+
+				PvfExecFuel(_, val) => {
+					check!(param_ident, val, val == 0);
+				},
 			}
 		}
 
@@ -392,6 +430,14 @@ impl ExecutorParams {
 			}
 		}
 
+		if let (Some(backing), Some(approval)) =
+			(seen.get("PvfExecFuel::Backing"), seen.get("PvfExecFuel::Approval"))
+		{
+			if *backing >= *approval {
+				return Err(IncompatibleValues("PvfExecFuel::Backing", "PvfExecFuel::Approval"))
+			}
+		}
+
 		Ok(())
 	}
 }
@@ -429,6 +475,8 @@ fn ensure_prep_hash_changes() {
 			PvfExecTimeout(PvfExecKind::Backing, 0),
 			PvfExecTimeout(PvfExecKind::Approval, 0),
 			WasmExtBulkMemory,
+			PvfExecFuel(PvfExecKind::Backing, 0),
+			PvfExecFuel(PvfExecKind::Approval, 0),
 		][..],
 	);
 
@@ -452,6 +500,8 @@ fn ensure_prep_hash_changes() {
 			PvfExecTimeout(_, _) => continue,
 			WasmExtBulkMemory =>
 				(ExecutorParams::default(), ExecutorParams::from(&[WasmExtBulkMemory][..])),
+			PvfExecFuel(kind, _) =>
+				(ExecutorParams::default(), ExecutorParams::from(&[PvfExecFuel(*kind, 1)][..])),
 		};
 
 		assert_ne!(ep1.prep_hash(), ep2.prep_hash());
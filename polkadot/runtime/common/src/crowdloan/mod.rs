@@ -354,6 +354,13 @@ pub mod pallet {
 				T::DbWeight::get().reads(1)
 			}
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_decode_child_tries(
+			select: &frame_support::traits::DecodeSelect,
+		) -> Vec<frame_support::traits::StorageItemDecodeReport> {
+			Self::decode_contributions(select).into_iter().collect()
+		}
 	}
 
 	#[pallet::call]
@@ -717,6 +724,34 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Decode the contributions in the child tries of all funds, if they are included in
+	/// `select`.
+	#[cfg(feature = "try-runtime")]
+	fn decode_contributions(
+		select: &frame_support::traits::DecodeSelect,
+	) -> Option<frame_support::traits::StorageItemDecodeReport> {
+		use frame_support::traits::{PalletInfoAccess, StorageInfo, StorageItemDecodeReport};
+
+		let info = StorageInfo {
+			pallet_name: <Self as PalletInfoAccess>::name().as_bytes().to_vec(),
+			storage_name: b"Contributions".to_vec(),
+			prefix: Vec::new(),
+			max_values: None,
+			max_size: None,
+		};
+		if !select.includes(&info) {
+			return None
+		}
+
+		let mut report = StorageItemDecodeReport::new(info);
+		for fund in Funds::<T>::iter_values() {
+			report.decode_child_trie::<(BalanceOf<T>, Vec<u8>)>(&Self::id_from_index(
+				fund.fund_index,
+			));
+		}
+		Some(report)
+	}
+
 	/// This function checks all conditions which would qualify a crowdloan has ended.
 	/// * If we have reached the `fund.end` block OR the first lease period the fund is trying to
 	///   bid for has started already.
@@ -1293,6 +1328,40 @@ mod tests {
 		});
 	}
 
+	#[test]
+	#[cfg(feature = "try-runtime")]
+	fn decode_entire_state_reports_contributions() {
+		use frame_support::traits::{DecodeSelect, TryDecodeEntireStorage};
+
+		new_test_ext().execute_with(|| {
+			let para = new_para();
+			let index = NextFundIndex::<Test>::get();
+			assert_ok!(Crowdloan::create(RuntimeOrigin::signed(1), para, 1000, 1, 4, 9, None));
+			assert_ok!(Crowdloan::contribute(RuntimeOrigin::signed(1), para, 49, None));
+			assert_ok!(Crowdloan::contribute(RuntimeOrigin::signed(2), para, 50, None));
+
+			let select: DecodeSelect = "Crowdloan::Contributions".parse().unwrap();
+			let report = Crowdloan::decode_entire_state_report(&select);
+			assert_eq!(report.items.len(), 1);
+			assert_eq!((report.items[0].keys, report.items[0].failures), (2, 0));
+
+			// A contribution that cannot be decoded is reported with its key in the child trie.
+			frame_support::storage::child::put_raw(
+				&Crowdloan::id_from_index(index),
+				&3u64.encode(),
+				&[0u8],
+			);
+			let report = Crowdloan::decode_entire_state_report(&select);
+			assert_eq!((report.items[0].keys, report.items[0].failures), (3, 1));
+			assert_eq!(report.items[0].failed[0].key, 3u64.encode());
+
+			// Contributions are not reported when only other storage items are selected.
+			let select: DecodeSelect = "Crowdloan::Funds".parse().unwrap();
+			let report = Crowdloan::decode_entire_state_report(&select);
+			assert!(report.items.iter().all(|item| item.info.storage_name == b"Funds".to_vec()));
+		});
+	}
+
 	#[test]
 	fn contribute_with_verifier_works() {
 		new_test_ext().execute_with(|| {
@@ -35,6 +35,7 @@ use xcm_executor::traits::TransactAsset;
 
 use crate::{
 	assigner_coretime::{self, PartsOf57600},
+	configuration, inclusion,
 	initializer::{OnNewSession, SessionChangeNotification},
 	on_demand,
 	origin::{ensure_parachain, Origin},
@@ -42,6 +43,8 @@ use crate::{
 
 mod benchmarking;
 pub mod migration;
+#[cfg(test)]
+mod tests;
 
 const LOG_TARGET: &str = "runtime::parachains::coretime";
 
@@ -96,6 +99,8 @@ enum CoretimeCalls {
 	NotifyCoreCount(u16),
 	#[codec(index = 20)]
 	NotifyRevenue((BlockNumber, Balance)),
+	#[codec(index = 29)]
+	NotifyCoreUtilization(BlockNumber, Vec<BrokerCoreIndex>),
 	#[codec(index = 99)]
 	SwapLeases(ParaId, ParaId),
 }
@@ -115,7 +120,9 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config + assigner_coretime::Config + on_demand::Config {
+	pub trait Config:
+		frame_system::Config + assigner_coretime::Config + on_demand::Config + inclusion::Config
+	{
 		type RuntimeOrigin: From<<Self as frame_system::Config>::RuntimeOrigin>
 			+ Into<result::Result<Origin, <Self as Config>::RuntimeOrigin>>;
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -136,7 +143,8 @@ pub mod pallet {
 
 		/// Maximum weight for any XCM transact call that should be executed on the coretime chain.
 		///
-		/// Basically should be `max_weight(set_leases, reserve, notify_core_count)`.
+		/// Basically should be `max_weight(set_leases, reserve, notify_core_count,
+		/// notify_core_utilization)`.
 		type MaxXcmTransactWeight: Get<Weight>;
 	}
 
@@ -184,7 +192,10 @@ pub mod pallet {
 		/// last claimed until and up to the block specified. The claimed amount value is sent back
 		/// to the Coretime chain in a `notify_revenue` message. At the same time, the amount is
 		/// teleported to the Coretime chain.
-		#[pallet::weight(<T as Config>::WeightInfo::request_revenue_at())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::request_revenue_at()
+				.saturating_add(Pallet::<T>::take_core_utilization_weight())
+		)]
 		#[pallet::call_index(2)]
 		pub fn request_revenue_at(origin: OriginFor<T>, when: BlockNumber) -> DispatchResult {
 			// Ignore requests not coming from the Coretime Chain or Root.
@@ -305,9 +316,49 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::AssetTransferFailed
 		})?;
 
+		Self::notify_core_utilization(until);
+
 		Ok(())
 	}
 
+	/// The weight of taking the core utilization in [`Self::notify_core_utilization`].
+	///
+	/// Not part of the `request_revenue_at` benchmark, which runs without any included candidates.
+	/// At worst, a candidate was included on every core, so that every core has an entry to drain.
+	fn take_core_utilization_weight() -> Weight {
+		let num_cores = configuration::ActiveConfig::<T>::get().scheduler_params.num_cores;
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(num_cores.into()))
+	}
+
+	/// Report the cores on which no candidate was included since the last report to the coretime
+	/// chain, so that the broker can pool idle Regions whose owners opted into it.
+	fn notify_core_utilization(until: BlockNumber) {
+		let included = <inclusion::Pallet<T>>::take_core_utilization();
+		let num_cores = configuration::ActiveConfig::<T>::get().scheduler_params.num_cores;
+		let idle_cores = (0..num_cores)
+			.filter(|core| !included.contains_key(&CoreIndex(*core)))
+			.map(|core| core.saturated_into())
+			.collect::<Vec<BrokerCoreIndex>>();
+
+		let message = Xcm(vec![
+			Instruction::UnpaidExecution {
+				weight_limit: WeightLimit::Unlimited,
+				check_origin: None,
+			},
+			mk_coretime_call::<T>(crate::coretime::CoretimeCalls::NotifyCoreUtilization(
+				until, idle_cores,
+			)),
+		]);
+		if let Err(err) = send_xcm::<T::SendXcm>(
+			Location::new(0, [Junction::Parachain(T::BrokerId::get())]),
+			message,
+		) {
+			log::error!(target: LOG_TARGET, "Sending `NotifyCoreUtilization` to coretime chain failed: {:?}", err);
+		}
+	}
+
 	// Handle legacy swaps in coretime. Notifies coretime chain that a lease swap has occurred via
 	// XCM message. This function is meant to be used in an implementation of `OnSwap` trait.
 	pub fn on_legacy_lease_swap(one: ParaId, other: ParaId) {
@@ -0,0 +1,55 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::{new_test_ext, BrokerId, Coretime, SentXcm, Test};
+
+#[test]
+fn notify_core_utilization_reports_idle_cores() {
+	new_test_ext(Default::default()).execute_with(|| {
+		configuration::ActiveConfig::<Test>::mutate(|config| {
+			config.scheduler_params.num_cores = 3;
+		});
+		inclusion::CoreUtilization::<Test>::insert(CoreIndex(1), 2);
+
+		Coretime::notify_core_utilization(10);
+
+		let message = Xcm(vec![
+			Instruction::UnpaidExecution {
+				weight_limit: WeightLimit::Unlimited,
+				check_origin: None,
+			},
+			mk_coretime_call::<Test>(CoretimeCalls::NotifyCoreUtilization(10, vec![0, 2])),
+		]);
+		assert_eq!(
+			SentXcm::take(),
+			vec![(Location::new(0, [Junction::Parachain(BrokerId::get())]), message)]
+		);
+		// The utilization is reset for the next report.
+		assert!(inclusion::CoreUtilization::<Test>::iter().next().is_none());
+
+		// Without any included candidate, all cores are reported idle.
+		Coretime::notify_core_utilization(20);
+		let message = Xcm(vec![
+			Instruction::UnpaidExecution {
+				weight_limit: WeightLimit::Unlimited,
+				check_origin: None,
+			},
+			mk_coretime_call::<Test>(CoretimeCalls::NotifyCoreUtilization(20, vec![0, 1, 2])),
+		]);
+		assert_eq!(SentXcm::take()[0].1, message);
+	});
+}
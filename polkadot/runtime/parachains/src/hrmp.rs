@@ -25,7 +25,9 @@ use alloc::{
 };
 use codec::{Decode, Encode};
 use core::{fmt, mem};
-use frame_support::{pallet_prelude::*, traits::ReservableCurrency, DefaultNoBound};
+use frame_support::{
+	pallet_prelude::*, storage::with_storage_layer, traits::ReservableCurrency, DefaultNoBound,
+};
 use frame_system::pallet_prelude::*;
 use polkadot_parachain_primitives::primitives::{HorizontalMessages, IsSystem};
 use polkadot_primitives::{
@@ -67,6 +69,10 @@ pub trait WeightInfo {
 	fn establish_system_channel() -> Weight;
 	fn poke_channel_deposits() -> Weight;
 	fn establish_channel_with_system() -> Weight;
+	fn hrmp_request_channel_resize() -> Weight;
+	fn hrmp_accept_channel_resize() -> Weight;
+	fn force_resize_hrmp_channel() -> Weight;
+	fn force_process_hrmp_resize(c: u32) -> Weight;
 }
 
 /// A weight info that is only suitable for testing.
@@ -109,6 +115,18 @@ impl WeightInfo for TestWeightInfo {
 	fn establish_channel_with_system() -> Weight {
 		Weight::MAX
 	}
+	fn hrmp_request_channel_resize() -> Weight {
+		Weight::MAX
+	}
+	fn hrmp_accept_channel_resize() -> Weight {
+		Weight::MAX
+	}
+	fn force_resize_hrmp_channel() -> Weight {
+		Weight::MAX
+	}
+	fn force_process_hrmp_resize(_: u32) -> Weight {
+		Weight::MAX
+	}
 }
 
 /// A description of a request to open an HRMP channel.
@@ -129,6 +147,21 @@ pub struct HrmpOpenChannelRequest {
 	pub max_total_size: u32,
 }
 
+/// A description of a request to change the limits of an open HRMP channel.
+#[derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(test, derive(Debug))]
+pub struct HrmpChannelResizeRequest {
+	/// The participant of the channel that initiated this request, or `None` if the resize was
+	/// forced by the `ChannelManager`.
+	pub initiator: Option<ParaId>,
+	/// Indicates if this request was confirmed by the counterparty of the initiator.
+	pub confirmed: bool,
+	/// The new maximum number of messages that can be pending in the channel at once.
+	pub max_capacity: u32,
+	/// The new maximum message size that could be put into the channel.
+	pub max_message_size: u32,
+}
+
 /// A metadata of an HRMP channel.
 #[derive(Encode, Decode, TypeInfo)]
 #[cfg_attr(test, derive(Debug))]
@@ -323,6 +356,23 @@ pub mod pallet {
 		},
 		/// An HRMP channel's deposits were updated.
 		OpenChannelDepositsUpdated { sender: ParaId, recipient: ParaId },
+		/// Resizing of an open HRMP channel was requested by one of its participants.
+		ChannelResizeRequested {
+			by_parachain: ParaId,
+			channel_id: HrmpChannelId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+		},
+		/// Resizing of an open HRMP channel was accepted by the counterparty of the initiator.
+		ChannelResizeAccepted { by_parachain: ParaId, channel_id: HrmpChannelId },
+		/// Resizing of an open HRMP channel was requested via Root origin.
+		HrmpChannelForceResized {
+			channel_id: HrmpChannelId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+		},
+		/// The new limits of an HRMP channel came into effect.
+		ChannelResized { channel_id: HrmpChannelId, max_capacity: u32, max_message_size: u32 },
 	}
 
 	#[pallet::error]
@@ -367,6 +417,17 @@ pub mod pallet {
 		WrongWitness,
 		/// The channel between these two chains cannot be authorized.
 		ChannelCreationNotAuthorized,
+		/// The channel to be resized doesn't exist.
+		ResizeHrmpChannelDoesntExist,
+		/// The origin tries to resize a channel where it is neither the sender nor the recipient,
+		/// or to accept a resize request it initiated itself.
+		ResizeHrmpChannelUnauthorized,
+		/// The channel to be resized is going to be closed on the next session change.
+		ResizeHrmpChannelClosing,
+		/// The resize request to be accepted doesn't exist.
+		ResizeHrmpChannelRequestDoesntExist,
+		/// The resize request is already confirmed.
+		ResizeHrmpChannelAlreadyConfirmed,
 	}
 
 	/// The set of pending HRMP open channel requests.
@@ -414,6 +475,22 @@ pub mod pallet {
 	pub type HrmpCloseChannelRequestsList<T: Config> =
 		StorageValue<_, Vec<HrmpChannelId>, ValueQuery>;
 
+	/// The set of pending requests to change the limits of open HRMP channels. Confirmed requests
+	/// are enacted during the session change, as long as the messages pending in the channel fit
+	/// into the new limits.
+	///
+	/// The set is accompanied by a list for iteration.
+	///
+	/// Invariant:
+	/// - There are no channels that exists in list but not in the set and vice versa.
+	#[pallet::storage]
+	pub type HrmpChannelResizeRequests<T: Config> =
+		StorageMap<_, Twox64Concat, HrmpChannelId, HrmpChannelResizeRequest>;
+
+	#[pallet::storage]
+	pub type HrmpChannelResizeRequestsList<T: Config> =
+		StorageValue<_, Vec<HrmpChannelId>, ValueQuery>;
+
 	/// The HRMP watermark associated with each para.
 	/// Invariant:
 	/// - each para `P` used here as a key should satisfy `Paras::is_valid_para(P)` within a
@@ -571,7 +648,12 @@ pub mod pallet {
 		///
 		/// Origin must be the `ChannelManager`.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::force_clean_hrmp(*num_inbound, *num_outbound))]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::force_clean_hrmp(*num_inbound, *num_outbound)
+				.saturating_add(Pallet::<T>::drop_resize_requests_weight(
+					num_inbound.saturating_add(*num_outbound),
+				))
+		)]
 		pub fn force_clean_hrmp(
 			origin: OriginFor<T>,
 			para: ParaId,
@@ -628,7 +710,10 @@ pub mod pallet {
 		///
 		/// Origin must be the `ChannelManager`.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::force_process_hrmp_close(*channels))]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::force_process_hrmp_close(*channels)
+				.saturating_add(Pallet::<T>::drop_resize_requests_weight(*channels))
+		)]
 		pub fn force_process_hrmp_close(origin: OriginFor<T>, channels: u32) -> DispatchResult {
 			T::ChannelManager::ensure_origin(origin)?;
 
@@ -776,77 +861,11 @@ pub mod pallet {
 		) -> DispatchResult {
 			let _caller = ensure_signed(origin)?;
 			let channel_id = HrmpChannelId { sender, recipient };
-			let is_system = sender.is_system() || recipient.is_system();
 
 			let config = configuration::ActiveConfig::<T>::get();
-
-			// Channels with and amongst the system do not require a deposit.
-			let (new_sender_deposit, new_recipient_deposit) = if is_system {
-				(0, 0)
-			} else {
-				(config.hrmp_sender_deposit, config.hrmp_recipient_deposit)
-			};
-
-			let _ = HrmpChannels::<T>::mutate(&channel_id, |channel| -> DispatchResult {
-				if let Some(ref mut channel) = channel {
-					let current_sender_deposit = channel.sender_deposit;
-					let current_recipient_deposit = channel.recipient_deposit;
-
-					// nothing to update
-					if current_sender_deposit == new_sender_deposit &&
-						current_recipient_deposit == new_recipient_deposit
-					{
-						return Ok(())
-					}
-
-					// sender
-					if current_sender_deposit > new_sender_deposit {
-						// Can never underflow, but be paranoid.
-						let amount = current_sender_deposit
-							.checked_sub(new_sender_deposit)
-							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::unreserve(
-							&channel_id.sender.into_account_truncating(),
-							// The difference should always be convertible into `Balance`, but be
-							// paranoid and do nothing in case.
-							amount.try_into().unwrap_or(Zero::zero()),
-						);
-					} else if current_sender_deposit < new_sender_deposit {
-						let amount = new_sender_deposit
-							.checked_sub(current_sender_deposit)
-							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::reserve(
-							&channel_id.sender.into_account_truncating(),
-							amount.try_into().unwrap_or(Zero::zero()),
-						)?;
-					}
-
-					// recipient
-					if current_recipient_deposit > new_recipient_deposit {
-						let amount = current_recipient_deposit
-							.checked_sub(new_recipient_deposit)
-							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::unreserve(
-							&channel_id.recipient.into_account_truncating(),
-							amount.try_into().unwrap_or(Zero::zero()),
-						);
-					} else if current_recipient_deposit < new_recipient_deposit {
-						let amount = new_recipient_deposit
-							.checked_sub(current_recipient_deposit)
-							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::reserve(
-							&channel_id.recipient.into_account_truncating(),
-							amount.try_into().unwrap_or(Zero::zero()),
-						)?;
-					}
-
-					// update storage
-					channel.sender_deposit = new_sender_deposit;
-					channel.recipient_deposit = new_recipient_deposit;
-				} else {
-					return Err(Error::<T>::OpenHrmpChannelDoesntExist.into())
-				}
-				Ok(())
+			HrmpChannels::<T>::try_mutate(&channel_id, |channel| -> DispatchResult {
+				let channel = channel.as_mut().ok_or(Error::<T>::OpenHrmpChannelDoesntExist)?;
+				Self::update_channel_deposits(&channel_id, channel, &config)
 			})?;
 
 			Self::deposit_event(Event::OpenChannelDepositsUpdated { sender, recipient });
@@ -897,6 +916,117 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
+
+		/// Request changing the limits of an open channel. The origin must be either the sender or
+		/// the recipient of the channel.
+		///
+		/// - `proposed_max_capacity` - specifies how many messages can be in the channel at once.
+		/// - `proposed_max_message_size` - specifies the maximum size of the messages.
+		///
+		/// These numbers are a subject to the relay-chain configuration limits. A previous request
+		/// that was not accepted yet is replaced.
+		///
+		/// The new limits are applied only after the counterparty accepts them and only on a
+		/// session change. The deposits held for the channel are updated to the latest
+		/// `Configuration` at the same time.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::hrmp_request_channel_resize())]
+		pub fn hrmp_request_channel_resize(
+			origin: OriginFor<T>,
+			channel_id: HrmpChannelId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+		) -> DispatchResult {
+			let origin = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+			Self::request_channel_resize(
+				origin,
+				channel_id.clone(),
+				proposed_max_capacity,
+				proposed_max_message_size,
+			)?;
+			Self::deposit_event(Event::ChannelResizeRequested {
+				by_parachain: origin,
+				channel_id,
+				proposed_max_capacity,
+				proposed_max_message_size,
+			});
+			Ok(())
+		}
+
+		/// Accept a pending request to resize a channel. The origin must be the participant of the
+		/// channel that did not initiate the request.
+		///
+		/// The new limits will be applied only on the next session boundary.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::hrmp_accept_channel_resize())]
+		pub fn hrmp_accept_channel_resize(
+			origin: OriginFor<T>,
+			channel_id: HrmpChannelId,
+		) -> DispatchResult {
+			let origin = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+			Self::accept_channel_resize(origin, channel_id.clone())?;
+			Self::deposit_event(Event::ChannelResizeAccepted { by_parachain: origin, channel_id });
+			Ok(())
+		}
+
+		/// Change the limits of an open channel without the consent of its participants. Although
+		/// forced by governance, the `max_capacity` and `max_message_size` are still subject to
+		/// the Relay Chain's configured limits.
+		///
+		/// Any pending resize request for the channel is replaced. The new limits will be applied
+		/// only on the next session boundary.
+		///
+		/// Origin must be the `ChannelManager`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_resize_hrmp_channel())]
+		pub fn force_resize_hrmp_channel(
+			origin: OriginFor<T>,
+			channel_id: HrmpChannelId,
+			max_capacity: u32,
+			max_message_size: u32,
+		) -> DispatchResult {
+			T::ChannelManager::ensure_origin(origin)?;
+
+			Self::schedule_channel_resize(
+				&channel_id,
+				HrmpChannelResizeRequest {
+					initiator: None,
+					confirmed: true,
+					max_capacity,
+					max_message_size,
+				},
+			)?;
+			Self::deposit_event(Event::HrmpChannelForceResized {
+				channel_id,
+				proposed_max_capacity: max_capacity,
+				proposed_max_message_size: max_message_size,
+			});
+			Ok(())
+		}
+
+		/// Force process HRMP channel resize requests.
+		///
+		/// If there are accepted HRMP channel resize requests, you can use this function to
+		/// process all of those requests immediately.
+		///
+		/// Total number of pending resize requests must be provided as witness data.
+		///
+		/// Origin must be the `ChannelManager`.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_process_hrmp_resize(*channels))]
+		pub fn force_process_hrmp_resize(origin: OriginFor<T>, channels: u32) -> DispatchResult {
+			T::ChannelManager::ensure_origin(origin)?;
+
+			ensure!(
+				HrmpChannelResizeRequestsList::<T>::decode_len().unwrap_or_default() as u32 <=
+					channels,
+				Error::<T>::WrongWitness
+			);
+
+			let host_config = configuration::ActiveConfig::<T>::get();
+			Self::process_hrmp_channel_resize_requests(&host_config);
+			Ok(())
+		}
 	}
 }
 
@@ -941,12 +1071,26 @@ impl<T: Config> Pallet<T> {
 		let w1 = Self::perform_outgoing_para_cleanup(&notification.prev_config, outgoing_paras);
 		Self::process_hrmp_open_channel_requests(&notification.prev_config);
 		Self::process_hrmp_close_channel_requests();
+		let resize_requests =
+			HrmpChannelResizeRequestsList::<T>::decode_len().unwrap_or_default() as u32;
+		Self::process_hrmp_channel_resize_requests(&notification.prev_config);
 		w1.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_open(
 			outgoing_paras.len() as u32
 		))
 		.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_close(
-			outgoing_paras.len() as u32,
+			outgoing_paras.len() as u32
 		))
+		.saturating_add(Self::drop_resize_requests_weight(outgoing_paras.len() as u32))
+		.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_resize(resize_requests))
+	}
+
+	/// The weight of dropping the pending resize requests of `channels` closed channels, i.e. of
+	/// taking the request and removing it from the list.
+	///
+	/// The benchmarks of the calls closing channels predate the resize requests, so this is added
+	/// on top of their weights.
+	fn drop_resize_requests_weight(channels: u32) -> Weight {
+		T::DbWeight::get().reads_writes(2, 2).saturating_mul(channels.into())
 	}
 
 	/// Iterate over all paras that were noted for offboarding and remove all the data
@@ -965,10 +1109,14 @@ impl<T: Config> Pallet<T> {
 				HrmpIngressChannelsIndex::<T>::decode_len(outgoing_para).unwrap_or_default() as u32;
 			let egress_count =
 				HrmpEgressChannelsIndex::<T>::decode_len(outgoing_para).unwrap_or_default() as u32;
-			w = w.saturating_add(<T as Config>::WeightInfo::force_clean_hrmp(
-				ingress_count,
-				egress_count,
-			));
+			w = w
+				.saturating_add(<T as Config>::WeightInfo::force_clean_hrmp(
+					ingress_count,
+					egress_count,
+				))
+				.saturating_add(Self::drop_resize_requests_weight(
+					ingress_count.saturating_add(egress_count),
+				));
 		}
 		w
 	}
@@ -1119,6 +1267,141 @@ impl<T: Config> Pallet<T> {
 		HrmpOpenChannelRequestsList::<T>::put(open_req_channels);
 	}
 
+	/// Iterate over all channel resize requests and enact the confirmed ones.
+	///
+	/// Requests for channels that no longer exist are dropped. Requests whose limits cannot
+	/// accommodate the messages currently pending in the channel are kept until a later session
+	/// change, when the recipient has processed enough of them.
+	fn process_hrmp_channel_resize_requests(config: &HostConfiguration<BlockNumberFor<T>>) {
+		let mut resize_req_channels = HrmpChannelResizeRequestsList::<T>::get();
+		if resize_req_channels.is_empty() {
+			return
+		}
+
+		resize_req_channels.retain(|channel_id| {
+			let request = HrmpChannelResizeRequests::<T>::get(channel_id).expect(
+				"can't be `None` due to the invariant that the list contains the same items as the set; qed",
+			);
+			let Some(mut channel) = HrmpChannels::<T>::get(channel_id) else {
+				HrmpChannelResizeRequests::<T>::remove(channel_id);
+				return false
+			};
+			if !request.confirmed {
+				return true
+			}
+
+			let max_total_size = config.hrmp_channel_max_total_size;
+			if channel.msg_count > request.max_capacity || channel.total_size > max_total_size {
+				return true
+			}
+
+			channel.max_capacity = request.max_capacity;
+			channel.max_message_size = request.max_message_size;
+			channel.max_total_size = max_total_size;
+
+			// The new limits are applied even if the deposits cannot be updated, in which case the
+			// previous deposits stay in place and can be updated later by poking them.
+			if let Err(err) = with_storage_layer(|| {
+				Self::update_channel_deposits(channel_id, &mut channel, config)
+			}) {
+				log::warn!(
+					target: "runtime::hrmp",
+					"failed to update the deposits of the resized channel {:?}->{:?}: {:?}",
+					channel_id.sender,
+					channel_id.recipient,
+					err,
+				);
+			}
+
+			HrmpChannels::<T>::insert(channel_id, &channel);
+			HrmpChannelResizeRequests::<T>::remove(channel_id);
+			Self::deposit_event(Event::ChannelResized {
+				channel_id: channel_id.clone(),
+				max_capacity: channel.max_capacity,
+				max_message_size: channel.max_message_size,
+			});
+			false
+		});
+
+		HrmpChannelResizeRequestsList::<T>::put(resize_req_channels);
+	}
+
+	/// Reserve or unreserve the difference between the deposits held for the given channel and
+	/// the ones required by the given configuration. Channels with and amongst the system do not
+	/// require a deposit.
+	///
+	/// The deposits recorded in `channel` are updated only if all the reservations succeed.
+	fn update_channel_deposits(
+		channel_id: &HrmpChannelId,
+		channel: &mut HrmpChannel,
+		config: &HostConfiguration<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let is_system = channel_id.sender.is_system() || channel_id.recipient.is_system();
+
+		// Channels with and amongst the system do not require a deposit.
+		let (new_sender_deposit, new_recipient_deposit) = if is_system {
+			(0, 0)
+		} else {
+			(config.hrmp_sender_deposit, config.hrmp_recipient_deposit)
+		};
+
+		let current_sender_deposit = channel.sender_deposit;
+		let current_recipient_deposit = channel.recipient_deposit;
+
+		// nothing to update
+		if current_sender_deposit == new_sender_deposit &&
+			current_recipient_deposit == new_recipient_deposit
+		{
+			return Ok(())
+		}
+
+		// sender
+		if current_sender_deposit > new_sender_deposit {
+			// Can never underflow, but be paranoid.
+			let amount = current_sender_deposit
+				.checked_sub(new_sender_deposit)
+				.ok_or(ArithmeticError::Underflow)?;
+			T::Currency::unreserve(
+				&channel_id.sender.into_account_truncating(),
+				// The difference should always be convertible into `Balance`, but be
+				// paranoid and do nothing in case.
+				amount.try_into().unwrap_or(Zero::zero()),
+			);
+		} else if current_sender_deposit < new_sender_deposit {
+			let amount = new_sender_deposit
+				.checked_sub(current_sender_deposit)
+				.ok_or(ArithmeticError::Underflow)?;
+			T::Currency::reserve(
+				&channel_id.sender.into_account_truncating(),
+				amount.try_into().unwrap_or(Zero::zero()),
+			)?;
+		}
+
+		// recipient
+		if current_recipient_deposit > new_recipient_deposit {
+			let amount = current_recipient_deposit
+				.checked_sub(new_recipient_deposit)
+				.ok_or(ArithmeticError::Underflow)?;
+			T::Currency::unreserve(
+				&channel_id.recipient.into_account_truncating(),
+				amount.try_into().unwrap_or(Zero::zero()),
+			);
+		} else if current_recipient_deposit < new_recipient_deposit {
+			let amount = new_recipient_deposit
+				.checked_sub(current_recipient_deposit)
+				.ok_or(ArithmeticError::Underflow)?;
+			T::Currency::reserve(
+				&channel_id.recipient.into_account_truncating(),
+				amount.try_into().unwrap_or(Zero::zero()),
+			)?;
+		}
+
+		// update the recorded deposits
+		channel.sender_deposit = new_sender_deposit;
+		channel.recipient_deposit = new_recipient_deposit;
+		Ok(())
+	}
+
 	/// Iterate over all close channel requests unconditionally closing the channels.
 	fn process_hrmp_close_channel_requests() {
 		let close_reqs = HrmpCloseChannelRequestsList::<T>::take();
@@ -1130,7 +1413,7 @@ impl<T: Config> Pallet<T> {
 
 	/// Close and remove the designated HRMP channel.
 	///
-	/// This includes returning the deposits.
+	/// This includes returning the deposits and dropping any pending resize request.
 	///
 	/// This function is idempotent, meaning that after the first application it should have no
 	/// effect (i.e. it won't return the deposits twice).
@@ -1150,6 +1433,10 @@ impl<T: Config> Pallet<T> {
 
 		HrmpChannelContents::<T>::remove(channel_id);
 
+		if HrmpChannelResizeRequests::<T>::take(channel_id).is_some() {
+			HrmpChannelResizeRequestsList::<T>::mutate(|list| list.retain(|id| id != channel_id));
+		}
+
 		HrmpEgressChannelsIndex::<T>::mutate(&channel_id.sender, |v| {
 			if let Ok(i) = v.binary_search(&channel_id.recipient) {
 				v.remove(i);
@@ -1438,16 +1725,11 @@ impl<T: Config> Pallet<T> {
 		);
 
 		let config = configuration::ActiveConfig::<T>::get();
-		ensure!(proposed_max_capacity > 0, Error::<T>::OpenHrmpChannelZeroCapacity);
-		ensure!(
-			proposed_max_capacity <= config.hrmp_channel_max_capacity,
-			Error::<T>::OpenHrmpChannelCapacityExceedsLimit,
-		);
-		ensure!(proposed_max_message_size > 0, Error::<T>::OpenHrmpChannelZeroMessageSize);
-		ensure!(
-			proposed_max_message_size <= config.hrmp_channel_max_message_size,
-			Error::<T>::OpenHrmpChannelMessageSizeExceedsLimit,
-		);
+		Self::ensure_valid_channel_limits(
+			&config,
+			proposed_max_capacity,
+			proposed_max_message_size,
+		)?;
 
 		let channel_id = HrmpChannelId { sender: origin, recipient };
 		ensure!(
@@ -1630,6 +1912,117 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Request changing the limits of an open channel on behalf of one of its participants.
+	///
+	/// Basically the same as [`hrmp_request_channel_resize`](Pallet::hrmp_request_channel_resize)
+	/// but intended for calling directly from other pallets rather than dispatched.
+	pub fn request_channel_resize(
+		origin: ParaId,
+		channel_id: HrmpChannelId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	) -> DispatchResult {
+		ensure!(channel_id.is_participant(origin), Error::<T>::ResizeHrmpChannelUnauthorized);
+
+		Self::schedule_channel_resize(
+			&channel_id,
+			HrmpChannelResizeRequest {
+				initiator: Some(origin),
+				confirmed: false,
+				max_capacity: proposed_max_capacity,
+				max_message_size: proposed_max_message_size,
+			},
+		)?;
+
+		let config = configuration::ActiveConfig::<T>::get();
+		let opposite_party =
+			if origin == channel_id.sender { channel_id.recipient } else { channel_id.sender };
+
+		Self::send_to_para(
+			"request_channel_resize",
+			&config,
+			opposite_party,
+			Self::wrap_notification(|| {
+				use xcm::opaque::latest::{prelude::*, Xcm};
+				Xcm(vec![HrmpChannelResizing {
+					initiator: origin.into(),
+					sender: channel_id.sender.into(),
+					recipient: channel_id.recipient.into(),
+					max_message_size: proposed_max_message_size,
+					max_capacity: proposed_max_capacity,
+				}])
+			}),
+		);
+
+		Ok(())
+	}
+
+	/// Accept a pending request to resize the given channel.
+	///
+	/// Basically the same as [`hrmp_accept_channel_resize`](Pallet::hrmp_accept_channel_resize)
+	/// but intended for calling directly from other pallets rather than dispatched.
+	pub fn accept_channel_resize(origin: ParaId, channel_id: HrmpChannelId) -> DispatchResult {
+		ensure!(channel_id.is_participant(origin), Error::<T>::ResizeHrmpChannelUnauthorized);
+
+		HrmpChannelResizeRequests::<T>::try_mutate(&channel_id, |request| -> DispatchResult {
+			let request =
+				request.as_mut().ok_or(Error::<T>::ResizeHrmpChannelRequestDoesntExist)?;
+			ensure!(!request.confirmed, Error::<T>::ResizeHrmpChannelAlreadyConfirmed);
+			ensure!(request.initiator != Some(origin), Error::<T>::ResizeHrmpChannelUnauthorized);
+			request.confirmed = true;
+			Ok(())
+		})
+	}
+
+	/// Store the resize request for the given open channel, replacing the pending one unless it
+	/// was already accepted by the counterparty. Forced requests replace any pending request.
+	fn schedule_channel_resize(
+		channel_id: &HrmpChannelId,
+		request: HrmpChannelResizeRequest,
+	) -> DispatchResult {
+		let config = configuration::ActiveConfig::<T>::get();
+		Self::ensure_valid_channel_limits(&config, request.max_capacity, request.max_message_size)?;
+
+		ensure!(
+			HrmpChannels::<T>::contains_key(channel_id),
+			Error::<T>::ResizeHrmpChannelDoesntExist,
+		);
+		ensure!(
+			!HrmpCloseChannelRequests::<T>::contains_key(channel_id),
+			Error::<T>::ResizeHrmpChannelClosing,
+		);
+
+		match HrmpChannelResizeRequests::<T>::get(channel_id) {
+			Some(pending) => ensure!(
+				request.initiator.is_none() || !pending.confirmed,
+				Error::<T>::ResizeHrmpChannelAlreadyConfirmed,
+			),
+			None => HrmpChannelResizeRequestsList::<T>::append(channel_id.clone()),
+		}
+		HrmpChannelResizeRequests::<T>::insert(channel_id, request);
+
+		Ok(())
+	}
+
+	/// Check the proposed limits of a channel against the limits set by the configuration.
+	fn ensure_valid_channel_limits(
+		config: &HostConfiguration<BlockNumberFor<T>>,
+		max_capacity: u32,
+		max_message_size: u32,
+	) -> DispatchResult {
+		ensure!(max_capacity > 0, Error::<T>::OpenHrmpChannelZeroCapacity);
+		ensure!(
+			max_capacity <= config.hrmp_channel_max_capacity,
+			Error::<T>::OpenHrmpChannelCapacityExceedsLimit,
+		);
+		ensure!(max_message_size > 0, Error::<T>::OpenHrmpChannelZeroMessageSize);
+		ensure!(
+			max_message_size <= config.hrmp_channel_max_message_size,
+			Error::<T>::OpenHrmpChannelMessageSizeExceedsLimit,
+		);
+		Ok(())
+	}
+
 	/// Returns the list of MQC heads for the inbound channels of the given recipient para paired
 	/// with the sender para ids. This vector is sorted ascending by the para id and doesn't contain
 	/// multiple entries with the same sender.
@@ -1755,6 +2148,15 @@ impl<T: Config> Pallet<T> {
 			HrmpCloseChannelRequestsList::<T>::get().into_iter().collect::<BTreeSet<_>>(),
 		);
 
+		assert_eq!(
+			HrmpChannelResizeRequests::<T>::iter().map(|(k, _)| k).collect::<BTreeSet<_>>(),
+			HrmpChannelResizeRequestsList::<T>::get().into_iter().collect::<BTreeSet<_>>(),
+		);
+		// Only open channels can be resized.
+		for (channel_id, _) in HrmpChannelResizeRequests::<T>::iter() {
+			assert!(HrmpChannels::<T>::contains_key(&channel_id));
+		}
+
 		// A HRMP watermark can be None for an onboarded parachain. However, an offboarded parachain
 		// cannot have an HRMP watermark: it should've been cleanup.
 		assert_contains_only_onboarded(
@@ -561,6 +561,122 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn hrmp_request_channel_resize() {
+		let [(sender, sender_origin), (recipient, _)] =
+			establish_para_connection::<T>(1, 2, ParachainSetupStep::Established);
+		let channel_id = HrmpChannelId { sender, recipient };
+
+		let config = configuration::ActiveConfig::<T>::get();
+		let capacity = config.hrmp_channel_max_capacity;
+		let message_size = config.hrmp_channel_max_message_size;
+
+		#[extrinsic_call]
+		_(sender_origin, channel_id.clone(), capacity, message_size);
+
+		assert_last_event::<T>(
+			Event::<T>::ChannelResizeRequested {
+				by_parachain: sender,
+				channel_id,
+				proposed_max_capacity: capacity,
+				proposed_max_message_size: message_size,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn hrmp_accept_channel_resize() {
+		let [(sender, sender_origin), (recipient, recipient_origin)] =
+			establish_para_connection::<T>(1, 2, ParachainSetupStep::Established);
+		let channel_id = HrmpChannelId { sender, recipient };
+
+		let config = configuration::ActiveConfig::<T>::get();
+		assert_ok!(Hrmp::<T>::hrmp_request_channel_resize(
+			sender_origin.into(),
+			channel_id.clone(),
+			config.hrmp_channel_max_capacity,
+			config.hrmp_channel_max_message_size,
+		));
+
+		#[extrinsic_call]
+		_(recipient_origin, channel_id.clone());
+
+		assert_last_event::<T>(
+			Event::<T>::ChannelResizeAccepted { by_parachain: recipient, channel_id }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn force_resize_hrmp_channel() {
+		let [(sender, _), (recipient, _)] =
+			establish_para_connection::<T>(1, 2, ParachainSetupStep::Established);
+		let channel_id = HrmpChannelId { sender, recipient };
+
+		let config = configuration::ActiveConfig::<T>::get();
+		let capacity = config.hrmp_channel_max_capacity;
+		let message_size = config.hrmp_channel_max_message_size;
+
+		#[extrinsic_call]
+		_(frame_system::Origin::<T>::Root, channel_id.clone(), capacity, message_size);
+
+		assert_last_event::<T>(
+			Event::<T>::HrmpChannelForceResized {
+				channel_id,
+				proposed_max_capacity: capacity,
+				proposed_max_message_size: message_size,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn force_process_hrmp_resize(
+		// number of channels that need to be processed. Worse case is an N-M relation: unique
+		// sender and recipients for all channels.
+		c: Linear<0, MAX_UNIQUE_CHANNELS>,
+	) {
+		let config = configuration::ActiveConfig::<T>::get();
+		for id in 0..c {
+			let [(sender, _), (recipient, _)] = establish_para_connection::<T>(
+				PREFIX_0 + id,
+				PREFIX_1 + id,
+				ParachainSetupStep::Established,
+			);
+			let channel_id = HrmpChannelId { sender, recipient };
+
+			// Release the deposits, such that they have to be reserved again when the channel is
+			// resized.
+			let channel = HrmpChannels::<T>::get(&channel_id).unwrap();
+			T::Currency::unreserve(
+				&sender.into_account_truncating(),
+				channel.sender_deposit.unique_saturated_into(),
+			);
+			T::Currency::unreserve(
+				&recipient.into_account_truncating(),
+				channel.recipient_deposit.unique_saturated_into(),
+			);
+			HrmpChannels::<T>::mutate(&channel_id, |channel| {
+				let channel = channel.as_mut().unwrap();
+				channel.sender_deposit = 0;
+				channel.recipient_deposit = 0;
+			});
+
+			assert_ok!(Hrmp::<T>::force_resize_hrmp_channel(
+				frame_system::Origin::<T>::Root.into(),
+				channel_id,
+				config.hrmp_channel_max_capacity,
+				config.hrmp_channel_max_message_size,
+			));
+		}
+		assert_eq!(HrmpChannelResizeRequestsList::<T>::decode_len().unwrap_or_default() as u32, c);
+
+		#[extrinsic_call]
+		_(frame_system::Origin::<T>::Root, c);
+
+		assert_eq!(HrmpChannelResizeRequestsList::<T>::decode_len().unwrap_or_default() as u32, 0);
+	}
+
 	impl_benchmark_test_suite!(
 		Hrmp,
 		crate::mock::new_test_ext(crate::hrmp::tests::GenesisConfigBuilder::default().build()),
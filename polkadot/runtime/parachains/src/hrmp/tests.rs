@@ -1142,3 +1142,365 @@ fn hrmp_notifications_works() {
 		);
 	});
 }
+
+#[test]
+fn resize_channel_works() {
+	let para_a = 2001.into();
+	let para_a_origin: crate::Origin = 2001.into();
+	let para_b = 2003.into();
+	let para_b_origin: crate::Origin = 2003.into();
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		register_parachain(para_a);
+		register_parachain(para_b);
+
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(6, Some(vec![6]));
+		assert!(channel_exists(para_a, para_b));
+
+		// The recipient asks for smaller limits.
+		let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+		assert_ok!(Hrmp::hrmp_request_channel_resize(
+			para_b_origin.clone().into(),
+			channel_id.clone(),
+			1,
+			4
+		));
+		Hrmp::assert_storage_consistency_exhaustive();
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::ChannelResizeRequested {
+				by_parachain: para_b,
+				channel_id: channel_id.clone(),
+				proposed_max_capacity: 1,
+				proposed_max_message_size: 4,
+			})));
+
+		// Unless accepted, the request has no effect on the session change.
+		run_to_block(8, Some(vec![8]));
+		let channel = HrmpChannels::<Test>::get(&channel_id).unwrap();
+		assert_eq!((channel.max_capacity, channel.max_message_size), (2, 8));
+
+		// The initiator cannot accept its own request.
+		assert_noop!(
+			Hrmp::hrmp_accept_channel_resize(para_b_origin.into(), channel_id.clone()),
+			Error::<Test>::ResizeHrmpChannelUnauthorized,
+		);
+		assert_ok!(Hrmp::hrmp_accept_channel_resize(
+			para_a_origin.clone().into(),
+			channel_id.clone()
+		));
+		assert_noop!(
+			Hrmp::hrmp_accept_channel_resize(para_a_origin.into(), channel_id.clone()),
+			Error::<Test>::ResizeHrmpChannelAlreadyConfirmed,
+		);
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// The new limits are applied only on the session change.
+		run_to_block(9, None);
+		let channel = HrmpChannels::<Test>::get(&channel_id).unwrap();
+		assert_eq!((channel.max_capacity, channel.max_message_size), (2, 8));
+
+		run_to_block(10, Some(vec![10]));
+		let channel = HrmpChannels::<Test>::get(&channel_id).unwrap();
+		assert_eq!((channel.max_capacity, channel.max_message_size), (1, 4));
+		assert!(HrmpChannelResizeRequests::<Test>::get(&channel_id).is_none());
+		Hrmp::assert_storage_consistency_exhaustive();
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::ChannelResized {
+				channel_id: channel_id.clone(),
+				max_capacity: 1,
+				max_message_size: 4,
+			})));
+	});
+}
+
+#[test]
+fn resize_request_notifies_counterparty() {
+	use xcm::opaque::{
+		latest::{prelude::*, Xcm},
+		VersionedXcm,
+	};
+
+	let para_a = 2001.into();
+	let para_b = 2003.into();
+	let para_b_origin: crate::Origin = 2003.into();
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		register_parachain(para_a);
+		register_parachain(para_b);
+
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(6, Some(vec![6]));
+		assert!(channel_exists(para_a, para_b));
+		let _ = Dmp::prune_dmq(para_a, 1000);
+		let _ = Dmp::prune_dmq(para_b, 1000);
+
+		// The notification cannot be expressed in older XCM versions, so the latest one is used
+		// even though the sender of the channel only supports the previous version.
+		TestUsesOnlyStoredVersionWrapper::set_version(
+			Location::new(0, [Junction::Parachain(para_a.into())]),
+			Some(XCM_VERSION - 1),
+		);
+
+		let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+		assert_ok!(Hrmp::hrmp_request_channel_resize(para_b_origin.into(), channel_id, 1, 4));
+
+		assert_eq!(
+			Dmp::dmq_contents(para_a),
+			vec![InboundDownwardMessage {
+				sent_at: 6,
+				msg: VersionedXcm::from(Xcm(vec![HrmpChannelResizing {
+					initiator: u32::from(para_b),
+					sender: u32::from(para_a),
+					recipient: u32::from(para_b),
+					max_message_size: 4,
+					max_capacity: 1,
+				}]))
+				.encode(),
+			}]
+		);
+		// The initiator is not notified about its own request.
+		assert!(Dmp::dmq_contents(para_b).is_empty());
+	});
+}
+
+#[test]
+fn resize_channel_with_invalid_args() {
+	let para_a = 2001.into();
+	let para_a_origin: crate::Origin = 2001.into();
+	let para_b = 2003.into();
+	let para_c_origin: crate::Origin = 2004.into();
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		register_parachain(para_a);
+		register_parachain(para_b);
+
+		run_to_block(5, Some(vec![4, 5]));
+		let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+		assert_noop!(
+			Hrmp::hrmp_request_channel_resize(
+				para_a_origin.clone().into(),
+				channel_id.clone(),
+				1,
+				4
+			),
+			Error::<Test>::ResizeHrmpChannelDoesntExist,
+		);
+
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(6, Some(vec![6]));
+
+		assert_noop!(
+			Hrmp::hrmp_request_channel_resize(para_c_origin.into(), channel_id.clone(), 1, 4),
+			Error::<Test>::ResizeHrmpChannelUnauthorized,
+		);
+		assert_noop!(
+			Hrmp::hrmp_request_channel_resize(
+				para_a_origin.clone().into(),
+				channel_id.clone(),
+				3,
+				4
+			),
+			Error::<Test>::OpenHrmpChannelCapacityExceedsLimit,
+		);
+		assert_noop!(
+			Hrmp::hrmp_request_channel_resize(
+				para_a_origin.clone().into(),
+				channel_id.clone(),
+				1,
+				0
+			),
+			Error::<Test>::OpenHrmpChannelZeroMessageSize,
+		);
+		assert_noop!(
+			Hrmp::hrmp_accept_channel_resize(para_a_origin.clone().into(), channel_id.clone()),
+			Error::<Test>::ResizeHrmpChannelRequestDoesntExist,
+		);
+
+		// A channel that is being closed cannot be resized.
+		Hrmp::close_channel(para_b, channel_id.clone()).unwrap();
+		assert_noop!(
+			Hrmp::hrmp_request_channel_resize(para_a_origin.into(), channel_id.clone(), 1, 4),
+			Error::<Test>::ResizeHrmpChannelClosing,
+		);
+		assert_noop!(
+			Hrmp::force_resize_hrmp_channel(RuntimeOrigin::signed(1), channel_id, 1, 4),
+			BadOrigin,
+		);
+	});
+}
+
+#[test]
+fn force_resize_channel_works() {
+	let para_a = 2001.into();
+	let para_a_origin: crate::Origin = 2001.into();
+	let para_b = 2003.into();
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		register_parachain(para_a);
+		register_parachain(para_b);
+
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(6, Some(vec![6]));
+
+		// A pending request of the participants is replaced by the forced one.
+		let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+		assert_ok!(Hrmp::hrmp_request_channel_resize(
+			para_a_origin.clone().into(),
+			channel_id.clone(),
+			2,
+			4
+		));
+		assert_ok!(Hrmp::force_resize_hrmp_channel(
+			RuntimeOrigin::root(),
+			channel_id.clone(),
+			1,
+			2
+		));
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// Once confirmed, the participants cannot replace the request anymore.
+		assert_noop!(
+			Hrmp::hrmp_request_channel_resize(para_a_origin.into(), channel_id.clone(), 2, 4),
+			Error::<Test>::ResizeHrmpChannelAlreadyConfirmed,
+		);
+
+		run_to_block(8, Some(vec![8]));
+		let channel = HrmpChannels::<Test>::get(&channel_id).unwrap();
+		assert_eq!((channel.max_capacity, channel.max_message_size), (1, 2));
+		assert!(HrmpChannelResizeRequestsList::<Test>::get().is_empty());
+		Hrmp::assert_storage_consistency_exhaustive();
+	});
+}
+
+#[test]
+fn resize_channel_waits_for_pending_messages() {
+	let para_a = 2032.into();
+	let para_b = 2064.into();
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		register_parachain(para_a);
+		register_parachain(para_b);
+
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(6, Some(vec![6]));
+
+		// A sends two messages to B in consecutive blocks, filling up the channel.
+		let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+		let config = configuration::ActiveConfig::<Test>::get();
+		for _ in 0..2 {
+			let msgs: HorizontalMessages =
+				vec![OutboundHrmpMessage { recipient: para_b, data: b"knock".to_vec() }]
+					.try_into()
+					.unwrap();
+			assert!(Hrmp::check_outbound_hrmp(&config, para_a, &msgs).is_ok());
+			let _ = Hrmp::queue_outbound_hrmp(para_a, msgs);
+			run_to_block(System::block_number() + 1, None);
+		}
+		assert_eq!(HrmpChannels::<Test>::get(&channel_id).unwrap().msg_count, 2);
+
+		// The channel can only hold a single message after the resize.
+		Hrmp::request_channel_resize(para_b, channel_id.clone(), 1, 8).unwrap();
+		Hrmp::accept_channel_resize(para_a, channel_id.clone()).unwrap();
+
+		// The messages were not processed yet, hence the request stays pending.
+		run_to_block(9, Some(vec![9]));
+		assert_eq!(HrmpChannels::<Test>::get(&channel_id).unwrap().max_capacity, 2);
+		assert!(HrmpChannelResizeRequests::<Test>::get(&channel_id).is_some());
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// B processes the messages, after which the resize is enacted.
+		assert!(Hrmp::check_hrmp_watermark(para_b, 9, 7).is_ok());
+		let _ = Hrmp::prune_hrmp(para_b, 7);
+		run_to_block(10, Some(vec![10]));
+		assert_eq!(HrmpChannels::<Test>::get(&channel_id).unwrap().max_capacity, 1);
+		assert!(HrmpChannelResizeRequests::<Test>::get(&channel_id).is_none());
+		Hrmp::assert_storage_consistency_exhaustive();
+	});
+}
+
+#[test]
+fn resize_request_is_dropped_with_channel() {
+	let para_a = 2032.into();
+	let para_b = 2064.into();
+	let para_c = 2097.into();
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		register_parachain(para_a);
+		register_parachain(para_b);
+		register_parachain(para_c);
+
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		Hrmp::init_open_channel(para_c, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_c).unwrap();
+		run_to_block(6, Some(vec![6]));
+
+		// A closed channel takes its unconfirmed resize request with it.
+		let channel_ab = HrmpChannelId { sender: para_a, recipient: para_b };
+		Hrmp::request_channel_resize(para_a, channel_ab.clone(), 1, 4).unwrap();
+		Hrmp::close_channel(para_b, channel_ab.clone()).unwrap();
+		run_to_block(8, Some(vec![8]));
+		assert!(!channel_exists(para_a, para_b));
+		assert!(HrmpChannelResizeRequests::<Test>::get(&channel_ab).is_none());
+		assert!(HrmpChannelResizeRequestsList::<Test>::get().is_empty());
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// So does a channel closed because its sender is offboarded.
+		let channel_cb = HrmpChannelId { sender: para_c, recipient: para_b };
+		Hrmp::request_channel_resize(para_b, channel_cb.clone(), 1, 4).unwrap();
+		deregister_parachain(para_c);
+		run_to_block(10, Some(vec![9, 10]));
+		assert!(!channel_exists(para_c, para_b));
+		assert!(HrmpChannelResizeRequests::<Test>::get(&channel_cb).is_none());
+		assert!(HrmpChannelResizeRequestsList::<Test>::get().is_empty());
+		Hrmp::assert_storage_consistency_exhaustive();
+	});
+}
+
+#[test]
+fn resize_channel_updates_deposits() {
+	let para_a = 2032.into();
+	let para_b = 2064.into();
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(6, Some(vec![6]));
+
+		// The deposits required by the configuration change in the meantime.
+		configuration::ActiveConfig::<Test>::mutate(|config| {
+			config.hrmp_sender_deposit = 30;
+			config.hrmp_recipient_deposit = 5;
+		});
+
+		let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+		Hrmp::request_channel_resize(para_a, channel_id.clone(), 1, 8).unwrap();
+		Hrmp::accept_channel_resize(para_b, channel_id.clone()).unwrap();
+		run_to_block(8, Some(vec![8]));
+
+		let channel = HrmpChannels::<Test>::get(&channel_id).unwrap();
+		assert_eq!((channel.sender_deposit, channel.recipient_deposit), (30, 5));
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()), 70);
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&para_b.into_account_truncating()),
+			105
+		);
+	});
+}
@@ -369,6 +369,12 @@ pub mod pallet {
 		VecDeque<CandidatePendingAvailability<T::Hash, BlockNumberFor<T>>>,
 	>;
 
+	/// The number of candidates included on each core since the utilization was last taken with
+	/// [`Pallet::take_core_utilization`].
+	#[pallet::storage]
+	pub(crate) type CoreUtilization<T: Config> =
+		StorageMap<_, Twox64Concat, CoreIndex, u32, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {}
 }
@@ -908,6 +914,7 @@ impl<T: Config> Pallet<T> {
 			commitments.horizontal_messages,
 		);
 
+		CoreUtilization::<T>::mutate(core_index, |included| included.saturating_inc());
 		Self::deposit_event(Event::<T>::CandidateIncluded(
 			plain,
 			commitments.head_data.clone(),
@@ -922,6 +929,12 @@ impl<T: Config> Pallet<T> {
 		);
 	}
 
+	/// Returns the number of candidates included on each core since the last call and resets the
+	/// counters. Cores without any included candidate are absent.
+	pub(crate) fn take_core_utilization() -> BTreeMap<CoreIndex, u32> {
+		CoreUtilization::<T>::drain().collect()
+	}
+
 	pub(crate) fn relay_dispatch_queue_size(para_id: ParaId) -> (u32, u32) {
 		let fp = T::MessageQueue::footprint(AggregateMessageOrigin::Ump(UmpQueueId::Para(para_id)));
 		(fp.storage.count as u32, fp.storage.size as u32)
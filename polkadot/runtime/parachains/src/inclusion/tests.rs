@@ -1119,6 +1119,13 @@ fn supermajority_bitfields_trigger_availability() {
 			assert_eq!(rewards.get(&ValidatorIndex(1)).unwrap(), &1);
 		}
 
+		// Included candidates are counted per core until the utilization is taken.
+		assert_eq!(
+			ParaInclusion::take_core_utilization(),
+			[(CoreIndex(0), 1), (CoreIndex(2), 1)].into()
+		);
+		assert!(ParaInclusion::take_core_utilization().is_empty());
+
 		// Add a new bitfield which will make candidate C2 available also. This will also evict and
 		// enact C3.
 		let signed_bitfields = vec![sign_bitfield(
@@ -1187,6 +1194,11 @@ fn supermajority_bitfields_trigger_availability() {
 			assert_eq!(rewards.get(&ValidatorIndex(5)).unwrap(), &1);
 			assert_eq!(rewards.get(&ValidatorIndex(6)).unwrap(), &1);
 		}
+
+		assert_eq!(
+			ParaInclusion::take_core_utilization(),
+			[(CoreIndex(3), 1), (CoreIndex(4), 1)].into()
+		);
 	});
 }
 
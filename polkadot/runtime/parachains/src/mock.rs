@@ -446,15 +446,25 @@ impl coretime::Config for Test {
 	type AccountToLocation = ();
 }
 
+parameter_types! {
+	/// The messages delivered by [`DummyXcmSender`].
+	pub static SentXcm: Vec<(Location, Xcm<()>)> = Vec::new();
+}
+
 pub struct DummyXcmSender;
 impl SendXcm for DummyXcmSender {
-	type Ticket = ();
-	fn validate(_: &mut Option<Location>, _: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
-		Ok(((), Assets::new()))
+	type Ticket = (Location, Xcm<()>);
+	fn validate(
+		dest: &mut Option<Location>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let ticket = (dest.take().ok_or(SendError::MissingArgument)?, msg.take().unwrap());
+		Ok((ticket, Assets::new()))
 	}
 
 	/// Actually carry out the delivery operation for a previously validated message sending.
-	fn deliver(_ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		SentXcm::mutate(|sent| sent.push(ticket));
 		Ok([0u8; 32])
 	}
 }
@@ -111,6 +111,9 @@ pub use sp_runtime::BuildStorage;
 use test_runtime_constants::{currency::*, fee::*, time::*};
 pub mod xcm_config;
 
+#[cfg(feature = "std")]
+pub mod simulation;
+
 impl_runtime_weights!(test_runtime_constants);
 
 // Make the WASM binary available.
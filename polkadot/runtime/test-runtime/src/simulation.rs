@@ -0,0 +1,266 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! In-process execution of the test runtime for simulating disputes and slashing.
+//!
+//! Blocks are built by calling the hooks of the pallets involved directly, without consensus
+//! digests or inherents. Disputes are handed to the disputes pallet the same way the parachains
+//! inherent does, and slashing reports are dispatched as the unsigned extrinsics the node would
+//! submit. The runtime API answers match those of the real runtime API implementation.
+
+use frame_support::traits::{OnFinalize, OnInitialize};
+use pallet_staking::Forcing;
+use polkadot_primitives::{DisputeStatementSet, SchedulerParams, MAX_CODE_SIZE, MAX_POV_SIZE};
+use polkadot_runtime_parachains::{configuration::HostConfiguration, disputes::DisputesHandler};
+use sp_core::crypto::get_public_from_string_or_panic;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{
+	traits::{Dispatchable, Header as _},
+	DispatchError,
+};
+use sp_staking::offence::Kind;
+use test_runtime_constants::currency::DOTS;
+
+use super::*;
+
+const ENDOWMENT: Balance = 1_000_000 * DOTS;
+const STASH: Balance = 100 * DOTS;
+
+/// A chain running the test runtime in memory.
+pub struct Simulation {
+	ext: sp_io::TestExternalities,
+}
+
+impl Simulation {
+	/// Start a chain with the given validators and open its first block.
+	///
+	/// All validators are invulnerable stakers with equal stake, their stash accounts are the
+	/// keyring accounts.
+	pub fn new(validators: &[Sr25519Keyring]) -> Self {
+		let storage = genesis(validators).build_storage().expect("Genesis config is valid");
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| start_block(1, Default::default()));
+		Self { ext }
+	}
+
+	/// Execute the given closure in the context of the current block.
+	pub fn execute_with<R>(&mut self, f: impl FnOnce() -> R) -> R {
+		self.ext.execute_with(f)
+	}
+
+	/// Finish the current block and open the next one.
+	pub fn next_block(&mut self) {
+		self.ext.execute_with(|| {
+			let header = finish_block();
+			start_block(header.number + 1, header.hash());
+		})
+	}
+
+	/// Rotate to the next session.
+	///
+	/// Like on a live chain, the parachains runtime applies the session change at the end of the
+	/// current block, so it is observed from the next block on.
+	pub fn new_session(&mut self) {
+		self.ext.execute_with(Session::rotate_session);
+		self.next_block();
+	}
+
+	/// Number of the current block.
+	pub fn block_number(&mut self) -> BlockNumber {
+		self.ext.execute_with(System::block_number)
+	}
+
+	/// Index of the session a child of the current block would be in.
+	pub fn session_index_for_child(&mut self) -> SessionIndex {
+		self.ext.execute_with(runtime_impl::session_index_for_child::<Runtime>)
+	}
+
+	/// Information about the given session, as stored by the parachains runtime.
+	pub fn session_info(&mut self, session: SessionIndex) -> Option<SessionInfoData> {
+		self.ext.execute_with(|| runtime_impl::session_info::<Runtime>(session))
+	}
+
+	/// Candidate events of the current block.
+	pub fn candidate_events(&mut self) -> Vec<CandidateEvent<HashT>> {
+		self.ext.execute_with(|| {
+			runtime_impl::candidate_events::<Runtime, _>(|trait_event| trait_event.try_into().ok())
+		})
+	}
+
+	/// Votes put on chain by the current block.
+	pub fn on_chain_votes(&mut self) -> Option<ScrapedOnChainVotes<HashT>> {
+		self.ext.execute_with(runtime_impl::on_chain_votes::<Runtime>)
+	}
+
+	/// Validators disabled in the current session.
+	pub fn disabled_validators(&mut self) -> Vec<ValidatorIndex> {
+		self.ext.execute_with(runtime_impl::disabled_validators::<Runtime>)
+	}
+
+	/// Import a dispute statement set, as the parachains inherent would.
+	///
+	/// Returns the disputes initiated by this set.
+	pub fn submit_disputes(
+		&mut self,
+		set: DisputeStatementSet,
+	) -> Result<Vec<(SessionIndex, CandidateHash)>, DispatchError> {
+		self.ext.execute_with(|| {
+			let config = parachains_configuration::ActiveConfig::<Runtime>::get();
+			let checked = <ParasDisputes as DisputesHandler<BlockNumber>>::filter_dispute_data(
+				set,
+				config.dispute_post_conclusion_acceptance_period,
+			)
+			.ok_or(DispatchError::Other("Dispute statement set was filtered out"))?;
+			<ParasDisputes as DisputesHandler<BlockNumber>>::process_checked_multi_dispute_data(
+				&vec![checked],
+			)
+		})
+	}
+
+	/// Validators that lost a past session dispute and have not been reported yet.
+	pub fn unapplied_slashes(
+		&mut self,
+	) -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)> {
+		self.ext.execute_with(runtime_impl::unapplied_slashes::<Runtime>)
+	}
+
+	/// Proof that the given key is part of the current validator set.
+	pub fn key_ownership_proof(
+		&mut self,
+		validator_id: ValidatorId,
+	) -> Option<slashing::OpaqueKeyOwnershipProof> {
+		self.ext.execute_with(|| {
+			Historical::prove((PARACHAIN_KEY_TYPE_ID, validator_id))
+				.map(|p| p.encode())
+				.map(slashing::OpaqueKeyOwnershipProof::new)
+		})
+	}
+
+	/// Validate and dispatch an unsigned slashing report, as if it was submitted by the local
+	/// node and included in the current block.
+	///
+	/// Returns `None` if the report was rejected.
+	pub fn submit_report_dispute_lost(
+		&mut self,
+		dispute_proof: slashing::DisputeProof,
+		key_ownership_proof: slashing::OpaqueKeyOwnershipProof,
+	) -> Option<()> {
+		self.ext.execute_with(|| {
+			let key_owner_proof = key_ownership_proof.decode()?;
+			let call = parachains_slashing::Call::<Runtime>::report_dispute_lost_unsigned {
+				dispute_proof: Box::new(dispute_proof),
+				key_owner_proof,
+			};
+			ParasSlashing::validate_unsigned(TransactionSource::Local, &call).ok()?;
+			ParasSlashing::pre_dispatch(&call).ok()?;
+
+			RuntimeCall::ParasSlashing(call)
+				.dispatch(RuntimeOrigin::none())
+				.ok()
+				.map(|_| ())
+		})
+	}
+
+	/// Offences reported so far, as offence kind and stash account of the offender.
+	pub fn offences(&mut self) -> Vec<(Kind, AccountId)> {
+		self.ext.execute_with(|| {
+			pallet_offences::ConcurrentReportsIndex::<Runtime>::iter()
+				.flat_map(|(kind, _time_slot, report_ids)| {
+					report_ids.into_iter().map(move |report_id| (kind, report_id))
+				})
+				.filter_map(|(kind, report_id)| {
+					let report = pallet_offences::Reports::<Runtime>::get(report_id)?;
+					Some((kind, report.offender.0))
+				})
+				.collect()
+		})
+	}
+}
+
+fn start_block(number: BlockNumber, parent_hash: HashT) {
+	System::reset_events();
+	System::initialize(&number, &parent_hash, &Default::default());
+	Babe::on_initialize(number);
+	Initializer::on_initialize(number);
+}
+
+fn finish_block() -> Header {
+	let number = System::block_number();
+	Initializer::on_finalize(number);
+	Babe::on_finalize(number);
+	System::finalize()
+}
+
+fn session_keys(keyring: Sr25519Keyring) -> SessionKeys {
+	SessionKeys {
+		grandpa: get_public_from_string_or_panic::<GrandpaId>(&keyring.to_string()),
+		babe: keyring.public().into(),
+		para_validator: keyring.public().into(),
+		para_assignment: keyring.public().into(),
+		authority_discovery: keyring.public().into(),
+	}
+}
+
+fn genesis(validators: &[Sr25519Keyring]) -> RuntimeGenesisConfig {
+	let accounts: Vec<AccountId> = validators.iter().map(|v| v.to_account_id()).collect();
+
+	RuntimeGenesisConfig {
+		balances: BalancesConfig {
+			balances: accounts.iter().map(|a| (a.clone(), ENDOWMENT)).collect(),
+			..Default::default()
+		},
+		session: SessionConfig {
+			keys: validators
+				.iter()
+				.map(|v| (v.to_account_id(), v.to_account_id(), session_keys(*v)))
+				.collect(),
+			..Default::default()
+		},
+		staking: StakingConfig {
+			minimum_validator_count: 1,
+			validator_count: validators.len() as u32,
+			stakers: accounts
+				.iter()
+				.map(|a| (a.clone(), a.clone(), STASH, StakerStatus::Validator))
+				.collect(),
+			invulnerables: accounts.clone().try_into().expect("Too many validators"),
+			force_era: Forcing::NotForcing,
+			slash_reward_fraction: Perbill::from_percent(10),
+			..Default::default()
+		},
+		babe: BabeConfig { epoch_config: BABE_GENESIS_EPOCH_CONFIG, ..Default::default() },
+		configuration: ConfigurationConfig {
+			config: HostConfiguration {
+				validation_upgrade_cooldown: 10u32,
+				validation_upgrade_delay: 5,
+				code_retention_period: 1200,
+				max_code_size: MAX_CODE_SIZE,
+				max_pov_size: MAX_POV_SIZE,
+				max_head_data_size: 32 * 1024,
+				no_show_slots: 10,
+				minimum_validation_upgrade_delay: 5,
+				max_downward_message_size: 1024,
+				scheduler_params: SchedulerParams {
+					group_rotation_frequency: 20,
+					paras_availability_period: 4,
+					..Default::default()
+				},
+				..Default::default()
+			},
+		},
+		..Default::default()
+	}
+}
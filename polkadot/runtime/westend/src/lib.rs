@@ -1285,6 +1285,14 @@ parameter_types! {
 	pub const MessageQueueMaxStale: u32 = 48;
 }
 
+/// Processes the XCMs of the `MessageProcessor`, recording their outcome in the `XcmPallet`.
+type XcmMessageProcessor = xcm_builder::ProcessXcmMessage<
+	Junction,
+	xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
+	RuntimeCall,
+	XcmPallet,
+>;
+
 /// Message processor to handle any messages that were enqueued into the `MessageQueue` pallet.
 pub struct MessageProcessor;
 impl ProcessMessage for MessageProcessor {
@@ -1299,11 +1307,24 @@ impl ProcessMessage for MessageProcessor {
 		let para = match origin {
 			AggregateMessageOrigin::Ump(UmpQueueId::Para(para)) => para,
 		};
-		xcm_builder::ProcessXcmMessage::<
-			Junction,
-			xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
-			RuntimeCall,
-		>::process_message(message, Junction::Parachain(para.into()), meter, id)
+		XcmMessageProcessor::process_message(message, Junction::Parachain(para.into()), meter, id)
+	}
+
+	fn process_message_failed(
+		origin: Self::Origin,
+		meter: &mut WeightMeter,
+		id: &[u8; 32],
+		error: ProcessMessageError,
+	) {
+		let para = match origin {
+			AggregateMessageOrigin::Ump(UmpQueueId::Para(para)) => para,
+		};
+		XcmMessageProcessor::process_message_failed(
+			Junction::Parachain(para.into()),
+			meter,
+			id,
+			error,
+		)
 	}
 }
 
@@ -2604,7 +2625,18 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_migrations::runtime_api::MigrationsApi<Block> for Runtime {
+		fn migration_status() -> pallet_migrations::MigrationStatus {
+			MultiBlockMigrations::migration_status()
+		}
+
+		fn last_failure() -> Option<pallet_migrations::MigrationProgress> {
+			MultiBlockMigrations::last_failure()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
+	#[api_version(2)]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
 			log::info!("try-runtime::on_runtime_upgrade westend.");
@@ -2612,6 +2644,13 @@ sp_api::impl_runtime_apis! {
 			(weight, BlockWeights::get().max_block)
 		}
 
+		fn on_runtime_upgrade_with_report(
+			checks: frame_try_runtime::UpgradeCheckSelect,
+		) -> frame_try_runtime::UpgradeReport {
+			// NOTE: intentional unwrap, to fail loudly if the upgrade checks fail.
+			Executive::try_runtime_upgrade_with_report(checks, || None).unwrap()
+		}
+
 		fn execute_block(
 			block: Block,
 			state_root_check: bool,
@@ -2624,6 +2663,15 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryDecodeState<Block> for Runtime {
+		fn decode_entire_state(
+			select: frame_try_runtime::DecodeSelect,
+		) -> frame_try_runtime::DecodeEntireStateReport {
+			Executive::try_decode_entire_state(select)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -2899,4 +2947,14 @@ sp_api::impl_runtime_apis! {
 			XcmPallet::is_trusted_teleporter(asset, location)
 		}
 	}
+
+	impl xcm_runtime_apis::outcomes::XcmOutcomesApi<Block, BlockNumber> for Runtime {
+		fn recent_xcm_outcomes(
+			filter: xcm_runtime_apis::outcomes::XcmOutcomesFilter,
+			cursor: Option<u64>,
+			limit: u32,
+		) -> Result<xcm_runtime_apis::outcomes::XcmOutcomesPage<BlockNumber>, xcm_runtime_apis::outcomes::Error> {
+			XcmPallet::recent_xcm_outcomes(filter, cursor, limit)
+		}
+	}
 }
@@ -381,4 +381,22 @@ impl<T: frame_system::Config> polkadot_runtime_parachains::hrmp::WeightInfo for
 			.saturating_add(T::DbWeight::get().reads(23))
 			.saturating_add(T::DbWeight::get().writes(11))
 	}
+	// The resize weights below are placeholders until the resize benchmarks are run for this
+	// runtime. Each of them is the weight of a benchmarked call accessing a superset of the storage
+	// accessed by the resize call.
+	fn hrmp_request_channel_resize() -> Weight {
+		// Like opening a channel, reads the channel and its requests and notifies the counterparty.
+		Self::hrmp_init_open_channel()
+	}
+	fn hrmp_accept_channel_resize() -> Weight {
+		Self::hrmp_accept_open_channel()
+	}
+	fn force_resize_hrmp_channel() -> Weight {
+		Self::hrmp_init_open_channel()
+	}
+	fn force_process_hrmp_resize(c: u32, ) -> Weight {
+		// Enacting a request updates a channel like opening it, and its deposits like poking them.
+		Self::force_process_hrmp_open(c)
+			.saturating_add(Self::poke_channel_deposits().saturating_mul(c.into()))
+	}
 }
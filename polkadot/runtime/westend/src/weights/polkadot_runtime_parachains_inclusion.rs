@@ -134,5 +134,8 @@ impl<T: frame_system::Config> polkadot_runtime_parachains::inclusion::WeightInfo
 			.saturating_add(Weight::from_parts(0, 25419).saturating_mul(c.into()))
 			.saturating_add(Weight::from_parts(0, 29985).saturating_mul(h.into()))
 			.saturating_add(Weight::from_parts(0, 82828).saturating_mul(u.into()))
+			// Placeholder until the benchmark is re-run: counting the included candidate in
+			// `ParaInclusion::CoreUtilization`.
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
 	}
 }
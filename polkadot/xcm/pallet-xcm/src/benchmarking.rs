@@ -597,6 +597,27 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_xcm_outcome_history_size() {
+		// Worst case: a full history of the maximum size has to be cleared.
+		XcmOutcomeHistorySize::<T>::put(MAX_XCM_OUTCOME_HISTORY_SIZE);
+		for i in 0..MAX_XCM_OUTCOME_HISTORY_SIZE {
+			crate::Pallet::<T>::record_xcm_outcome(
+				[i as u8; 32],
+				XcmDirection::Outbound,
+				&Here.into(),
+				Some(&Parent.into()),
+				XcmOutcomeStatus::Sent,
+				Weight::zero(),
+			);
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, MAX_XCM_OUTCOME_HISTORY_SIZE);
+
+		assert_eq!(XcmOutcomeHistorySize::<T>::get(), MAX_XCM_OUTCOME_HISTORY_SIZE);
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext_with_balances(Vec::new()),
@@ -41,6 +41,7 @@ use frame_support::{
 		Contains, ContainsPair, Currency, Defensive, EnsureOrigin, Get, LockableCurrency,
 		OriginTrait, WithdrawReasons,
 	},
+	weights::WeightMeter,
 	PalletId,
 };
 use frame_system::pallet_prelude::{BlockNumberFor, *};
@@ -62,14 +63,18 @@ use xcm_executor::{
 	traits::{
 		AssetTransferError, CheckSuspension, ClaimAssets, ConvertLocation, ConvertOrigin,
 		DropAssets, MatchesFungible, OnResponse, Properties, QueryHandler, QueryResponseStatus,
-		RecordXcm, TransactAsset, TransferType, VersionChangeNotifier, WeightBounds,
-		XcmAssetTransfers,
+		RecordXcm, RecordXcmOutcome, TransactAsset, TransferType, VersionChangeNotifier,
+		WeightBounds, XcmAssetTransfers,
 	},
 	AssetsInHolding,
 };
 use xcm_runtime_apis::{
 	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
 	fees::Error as XcmPaymentApiError,
+	outcomes::{
+		Error as XcmOutcomesApiError, XcmDirection, XcmOutcomeRecord, XcmOutcomeStatus,
+		XcmOutcomesFilter, XcmOutcomesPage,
+	},
 	trusted_query::Error as TrustedQueryApiError,
 };
 
@@ -98,6 +103,7 @@ pub trait WeightInfo {
 	fn new_query() -> Weight;
 	fn take_response() -> Weight;
 	fn claim_assets() -> Weight;
+	fn set_xcm_outcome_history_size() -> Weight;
 }
 
 /// fallback implementation
@@ -182,6 +188,10 @@ impl WeightInfo for TestWeightInfo {
 	fn claim_assets() -> Weight {
 		Weight::from_parts(100_000_000, 0)
 	}
+
+	fn set_xcm_outcome_history_size() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
 }
 
 #[frame_support::pallet]
@@ -345,7 +355,7 @@ pub mod pallet {
 
 	impl<T: Config> SendControllerWeightInfo for Pallet<T> {
 		fn send() -> Weight {
-			T::WeightInfo::send()
+			T::WeightInfo::send().saturating_add(Pallet::<T>::record_outcome_weight())
 		}
 	}
 
@@ -367,6 +377,7 @@ pub mod pallet {
 					tracing::error!(target: "xcm::pallet_xcm::send", ?error, ?dest, ?message, "XCM send failed with error");
 					Error::<T>::from(error)
 				})?;
+			Self::record_outbound(message_id, &origin_location, &dest);
 			let e = Event::Sent { origin: origin_location, destination: dest, message, message_id };
 			Self::deposit_event(e);
 			Ok(message_id)
@@ -505,6 +516,9 @@ pub mod pallet {
 		AssetsClaimed { hash: H256, origin: Location, assets: VersionedAssets },
 		/// A XCM version migration finished.
 		VersionMigrationFinished { version: XcmVersion },
+		/// The number of XCM outcomes kept in the history was changed. Existing records were
+		/// cleared.
+		XcmOutcomeHistorySizeSet { size: u32 },
 	}
 
 	#[pallet::origin]
@@ -588,6 +602,10 @@ pub mod pallet {
 		/// Local XCM execution incomplete.
 		#[codec(index = 24)]
 		LocalExecutionIncomplete,
+		/// The requested size of the XCM outcome history exceeds
+		/// [`MAX_XCM_OUTCOME_HISTORY_SIZE`].
+		#[codec(index = 25)]
+		XcmOutcomeHistoryTooLarge,
 	}
 
 	impl<T: Config> From<SendError> for Error<T> {
@@ -807,6 +825,24 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type RecordedXcm<T: Config> = StorageValue<_, Xcm<()>>;
 
+	/// The maximum number of records kept in [`XcmOutcomeHistory`]. Recording is disabled when
+	/// zero, which is the default.
+	///
+	/// Inbound messages are only recorded if this pallet is being used as the
+	/// [`xcm_executor::traits::RecordXcmOutcome`] implementation of the message processor.
+	#[pallet::storage]
+	pub(crate) type XcmOutcomeHistorySize<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The number of XCM outcomes recorded so far, which is the index of the next record.
+	#[pallet::storage]
+	pub(crate) type XcmOutcomeCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Ring buffer of the most recently sent and received XCMs, keyed by the index of the record
+	/// modulo [`XcmOutcomeHistorySize`].
+	#[pallet::storage]
+	pub(crate) type XcmOutcomeHistory<T: Config> =
+		StorageMap<_, Twox64Concat, u32, XcmOutcomeRecord<BlockNumberFor<T>>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		#[serde(skip)]
@@ -946,6 +982,7 @@ pub mod pallet {
 	#[pallet::call(weight(<T as Config>::WeightInfo))]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
+		#[pallet::weight(<Self as SendControllerWeightInfo>::send())]
 		pub fn send(
 			origin: OriginFor<T>,
 			dest: Box<VersionedLocation>,
@@ -974,6 +1011,9 @@ pub mod pallet {
 		/// - `fee_asset_item`: The index into `assets` of the item which should be used to pay
 		///   fees.
 		#[pallet::call_index(1)]
+		#[pallet::weight(
+			T::WeightInfo::teleport_assets().saturating_add(Self::record_outcome_weight())
+		)]
 		#[allow(deprecated)]
 		#[deprecated(
 			note = "This extrinsic uses `WeightLimit::Unlimited`, please migrate to `limited_teleport_assets` or `transfer_assets`"
@@ -1019,6 +1059,9 @@ pub mod pallet {
 		/// - `fee_asset_item`: The index into `assets` of the item which should be used to pay
 		///   fees.
 		#[pallet::call_index(2)]
+		#[pallet::weight(
+			T::WeightInfo::reserve_transfer_assets().saturating_add(Self::record_outcome_weight())
+		)]
 		#[allow(deprecated)]
 		#[deprecated(
 			note = "This extrinsic uses `WeightLimit::Unlimited`, please migrate to `limited_reserve_transfer_assets` or `transfer_assets`"
@@ -1169,7 +1212,9 @@ pub mod pallet {
 		///   fees.
 		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
 		#[pallet::call_index(8)]
-		#[pallet::weight(T::WeightInfo::reserve_transfer_assets())]
+		#[pallet::weight(
+			T::WeightInfo::reserve_transfer_assets().saturating_add(Self::record_outcome_weight())
+		)]
 		pub fn limited_reserve_transfer_assets(
 			origin: OriginFor<T>,
 			dest: Box<VersionedLocation>,
@@ -1207,7 +1252,9 @@ pub mod pallet {
 		///   fees.
 		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
 		#[pallet::call_index(9)]
-		#[pallet::weight(T::WeightInfo::teleport_assets())]
+		#[pallet::weight(
+			T::WeightInfo::teleport_assets().saturating_add(Self::record_outcome_weight())
+		)]
 		pub fn limited_teleport_assets(
 			origin: OriginFor<T>,
 			dest: Box<VersionedLocation>,
@@ -1271,6 +1318,9 @@ pub mod pallet {
 		///   fees.
 		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
 		#[pallet::call_index(11)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_assets().saturating_add(Self::record_outcome_weight())
+		)]
 		pub fn transfer_assets(
 			origin: OriginFor<T>,
 			dest: Box<VersionedLocation>,
@@ -1399,7 +1449,9 @@ pub mod pallet {
 		///   transfer, which also determines what happens to the assets on the destination chain.
 		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
 		#[pallet::call_index(13)]
-		#[pallet::weight(T::WeightInfo::transfer_assets())]
+		#[pallet::weight(
+			T::WeightInfo::transfer_assets().saturating_add(Self::record_outcome_weight())
+		)]
 		pub fn transfer_assets_using_type_and_then(
 			origin: OriginFor<T>,
 			dest: Box<VersionedLocation>,
@@ -1439,9 +1491,30 @@ pub mod pallet {
 				weight_limit,
 			)
 		}
+
+		/// Set the number of recently sent and received XCMs whose outcome is kept, so that they
+		/// can be queried through the `XcmOutcomesApi` runtime API. Existing records are cleared.
+		///
+		/// - `origin`: Must be an origin specified by AdminOrigin.
+		/// - `size`: The number of records to keep, at most [`MAX_XCM_OUTCOME_HISTORY_SIZE`]. `0`
+		///   disables recording.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::set_xcm_outcome_history_size())]
+		pub fn set_xcm_outcome_history_size(origin: OriginFor<T>, size: u32) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(size <= MAX_XCM_OUTCOME_HISTORY_SIZE, Error::<T>::XcmOutcomeHistoryTooLarge);
+			let previous_size = XcmOutcomeHistorySize::<T>::get();
+			let _ = XcmOutcomeHistory::<T>::clear(previous_size, None);
+			XcmOutcomeHistorySize::<T>::put(size);
+			Self::deposit_event(Event::XcmOutcomeHistorySizeSet { size });
+			Ok(())
+		}
 	}
 }
 
+/// The maximum number of records that can be kept in the XCM outcome history.
+pub const MAX_XCM_OUTCOME_HISTORY_SIZE: u32 = 1024;
+
 /// The maximum number of distinct assets allowed to be transferred in a single helper extrinsic.
 const MAX_ASSETS_FOR_TRANSFER: usize = 2;
 
@@ -1832,6 +1905,7 @@ impl<T: Config> Pallet<T> {
 					Error::<T>::from(error)
 				})?;
 
+			Self::record_outbound(message_id, &origin, &dest);
 			let e = Event::Sent { origin, destination: dest, message: remote_xcm, message_id };
 			Self::deposit_event(e);
 		}
@@ -2732,6 +2806,86 @@ impl<T: Config> Pallet<T> {
 		Ok(<T::XcmExecutor as XcmAssetTransfers>::IsTeleporter::contains(&a, &location))
 	}
 
+	/// Returns the most recent recorded XCM outcomes matching `filter`, starting before the
+	/// record `cursor` if given.
+	///
+	/// Meant to be used in the `XcmOutcomesApi` runtime API implementation.
+	pub fn recent_xcm_outcomes(
+		filter: XcmOutcomesFilter,
+		cursor: Option<u64>,
+		limit: u32,
+	) -> Result<XcmOutcomesPage<BlockNumberFor<T>>, XcmOutcomesApiError> {
+		let origin = filter
+			.origin
+			.map(Location::try_from)
+			.transpose()
+			.map_err(|()| XcmOutcomesApiError::VersionedConversionFailed)?;
+		let size = XcmOutcomeHistorySize::<T>::get();
+		let count = XcmOutcomeCount::<T>::get();
+		let oldest = count.saturating_sub(size.into());
+		let mut next = cursor.unwrap_or(count).min(count);
+
+		let mut records = Vec::new();
+		while next > oldest && records.len() < limit as usize {
+			next -= 1;
+			let Some(record) = XcmOutcomeHistory::<T>::get((next % size as u64) as u32) else {
+				continue
+			};
+			if record.index != next ||
+				filter.message_id.is_some_and(|id| id != record.message_id) ||
+				origin.as_ref().is_some_and(|origin| {
+					Location::try_from(record.origin.clone()).as_ref() != Ok(origin)
+				}) {
+				continue
+			}
+			records.push(record);
+		}
+
+		Ok(XcmOutcomesPage { records, next_cursor: (next > oldest).then_some(next) })
+	}
+
+	/// The weight of adding a record to the XCM outcome history.
+	///
+	/// Reads the history size, then reads and bumps the count and writes the record. The
+	/// benchmarks of the extrinsics that send messages run with the history disabled, so this is
+	/// added to their weight explicitly.
+	fn record_outcome_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	/// Adds a record to the XCM outcome history, overwriting the oldest one, if recording is
+	/// enabled.
+	fn record_xcm_outcome(
+		message_id: XcmHash,
+		direction: XcmDirection,
+		origin: &Location,
+		destination: Option<&Location>,
+		status: XcmOutcomeStatus,
+		weight_used: Weight,
+	) {
+		let size = XcmOutcomeHistorySize::<T>::get();
+		if size == 0 {
+			return
+		}
+		let index = XcmOutcomeCount::<T>::mutate(|count| {
+			let index = *count;
+			*count = count.saturating_add(1);
+			index
+		});
+		let record = XcmOutcomeRecord {
+			index,
+			message_id,
+			direction,
+			origin: VersionedLocation::from(origin.clone()),
+			destination: destination
+				.map(|destination| VersionedLocation::from(destination.clone())),
+			status,
+			weight_used,
+			block_number: frame_system::Pallet::<T>::block_number(),
+		};
+		XcmOutcomeHistory::<T>::insert((index % size as u64) as u32, record);
+	}
+
 	pub fn query_delivery_fees(
 		destination: VersionedLocation,
 		message: VersionedXcm<()>,
@@ -3462,6 +3616,52 @@ impl<T: Config> CheckSuspension for Pallet<T> {
 	}
 }
 
+impl<T: Config> RecordXcmOutcome for Pallet<T> {
+	fn record_inbound(
+		message_id: XcmHash,
+		origin: &Location,
+		outcome: &Outcome,
+		meter: &mut WeightMeter,
+	) {
+		let weight = if XcmOutcomeHistorySize::<T>::get() == 0 {
+			T::DbWeight::get().reads(1)
+		} else {
+			Self::record_outcome_weight()
+		};
+		if meter.try_consume(weight).is_err() {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::record_inbound",
+				?message_id, ?origin, "Not enough weight left to record the XCM outcome",
+			);
+			return
+		}
+		let (status, weight_used) = match outcome {
+			Outcome::Complete { used } => (XcmOutcomeStatus::Complete, *used),
+			Outcome::Incomplete { used, .. } => (XcmOutcomeStatus::Incomplete, *used),
+			Outcome::Error { .. } => (XcmOutcomeStatus::Error, Weight::zero()),
+		};
+		Self::record_xcm_outcome(
+			message_id,
+			XcmDirection::Inbound,
+			origin,
+			None,
+			status,
+			weight_used,
+		);
+	}
+
+	fn record_outbound(message_id: XcmHash, origin: &Location, destination: &Location) {
+		Self::record_xcm_outcome(
+			message_id,
+			XcmDirection::Outbound,
+			origin,
+			Some(destination),
+			XcmOutcomeStatus::Sent,
+			Weight::zero(),
+		);
+	}
+}
+
 impl<T: Config> RecordXcm for Pallet<T> {
 	fn should_record() -> bool {
 		ShouldRecordXcm::<T>::get()
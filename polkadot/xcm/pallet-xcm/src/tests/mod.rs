@@ -25,22 +25,23 @@ use crate::{
 	AssetTraps, Config, CurrentMigration, Error, ExecuteControllerWeightInfo,
 	LatestVersionedLocation, Pallet, Queries, QueryStatus, RecordedXcm, RemoteLockedFungibleRecord,
 	ShouldRecordXcm, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
-	VersionNotifyTargets, WeightInfo,
+	VersionNotifyTargets, WeightInfo, XcmOutcomeHistory, MAX_XCM_OUTCOME_HISTORY_SIZE,
 };
 use bounded_collections::BoundedVec;
 use frame_support::{
 	assert_err_ignore_postinfo, assert_noop, assert_ok,
 	traits::{Currency, Hooks},
-	weights::Weight,
+	weights::{Weight, WeightMeter},
 };
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use sp_runtime::traits::{AccountIdConversion, BlakeTwo256, Hash};
 use xcm::{latest::QueryResponseInfo, prelude::*};
 use xcm_builder::AllowKnownQueryResponses;
 use xcm_executor::{
-	traits::{Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
+	traits::{Properties, QueryHandler, QueryResponseStatus, RecordXcmOutcome, ShouldExecute},
 	XcmExecutor,
 };
+use xcm_runtime_apis::outcomes::{XcmDirection, XcmOutcomeStatus, XcmOutcomesFilter};
 
 const ALICE: AccountId = AccountId::new([0u8; 32]);
 const BOB: AccountId = AccountId::new([1u8; 32]);
@@ -1456,3 +1457,82 @@ fn record_xcm_works() {
 		assert_eq!(RecordedXcm::<Test>::get(), Some(message.into()));
 	});
 }
+
+#[test]
+fn xcm_outcome_history_works() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let sender: Location = AccountId32 { network: None, id: ALICE.into() }.into();
+		let send = |instructions: usize| {
+			let message = Xcm(vec![ClearOrigin; instructions]);
+			assert_ok!(XcmPallet::send(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(RelayLocation::get().into()),
+				Box::new(VersionedXcm::from(message)),
+			));
+			match last_event() {
+				RuntimeEvent::XcmPallet(crate::Event::Sent { message_id, .. }) => message_id,
+				_ => unreachable!(),
+			}
+		};
+		let recent = |filter: XcmOutcomesFilter, cursor, limit| {
+			XcmPallet::recent_xcm_outcomes(filter, cursor, limit).unwrap()
+		};
+
+		// Nothing is recorded by default.
+		send(1);
+		assert!(recent(Default::default(), None, 10).records.is_empty());
+		assert_eq!(XcmOutcomeHistory::<Test>::iter().count(), 0);
+
+		// Only admin can enable recording, up to a maximum size.
+		assert_noop!(
+			XcmPallet::set_xcm_outcome_history_size(RuntimeOrigin::signed(ALICE), 2),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmPallet::set_xcm_outcome_history_size(
+				RuntimeOrigin::root(),
+				MAX_XCM_OUTCOME_HISTORY_SIZE + 1
+			),
+			Error::<Test>::XcmOutcomeHistoryTooLarge
+		);
+		assert_ok!(XcmPallet::set_xcm_outcome_history_size(RuntimeOrigin::root(), 2));
+
+		// Only the most recent records are kept.
+		let ids: Vec<_> = (1..=3).map(send).collect();
+		let page = recent(Default::default(), None, 10);
+		assert_eq!(page.records.iter().map(|r| r.message_id).collect::<Vec<_>>(), [ids[2], ids[1]]);
+		assert_eq!(page.next_cursor, None);
+		let record = &page.records[0];
+		assert_eq!(record.direction, XcmDirection::Outbound);
+		assert_eq!(record.status, XcmOutcomeStatus::Sent);
+		assert_eq!(record.origin, sender.clone().into());
+		assert_eq!(record.destination, Some(RelayLocation::get().into()));
+
+		// Pagination.
+		let page = recent(Default::default(), None, 1);
+		assert_eq!(page.records[0].message_id, ids[2]);
+		let page = recent(Default::default(), page.next_cursor, 1);
+		assert_eq!(page.records[0].message_id, ids[1]);
+		assert_eq!(page.next_cursor, None);
+
+		// Inbound outcomes are recorded along with the weight used, and can be filtered by
+		// origin and message id.
+		let used = Weight::from_parts(1_000, 10);
+		let outcome = Outcome::Complete { used };
+		XcmPallet::record_inbound([7; 32], &Parent.into(), &outcome, &mut WeightMeter::new());
+		let by_origin = XcmOutcomesFilter { origin: Some(Parent.into()), ..Default::default() };
+		let page = recent(by_origin, None, 10);
+		assert_eq!(page.records.len(), 1);
+		assert_eq!(page.records[0].direction, XcmDirection::Inbound);
+		assert_eq!(page.records[0].status, XcmOutcomeStatus::Complete);
+		assert_eq!(page.records[0].weight_used, used);
+		let by_id = XcmOutcomesFilter { message_id: Some(ids[2]), ..Default::default() };
+		assert_eq!(recent(by_id, None, 10).records[0].message_id, ids[2]);
+
+		// Changing the size clears the history.
+		assert_ok!(XcmPallet::set_xcm_outcome_history_size(RuntimeOrigin::root(), 0));
+		assert!(recent(Default::default(), None, 10).records.is_empty());
+		assert_eq!(XcmOutcomeHistory::<Test>::iter().count(), 0);
+	});
+}
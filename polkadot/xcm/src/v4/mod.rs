@@ -1443,7 +1443,8 @@ impl<Call: Decode + GetDispatchInfo> TryFrom<NewInstruction<Call>> for Instructi
 			InitiateTransfer { .. } |
 			PayFees { .. } |
 			SetHints { .. } |
-			ExecuteWithOrigin { .. } => {
+			ExecuteWithOrigin { .. } |
+			HrmpChannelResizing { .. } => {
 				log::debug!(target: "xcm::versions::v5tov4", "`{new_instruction:?}` not supported by v4");
 				return Err(());
 			},
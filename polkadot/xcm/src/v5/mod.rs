@@ -1145,6 +1145,30 @@ pub enum Instruction<Call> {
 	/// - `hints`: A bounded vector of `ExecutionHint`, specifying the different hints that will
 	/// be activated.
 	SetHints { hints: BoundedVec<Hint, HintNumVariants> },
+
+	/// A message to notify that the other party in an open channel requested to change its
+	/// limits. In particular, `initiator` wants the channel opened from `sender` to the
+	/// `recipient` to accept at most `max_capacity` messages of at most `max_message_size` bytes.
+	/// The new limits will be applied at a relay-chain session change after the receiver of this
+	/// notification accepts them. This message is meant to be sent by the relay-chain to a para.
+	///
+	/// Safety: The message should originate directly from the relay-chain.
+	///
+	/// Kind: *System Notification*
+	///
+	/// Errors:
+	HrmpChannelResizing {
+		#[codec(compact)]
+		initiator: u32,
+		#[codec(compact)]
+		sender: u32,
+		#[codec(compact)]
+		recipient: u32,
+		#[codec(compact)]
+		max_message_size: u32,
+		#[codec(compact)]
+		max_capacity: u32,
+	},
 }
 
 #[derive(
@@ -1247,6 +1271,14 @@ impl<Call> Instruction<Call> {
 				InitiateTransfer { destination, remote_fees, preserve_origin, assets, remote_xcm },
 			ExecuteWithOrigin { descendant_origin, xcm } =>
 				ExecuteWithOrigin { descendant_origin, xcm: xcm.into() },
+			HrmpChannelResizing {
+				initiator,
+				sender,
+				recipient,
+				max_message_size,
+				max_capacity,
+			} =>
+				HrmpChannelResizing { initiator, sender, recipient, max_message_size, max_capacity },
 		}
 	}
 }
@@ -1322,6 +1354,19 @@ impl<Call, W: XcmWeightInfo<Call>> GetWeight<W> for Instruction<Call> {
 				W::initiate_transfer(destination, remote_fees, preserve_origin, assets, remote_xcm),
 			ExecuteWithOrigin { descendant_origin, xcm } =>
 				W::execute_with_origin(descendant_origin, xcm),
+			HrmpChannelResizing {
+				initiator,
+				sender,
+				recipient,
+				max_message_size,
+				max_capacity,
+			} => W::hrmp_channel_resizing(
+				initiator,
+				sender,
+				recipient,
+				max_message_size,
+				max_capacity,
+			),
 		}
 	}
 }
@@ -489,8 +489,8 @@ impl<T: Contains<Location>> ShouldExecute for AllowSubscriptionsFrom<T> {
 }
 
 /// Allows execution for the Relay Chain origin (represented as `Location::parent()`) if it is just
-/// a straight `HrmpNewChannelOpenRequest`, `HrmpChannelAccepted`, or `HrmpChannelClosing`
-/// instruction.
+/// a straight `HrmpNewChannelOpenRequest`, `HrmpChannelAccepted`, `HrmpChannelClosing` or
+/// `HrmpChannelResizing` instruction.
 ///
 /// Note: This barrier fulfills safety recommendations for the mentioned instructions - see their
 /// documentation.
@@ -516,7 +516,8 @@ impl ShouldExecute for AllowHrmpNotificationsFromRelayChain {
 			.match_next_inst(|inst| match inst {
 				HrmpNewChannelOpenRequest { .. } |
 				HrmpChannelAccepted { .. } |
-				HrmpChannelClosing { .. } => Ok(()),
+				HrmpChannelClosing { .. } |
+				HrmpChannelResizing { .. } => Ok(()),
 				_ => Err(ProcessMessageError::BadFormat),
 			})?;
 		Ok(())
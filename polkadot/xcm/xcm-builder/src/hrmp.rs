@@ -0,0 +1,84 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Adapters handling the HRMP notifications sent by the relay chain.
+
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+use frame_support::traits::{Contains, Get};
+use polkadot_parachain_primitives::primitives::Id as ParaId;
+use sp_runtime::traits::Convert;
+use xcm::prelude::*;
+use xcm_executor::traits::HandleHrmpChannelResizing;
+
+/// Accepts the requests of the counterparties in `Initiators` to resize the HRMP channels of
+/// `SelfParaId`, by answering the `HrmpChannelResizing` notification with the relay-chain call
+/// built by `AcceptCall` for the `(sender, recipient)` of the channel.
+///
+/// The call is dispatched on the relay chain with the origin of this para through an unpaid
+/// `Transact` sent via `Router`, so the relay chain must allow unpaid execution for this para, as
+/// it does for system parachains.
+pub struct AcceptHrmpChannelResizing<SelfParaId, Initiators, AcceptCall, Router>(
+	PhantomData<(SelfParaId, Initiators, AcceptCall, Router)>,
+);
+impl<
+		SelfParaId: Get<ParaId>,
+		Initiators: Contains<ParaId>,
+		AcceptCall: Convert<(ParaId, ParaId), Vec<u8>>,
+		Router: SendXcm,
+	> HandleHrmpChannelResizing
+	for AcceptHrmpChannelResizing<SelfParaId, Initiators, AcceptCall, Router>
+{
+	fn handle(
+		initiator: u32,
+		sender: u32,
+		recipient: u32,
+		_max_message_size: u32,
+		_max_capacity: u32,
+	) -> XcmResult {
+		let this = u32::from(SelfParaId::get());
+		// Only the counterparty of the initiator can accept the request.
+		if initiator == this || (sender != this && recipient != this) {
+			return Ok(())
+		}
+		if !Initiators::contains(&initiator.into()) {
+			tracing::trace!(
+				target: "xcm::hrmp",
+				?initiator, ?sender, ?recipient,
+				"Ignoring HRMP channel resize request",
+			);
+			return Ok(())
+		}
+
+		let message = Xcm(vec![
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			Transact {
+				origin_kind: OriginKind::Native,
+				call: AcceptCall::convert((sender.into(), recipient.into())).into(),
+				fallback_max_weight: None,
+			},
+		]);
+		send_xcm::<Router>(Location::parent(), message).map_err(|error| {
+			tracing::debug!(
+				target: "xcm::hrmp",
+				?error, ?sender, ?recipient,
+				"Failed to accept HRMP channel resize request",
+			);
+			XcmError::from(error)
+		})?;
+		Ok(())
+	}
+}
@@ -75,6 +75,9 @@ pub use fungibles_adapter::{
 	LocalMint, MintLocation, NoChecking, NonLocalMint,
 };
 
+mod hrmp;
+pub use hrmp::AcceptHrmpChannelResizing;
+
 mod location_conversion;
 #[allow(deprecated)]
 pub use location_conversion::ForeignChainAliasAccount;
@@ -25,18 +25,24 @@ use frame_support::{
 use scale_info::TypeInfo;
 use sp_weights::{Weight, WeightMeter};
 use xcm::prelude::*;
+use xcm_executor::traits::RecordXcmOutcome;
 
 const LOG_TARGET: &str = "xcm::process-message";
 
 /// A message processor that delegates execution to an `XcmExecutor`.
-pub struct ProcessXcmMessage<MessageOrigin, XcmExecutor, Call>(
-	PhantomData<(MessageOrigin, XcmExecutor, Call)>,
+///
+/// The outcome of every processed message is handed to `Recorder`, which does nothing by default.
+/// Failed messages are recorded in [`ProcessMessage::process_message_failed`], after their storage
+/// changes have been rolled back.
+pub struct ProcessXcmMessage<MessageOrigin, XcmExecutor, Call, Recorder = ()>(
+	PhantomData<(MessageOrigin, XcmExecutor, Call, Recorder)>,
 );
 impl<
 		MessageOrigin: Into<Location> + FullCodec + MaxEncodedLen + Clone + Eq + PartialEq + TypeInfo + Debug,
 		XcmExecutor: ExecuteXcm<Call>,
 		Call: Decode + GetDispatchInfo,
-	> ProcessMessage for ProcessXcmMessage<MessageOrigin, XcmExecutor, Call>
+		Recorder: RecordXcmOutcome,
+	> ProcessMessage for ProcessXcmMessage<MessageOrigin, XcmExecutor, Call, Recorder>
 {
 	type Origin = MessageOrigin;
 
@@ -84,21 +90,23 @@ impl<
 			return Err(ProcessMessageError::Overweight(required))
 		}
 
-		let (consumed, result) = match XcmExecutor::execute(origin.into(), pre, id, Weight::zero())
-		{
+		let origin: Location = origin.into();
+		let outcome = XcmExecutor::execute(origin.clone(), pre, id, Weight::zero());
+
+		let (consumed, result) = match &outcome {
 			Outcome::Complete { used } => {
 				tracing::trace!(
 					target: LOG_TARGET,
 					"XCM message execution complete, used weight: {used}",
 				);
-				(used, Ok(true))
+				(*used, Ok(true))
 			},
 			Outcome::Incomplete { used, error } => {
 				tracing::trace!(
 					target: LOG_TARGET,
 					"XCM message execution incomplete, used weight: {used}, error: {error:?}",
 				);
-				(used, Ok(false))
+				(*used, Ok(false))
 			},
 			// In the error-case we assume the worst case and consume all possible weight.
 			Outcome::Error { error } => {
@@ -115,8 +123,26 @@ impl<
 			},
 		};
 		meter.consume(consumed);
+		if result.is_ok() {
+			Recorder::record_inbound(*id, &origin, &outcome, meter);
+		}
 		result
 	}
+
+	fn process_message_failed(
+		origin: Self::Origin,
+		meter: &mut WeightMeter,
+		id: &XcmHash,
+		error: ProcessMessageError,
+	) {
+		let error = match error {
+			ProcessMessageError::StackLimitReached => XcmError::ExceedsStackLimit,
+			ProcessMessageError::BadFormat | ProcessMessageError::Corrupt =>
+				XcmError::FailedToDecode,
+			_ => XcmError::Unimplemented,
+		};
+		Recorder::record_inbound(*id, &origin.into(), &Outcome::Error { error }, meter);
+	}
 }
 
 #[cfg(test)]
@@ -223,6 +249,62 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn process_message_records_outcome() {
+		std::thread_local! {
+			static RECORDED: core::cell::RefCell<Vec<(XcmHash, Location, bool)>> =
+				core::cell::RefCell::new(Vec::new());
+		}
+		struct MockedRecorder;
+		impl RecordXcmOutcome for MockedRecorder {
+			fn record_inbound(
+				message_id: XcmHash,
+				origin: &Location,
+				outcome: &Outcome,
+				meter: &mut WeightMeter,
+			) {
+				meter.consume(10.into());
+				let complete = matches!(outcome, Outcome::Complete { .. });
+				RECORDED.with(|r| r.borrow_mut().push((message_id, origin.clone(), complete)));
+			}
+			fn record_outbound(_: XcmHash, _: &Location, _: &Location) {
+				unreachable!()
+			}
+		}
+
+		type Processor = ProcessXcmMessage<
+			Junction,
+			xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
+			RuntimeCall,
+			MockedRecorder,
+		>;
+
+		for (id, success) in [([1; 32], true), ([2; 32], false)] {
+			let mut id = id;
+			let msg = v5_xcm(success).encode();
+			let meter = &mut WeightMeter::new();
+			assert_eq!(Processor::process_message(&msg, ORIGIN, meter, &mut id), Ok(success));
+			// The recording weight is charged on top of the execution weight.
+			assert_eq!(meter.consumed(), 1010.into());
+		}
+		// Failed messages are only recorded once their storage changes were rolled back.
+		let meter = &mut WeightMeter::new();
+		assert_err!(Processor::process_message(&[55, 66], ORIGIN, meter, &mut [3; 32]), Corrupt);
+		assert_eq!(meter.consumed(), 0.into());
+		Processor::process_message_failed(ORIGIN, meter, &[3; 32], Corrupt);
+		assert_eq!(meter.consumed(), 10.into());
+
+		let origin: Location = ORIGIN.into();
+		assert_eq!(
+			RECORDED.with(|r| r.take()),
+			vec![
+				([1; 32], origin.clone(), true),
+				([2; 32], origin.clone(), false),
+				([3; 32], origin, false)
+			]
+		);
+	}
+
 	fn v3_xcm(success: bool) -> VersionedXcm<RuntimeCall> {
 		let instr = if success {
 			v3::Instruction::<RuntimeCall>::ClearOrigin
@@ -0,0 +1,80 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+fn resize_request(initiator: u32, sender: u32, recipient: u32) -> Xcm<TestCall> {
+	Xcm(vec![HrmpChannelResizing {
+		initiator,
+		sender,
+		recipient,
+		max_message_size: 4,
+		max_capacity: 1,
+	}])
+}
+
+fn execute_from_relay_chain(message: Xcm<TestCall>) -> Outcome {
+	let mut hash = fake_message_hash(&message);
+	XcmExecutor::<TestConfig>::prepare_and_execute(
+		Parent,
+		message,
+		&mut hash,
+		Weight::from_parts(10, 10),
+		Weight::zero(),
+	)
+}
+
+#[test]
+fn hrmp_channel_resizing_is_accepted() {
+	AllowUnpaidFrom::set(vec![Parent.into()]);
+	AcceptHrmpResizeFrom::set(vec![1000.into()]);
+
+	// The counterparty wants to resize the channel from this para.
+	let r = execute_from_relay_chain(resize_request(1000, 42, 1000));
+	assert_eq!(r, Outcome::Complete { used: Weight::from_parts(10, 10) });
+
+	let accept_call = TestAcceptHrmpChannelResize::convert((42.into(), 1000.into()));
+	let expected = Xcm::<()>(vec![
+		UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+		Transact {
+			origin_kind: OriginKind::Native,
+			call: accept_call.into(),
+			fallback_max_weight: None,
+		},
+	]);
+	assert_eq!(
+		sent_xcm().into_iter().map(|(dest, msg, _)| (dest, msg)).collect::<Vec<_>>(),
+		vec![(Parent.into(), expected)]
+	);
+}
+
+#[test]
+fn hrmp_channel_resizing_is_ignored_unless_accepted_from_initiator() {
+	AllowUnpaidFrom::set(vec![Parent.into()]);
+	AcceptHrmpResizeFrom::set(vec![1000.into()]);
+
+	// Requests of other paras are not accepted.
+	let r = execute_from_relay_chain(resize_request(2000, 2000, 42));
+	assert_eq!(r, Outcome::Complete { used: Weight::from_parts(10, 10) });
+	// Requests initiated by this para must be accepted by the counterparty.
+	let r = execute_from_relay_chain(resize_request(42, 42, 1000));
+	assert_eq!(r, Outcome::Complete { used: Weight::from_parts(10, 10) });
+	// Channels of other paras are none of our business.
+	let r = execute_from_relay_chain(resize_request(1000, 1000, 2000));
+	assert_eq!(r, Outcome::Complete { used: Weight::from_parts(10, 10) });
+
+	assert!(sent_xcm().is_empty());
+}
@@ -22,9 +22,9 @@ use crate::{
 	EnsureDecodableXcm,
 };
 pub use crate::{
-	AliasChildLocation, AliasForeignAccountId32, AllowExplicitUnpaidExecutionFrom,
-	AllowKnownQueryResponses, AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom,
-	FixedRateOfFungible, FixedWeightBounds, TakeWeightCredit,
+	AcceptHrmpChannelResizing, AliasChildLocation, AliasForeignAccountId32,
+	AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses, AllowTopLevelPaidExecutionFrom,
+	AllowUnpaidExecutionFrom, FixedRateOfFungible, FixedWeightBounds, TakeWeightCredit,
 };
 pub use alloc::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 pub use codec::{Decode, DecodeWithMemTracking, Encode};
@@ -40,6 +40,8 @@ pub use frame_support::{
 	sp_runtime::{traits::Dispatchable, DispatchError, DispatchErrorWithPostInfo},
 	traits::{Contains, Get, IsInVec},
 };
+pub use polkadot_parachain_primitives::primitives::Id as ParaId;
+pub use sp_runtime::traits::Convert;
 pub use xcm::latest::{prelude::*, QueryId, Weight};
 pub use xcm_executor::{
 	traits::{
@@ -738,6 +740,20 @@ impl Contains<Location> for ParentPrefix {
 /// Pairs (location1, location2) where location1 can alias as location2.
 pub type Aliasers = (AliasForeignAccountId32<SiblingPrefix>, AliasChildLocation);
 
+parameter_types! {
+	pub const SelfParaId: ParaId = ParaId::new(42);
+	// No HRMP channel resize request is accepted by default.
+	pub static AcceptHrmpResizeFrom: Vec<ParaId> = vec![];
+}
+
+/// Encodes the relay-chain call accepting the resize of a channel as `(sender, recipient)`.
+pub struct TestAcceptHrmpChannelResize;
+impl Convert<(ParaId, ParaId), Vec<u8>> for TestAcceptHrmpChannelResize {
+	fn convert(channel: (ParaId, ParaId)) -> Vec<u8> {
+		channel.encode()
+	}
+}
+
 pub struct TestConfig;
 impl Config for TestConfig {
 	type RuntimeCall = TestCall;
@@ -768,6 +784,12 @@ impl Config for TestConfig {
 	type HrmpNewChannelOpenRequestHandler = ();
 	type HrmpChannelAcceptedHandler = ();
 	type HrmpChannelClosingHandler = ();
+	type HrmpChannelResizingHandler = AcceptHrmpChannelResizing<
+		SelfParaId,
+		IsInVec<AcceptHrmpResizeFrom>,
+		TestAcceptHrmpChannelResize,
+		TestMessageSender,
+	>;
 	type XcmRecorder = ();
 }
 
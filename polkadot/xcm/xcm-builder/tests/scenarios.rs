@@ -398,6 +398,7 @@ fn recursive_xcm_execution_fail() {
 		type HrmpNewChannelOpenRequestHandler = ();
 		type HrmpChannelAcceptedHandler = ();
 		type HrmpChannelClosingHandler = ();
+		type HrmpChannelResizingHandler = ();
 		type XcmRecorder = XcmPallet;
 	}
 
@@ -35,9 +35,9 @@ pub mod traits;
 use traits::{
 	validate_export, AssetExchange, AssetLock, CallDispatcher, ClaimAssets, ConvertOrigin,
 	DropAssets, Enact, ExportXcm, FeeManager, FeeReason, HandleHrmpChannelAccepted,
-	HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest, OnResponse, ProcessTransaction,
-	Properties, ShouldExecute, TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader,
-	XcmAssetTransfers,
+	HandleHrmpChannelClosing, HandleHrmpChannelResizing, HandleHrmpNewChannelOpenRequest,
+	OnResponse, ProcessTransaction, Properties, ShouldExecute, TransactAsset,
+	VersionChangeNotifier, WeightBounds, WeightTrader, XcmAssetTransfers,
 };
 
 pub use traits::RecordXcm;
@@ -1701,6 +1701,16 @@ impl<Config: config::Config> XcmExecutor<Config> {
 				Config::TransactionalProcessor::process(|| {
 					Config::HrmpChannelClosingHandler::handle(initiator, sender, recipient)
 				}),
+			HrmpChannelResizing { initiator, sender, recipient, max_message_size, max_capacity } =>
+				Config::TransactionalProcessor::process(|| {
+					Config::HrmpChannelResizingHandler::handle(
+						initiator,
+						sender,
+						recipient,
+						max_message_size,
+						max_capacity,
+					)
+				}),
 		}
 	}
 
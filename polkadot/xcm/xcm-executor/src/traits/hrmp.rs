@@ -31,6 +31,17 @@ pub trait HandleHrmpChannelClosing {
 	fn handle(initiator: u32, sender: u32, recipient: u32) -> XcmResult;
 }
 
+/// Executes optional logic when a `HrmpChannelResizing` XCM notification is received.
+pub trait HandleHrmpChannelResizing {
+	fn handle(
+		initiator: u32,
+		sender: u32,
+		recipient: u32,
+		max_message_size: u32,
+		max_capacity: u32,
+	) -> XcmResult;
+}
+
 #[impl_trait_for_tuples::impl_for_tuples(30)]
 impl HandleHrmpNewChannelOpenRequest for Tuple {
 	fn handle(sender: u32, max_message_size: u32, max_capacity: u32) -> XcmResult {
@@ -54,3 +65,17 @@ impl HandleHrmpChannelClosing for Tuple {
 		Ok(())
 	}
 }
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl HandleHrmpChannelResizing for Tuple {
+	fn handle(
+		initiator: u32,
+		sender: u32,
+		recipient: u32,
+		max_message_size: u32,
+		max_capacity: u32,
+	) -> XcmResult {
+		for_tuples!( #( Tuple::handle(initiator, sender, recipient, max_message_size, max_capacity)?; )* );
+		Ok(())
+	}
+}
@@ -47,10 +47,13 @@ mod transact_asset;
 pub use transact_asset::TransactAsset;
 mod hrmp;
 pub use hrmp::{
-	HandleHrmpChannelAccepted, HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest,
+	HandleHrmpChannelAccepted, HandleHrmpChannelClosing, HandleHrmpChannelResizing,
+	HandleHrmpNewChannelOpenRequest,
 };
+mod record_outcome;
 mod record_xcm;
 mod weight;
+pub use record_outcome::RecordXcmOutcome;
 pub use record_xcm::RecordXcm;
 #[deprecated = "Use `sp_runtime::traits::` instead"]
 pub use sp_runtime::traits::{Identity, TryConvertInto as JustTry};
@@ -0,0 +1,41 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Trait for recording the outcomes of sent and received XCMs and a dummy implementation.
+
+use sp_weights::WeightMeter;
+use xcm::latest::{Location, Outcome, XcmHash};
+
+/// Trait for recording the outcomes of sent and received XCMs.
+pub trait RecordXcmOutcome {
+	/// Record the `outcome` of the message `message_id` received from `origin`.
+	///
+	/// The weight of recording is consumed from `meter`. Nothing is recorded if it does not fit.
+	fn record_inbound(
+		message_id: XcmHash,
+		origin: &Location,
+		outcome: &Outcome,
+		meter: &mut WeightMeter,
+	);
+	/// Record that the message `message_id` was sent by `origin` to `destination`.
+	fn record_outbound(message_id: XcmHash, origin: &Location, destination: &Location);
+}
+
+impl RecordXcmOutcome for () {
+	fn record_inbound(_: XcmHash, _: &Location, _: &Outcome, _: &mut WeightMeter) {}
+
+	fn record_outbound(_: XcmHash, _: &Location, _: &Location) {}
+}
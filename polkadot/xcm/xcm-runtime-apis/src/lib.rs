@@ -31,6 +31,11 @@ pub mod dry_run;
 /// Given an XCM program, it will return the fees needed to execute it properly or send it.
 pub mod fees;
 
+/// Outcomes API.
+/// Returns the outcomes of the XCMs recently sent or received, paginated and filtered by message
+/// id and origin.
+pub mod outcomes;
+
 // Exposes runtime API for querying whether a Location is trusted as a reserve or teleporter for a
 // given Asset.
 pub mod trusted_query;
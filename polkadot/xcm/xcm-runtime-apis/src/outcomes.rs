@@ -0,0 +1,121 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for querying the outcomes of recently sent and received XCMs.
+//! This API can be used, for example, by wallets to show the status of a cross-chain transfer
+//! without relying on an indexer.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::TypeInfo;
+use sp_weights::Weight;
+use xcm::{prelude::XcmHash, VersionedLocation};
+
+/// Whether a recorded message was received or sent by this chain.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum XcmDirection {
+	/// The message was received and executed by this chain.
+	#[codec(index = 0)]
+	Inbound,
+	/// The message was sent by this chain.
+	#[codec(index = 1)]
+	Outbound,
+}
+
+/// What is known about a recorded message on this chain.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum XcmOutcomeStatus {
+	/// The message was handed over to the transport towards its destination.
+	#[codec(index = 0)]
+	Sent,
+	/// The message was executed completely.
+	#[codec(index = 1)]
+	Complete,
+	/// The execution of the message started but did not complete.
+	#[codec(index = 2)]
+	Incomplete,
+	/// The execution of the message did not start, e.g. because it was refused by the barrier.
+	#[codec(index = 3)]
+	Error,
+}
+
+/// A message recently sent or received by this chain.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct XcmOutcomeRecord<BlockNumber> {
+	/// Sequence number of the record, increasing with every recorded message.
+	pub index: u64,
+	/// The id of the message, which is its `SetTopic` id if it has one.
+	pub message_id: XcmHash,
+	/// Whether the message was received or sent.
+	pub direction: XcmDirection,
+	/// The origin of the message, relative to this chain.
+	pub origin: VersionedLocation,
+	/// The destination of the message, relative to this chain. Only known for sent messages.
+	pub destination: Option<VersionedLocation>,
+	/// The outcome of the message.
+	pub status: XcmOutcomeStatus,
+	/// The weight used to execute the message. Zero for sent messages.
+	pub weight_used: Weight,
+	/// The block in which the message was recorded.
+	pub block_number: BlockNumber,
+}
+
+/// Criteria the returned records must match. Empty fields match any record.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct XcmOutcomesFilter {
+	/// Only return records of messages with this id.
+	pub message_id: Option<XcmHash>,
+	/// Only return records of messages with this origin.
+	pub origin: Option<VersionedLocation>,
+}
+
+/// A page of records, from the most recent to the oldest.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct XcmOutcomesPage<BlockNumber> {
+	/// The records matching the filter.
+	pub records: Vec<XcmOutcomeRecord<BlockNumber>>,
+	/// The cursor to pass to get the next page, if there are more records to look at.
+	pub next_cursor: Option<u64>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for querying the outcomes of the messages recently sent or received by this chain.
+	///
+	/// Only a limited number of records is kept, and recording is disabled by default, in which
+	/// case no records are returned.
+	pub trait XcmOutcomesApi<BlockNumber> where BlockNumber: codec::Codec {
+		/// Returns the most recent records matching `filter`.
+		///
+		/// # Arguments
+		///
+		/// * `filter`: The criteria the records must match.
+		/// * `cursor`: `None` to start from the most recent record, or the `next_cursor` of the
+		///   previous page.
+		/// * `limit`: The maximum number of records to return.
+		fn recent_xcm_outcomes(
+			filter: XcmOutcomesFilter,
+			cursor: Option<u64>,
+			limit: u32,
+		) -> Result<XcmOutcomesPage<BlockNumber>, Error>;
+	}
+}
+
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub enum Error {
+	/// Converting a versioned data structure from one version to another failed.
+	#[codec(index = 0)]
+	VersionedConversionFailed,
+}
@@ -48,6 +48,7 @@ use xcm_runtime_apis::{
 	conversions::{Error as LocationToAccountApiError, LocationToAccountApi},
 	dry_run::{CallDryRunEffects, DryRunApi, Error as XcmDryRunApiError, XcmDryRunEffects},
 	fees::{Error as XcmPaymentApiError, XcmPaymentApi},
+	outcomes::{Error as XcmOutcomesApiError, XcmOutcomesApi, XcmOutcomesFilter, XcmOutcomesPage},
 	trusted_query::{Error as TrustedQueryApiError, TrustedQueryApi},
 };
 
@@ -326,6 +327,7 @@ impl xcm_executor::Config for XcmConfig {
 	type HrmpNewChannelOpenRequestHandler = ();
 	type HrmpChannelAcceptedHandler = ();
 	type HrmpChannelClosingHandler = ();
+	type HrmpChannelResizingHandler = ();
 	type XcmRecorder = XcmPallet;
 }
 
@@ -449,6 +451,16 @@ sp_api::mock_impl_runtime_apis! {
 		}
 	}
 
+	impl XcmOutcomesApi<Block, u64> for RuntimeApi {
+		fn recent_xcm_outcomes(
+			filter: XcmOutcomesFilter,
+			cursor: Option<u64>,
+			limit: u32,
+		) -> Result<XcmOutcomesPage<u64>, XcmOutcomesApiError> {
+			XcmPallet::recent_xcm_outcomes(filter, cursor, limit)
+		}
+	}
+
 	impl LocationToAccountApi<Block, AccountId> for RuntimeApi {
 		fn convert_location(location: VersionedLocation) -> Result<AccountId, LocationToAccountApiError> {
 			let location = location.try_into().map_err(|_| LocationToAccountApiError::VersionedConversionFailed)?;
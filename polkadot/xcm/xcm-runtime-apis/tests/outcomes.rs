@@ -0,0 +1,102 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod mock;
+
+use codec::Encode;
+use frame_support::{
+	assert_err, assert_ok,
+	sp_runtime::testing::H256,
+	traits::{ProcessMessage, ProcessMessageError},
+	weights::WeightMeter,
+};
+use mock::*;
+use sp_api::ProvideRuntimeApi;
+use xcm::prelude::*;
+use xcm_runtime_apis::outcomes::{
+	XcmDirection, XcmOutcomeStatus, XcmOutcomesApi, XcmOutcomesFilter,
+};
+
+type Processor = xcm_builder::ProcessXcmMessage<
+	Junction,
+	xcm_executor::XcmExecutor<XcmConfig>,
+	RuntimeCall,
+	XcmPallet,
+>;
+
+#[test]
+fn recent_xcm_outcomes_are_queryable() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let client = TestClient {};
+		let runtime_api = client.runtime_api();
+		let topic = [1; 32];
+		// Not paying for execution, so the barrier refuses it.
+		let message = VersionedXcm::from(Xcm::<RuntimeCall>(vec![ClearOrigin, SetTopic(topic)]));
+		let process = || {
+			let mut id = topic;
+			Processor::process_message(
+				&message.encode(),
+				Parachain(2000),
+				&mut WeightMeter::new(),
+				&mut id,
+			)
+		};
+
+		// Nothing is recorded by default.
+		assert_err!(process(), ProcessMessageError::Unsupported);
+		let page = runtime_api
+			.recent_xcm_outcomes(H256::zero(), Default::default(), None, 10)
+			.unwrap()
+			.unwrap();
+		assert!(page.records.is_empty());
+
+		assert_ok!(XcmPallet::set_xcm_outcome_history_size(RuntimeOrigin::root(), 10));
+		assert_err!(process(), ProcessMessageError::Unsupported);
+		// The failure is recorded once the message queue rolled back the storage changes.
+		Processor::process_message_failed(
+			Parachain(2000),
+			&mut WeightMeter::new(),
+			&topic,
+			ProcessMessageError::Unsupported,
+		);
+
+		let filter = XcmOutcomesFilter {
+			message_id: Some(topic),
+			origin: Some(Location::new(0, [Parachain(2000)]).into()),
+		};
+		let page = runtime_api
+			.recent_xcm_outcomes(H256::zero(), filter, None, 10)
+			.unwrap()
+			.unwrap();
+		assert_eq!(page.records.len(), 1);
+		assert_eq!(page.records[0].message_id, topic);
+		assert_eq!(page.records[0].direction, XcmDirection::Inbound);
+		assert_eq!(page.records[0].status, XcmOutcomeStatus::Error);
+		assert_eq!(page.records[0].block_number, 1);
+		assert_eq!(page.next_cursor, None);
+
+		let other_origin = XcmOutcomesFilter {
+			origin: Some(Location::new(0, [Parachain(2001)]).into()),
+			..Default::default()
+		};
+		let page = runtime_api
+			.recent_xcm_outcomes(H256::zero(), other_origin, None, 10)
+			.unwrap()
+			.unwrap();
+		assert!(page.records.is_empty());
+	});
+}
@@ -587,6 +587,18 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	(with_startup_data)(&block_import, &babe_link);
 
 	if let sc_service::config::Role::Authority { .. } = &role {
+		// Submit the valid tasks of the runtime, so that they end up in our blocks.
+		task_manager.spawn_handle().spawn(
+			"task-runner",
+			Some("block-authoring"),
+			sc_basic_authorship::TaskRunner::new(
+				client.clone(),
+				transaction_pool.clone(),
+				Default::default(),
+			)
+			.run(),
+		);
+
 		let proposer = sc_basic_authorship::ProposerFactory::new(
 			task_manager.spawn_handle(),
 			client.clone(),
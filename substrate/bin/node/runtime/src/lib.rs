@@ -2597,6 +2597,7 @@ impl pallet_broker::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type SovereignAccountOf = SovereignAccountOf;
 	type MaxAutoRenewals = ConstU32<10>;
+	type MaxAutoPoolsPerCore = ConstU32<10>;
 	type PriceAdapter = pallet_broker::CenterTargetPrice<Balance>;
 	type MinimumCreditPurchase = MinimumCreditPurchase;
 }
@@ -3511,6 +3512,19 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::TasksApi<Block> for Runtime {
+		fn valid_task_extrinsics(max_weight: Weight) -> Vec<<Block as BlockT>::Extrinsic> {
+			#[cfg(feature = "experimental")]
+			return System::valid_task_extrinsics(max_weight);
+
+			#[cfg(not(feature = "experimental"))]
+			{
+				let _ = max_weight;
+				Vec::new()
+			}
+		}
+	}
+
 	impl assets_api::AssetsApi<
 		Block,
 		AccountId,
@@ -4018,7 +4032,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_migrations::runtime_api::MigrationsApi<Block> for Runtime {
+		fn migration_status() -> pallet_migrations::MigrationStatus {
+			MultiBlockMigrations::migration_status()
+		}
+
+		fn last_failure() -> Option<pallet_migrations::MigrationProgress> {
+			MultiBlockMigrations::last_failure()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
+	#[api_version(2)]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
 			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
@@ -4028,6 +4053,13 @@ impl_runtime_apis! {
 			(weight, RuntimeBlockWeights::get().max_block)
 		}
 
+		fn on_runtime_upgrade_with_report(
+			checks: frame_try_runtime::UpgradeCheckSelect,
+		) -> frame_try_runtime::UpgradeReport {
+			// NOTE: intentional unwrap, to fail loudly if the upgrade checks fail.
+			Executive::try_runtime_upgrade_with_report(checks, || None).unwrap()
+		}
+
 		fn execute_block(
 			block: Block,
 			state_root_check: bool,
@@ -4040,6 +4072,15 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryDecodeState<Block> for Runtime {
+		fn decode_entire_state(
+			select: frame_try_runtime::DecodeSelect,
+		) -> frame_try_runtime::DecodeEntireStateReport {
+			Executive::try_decode_entire_state(select)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -4153,6 +4194,57 @@ mod tests {
 			maximum_chain_accuracy.iter().fold(0, |acc, x| acc.checked_add(*x).unwrap());
 	}
 
+	#[test]
+	fn metadata_round_trips() {
+		use sp_metadata_ir::frame_metadata::RuntimeMetadataPrefixed;
+
+		for version in Runtime::metadata_versions() {
+			let encoded = Runtime::metadata_at_version(version).expect("version is supported");
+			let metadata = RuntimeMetadataPrefixed::decode(&mut &encoded[..]).unwrap();
+
+			assert_eq!(metadata.encode(), *encoded, "metadata V{version} does not round trip");
+		}
+	}
+
+	#[test]
+	fn metadata_v16_is_complete() {
+		use sp_metadata_ir::frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+
+		let encoded = Runtime::metadata_at_version(16).expect("V16 is supported");
+		let RuntimeMetadata::V16(metadata) =
+			RuntimeMetadataPrefixed::decode(&mut &encoded[..]).unwrap().1
+		else {
+			panic!("metadata V16 was expected");
+		};
+		let ir = Runtime::metadata_ir();
+
+		assert_eq!(metadata.pallets.len(), ir.pallets.len());
+		for (pallet, pallet_ir) in metadata.pallets.iter().zip(&ir.pallets) {
+			assert_eq!(pallet.name, pallet_ir.name);
+			assert_eq!(
+				pallet.view_functions.iter().map(|f| f.id).collect::<Vec<_>>(),
+				pallet_ir.view_functions.iter().map(|f| f.id).collect::<Vec<_>>(),
+			);
+			assert_eq!(
+				pallet.associated_types.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+				pallet_ir.associated_types.iter().map(|t| t.name).collect::<Vec<_>>(),
+			);
+		}
+		assert!(metadata.pallets.iter().any(|pallet| !pallet.view_functions.is_empty()));
+		assert!(metadata.pallets.iter().any(|pallet| !pallet.associated_types.is_empty()));
+
+		// All transaction extensions are used by the only extension version.
+		let extensions = metadata.extrinsic.transaction_extensions.len() as u32;
+		assert_eq!(
+			metadata
+				.extrinsic
+				.transaction_extensions_by_version
+				.into_iter()
+				.collect::<Vec<_>>(),
+			vec![(0, (0..extensions).collect::<Vec<_>>())],
+		);
+	}
+
 	#[test]
 	fn call_size() {
 		let size = core::mem::size_of::<RuntimeCall>();
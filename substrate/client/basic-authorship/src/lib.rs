@@ -71,5 +71,9 @@
 //! ```
 
 mod basic_authorship;
+mod tasks;
 
-pub use crate::basic_authorship::{Proposer, ProposerFactory, DEFAULT_BLOCK_SIZE_LIMIT};
+pub use crate::{
+	basic_authorship::{Proposer, ProposerFactory, DEFAULT_BLOCK_SIZE_LIMIT},
+	tasks::{TaskRunner, TaskRunnerConfig, DEFAULT_TASKS_MAX_WEIGHT},
+};
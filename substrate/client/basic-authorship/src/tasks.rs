@@ -0,0 +1,229 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Discovery and submission of runtime tasks.
+//!
+//! Pallets can declare tasks, i.e. idempotent pieces of work that anyone may execute through
+//! `frame_system::Call::do_task` as long as the task is valid. The [`TaskRunner`] asks the runtime
+//! for the valid tasks at every new best block, using the
+//! [`TasksApi`](frame_system_rpc_runtime_api::TasksApi), and submits them as unsigned
+//! transactions to the transaction pool, from where they are included into the blocks authored by
+//! this node.
+
+use frame_system_rpc_runtime_api::TasksApi;
+use futures::StreamExt;
+use log::{debug, trace, warn};
+use sc_client_api::BlockchainEvents;
+use sc_transaction_pool_api::{
+	error::{Error as PoolError, IntoPoolError},
+	TransactionPool, TransactionSource,
+};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_runtime::{traits::Block as BlockT, Weight};
+use std::{marker::PhantomData, sync::Arc};
+
+const LOG_TARGET: &str = "basic-authorship::tasks";
+
+/// The default weight of the tasks submitted per block: 100ms of execution time and 1MiB of proof
+/// size.
+pub const DEFAULT_TASKS_MAX_WEIGHT: Weight = Weight::from_parts(100_000_000_000, 1024 * 1024);
+
+/// Configuration of the [`TaskRunner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskRunnerConfig {
+	/// The maximum total weight of the tasks submitted at each new best block.
+	pub max_weight: Weight,
+}
+
+impl Default for TaskRunnerConfig {
+	fn default() -> Self {
+		Self { max_weight: DEFAULT_TASKS_MAX_WEIGHT }
+	}
+}
+
+/// Submits the valid tasks of the runtime to the transaction pool.
+///
+/// Runtimes that don't implement the [`TasksApi`] are ignored.
+pub struct TaskRunner<Block, C, A> {
+	client: Arc<C>,
+	transaction_pool: Arc<A>,
+	config: TaskRunnerConfig,
+	_phantom: PhantomData<Block>,
+}
+
+impl<Block, C, A> TaskRunner<Block, C, A>
+where
+	Block: BlockT,
+	C: BlockchainEvents<Block> + ProvideRuntimeApi<Block> + Send + Sync + 'static,
+	C::Api: TasksApi<Block>,
+	A: TransactionPool<Block = Block> + 'static,
+{
+	/// Run the task runner.
+	///
+	/// The returned future only finishes when the import notification stream of the client ends.
+	pub async fn run(self) {
+		let mut imported_blocks = self.client.import_notification_stream();
+
+		while let Some(notification) = imported_blocks.next().await {
+			if notification.is_new_best {
+				self.submit_tasks(notification.hash).await;
+			}
+		}
+	}
+}
+
+impl<Block, C, A> TaskRunner<Block, C, A>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: TasksApi<Block>,
+	A: TransactionPool<Block = Block>,
+{
+	/// Create a new task runner.
+	pub fn new(client: Arc<C>, transaction_pool: Arc<A>, config: TaskRunnerConfig) -> Self {
+		Self { client, transaction_pool, config, _phantom: PhantomData }
+	}
+
+	/// Submit the tasks that are valid at `at`.
+	async fn submit_tasks(&self, at: Block::Hash) {
+		let api = self.client.runtime_api();
+
+		match api.has_api::<dyn TasksApi<Block>>(at) {
+			Ok(true) => {},
+			Ok(false) => {
+				trace!(target: LOG_TARGET, "Runtime at {at:?} doesn't support tasks.");
+				return
+			},
+			Err(e) => {
+				debug!(target: LOG_TARGET, "Failed to check the runtime APIs at {at:?}: {e}");
+				return
+			},
+		}
+
+		let extrinsics = match api.valid_task_extrinsics(at, self.config.max_weight) {
+			Ok(extrinsics) => extrinsics,
+			Err(e) => {
+				warn!(target: LOG_TARGET, "Failed to enumerate the valid tasks at {at:?}: {e}");
+				return
+			},
+		};
+
+		if extrinsics.is_empty() {
+			return
+		}
+
+		debug!(target: LOG_TARGET, "Submitting {} tasks at {at:?}.", extrinsics.len());
+
+		let results =
+			match self.transaction_pool.submit_at(at, TransactionSource::Local, extrinsics).await {
+				Ok(results) => results,
+				Err(e) => {
+					warn!(target: LOG_TARGET, "Failed to submit tasks at {at:?}: {e}");
+					return
+				},
+			};
+
+		for result in results {
+			match result.map_err(|e| e.into_pool_error()) {
+				Ok(hash) => trace!(target: LOG_TARGET, "Submitted task {hash:?}."),
+				// Tasks stay valid until they are executed, so most of them are already known.
+				Err(Ok(PoolError::AlreadyImported(_))) => {},
+				Err(Ok(e)) => debug!(target: LOG_TARGET, "Task was rejected by the pool: {e}"),
+				Err(Err(e)) => debug!(target: LOG_TARGET, "Task was rejected by the pool: {e}"),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::executor::block_on;
+	use sc_transaction_pool::BasicPool;
+	use sc_transaction_pool_api::InPoolTransaction;
+	use sp_api::ApiRef;
+	use substrate_test_runtime_client::{
+		runtime::{Block, Extrinsic},
+		Sr25519Keyring::Alice,
+	};
+	use substrate_test_runtime_transaction_pool::{uxt, TestApi};
+
+	/// Client whose runtime returns `tasks` as the valid task extrinsics.
+	struct TestClient {
+		tasks: Vec<Extrinsic>,
+	}
+
+	impl ProvideRuntimeApi<Block> for TestClient {
+		type Api = RuntimeApi;
+
+		fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+			RuntimeApi { tasks: self.tasks.clone() }.into()
+		}
+	}
+
+	struct RuntimeApi {
+		tasks: Vec<Extrinsic>,
+	}
+
+	sp_api::mock_impl_runtime_apis! {
+		impl TasksApi<Block> for RuntimeApi {
+			fn valid_task_extrinsics(_max_weight: Weight) -> Vec<Extrinsic> {
+				self.tasks.clone()
+			}
+		}
+	}
+
+	#[test]
+	fn valid_tasks_are_submitted_and_invalid_ones_are_not() {
+		let api = Arc::new(TestApi::with_alice_nonce(209));
+		let genesis_hash = api.genesis_hash();
+		let (pool, _background_task) =
+			BasicPool::new_test(api.clone(), genesis_hash, genesis_hash, Default::default());
+
+		let valid = uxt(Alice, 209);
+		// The task became invalid between its enumeration and its submission, so the validation
+		// of the pool rejects it, like `ValidateUnsigned` does for `do_task` of invalid tasks.
+		let invalid = uxt(Alice, 210);
+		api.add_invalid(&invalid);
+
+		let client = Arc::new(TestClient { tasks: vec![valid.clone(), invalid] });
+		let runner = TaskRunner::new(client, Arc::new(pool), Default::default());
+		block_on(runner.submit_tasks(genesis_hash));
+
+		let ready = runner.transaction_pool.ready().map(|tx| tx.data().clone()).collect::<Vec<_>>();
+		assert_eq!(ready, vec![valid]);
+		assert_eq!(runner.transaction_pool.status().future, 0);
+	}
+
+	#[test]
+	fn resubmitting_valid_tasks_keeps_them_in_the_pool() {
+		let api = Arc::new(TestApi::with_alice_nonce(209));
+		let genesis_hash = api.genesis_hash();
+		let (pool, _background_task) =
+			BasicPool::new_test(api.clone(), genesis_hash, genesis_hash, Default::default());
+
+		let task = uxt(Alice, 209);
+		let client = Arc::new(TestClient { tasks: vec![task.clone()] });
+		let runner = TaskRunner::new(client, Arc::new(pool), Default::default());
+		block_on(runner.submit_tasks(genesis_hash));
+		block_on(runner.submit_tasks(genesis_hash));
+
+		let ready = runner.transaction_pool.ready().map(|tx| tx.data().clone()).collect::<Vec<_>>();
+		assert_eq!(ready, vec![task]);
+	}
+}
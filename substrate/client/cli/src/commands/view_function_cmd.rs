@@ -0,0 +1,104 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{BlockNumberOrHash, DatabaseParams, PruningParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use sc_client_api::{ExecutorProvider, HeaderBackend};
+use sc_rpc::state::view_function::{call_view_function, ViewFunctionId};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{fmt::Debug, io::Write, str::FromStr, sync::Arc};
+
+/// The `view-function` command used to call a pallet view function against the local database.
+#[derive(Debug, Clone, Parser)]
+pub struct ViewFunctionCmd {
+	/// The hex encoded 32 bytes identifier of the view function.
+	#[arg(value_name = "ID")]
+	pub id: String,
+
+	/// The hex encoded SCALE arguments of the view function.
+	#[arg(long, value_name = "HEX")]
+	pub input: Option<String>,
+
+	/// Block hash or number to call the view function at. Defaults to the best block.
+	#[arg(long, value_name = "HASH or NUMBER")]
+	pub at: Option<BlockNumberOrHash>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ViewFunctionCmd {
+	/// Run the `view-function` command
+	pub async fn run<B, C>(&self, client: Arc<C>) -> error::Result<()>
+	where
+		B: BlockT,
+		C: ExecutorProvider<B> + HeaderBackend<B>,
+		<B::Hash as FromStr>::Err: Debug,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let id: ViewFunctionId =
+			array_bytes::hex2bytes(&self.id)?.try_into().map_err(|id: Vec<u8>| {
+				format!("Expected a 32 bytes view function id, got {} bytes", id.len())
+			})?;
+		let input =
+			self.input.as_ref().map(array_bytes::hex2bytes).transpose()?.unwrap_or_default();
+
+		let block_id = self.at.as_ref().map(|b| b.parse()).transpose()?;
+		let hash = match block_id {
+			Some(id) => client.expect_block_hash_from_id(&id)?,
+			None => client.info().best_hash,
+		};
+
+		let result = call_view_function::<B, _>(&*client, hash, id, &input)
+			.map_err(|e| error::Error::Application(Box::new(e)))?;
+
+		let json = serde_json::to_string_pretty(&result)
+			.map_err(|e| error::Error::Application(Box::new(e)))?;
+		if std::io::stdout().write_all(json.as_bytes()).is_err() {
+			let _ = std::io::stderr().write_all(b"Error writing to stdout\n");
+		}
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ViewFunctionCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
@@ -26,6 +26,7 @@ use clap::Args;
 use std::{
 	net::{Ipv4Addr, Ipv6Addr, SocketAddr},
 	num::NonZeroU32,
+	path::PathBuf,
 };
 
 const RPC_LISTEN_ADDR: &str = "listen-addr";
@@ -100,6 +101,17 @@ pub struct RpcParams {
 	#[arg(long)]
 	pub rpc_rate_limit_trust_proxy_headers: bool,
 
+	/// Path of a JSON file with the RPC access policy.
+	///
+	/// The policy can allow or deny methods, rate limit single methods and assign costs to
+	/// methods that are charged against a per IP address quota. It applies to all RPC
+	/// interfaces and the file is reloaded when it changes.
+	///
+	/// IP addresses in `--rpc-rate-limit-whitelisted-ips` are exempt from the rate limits, but not
+	/// from the allowed and denied methods.
+	#[arg(long, value_name = "PATH")]
+	pub rpc_policy: Option<PathBuf>,
+
 	/// Set the maximum RPC request payload size for both HTTP and WS in megabytes.
 	#[arg(long, default_value_t = RPC_DEFAULT_MAX_REQUEST_SIZE_MB)]
 	pub rpc_max_request_size: u32,
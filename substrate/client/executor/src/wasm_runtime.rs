@@ -318,6 +318,7 @@ where
 						wasm_bulk_memory: false,
 						wasm_reference_types: false,
 						wasm_simd: false,
+						consume_fuel: false,
 					},
 				},
 			)
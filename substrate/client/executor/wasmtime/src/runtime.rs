@@ -75,11 +75,17 @@ struct InstanceCreator {
 	engine: Engine,
 	instance_pre: Arc<wasmtime::InstancePre<StoreData>>,
 	instance_counter: Arc<InstanceCounter>,
+	fuel: Option<u64>,
 }
 
 impl InstanceCreator {
 	fn instantiate(&mut self) -> Result<InstanceWrapper> {
-		InstanceWrapper::new(&self.engine, &self.instance_pre, self.instance_counter.clone())
+		InstanceWrapper::new(
+			&self.engine,
+			&self.instance_pre,
+			self.instance_counter.clone(),
+			self.fuel,
+		)
 	}
 }
 
@@ -137,19 +143,45 @@ pub struct WasmtimeRuntime {
 	instance_pre: Arc<wasmtime::InstancePre<StoreData>>,
 	instantiation_strategy: InternalInstantiationStrategy,
 	instance_counter: Arc<InstanceCounter>,
+	consume_fuel: bool,
 }
 
-impl WasmModule for WasmtimeRuntime {
-	fn new_instance(&self) -> Result<Box<dyn WasmInstance>> {
+impl WasmtimeRuntime {
+	/// Creates a new instance which is given `fuel_limit` units of fuel for every call.
+	///
+	/// Running out of fuel aborts the call with a trap. The amount of fuel consumed by the last
+	/// call can be queried with [`WasmtimeInstance::fuel_consumed`].
+	///
+	/// Fails if the runtime was not created with [`Semantics::consume_fuel`] enabled.
+	pub fn new_metered_instance(&self, fuel_limit: u64) -> Result<WasmtimeInstance> {
+		if !self.consume_fuel {
+			return Err(Error::Other("fuel metering is not enabled for this runtime".into()))
+		}
+
+		Ok(self.instance_with_fuel(Some(fuel_limit)))
+	}
+
+	fn instance_with_fuel(&self, fuel: Option<u64>) -> WasmtimeInstance {
 		let strategy = match self.instantiation_strategy {
 			InternalInstantiationStrategy::Builtin => Strategy::RecreateInstance(InstanceCreator {
 				engine: self.engine.clone(),
 				instance_pre: self.instance_pre.clone(),
 				instance_counter: self.instance_counter.clone(),
+				fuel,
 			}),
 		};
 
-		Ok(Box::new(WasmtimeInstance { strategy }))
+		WasmtimeInstance { strategy, fuel_consumed: None }
+	}
+}
+
+impl WasmModule for WasmtimeRuntime {
+	fn new_instance(&self) -> Result<Box<dyn WasmInstance>> {
+		// With metering enabled every call must be given some fuel, so default to an amount that
+		// is never going to be exhausted.
+		let fuel = self.consume_fuel.then_some(u64::MAX);
+
+		Ok(Box::new(self.instance_with_fuel(fuel)))
 	}
 }
 
@@ -157,9 +189,17 @@ impl WasmModule for WasmtimeRuntime {
 /// to execute the compiled code.
 pub struct WasmtimeInstance {
 	strategy: Strategy,
+	fuel_consumed: Option<u64>,
 }
 
 impl WasmtimeInstance {
+	/// Returns the amount of fuel consumed by the last call.
+	///
+	/// Returns `None` if fuel metering is disabled or no call has been made yet.
+	pub fn fuel_consumed(&self) -> Option<u64> {
+		self.fuel_consumed
+	}
+
 	fn call_impl(
 		&mut self,
 		method: &str,
@@ -173,7 +213,16 @@ impl WasmtimeInstance {
 				let entrypoint = instance_wrapper.resolve_entrypoint(method)?;
 				let allocator = FreeingBumpHeapAllocator::new(heap_base);
 
-				perform_call(data, &mut instance_wrapper, entrypoint, allocator, allocation_stats)
+				let result = perform_call(
+					data,
+					&mut instance_wrapper,
+					entrypoint,
+					allocator,
+					allocation_stats,
+				);
+				self.fuel_consumed = instance_wrapper.store().fuel_consumed();
+
+				result
 			},
 		}
 	}
@@ -278,6 +327,8 @@ fn common_config(semantics: &Semantics) -> std::result::Result<wasmtime::Config,
 	config.wasm_threads(false);
 	config.wasm_memory64(false);
 
+	config.consume_fuel(semantics.consume_fuel);
+
 	let (use_pooling, use_cow) = match semantics.instantiation_strategy {
 		InstantiationStrategy::PoolingCopyOnWrite => (true, true),
 		InstantiationStrategy::Pooling => (true, false),
@@ -456,6 +507,16 @@ pub struct Semantics {
 
 	/// Enables WASM Fixed-Width SIMD proposal
 	pub wasm_simd: bool,
+
+	/// Enables deterministic metering of the executed code with wasmtime fuel.
+	///
+	/// Every executed instruction consumes fuel, so the amount of fuel consumed by a call is the
+	/// same across machines and wasmtime versions compiling the code identically. This changes
+	/// the generated machine code, so precompiled artifacts must be produced with the same
+	/// setting.
+	///
+	/// See [`WasmtimeRuntime::new_metered_instance`] for running calls with a fuel limit.
+	pub consume_fuel: bool,
 }
 
 #[derive(Clone)]
@@ -636,6 +697,7 @@ where
 		instance_pre: Arc::new(instance_pre),
 		instantiation_strategy,
 		instance_counter: Default::default(),
+		consume_fuel: config.semantics.consume_fuel,
 	})
 }
 
@@ -20,7 +20,7 @@ use codec::{Decode as _, Encode as _};
 use sc_executor_common::{
 	error::Error,
 	runtime_blob::RuntimeBlob,
-	wasm_runtime::{HeapAllocStrategy, WasmModule, DEFAULT_HEAP_ALLOC_STRATEGY},
+	wasm_runtime::{HeapAllocStrategy, WasmInstance, WasmModule, DEFAULT_HEAP_ALLOC_STRATEGY},
 };
 use sc_runtime_test::wasm_binary_unwrap;
 
@@ -70,6 +70,7 @@ struct RuntimeBuilder {
 	deterministic_stack: bool,
 	heap_pages: HeapAllocStrategy,
 	precompile_runtime: bool,
+	consume_fuel: bool,
 	tmpdir: Option<tempfile::TempDir>,
 }
 
@@ -82,6 +83,7 @@ impl RuntimeBuilder {
 			deterministic_stack: false,
 			heap_pages: DEFAULT_HEAP_ALLOC_STRATEGY,
 			precompile_runtime: false,
+			consume_fuel: false,
 			tmpdir: None,
 		}
 	}
@@ -111,7 +113,12 @@ impl RuntimeBuilder {
 		self
 	}
 
-	fn build(&mut self) -> impl WasmModule + '_ {
+	fn consume_fuel(mut self, consume_fuel: bool) -> Self {
+		self.consume_fuel = consume_fuel;
+		self
+	}
+
+	fn build(&mut self) -> crate::WasmtimeRuntime {
 		let blob = {
 			let wasm: Vec<u8>;
 
@@ -146,6 +153,7 @@ impl RuntimeBuilder {
 				wasm_bulk_memory: false,
 				wasm_reference_types: false,
 				wasm_simd: false,
+				consume_fuel: self.consume_fuel,
 			},
 		};
 
@@ -303,6 +311,52 @@ fn test_stack_depth_reaching(instantiation_strategy: InstantiationStrategy) {
 	}
 }
 
+test_wasm_execution!(test_fuel_metering);
+fn test_fuel_metering(instantiation_strategy: InstantiationStrategy) {
+	let wat = r#"
+		(module
+		  (memory $0 32)
+		  (export "memory" (memory $0))
+		  (global (export "__heap_base") i32 (i32.const 0))
+		  (func (export "main")
+		    (param i32 i32) (result i64)
+		    (local $i i32)
+		    (loop $loop
+		      (local.set $i (i32.add (local.get $i) (i32.const 1)))
+		      (br_if $loop (i32.lt_u (local.get $i) (i32.const 1000)))
+		    )
+		    (i64.const 0)
+		  )
+		)
+	"#;
+
+	let mut builder = RuntimeBuilder::new(instantiation_strategy)
+		.use_wat(wat.to_string())
+		.consume_fuel(true);
+	let runtime = builder.build();
+
+	let mut instance = runtime.new_metered_instance(1_000_000).unwrap();
+	assert_eq!(instance.fuel_consumed(), None);
+	instance.call_export("main", &[]).unwrap();
+	let consumed = instance.fuel_consumed().unwrap();
+	assert!(consumed >= 1000);
+
+	// Every call starts from a fresh instance and consumes exactly the same amount of fuel.
+	instance.call_export("main", &[]).unwrap();
+	assert_eq!(instance.fuel_consumed(), Some(consumed));
+
+	let mut instance = runtime.new_metered_instance(consumed - 1).unwrap();
+	match instance.call_export("main", &[]).unwrap_err() {
+		Error::AbortedDueToTrap(_) => {},
+		error => panic!("unexpected error: {:?}", error),
+	}
+	assert!(instance.fuel_consumed().unwrap() >= consumed - 1);
+
+	// Metering is disabled unless requested.
+	let mut builder = RuntimeBuilder::new(instantiation_strategy).use_wat(wat.to_string());
+	assert!(builder.build().new_metered_instance(1_000_000).is_err());
+}
+
 test_wasm_execution!(test_max_memory_pages_imported_memory_without_precompilation);
 fn test_max_memory_pages_imported_memory_without_precompilation(
 	instantiation_strategy: InstantiationStrategy,
@@ -473,6 +527,7 @@ fn test_instances_without_reuse_are_not_leaked() {
 				wasm_bulk_memory: false,
 				wasm_reference_types: false,
 				wasm_simd: false,
+				consume_fuel: false,
 			},
 		},
 	)
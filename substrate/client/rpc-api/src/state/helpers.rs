@@ -30,3 +30,19 @@ pub struct ReadProof<Hash> {
 	/// A proof used to prove that storage entries are included in the storage trie
 	pub proof: Vec<Bytes>,
 }
+
+/// The result of a view function call returned by the RPC.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewFunctionResult {
+	/// Name of the pallet declaring the view function, if it was found in the metadata.
+	pub pallet: Option<String>,
+	/// Name of the view function, if it was found in the metadata.
+	pub name: Option<String>,
+	/// The SCALE encoded output of the view function.
+	pub output: Bytes,
+	/// The output decoded using the type information of the runtime metadata.
+	///
+	/// Only available when the runtime exposes the view functions in its metadata.
+	pub decoded: Option<serde_json::Value>,
+}
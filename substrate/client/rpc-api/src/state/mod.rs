@@ -28,7 +28,7 @@ use sp_version::RuntimeVersion;
 pub mod error;
 pub mod helpers;
 
-pub use self::helpers::ReadProof;
+pub use self::helpers::{ReadProof, ViewFunctionResult};
 pub use error::Error;
 
 /// Substrate state API
@@ -38,6 +38,18 @@ pub trait StateApi<Hash> {
 	#[method(name = "state_call", aliases = ["state_callAt"], blocking)]
 	fn call(&self, name: String, bytes: Bytes, hash: Option<Hash>) -> Result<Bytes, Error>;
 
+	/// Call a pallet view function at a block's state.
+	///
+	/// `id` is the 32 bytes identifier of the view function and `input` its SCALE encoded
+	/// arguments. The output is decoded using the runtime metadata when possible.
+	#[method(name = "state_callViewFunction", blocking)]
+	fn call_view_function(
+		&self,
+		id: Bytes,
+		input: Bytes,
+		hash: Option<Hash>,
+	) -> Result<ViewFunctionResult, Error>;
+
 	/// Returns the keys with prefix, leave empty to get all the keys.
 	#[method(name = "state_getKeys", blocking)]
 	#[deprecated(since = "2.0.0", note = "Please use `getKeysPaged` with proper paging support")]
@@ -42,7 +42,9 @@ pub use jsonrpsee::{
 	core::id_providers::{RandomIntegerIdProvider, RandomStringIdProvider},
 	server::{middleware::rpc::RpcServiceBuilder, BatchRequestConfig},
 };
-pub use middleware::{Metrics, MiddlewareLayer, NodeHealthProxyLayer, RpcMetrics};
+pub use middleware::{
+	Metrics, MiddlewareLayer, NodeHealthProxyLayer, RpcMetrics, RpcPolicy, RpcPolicyConfig,
+};
 pub use utils::{RpcEndpoint, RpcMethods};
 
 const MEGABYTE: u32 = 1024 * 1024;
@@ -95,6 +97,8 @@ pub struct Config<M: Send + Sync + 'static> {
 	pub endpoints: Vec<RpcEndpoint>,
 	/// Metrics.
 	pub metrics: Option<RpcMetrics>,
+	/// RPC access policy, applied to all endpoints.
+	pub policy: Option<RpcPolicy>,
 	/// RPC API.
 	pub rpc_api: RpcModule<M>,
 	/// Subscription ID provider.
@@ -108,6 +112,7 @@ struct PerConnection {
 	methods: Methods,
 	stop_handle: StopHandle,
 	metrics: Option<RpcMetrics>,
+	policy: Option<RpcPolicy>,
 	tokio_handle: tokio::runtime::Handle,
 }
 
@@ -116,12 +121,18 @@ pub async fn start_server<M>(config: Config<M>) -> Result<Server, Box<dyn StdErr
 where
 	M: Send + Sync,
 {
-	let Config { endpoints, metrics, tokio_handle, rpc_api, id_provider } = config;
+	let Config { endpoints, metrics, policy, tokio_handle, rpc_api, id_provider } = config;
 
 	let (stop_handle, server_handle) = stop_channel();
+
+	if let Some(policy) = policy.as_ref().filter(|policy| policy.path().is_some()) {
+		tokio_handle.spawn(policy.clone().watch(stop_handle.clone().shutdown()));
+	}
+
 	let cfg = PerConnection {
 		methods: build_rpc_api(rpc_api).into(),
 		metrics,
+		policy,
 		tokio_handle: tokio_handle.clone(),
 		stop_handle,
 	};
@@ -215,17 +226,18 @@ where
 					tower::service_fn(move |mut req: http::Request<hyper::body::Incoming>| {
 						req.extensions_mut().insert(deny_unsafe);
 
-						let PerConnection { methods, metrics, tokio_handle, stop_handle } =
+						let PerConnection { methods, metrics, policy, tokio_handle, stop_handle } =
 							cfg2.clone();
 						let service_builder = service_builder2.clone();
 
 						let proxy_ip =
 							if rate_limit_trust_proxy_headers { get_proxy_ip(&req) } else { None };
 
-						let rate_limit_cfg = if rate_limit_whitelisted_ips2
+						let is_whitelisted = rate_limit_whitelisted_ips2
 							.iter()
-							.any(|ips| ips.contains(proxy_ip.unwrap_or(ip)))
-						{
+							.any(|ips| ips.contains(proxy_ip.unwrap_or(ip)));
+
+						let rate_limit_cfg = if is_whitelisted {
 							log::debug!(target: "rpc", "ip={ip}, proxy_ip={:?} is trusted, disabling rate-limit", proxy_ip);
 							None
 						} else {
@@ -238,20 +250,24 @@ where
 						let is_websocket = ws::is_upgrade_request(&req);
 						let transport_label = if is_websocket { "ws" } else { "http" };
 
-						let middleware_layer = match (metrics, rate_limit_cfg) {
-							(None, None) => None,
-							(Some(metrics), None) => Some(
-								MiddlewareLayer::new()
-									.with_metrics(Metrics::new(metrics, transport_label)),
-							),
-							(None, Some(rate_limit)) =>
-								Some(MiddlewareLayer::new().with_rate_limit_per_minute(rate_limit)),
-							(Some(metrics), Some(rate_limit)) => Some(
-								MiddlewareLayer::new()
-									.with_metrics(Metrics::new(metrics, transport_label))
-									.with_rate_limit_per_minute(rate_limit),
-							),
-						};
+						let middleware_layer = (metrics.is_some() ||
+							rate_limit_cfg.is_some() ||
+							policy.is_some())
+						.then(|| {
+							let mut layer = MiddlewareLayer::new();
+							if let Some(metrics) = metrics {
+								layer = layer.with_metrics(Metrics::new(metrics, transport_label));
+							}
+							if let Some(rate_limit) = rate_limit_cfg {
+								layer = layer.with_rate_limit_per_minute(rate_limit);
+							}
+							if let Some(policy) = policy {
+								// Whitelisted callers are exempt from the rate limits of the policy.
+								let caller = (!is_whitelisted).then(|| proxy_ip.unwrap_or(ip));
+								layer = layer.with_policy(policy, caller);
+							}
+							layer
+						});
 
 						let rpc_middleware = RpcServiceBuilder::new()
 							.rpc_logger(1024)
@@ -19,6 +19,7 @@
 //! JSON-RPC specific middleware.
 
 use std::{
+	net::IpAddr,
 	num::NonZeroU32,
 	time::{Duration, Instant},
 };
@@ -33,10 +34,12 @@ use jsonrpsee::{
 
 mod metrics;
 mod node_health;
+mod policy;
 mod rate_limit;
 
 pub use metrics::*;
 pub use node_health::*;
+pub use policy::*;
 pub use rate_limit::*;
 
 const MAX_JITTER: Duration = Duration::from_millis(50);
@@ -47,6 +50,15 @@ const MAX_RETRIES: usize = 10;
 pub struct MiddlewareLayer {
 	rate_limit: Option<RateLimit>,
 	metrics: Option<Metrics>,
+	policy: Option<PolicyGuard>,
+}
+
+/// The [`RpcPolicy`] applied to the calls of a connection.
+#[derive(Debug, Clone)]
+struct PolicyGuard {
+	policy: RpcPolicy,
+	/// The IP address of the caller, `None` if the caller is trusted.
+	ip: Option<IpAddr>,
 }
 
 impl MiddlewareLayer {
@@ -57,12 +69,20 @@ impl MiddlewareLayer {
 
 	/// Enable new rate limit middleware enforced per minute.
 	pub fn with_rate_limit_per_minute(self, n: NonZeroU32) -> Self {
-		Self { rate_limit: Some(RateLimit::per_minute(n)), metrics: self.metrics }
+		Self { rate_limit: Some(RateLimit::per_minute(n)), ..self }
 	}
 
 	/// Enable metrics middleware.
 	pub fn with_metrics(self, metrics: Metrics) -> Self {
-		Self { rate_limit: self.rate_limit, metrics: Some(metrics) }
+		Self { metrics: Some(metrics), ..self }
+	}
+
+	/// Enable the RPC policy middleware for calls made by `ip`.
+	///
+	/// Trusted callers should pass `None` as `ip`, the allow and deny lists of the policy still
+	/// apply to them but the rate limits don't.
+	pub fn with_policy(self, policy: RpcPolicy, ip: Option<IpAddr>) -> Self {
+		Self { policy: Some(PolicyGuard { policy, ip }), ..self }
 	}
 
 	/// Register a new websocket connection.
@@ -80,12 +100,17 @@ impl<S> tower::Layer<S> for MiddlewareLayer {
 	type Service = Middleware<S>;
 
 	fn layer(&self, service: S) -> Self::Service {
-		Middleware { service, rate_limit: self.rate_limit.clone(), metrics: self.metrics.clone() }
+		Middleware {
+			service,
+			rate_limit: self.rate_limit.clone(),
+			metrics: self.metrics.clone(),
+			policy: self.policy.clone(),
+		}
 	}
 }
 
-/// JSON-RPC middleware that handles metrics
-/// and rate-limiting.
+/// JSON-RPC middleware that handles metrics,
+/// rate-limiting and the RPC policy.
 ///
 /// These are part of the same middleware
 /// because the metrics needs to know whether
@@ -95,6 +120,7 @@ pub struct Middleware<S> {
 	service: S,
 	rate_limit: Option<RateLimit>,
 	metrics: Option<Metrics>,
+	policy: Option<PolicyGuard>,
 }
 
 impl<'a, S> RpcServiceT<'a> for Middleware<S>
@@ -108,6 +134,15 @@ where
 
 		self.metrics.as_ref().map(|m| m.on_call(&req));
 
+		if let Some(PolicyGuard { policy, ip }) = self.policy.as_ref() {
+			match policy.check(req.method_name(), *ip) {
+				Ok(()) => {},
+				Err(PolicyViolation::Denied) =>
+					return async move { reject_denied_call(req.id) }.boxed(),
+				Err(_) => return async move { reject_too_many_calls(req.id) }.boxed(),
+			}
+		}
+
 		let service = self.service.clone();
 		let rate_limit = self.rate_limit.clone();
 		let metrics = self.metrics.clone();
@@ -145,6 +180,13 @@ where
 	}
 }
 
+fn reject_denied_call(id: Id) -> MethodResponse {
+	MethodResponse::error(
+		id,
+		ErrorObject::owned(-32601, "Method not allowed by the RPC policy", None::<()>),
+	)
+}
+
 fn reject_too_many_calls(id: Id) -> MethodResponse {
 	MethodResponse::error(id, ErrorObject::owned(-32999, "RPC rate limit exceeded", None::<()>))
 }
@@ -0,0 +1,444 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC access policy.
+//!
+//! The policy is loaded from a JSON file and controls which methods may be called and how often:
+//!
+//! ```json
+//! {
+//!   "allow": ["chain_*", "state_*", "system_health"],
+//!   "deny": ["state_traceBlock"],
+//!   "defaultCost": 1,
+//!   "ipQuotaPerMinute": 1200,
+//!   "methods": {
+//!     "state_getKeysPaged": { "cost": 20, "callsPerMinute": 60 },
+//!     "archive_unstable_storage": { "cost": 50 }
+//!   }
+//! }
+//! ```
+//!
+//! - `allow`: if set, only the matching methods can be called.
+//! - `deny`: the matching methods can't be called, this takes precedence over `allow`.
+//! - `defaultCost`: the cost of a method without an explicit cost.
+//! - `ipQuotaPerMinute`: the cost each IP address may spend per minute.
+//! - `methods`: per method cost and rate limit (calls per minute per IP address).
+//!
+//! Method patterns are either a method name or a prefix followed by `*`.
+//!
+//! The file is checked for changes periodically and reloaded when it was modified. Reloading
+//! resets the rate limits.
+
+use std::{
+	collections::HashMap,
+	error::Error as StdError,
+	fmt,
+	net::IpAddr,
+	num::NonZeroU32,
+	path::{Path, PathBuf},
+	sync::{Arc, RwLock},
+	time::{Duration, SystemTime},
+};
+
+use governor::{DefaultKeyedRateLimiter, Quota};
+use prometheus_endpoint::{register, CounterVec, Opts, PrometheusError, Registry, U64};
+use serde::Deserialize;
+
+/// How often the policy file is checked for changes.
+const RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+
+type KeyedRateLimit = DefaultKeyedRateLimiter<IpAddr>;
+
+/// Configuration of the [`RpcPolicy`], see the module docs for the format.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RpcPolicyConfig {
+	/// If set, only the methods matching one of these patterns are allowed.
+	#[serde(default)]
+	pub allow: Option<Vec<String>>,
+	/// The methods matching one of these patterns are denied.
+	#[serde(default)]
+	pub deny: Vec<String>,
+	/// The cost of a method without an explicit cost.
+	#[serde(default = "default_cost")]
+	pub default_cost: NonZeroU32,
+	/// The cost each IP address may spend per minute.
+	#[serde(default)]
+	pub ip_quota_per_minute: Option<NonZeroU32>,
+	/// Per method settings.
+	#[serde(default)]
+	pub methods: HashMap<String, MethodPolicy>,
+}
+
+impl Default for RpcPolicyConfig {
+	fn default() -> Self {
+		Self {
+			allow: None,
+			deny: Vec::new(),
+			default_cost: default_cost(),
+			ip_quota_per_minute: None,
+			methods: HashMap::new(),
+		}
+	}
+}
+
+/// Policy of a single method.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MethodPolicy {
+	/// The cost of a call, charged against the quota of the IP address.
+	#[serde(default)]
+	pub cost: Option<NonZeroU32>,
+	/// The number of calls per minute allowed for each IP address.
+	#[serde(default)]
+	pub calls_per_minute: Option<NonZeroU32>,
+}
+
+fn default_cost() -> NonZeroU32 {
+	NonZeroU32::MIN
+}
+
+/// Returns whether `method` matches `pattern`.
+fn matches(pattern: &str, method: &str) -> bool {
+	match pattern.strip_suffix('*') {
+		Some(prefix) => method.starts_with(prefix),
+		None => pattern == method,
+	}
+}
+
+/// Why a call was rejected by the [`RpcPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyViolation {
+	/// The method isn't allowed.
+	Denied,
+	/// The rate limit of the method was exceeded.
+	MethodRateLimited,
+	/// The quota of the IP address was exceeded.
+	IpQuotaExceeded,
+}
+
+impl PolicyViolation {
+	/// The label used for this violation in the metrics.
+	pub fn label(&self) -> &'static str {
+		match self {
+			Self::Denied => "denied",
+			Self::MethodRateLimited => "method_rate_limited",
+			Self::IpQuotaExceeded => "ip_quota_exceeded",
+		}
+	}
+}
+
+/// The policy config together with the state of its rate limits.
+struct PolicyState {
+	config: RpcPolicyConfig,
+	ip_quota: Option<KeyedRateLimit>,
+	method_limits: HashMap<String, KeyedRateLimit>,
+}
+
+impl PolicyState {
+	fn new(config: RpcPolicyConfig) -> Self {
+		let ip_quota =
+			config.ip_quota_per_minute.map(|n| KeyedRateLimit::keyed(Quota::per_minute(n)));
+		let method_limits = config
+			.methods
+			.iter()
+			.filter_map(|(method, policy)| {
+				policy
+					.calls_per_minute
+					.map(|n| (method.clone(), KeyedRateLimit::keyed(Quota::per_minute(n))))
+			})
+			.collect();
+
+		Self { config, ip_quota, method_limits }
+	}
+
+	fn is_allowed(&self, method: &str) -> bool {
+		let allowed = self
+			.config
+			.allow
+			.as_ref()
+			.map_or(true, |allow| allow.iter().any(|pattern| matches(pattern, method)));
+
+		allowed && !self.config.deny.iter().any(|pattern| matches(pattern, method))
+	}
+
+	fn cost(&self, method: &str) -> NonZeroU32 {
+		self.config
+			.methods
+			.get(method)
+			.and_then(|policy| policy.cost)
+			.unwrap_or(self.config.default_cost)
+	}
+
+	fn check(&self, method: &str, ip: Option<IpAddr>) -> Result<(), PolicyViolation> {
+		if !self.is_allowed(method) {
+			return Err(PolicyViolation::Denied);
+		}
+
+		// Callers without an IP address are trusted and not rate limited.
+		let Some(ip) = ip else { return Ok(()) };
+
+		if let Some(limit) = self.method_limits.get(method) {
+			limit.check_key(&ip).map_err(|_| PolicyViolation::MethodRateLimited)?;
+		}
+
+		if let Some(quota) = &self.ip_quota {
+			// A cost above the quota can never be paid and is treated like an exceeded quota.
+			match quota.check_key_n(&ip, self.cost(method)) {
+				Ok(Ok(())) => {},
+				Ok(Err(_)) | Err(_) => return Err(PolicyViolation::IpQuotaExceeded),
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Drop the rate limit state of IP addresses that are back to a full quota.
+	fn retain_recent(&self) {
+		self.ip_quota.iter().chain(self.method_limits.values()).for_each(|limit| {
+			limit.retain_recent();
+			limit.shrink_to_fit();
+		});
+	}
+}
+
+#[derive(Debug, Clone)]
+struct PolicyMetrics {
+	throttled_calls: CounterVec<U64>,
+}
+
+impl PolicyMetrics {
+	fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			throttled_calls: register(
+				CounterVec::new(
+					Opts::new(
+						"substrate_rpc_policy_throttled_calls",
+						"Number of RPC calls rejected by the RPC policy",
+					),
+					&["method", "reason"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+}
+
+/// RPC access policy with method allow/deny lists, per method rate limits and cost based
+/// quotas per IP address.
+///
+/// Cloning the policy is cheap and all clones share the same state.
+#[derive(Clone)]
+pub struct RpcPolicy {
+	state: Arc<RwLock<Arc<PolicyState>>>,
+	path: Option<PathBuf>,
+	metrics: Option<PolicyMetrics>,
+}
+
+impl fmt::Debug for RpcPolicy {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("RpcPolicy")
+			.field("config", &self.current().config)
+			.field("path", &self.path)
+			.finish()
+	}
+}
+
+impl RpcPolicy {
+	/// Create a new policy from `config`.
+	pub fn new(
+		config: RpcPolicyConfig,
+		registry: Option<&Registry>,
+	) -> Result<Self, PrometheusError> {
+		let metrics = registry.map(PolicyMetrics::register).transpose()?;
+
+		Ok(Self {
+			state: Arc::new(RwLock::new(Arc::new(PolicyState::new(config)))),
+			path: None,
+			metrics,
+		})
+	}
+
+	/// Create a new policy from the config file at `path`.
+	///
+	/// The file is reloaded by the RPC server when it is modified.
+	pub fn from_file(
+		path: impl Into<PathBuf>,
+		registry: Option<&Registry>,
+	) -> Result<Self, Box<dyn StdError + Send + Sync>> {
+		let path = path.into();
+		let config = read_config(&path)?;
+
+		Ok(Self { path: Some(path), ..Self::new(config, registry)? })
+	}
+
+	/// Returns the config file of the policy, if any.
+	pub fn path(&self) -> Option<&Path> {
+		self.path.as_deref()
+	}
+
+	/// Replace the config of the policy, resetting all rate limits.
+	pub fn set_config(&self, config: RpcPolicyConfig) {
+		*self.state.write().expect("Lock is never poisoned; qed") =
+			Arc::new(PolicyState::new(config));
+	}
+
+	/// Reload the config from the config file.
+	///
+	/// The current config is kept if the file can't be read or parsed.
+	pub fn reload(&self) -> Result<(), Box<dyn StdError + Send + Sync>> {
+		let Some(path) = &self.path else { return Ok(()) };
+
+		self.set_config(read_config(path)?);
+		Ok(())
+	}
+
+	/// Check whether `method` may be called by `ip`.
+	///
+	/// `ip` should be `None` for trusted callers, which are not subject to rate limits. Denied
+	/// methods can't be called by anyone.
+	pub fn check(&self, method: &str, ip: Option<IpAddr>) -> Result<(), PolicyViolation> {
+		let result = self.current().check(method, ip);
+
+		if let (Err(violation), Some(metrics)) = (&result, &self.metrics) {
+			metrics.throttled_calls.with_label_values(&[method, violation.label()]).inc();
+		}
+
+		result
+	}
+
+	fn current(&self) -> Arc<PolicyState> {
+		self.state.read().expect("Lock is never poisoned; qed").clone()
+	}
+
+	/// Reload the config file whenever it is modified, until `stop` resolves.
+	pub(crate) async fn watch(self, stop: impl std::future::Future<Output = ()>) {
+		let mut last_modified = self.path.as_deref().and_then(modified_at);
+		let mut interval = tokio::time::interval(RELOAD_INTERVAL);
+		futures::pin_mut!(stop);
+
+		loop {
+			tokio::select! {
+				_ = interval.tick() => {},
+				_ = &mut stop => break,
+			}
+
+			self.current().retain_recent();
+
+			let Some(path) = self.path.as_deref() else { continue };
+			let modified = modified_at(path);
+			if modified == last_modified {
+				continue;
+			}
+			last_modified = modified;
+
+			match self.reload() {
+				Ok(()) => log::info!(target: "rpc", "Reloaded RPC policy from {}", path.display()),
+				Err(e) => log::warn!(
+					target: "rpc",
+					"Failed to reload RPC policy from {}, keeping the current policy: {e}",
+					path.display(),
+				),
+			}
+		}
+	}
+}
+
+fn read_config(path: &Path) -> Result<RpcPolicyConfig, Box<dyn StdError + Send + Sync>> {
+	let file = std::fs::File::open(path)
+		.map_err(|e| format!("Failed to open RPC policy {}: {e}", path.display()))?;
+
+	serde_json::from_reader(std::io::BufReader::new(file))
+		.map_err(|e| format!("Invalid RPC policy {}: {e}", path.display()).into())
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+	std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn ip(last: u8) -> Option<IpAddr> {
+		Some(IpAddr::from([10, 0, 0, last]))
+	}
+
+	fn policy(json: serde_json::Value) -> RpcPolicy {
+		RpcPolicy::new(serde_json::from_value(json).unwrap(), None).unwrap()
+	}
+
+	#[test]
+	fn allow_and_deny_lists_work() {
+		let policy = policy(serde_json::json!({
+			"allow": ["chain_*", "state_*"],
+			"deny": ["state_traceBlock"],
+		}));
+
+		assert_eq!(policy.check("chain_getBlock", ip(1)), Ok(()));
+		assert_eq!(policy.check("state_getStorage", ip(1)), Ok(()));
+		assert_eq!(policy.check("state_traceBlock", ip(1)), Err(PolicyViolation::Denied));
+		assert_eq!(policy.check("author_submitExtrinsic", None), Err(PolicyViolation::Denied));
+	}
+
+	#[test]
+	fn method_rate_limit_is_per_ip() {
+		let policy = policy(serde_json::json!({
+			"methods": { "state_getKeysPaged": { "callsPerMinute": 2 } },
+		}));
+
+		assert_eq!(policy.check("state_getKeysPaged", ip(1)), Ok(()));
+		assert_eq!(policy.check("state_getKeysPaged", ip(1)), Ok(()));
+		assert_eq!(
+			policy.check("state_getKeysPaged", ip(1)),
+			Err(PolicyViolation::MethodRateLimited)
+		);
+		assert_eq!(policy.check("state_getKeysPaged", ip(2)), Ok(()));
+		// Trusted callers are not rate limited.
+		assert_eq!(policy.check("state_getKeysPaged", None), Ok(()));
+		assert_eq!(policy.check("chain_getBlock", ip(1)), Ok(()));
+	}
+
+	#[test]
+	fn ip_quota_accounts_for_costs() {
+		let policy = policy(serde_json::json!({
+			"ipQuotaPerMinute": 10,
+			"methods": { "state_getKeysPaged": { "cost": 8 }, "archive_unstable_storage": { "cost": 20 } },
+		}));
+
+		assert_eq!(policy.check("state_getKeysPaged", ip(1)), Ok(()));
+		assert_eq!(policy.check("chain_getBlock", ip(1)), Ok(()));
+		assert_eq!(policy.check("chain_getBlock", ip(1)), Ok(()));
+		assert_eq!(policy.check("chain_getBlock", ip(1)), Err(PolicyViolation::IpQuotaExceeded));
+		assert_eq!(
+			policy.check("archive_unstable_storage", ip(2)),
+			Err(PolicyViolation::IpQuotaExceeded)
+		);
+
+		// Setting a new config resets the quotas.
+		policy.set_config(RpcPolicyConfig::default());
+		assert_eq!(policy.check("chain_getBlock", ip(1)), Ok(()));
+	}
+
+	#[test]
+	fn unknown_fields_are_rejected() {
+		assert!(
+			serde_json::from_value::<RpcPolicyConfig>(serde_json::json!({ "alow": [] })).is_err()
+		);
+	}
+}
@@ -94,6 +94,23 @@ pub trait ArchiveApi<Hash> {
 		call_parameters: String,
 	) -> RpcResult<MethodResult>;
 
+	/// Call a pallet view function at a specified block's state.
+	///
+	/// The `id` is the hexadecimal-encoded identifier of the view function and `input` its
+	/// hexadecimal-encoded SCALE arguments. The result contains the hexadecimal-encoded SCALE
+	/// output of the view function.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "archive_unstable_callViewFunction")]
+	fn archive_unstable_call_view_function(
+		&self,
+		hash: Hash,
+		id: String,
+		input: String,
+	) -> RpcResult<MethodResult>;
+
 	/// Returns storage entries at a specific block's state.
 	///
 	/// # Unstable
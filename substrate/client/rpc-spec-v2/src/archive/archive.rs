@@ -41,7 +41,10 @@ use sc_client_api::{
 	Backend, BlockBackend, BlockchainEvents, CallExecutor, ChildInfo, ExecutorProvider, StorageKey,
 	StorageProvider,
 };
-use sc_rpc::utils::Subscription;
+use sc_rpc::{
+	state::view_function::{execute_view_function, ViewFunctionId},
+	utils::Subscription,
+};
 use sp_api::{CallApiAt, CallContext};
 use sp_blockchain::{
 	Backend as BlockChainBackend, Error as BlockChainError, HeaderBackend, HeaderMetadata,
@@ -102,6 +105,17 @@ fn parse_hex_param(param: String) -> Result<Vec<u8>, ArchiveError> {
 	array_bytes::hex2bytes(&param).map_err(|_| ArchiveError::InvalidParam(param))
 }
 
+/// Parse the hex-encoded identifier of a view function.
+fn parse_view_function_id(param: String) -> Result<ViewFunctionId, ArchiveError> {
+	match array_bytes::hex2bytes(&param)
+		.ok()
+		.and_then(|id| ViewFunctionId::try_from(id).ok())
+	{
+		Some(id) => Ok(id),
+		None => Err(ArchiveError::InvalidParam(param)),
+	}
+}
+
 #[async_trait]
 impl<BE, Block, Client> ArchiveApiServer<Block::Hash> for Archive<BE, Block, Client>
 where
@@ -214,6 +228,21 @@ where
 		})
 	}
 
+	fn archive_unstable_call_view_function(
+		&self,
+		hash: Block::Hash,
+		id: String,
+		input: String,
+	) -> RpcResult<MethodResult> {
+		let id = parse_view_function_id(id)?;
+		let input = parse_hex_param(input)?;
+
+		Ok(match execute_view_function::<Block, _>(&*self.client, hash, id, &input) {
+			Ok(output) => MethodResult::ok(hex_string(&output)),
+			Err(error) => MethodResult::err(error.to_string()),
+		})
+	}
+
 	fn archive_unstable_storage(
 		&self,
 		pending: PendingSubscriptionSink,
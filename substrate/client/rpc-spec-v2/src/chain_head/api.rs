@@ -108,6 +108,24 @@ pub trait ChainHeadApi<Hash> {
 		call_parameters: String,
 	) -> ResponsePayload<'static, MethodResponse>;
 
+	/// Call a pallet view function at a specified block's state.
+	///
+	/// The `id` is the hexadecimal-encoded identifier of the view function and `input` its
+	/// hexadecimal-encoded SCALE arguments. The SCALE output of the view function is reported by
+	/// the `operationCallDone` event.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "chainHead_unstable_callViewFunction", with_extensions)]
+	async fn chain_head_unstable_call_view_function(
+		&self,
+		follow_subscription: String,
+		hash: Hash,
+		id: String,
+		input: String,
+	) -> ResponsePayload<'static, MethodResponse>;
+
 	/// Unpin a block or multiple blocks reported by the `follow` method.
 	///
 	/// Ongoing operations that require the provided block
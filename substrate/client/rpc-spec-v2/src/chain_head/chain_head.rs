@@ -45,7 +45,10 @@ use sc_client_api::{
 	Backend, BlockBackend, BlockchainEvents, CallExecutor, ChildInfo, ExecutorProvider, StorageKey,
 	StorageProvider,
 };
-use sc_rpc::utils::Subscription;
+use sc_rpc::{
+	state::view_function::{execute_view_function, ViewFunctionId},
+	utils::Subscription,
+};
 use sp_api::CallApiAt;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use sp_core::{traits::CallContext, Bytes};
@@ -159,6 +162,92 @@ impl<BE: Backend<Block>, Block: BlockT, Client> ChainHead<BE, Block, Client> {
 	}
 }
 
+impl<BE, Block, Client> ChainHead<BE, Block, Client>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: 'static,
+{
+	/// Start an operation executing `call` at the block `hash` of the `follow_subscription`.
+	///
+	/// The output of `call` is reported by the `operationCallDone` event.
+	fn start_call_operation<F>(
+		&self,
+		ext: &Extensions,
+		follow_subscription: String,
+		hash: Block::Hash,
+		call: F,
+	) -> ResponsePayload<'static, MethodResponse>
+	where
+		F: FnOnce(&Arc<Client>) -> Result<Vec<u8>, String> + Send + 'static,
+		Client: Send + Sync,
+	{
+		let conn_id = ext
+			.get::<ConnectionId>()
+			.copied()
+			.expect("ConnectionId is always set by jsonrpsee; qed");
+
+		if !self.subscriptions.contains_subscription(conn_id, &follow_subscription) {
+			// The spec says to return `LimitReached` if the follow subscription is invalid or
+			// stale.
+			return ResponsePayload::success(MethodResponse::LimitReached);
+		}
+
+		let mut block_guard = match self.subscriptions.lock_block(&follow_subscription, hash, 1) {
+			Ok(block) => block,
+			Err(SubscriptionManagementError::SubscriptionAbsent) |
+			Err(SubscriptionManagementError::ExceededLimits) => {
+				// Invalid invalid subscription ID.
+				return ResponsePayload::success(MethodResponse::LimitReached)
+			},
+			Err(SubscriptionManagementError::BlockHashAbsent) => {
+				// Block is not part of the subscription.
+				return ResponsePayload::error(ChainHeadRpcError::InvalidBlock)
+			},
+			Err(_) => return ResponsePayload::error(ChainHeadRpcError::InvalidBlock),
+		};
+
+		// Reject subscription if with_runtime is false.
+		if !block_guard.has_runtime() {
+			return ResponsePayload::error(ChainHeadRpcError::InvalidRuntimeCall(
+				"The runtime updates flag must be set".to_string(),
+			));
+		}
+
+		let operation_id = block_guard.operation().operation_id();
+		let client = self.client.clone();
+
+		let (rp, rp_fut) = method_started_response(operation_id.clone(), None);
+		let fut = async move {
+			// Wait for the server to send out the response and if it produces an error no event
+			// should be generated.
+			if rp_fut.await.is_err() {
+				return
+			}
+
+			let event = call(&client)
+				.map(|result| {
+					FollowEvent::<Block::Hash>::OperationCallDone(OperationCallDone {
+						operation_id: operation_id.clone(),
+						output: hex_string(&result),
+					})
+				})
+				.unwrap_or_else(|error| {
+					FollowEvent::<Block::Hash>::OperationError(OperationError {
+						operation_id: operation_id.clone(),
+						error,
+					})
+				});
+
+			let _ = block_guard.response_sender().send(event).await;
+		};
+		self.executor
+			.spawn_blocking("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+
+		rp
+	}
+}
+
 /// Helper to convert the `subscription ID` to a string.
 pub fn read_subscription_id_as_string(sink: &Subscription) -> String {
 	match sink.subscription_id() {
@@ -182,6 +271,17 @@ fn parse_hex_param(param: String) -> Result<Vec<u8>, ChainHeadRpcError> {
 	}
 }
 
+/// Parse the hex-encoded identifier of a view function.
+fn parse_view_function_id(param: String) -> Result<ViewFunctionId, ChainHeadRpcError> {
+	match array_bytes::hex2bytes(&param)
+		.ok()
+		.and_then(|id| ViewFunctionId::try_from(id).ok())
+	{
+		Some(id) => Ok(id),
+		None => Err(ChainHeadRpcError::InvalidParam(param)),
+	}
+}
+
 #[async_trait]
 impl<BE, Block, Client> ChainHeadApiServer<Block::Hash> for ChainHead<BE, Block, Client>
 where
@@ -473,71 +573,35 @@ where
 			Err(err) => return ResponsePayload::error(err),
 		};
 
-		let conn_id = ext
-			.get::<ConnectionId>()
-			.copied()
-			.expect("ConnectionId is always set by jsonrpsee; qed");
-
-		if !self.subscriptions.contains_subscription(conn_id, &follow_subscription) {
-			// The spec says to return `LimitReached` if the follow subscription is invalid or
-			// stale.
-			return ResponsePayload::success(MethodResponse::LimitReached);
-		}
-
-		let mut block_guard = match self.subscriptions.lock_block(&follow_subscription, hash, 1) {
-			Ok(block) => block,
-			Err(SubscriptionManagementError::SubscriptionAbsent) |
-			Err(SubscriptionManagementError::ExceededLimits) => {
-				// Invalid invalid subscription ID.
-				return ResponsePayload::success(MethodResponse::LimitReached)
-			},
-			Err(SubscriptionManagementError::BlockHashAbsent) => {
-				// Block is not part of the subscription.
-				return ResponsePayload::error(ChainHeadRpcError::InvalidBlock)
-			},
-			Err(_) => return ResponsePayload::error(ChainHeadRpcError::InvalidBlock),
-		};
-
-		// Reject subscription if with_runtime is false.
-		if !block_guard.has_runtime() {
-			return ResponsePayload::error(ChainHeadRpcError::InvalidRuntimeCall(
-				"The runtime updates flag must be set".to_string(),
-			));
-		}
-
-		let operation_id = block_guard.operation().operation_id();
-		let client = self.client.clone();
-
-		let (rp, rp_fut) = method_started_response(operation_id.clone(), None);
-		let fut = async move {
-			// Wait for the server to send out the response and if it produces an error no event
-			// should be generated.
-			if rp_fut.await.is_err() {
-				return
-			}
-
-			let event = client
+		self.start_call_operation(ext, follow_subscription, hash, move |client| {
+			client
 				.executor()
 				.call(hash, &function, &call_parameters, CallContext::Offchain)
-				.map(|result| {
-					FollowEvent::<Block::Hash>::OperationCallDone(OperationCallDone {
-						operation_id: operation_id.clone(),
-						output: hex_string(&result),
-					})
-				})
-				.unwrap_or_else(|error| {
-					FollowEvent::<Block::Hash>::OperationError(OperationError {
-						operation_id: operation_id.clone(),
-						error: error.to_string(),
-					})
-				});
+				.map_err(|error| error.to_string())
+		})
+	}
 
-			let _ = block_guard.response_sender().send(event).await;
+	async fn chain_head_unstable_call_view_function(
+		&self,
+		ext: &Extensions,
+		follow_subscription: String,
+		hash: Block::Hash,
+		id: String,
+		input: String,
+	) -> ResponsePayload<'static, MethodResponse> {
+		let id = match parse_view_function_id(id) {
+			Ok(id) => id,
+			Err(err) => return ResponsePayload::error(err),
+		};
+		let input = match parse_hex_param(input) {
+			Ok(input) => input,
+			Err(err) => return ResponsePayload::error(err),
 		};
-		self.executor
-			.spawn_blocking("substrate-rpc-subscription", Some("rpc"), fut.boxed());
 
-		rp
+		self.start_call_operation(ext, follow_subscription, hash, move |client| {
+			execute_view_function::<Block, _>(&**client, hash, id, &input)
+				.map_err(|error| error.to_string())
+		})
 	}
 
 	async fn chain_head_unstable_unpin(
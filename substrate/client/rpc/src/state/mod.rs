@@ -20,6 +20,7 @@
 
 mod state_full;
 mod utils;
+pub mod view_function;
 
 #[cfg(test)]
 mod tests;
@@ -59,6 +60,14 @@ where
 		call_data: Bytes,
 	) -> Result<Bytes, Error>;
 
+	/// Call a pallet view function at given block.
+	fn call_view_function(
+		&self,
+		block: Option<Block::Hash>,
+		id: view_function::ViewFunctionId,
+		input: Bytes,
+	) -> Result<ViewFunctionResult, Error>;
+
 	/// Returns the keys with prefix, leave empty to get all the keys.
 	fn storage_keys(
 		&self,
@@ -209,6 +218,18 @@ where
 		self.backend.call(block, method, data).map_err(Into::into)
 	}
 
+	fn call_view_function(
+		&self,
+		id: Bytes,
+		input: Bytes,
+		block: Option<Block::Hash>,
+	) -> Result<ViewFunctionResult, Error> {
+		let id = view_function::ViewFunctionId::try_from(&id[..]).map_err(|_| {
+			Error::ViewFunction(format!("Expected a 32 bytes identifier, got {}", id.len()))
+		})?;
+		self.backend.call_view_function(block, id, input)
+	}
+
 	fn storage_keys(
 		&self,
 		key_prefix: StorageKey,
@@ -23,6 +23,7 @@ use std::{collections::HashMap, marker::PhantomData, sync::Arc, time::Duration};
 use super::{
 	client_err,
 	error::{Error, Result},
+	view_function::{self, ViewFunctionId},
 	ChildStateBackend, StateBackend,
 };
 use crate::{
@@ -36,7 +37,7 @@ use sc_client_api::{
 	Backend, BlockBackend, BlockchainEvents, CallExecutor, ExecutorProvider, ProofProvider,
 	StorageProvider,
 };
-use sc_rpc_api::state::ReadProof;
+use sc_rpc_api::state::{ReadProof, ViewFunctionResult};
 use sp_api::{CallApiAt, Metadata, ProvideRuntimeApi};
 use sp_blockchain::{
 	CachedHeaderMetadata, Error as ClientError, HeaderBackend, HeaderMetadata,
@@ -204,6 +205,21 @@ where
 			.map_err(client_err)
 	}
 
+	fn call_view_function(
+		&self,
+		block: Option<Block::Hash>,
+		id: ViewFunctionId,
+		input: Bytes,
+	) -> std::result::Result<ViewFunctionResult, Error> {
+		let block = self.block_or_best(block).map_err(client_err)?;
+		view_function::call_view_function::<Block, _>(&*self.client, block, id, &input).map_err(
+			|e| match e {
+				view_function::Error::Client(e) => client_err(e),
+				e => Error::ViewFunction(e.to_string()),
+			},
+		)
+	}
+
 	// TODO: This is horribly broken; either remove it, or make it streaming.
 	fn storage_keys(
 		&self,
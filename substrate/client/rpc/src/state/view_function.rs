@@ -0,0 +1,381 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Calling pallet view functions.
+//!
+//! View functions are dispatched through the `RuntimeViewFunction` runtime API. Their output is
+//! decoded into JSON using the type information of the metadata, when the runtime exposes the
+//! view functions in it.
+
+use codec::{Compact, Decode, Encode, Input};
+use frame_metadata::{v16::RuntimeMetadataV16, RuntimeMetadata, RuntimeMetadataPrefixed};
+use sc_client_api::{CallExecutor, ExecutorProvider};
+use sc_rpc_api::state::ViewFunctionResult;
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef, TypeDefPrimitive};
+use serde_json::{Map, Value};
+use sp_core::traits::CallContext;
+use sp_runtime::traits::Block as BlockT;
+
+const LOG_TARGET: &str = "rpc::view_function";
+
+/// The runtime API function executing a view function.
+const EXECUTE_VIEW_FUNCTION: &str = "RuntimeViewFunction_execute_view_function";
+
+/// The runtime API function returning the metadata at a given version.
+const METADATA_AT_VERSION: &str = "Metadata_metadata_at_version";
+
+/// The metadata versions that contain the view functions, in order of preference.
+///
+/// The view functions are part of the V16 metadata. Older runtimes only expose it as unstable
+/// metadata under `u32::MAX`.
+const VIEW_FUNCTIONS_METADATA_VERSIONS: [u32; 2] = [16, u32::MAX];
+
+/// The identifier of a view function.
+///
+/// The 16 bytes prefix identifying the pallet, followed by the 16 bytes suffix identifying the
+/// function.
+pub type ViewFunctionId = [u8; 32];
+
+/// Errors of a view function call.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Calling into the runtime failed.
+	#[error(transparent)]
+	Client(#[from] sp_blockchain::Error),
+	/// The runtime doesn't support view functions.
+	#[error("The runtime doesn't implement view functions")]
+	NotImplemented,
+	/// The runtime doesn't know the view function.
+	#[error("View function {} not found", sp_core::bytes::to_hex(&.0[..], false))]
+	NotFound(ViewFunctionId),
+	/// The runtime failed to decode the input of the view function.
+	#[error("The runtime failed to decode the view function input")]
+	InvalidInput,
+	/// Decoding the result of the runtime failed.
+	#[error("Failed to decode the view function result: {0}")]
+	Codec(#[from] codec::Error),
+}
+
+/// Mirror of `frame_support::view_functions::ViewFunctionDispatchError`.
+#[derive(Decode)]
+enum DispatchError {
+	NotImplemented,
+	NotFound(ViewFunctionId),
+	Codec,
+}
+
+/// Call the view function `id` with the SCALE encoded `input` at the state of `at`.
+///
+/// The output is decoded using the metadata of the runtime if it describes the view function.
+pub fn call_view_function<Block, Client>(
+	client: &Client,
+	at: Block::Hash,
+	id: ViewFunctionId,
+	input: &[u8],
+) -> Result<ViewFunctionResult, Error>
+where
+	Block: BlockT,
+	Client: ExecutorProvider<Block>,
+{
+	let output = execute_view_function(client, at, id, input)?;
+	let mut result =
+		ViewFunctionResult { pallet: None, name: None, output: output.into(), decoded: None };
+
+	let metadata = match view_functions_metadata(client, at) {
+		Ok(Some(metadata)) => metadata,
+		Ok(None) => {
+			log::debug!(target: LOG_TARGET, "Runtime at {at:?} doesn't expose view functions.");
+			return Ok(result)
+		},
+		Err(e) => {
+			log::debug!(target: LOG_TARGET, "Failed to fetch the metadata at {at:?}: {e}");
+			return Ok(result)
+		},
+	};
+
+	let function = metadata.pallets.iter().find_map(|pallet| {
+		pallet
+			.view_functions
+			.iter()
+			.find(|function| function.id == id)
+			.map(|function| (pallet, function))
+	});
+
+	if let Some((pallet, function)) = function {
+		let mut output = &result.output[..];
+		let decoded = decode_value(&metadata.types, function.output.id, &mut output)?;
+		if !output.is_empty() {
+			return Err(Error::Codec("Output was not fully decoded".into()))
+		}
+
+		result.pallet = Some(pallet.name.clone());
+		result.name = Some(function.name.clone());
+		result.decoded = Some(decoded);
+	}
+
+	Ok(result)
+}
+
+/// Call the view function `id` with the SCALE encoded `input` and return its SCALE encoded output.
+pub fn execute_view_function<Block, Client>(
+	client: &Client,
+	at: Block::Hash,
+	id: ViewFunctionId,
+	input: &[u8],
+) -> Result<Vec<u8>, Error>
+where
+	Block: BlockT,
+	Client: ExecutorProvider<Block>,
+{
+	let call_data = (id, input).encode();
+	let result =
+		client
+			.executor()
+			.call(at, EXECUTE_VIEW_FUNCTION, &call_data, CallContext::Offchain)?;
+
+	match Result::<Vec<u8>, DispatchError>::decode(&mut &result[..])? {
+		Ok(output) => Ok(output),
+		Err(DispatchError::NotImplemented) => Err(Error::NotImplemented),
+		Err(DispatchError::NotFound(id)) => Err(Error::NotFound(id)),
+		Err(DispatchError::Codec) => Err(Error::InvalidInput),
+	}
+}
+
+/// Returns the metadata describing the view functions, if the runtime supports it.
+fn view_functions_metadata<Block, Client>(
+	client: &Client,
+	at: Block::Hash,
+) -> Result<Option<RuntimeMetadataV16>, Error>
+where
+	Block: BlockT,
+	Client: ExecutorProvider<Block>,
+{
+	for version in VIEW_FUNCTIONS_METADATA_VERSIONS {
+		let result = client.executor().call(
+			at,
+			METADATA_AT_VERSION,
+			&version.encode(),
+			CallContext::Offchain,
+		)?;
+
+		let Some(metadata) = Option::<Vec<u8>>::decode(&mut &result[..])? else { continue };
+
+		if let RuntimeMetadata::V16(metadata) =
+			RuntimeMetadataPrefixed::decode(&mut &metadata[..])?.1
+		{
+			return Ok(Some(metadata))
+		}
+	}
+
+	Ok(None)
+}
+
+/// Decode a SCALE encoded value of the type `ty` into JSON.
+///
+/// Composites with named fields are decoded into objects, variants into their name or into an
+/// object with their name as single key, and byte sequences into hex strings. Integers that don't
+/// fit into 64 bits are decoded into strings.
+pub fn decode_value(
+	registry: &PortableRegistry,
+	ty: u32,
+	input: &mut &[u8],
+) -> Result<Value, codec::Error> {
+	let ty = registry.resolve(ty).ok_or("Type not found in the registry")?;
+
+	match &ty.type_def {
+		TypeDef::Composite(composite) => decode_fields(registry, &composite.fields, input),
+		TypeDef::Variant(variant) => {
+			let index = u8::decode(input)?;
+			let variant = variant
+				.variants
+				.iter()
+				.find(|variant| variant.index == index)
+				.ok_or("Unknown variant index")?;
+
+			if variant.fields.is_empty() {
+				return Ok(Value::String(variant.name.clone()))
+			}
+
+			let fields = decode_fields(registry, &variant.fields, input)?;
+			Ok(Value::Object(Map::from_iter([(variant.name.clone(), fields)])))
+		},
+		TypeDef::Sequence(sequence) => {
+			let len = Compact::<u32>::decode(input)?.0;
+			decode_items(registry, sequence.type_param.id, len, input)
+		},
+		TypeDef::Array(array) => decode_items(registry, array.type_param.id, array.len, input),
+		TypeDef::Tuple(tuple) => tuple
+			.fields
+			.iter()
+			.map(|field| decode_value(registry, field.id, input))
+			.collect::<Result<_, _>>()
+			.map(Value::Array),
+		TypeDef::Primitive(primitive) => decode_primitive(primitive, input),
+		TypeDef::Compact(_) => Ok(u128_value(Compact::<u128>::decode(input)?.0)),
+		TypeDef::BitSequence(_) => Err("Bit sequences are not supported".into()),
+	}
+}
+
+fn decode_fields(
+	registry: &PortableRegistry,
+	fields: &[Field<PortableForm>],
+	input: &mut &[u8],
+) -> Result<Value, codec::Error> {
+	match fields {
+		[] => Ok(Value::Null),
+		[field] if field.name.is_none() => decode_value(registry, field.ty.id, input),
+		fields if fields.iter().all(|field| field.name.is_some()) => fields
+			.iter()
+			.map(|field| {
+				let name = field.name.clone().unwrap_or_default();
+				Ok((name, decode_value(registry, field.ty.id, input)?))
+			})
+			.collect::<Result<Map<_, _>, codec::Error>>()
+			.map(Value::Object),
+		fields => fields
+			.iter()
+			.map(|field| decode_value(registry, field.ty.id, input))
+			.collect::<Result<_, _>>()
+			.map(Value::Array),
+	}
+}
+
+fn decode_items(
+	registry: &PortableRegistry,
+	ty: u32,
+	len: u32,
+	input: &mut &[u8],
+) -> Result<Value, codec::Error> {
+	let is_byte = registry
+		.resolve(ty)
+		.map_or(false, |ty| matches!(ty.type_def, TypeDef::Primitive(TypeDefPrimitive::U8)));
+
+	if is_byte {
+		let len = len as usize;
+		if input.len() < len {
+			return Err("Not enough data to decode the bytes".into())
+		}
+		let mut bytes = vec![0; len];
+		input.read(&mut bytes)?;
+		return Ok(Value::String(sp_core::bytes::to_hex(&bytes, false)))
+	}
+
+	(0..len)
+		.map(|_| decode_value(registry, ty, input))
+		.collect::<Result<_, _>>()
+		.map(Value::Array)
+}
+
+fn decode_primitive(
+	primitive: &TypeDefPrimitive,
+	input: &mut &[u8],
+) -> Result<Value, codec::Error> {
+	Ok(match primitive {
+		TypeDefPrimitive::Bool => bool::decode(input)?.into(),
+		TypeDefPrimitive::Char => char::from_u32(u32::decode(input)?)
+			.ok_or("Invalid character")?
+			.to_string()
+			.into(),
+		TypeDefPrimitive::Str => String::decode(input)?.into(),
+		TypeDefPrimitive::U8 => u8::decode(input)?.into(),
+		TypeDefPrimitive::U16 => u16::decode(input)?.into(),
+		TypeDefPrimitive::U32 => u32::decode(input)?.into(),
+		TypeDefPrimitive::U64 => u64::decode(input)?.into(),
+		TypeDefPrimitive::U128 => u128_value(u128::decode(input)?),
+		TypeDefPrimitive::I8 => i8::decode(input)?.into(),
+		TypeDefPrimitive::I16 => i16::decode(input)?.into(),
+		TypeDefPrimitive::I32 => i32::decode(input)?.into(),
+		TypeDefPrimitive::I64 => i64::decode(input)?.into(),
+		TypeDefPrimitive::I128 => {
+			let value = i128::decode(input)?;
+			i64::try_from(value).map_or_else(|_| value.to_string().into(), Into::into)
+		},
+		TypeDefPrimitive::U256 | TypeDefPrimitive::I256 =>
+			sp_core::bytes::to_hex(&<[u8; 32]>::decode(input)?, false).into(),
+	})
+}
+
+fn u128_value(value: u128) -> Value {
+	u64::try_from(value).map_or_else(|_| value.to_string().into(), Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use scale_info::{meta_type, Registry, TypeInfo};
+	use serde_json::json;
+
+	#[derive(Encode, TypeInfo)]
+	enum Status {
+		Active,
+		Frozen(u32),
+	}
+
+	#[derive(Encode, TypeInfo)]
+	struct Account {
+		id: [u8; 4],
+		balance: u128,
+		nonce: Compact<u64>,
+		flags: Vec<bool>,
+		status: Status,
+		pair: (String, i16),
+	}
+
+	fn decode<T: Encode + TypeInfo + 'static>(value: T) -> Value {
+		let mut registry = Registry::new();
+		let ty = registry.register_type(&meta_type::<T>()).id;
+		let registry = PortableRegistry::from(registry);
+
+		let encoded = value.encode();
+		let mut input = &encoded[..];
+		let decoded = decode_value(&registry, ty, &mut input).unwrap();
+		assert!(input.is_empty());
+		decoded
+	}
+
+	#[test]
+	fn decodes_composites_and_variants() {
+		let account = Account {
+			id: [1, 2, 3, 4],
+			balance: 1000,
+			nonce: Compact(5),
+			flags: vec![true, false],
+			status: Status::Frozen(7),
+			pair: ("alice".into(), -3),
+		};
+
+		assert_eq!(
+			decode(account),
+			json!({
+				"id": "0x01020304",
+				"balance": 1000,
+				"nonce": 5,
+				"flags": [true, false],
+				"status": { "Frozen": 7 },
+				"pair": ["alice", -3],
+			}),
+		);
+		assert_eq!(decode(Status::Active), json!("Active"));
+	}
+
+	#[test]
+	fn large_integers_are_decoded_into_strings() {
+		assert_eq!(decode(u128::MAX), json!(u128::MAX.to_string()));
+		assert_eq!(decode(Some(vec![0u8, 255])), json!({ "Some": "0x00ff" }));
+	}
+}
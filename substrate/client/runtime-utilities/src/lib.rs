@@ -35,7 +35,14 @@ use std::borrow::Cow;
 
 pub mod error;
 
+/// The latest metadata version that is fetched from a runtime.
+///
+/// The metadata is decoded by tools built on `subxt`, which doesn't support newer versions yet.
+pub const LATEST_SUPPORTED_METADATA_VERSION: u32 = 15;
+
 /// Fetches the latest metadata from the given runtime blob.
+///
+/// Versions after [`LATEST_SUPPORTED_METADATA_VERSION`] are not considered.
 pub fn fetch_latest_metadata_from_code_blob<HF: HostFunctions>(
 	executor: &WasmExecutor<HF>,
 	code_bytes: Cow<[u8]>,
@@ -48,7 +55,7 @@ pub fn fetch_latest_metadata_from_code_blob<HF: HostFunctions>(
 			let supported_versions = Vec::<u32>::decode(&mut supported_versions.as_slice())?;
 			let latest_stable = supported_versions
 				.into_iter()
-				.filter(|v| *v != u32::MAX)
+				.filter(|v| *v <= LATEST_SUPPORTED_METADATA_VERSION)
 				.max()
 				.ok_or(Error::StableMetadataVersionNotFound)?;
 
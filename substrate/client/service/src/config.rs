@@ -321,6 +321,8 @@ pub struct RpcConfiguration {
 	pub rate_limit_whitelisted_ips: Vec<IpNetwork>,
 	/// RPC rate limit trust proxy headers.
 	pub rate_limit_trust_proxy_headers: bool,
+	/// Path of the RPC policy file, see [`sc_rpc_server::RpcPolicy`].
+	pub policy_file: Option<PathBuf>,
 }
 
 /// Runtime executor configuration.
@@ -436,12 +436,19 @@ where
 	};
 
 	let metrics = sc_rpc_server::RpcMetrics::new(registry)?;
+	let policy = rpc_configuration
+		.policy_file
+		.as_ref()
+		.map(|path| sc_rpc_server::RpcPolicy::from_file(path, registry))
+		.transpose()
+		.map_err(Error::Application)?;
 	let rpc_api = gen_rpc_module()?;
 
 	let server_config = sc_rpc_server::Config {
 		endpoints,
 		rpc_api,
 		metrics,
+		policy,
 		id_provider: rpc_id_provider,
 		tokio_handle: tokio_handle.clone(),
 	};
@@ -255,6 +255,7 @@ fn node_config<E: ChainSpecExtension + Clone + 'static + Send + Sync>(
 			rate_limit: None,
 			rate_limit_whitelisted_ips: Default::default(),
 			rate_limit_trust_proxy_headers: Default::default(),
+			policy_file: None,
 		},
 		prometheus_config: None,
 		telemetry_endpoints: None,
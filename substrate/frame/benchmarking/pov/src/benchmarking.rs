@@ -214,6 +214,41 @@ mod benchmarks {
 		}
 	}
 
+	// Inserts into an ordered map, which reads and writes one node per level of the tree.
+	#[benchmark]
+	fn storage_ordered_map_insert() {
+		(0..1000).for_each(|i| OrderedMap1M::<T>::insert(i * 2, i));
+
+		#[block]
+		{
+			OrderedMap1M::<T>::insert(1001, 0);
+		}
+
+		assert_eq!(OrderedMap1M::<T>::len(), 1001);
+	}
+
+	// Reads `n` consecutive entries of an ordered map. Should result in a linear component of
+	// about one node per `NodeCapacity / 2` entries.
+	#[benchmark]
+	fn storage_ordered_map_range_read(n: Linear<0, 1000>) {
+		(0..2000).for_each(|i| OrderedMap1M::<T>::insert(i, i));
+
+		#[block]
+		{
+			assert_eq!(OrderedMap1M::<T>::range(500..).take(n as usize).count(), n as usize);
+		}
+	}
+
+	#[benchmark]
+	fn storage_ordered_map_pop_first() {
+		(0..1000).for_each(|i| OrderedMap1M::<T>::insert(i, i));
+
+		#[block]
+		{
+			assert_eq!(OrderedMap1M::<T>::pop_first(), Some((0, 0)));
+		}
+	}
+
 	#[benchmark]
 	fn storage_value_bounded_read() {
 		#[block]
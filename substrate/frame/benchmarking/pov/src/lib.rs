@@ -98,6 +98,11 @@ pub mod pallet {
 		MaxValues = ConstU32<1_000_000>,
 	>;
 
+	/// An ordered map with a maximum of 1M entries.
+	#[pallet::storage]
+	pub(crate) type OrderedMap1M<T: Config> =
+		OrderedStorageMap<Key = u32, Value = u32, MaxValues = ConstU32<1_000_000>>;
+
 	#[pallet::storage]
 	#[pallet::unbounded]
 	pub(crate) type UnboundedMap<T: Config> =
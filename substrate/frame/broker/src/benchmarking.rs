@@ -1252,6 +1252,120 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn enable_auto_pool() -> Result<(), BenchmarkError> {
+		setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(10_000_000u32.into()),
+		);
+
+		let region = Broker::<T>::do_purchase(caller.clone(), 10_000_000u32.into())
+			.expect("Offer not high enough for configuration.");
+
+		// Worst case: the core already carries all other Regions which may be pooled.
+		for i in 1..T::MaxAutoPoolsPerCore::get() {
+			let part = i % 80;
+			let other = Broker::<T>::issue(
+				region.core,
+				region.begin,
+				CoreMask::from_chunk(part, part + 1),
+				region.begin.saturating_add(1),
+				Some(caller.clone()),
+				None,
+			);
+			Broker::<T>::do_enable_auto_pool(other, caller.clone(), caller.clone(), 1)?;
+		}
+
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), region, recipient.clone(), 1);
+
+		assert!(AutoPools::<T>::get(region.core, region).is_some());
+		assert_last_event::<T>(
+			Event::AutoPoolEnabled { region_id: region, payee: recipient, idle_timeslices: 1 }
+				.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn disable_auto_pool() -> Result<(), BenchmarkError> {
+		setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(10_000_000u32.into()),
+		);
+
+		let region = Broker::<T>::do_purchase(caller.clone(), 10_000_000u32.into())
+			.expect("Offer not high enough for configuration.");
+
+		Broker::<T>::do_enable_auto_pool(region, caller.clone(), caller.clone(), 1)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), region);
+
+		assert!(AutoPools::<T>::get(region.core, region).is_none());
+		assert_last_event::<T>(Event::AutoPoolDisabled { region_id: region }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn notify_core_utilization(n: Linear<0, 100>) -> Result<(), BenchmarkError> {
+		setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let sale = SaleInfo::<T>::get().expect("Sale has already started.");
+		let caller: T::AccountId = whitelisted_caller();
+		let per_core = T::MaxAutoPoolsPerCore::get().max(1);
+
+		// Worst case: every reported core carries the maximum number of finally assigned and
+		// potentially renewable Regions, which all get pooled.
+		let mut idle_cores = Vec::new();
+		for i in 0..n {
+			let core = (i / per_core) as CoreIndex;
+			let part = i % per_core % 80;
+			let region = Broker::<T>::issue(
+				core,
+				sale.region_begin,
+				CoreMask::from_chunk(part, part + 1),
+				sale.region_end,
+				Some(caller.clone()),
+				Some(10u32.into()),
+			);
+			Broker::<T>::do_enable_auto_pool(region, caller.clone(), caller.clone(), 1)?;
+			Broker::<T>::do_assign(region, None, 1000, Final)
+				.map_err(|_| BenchmarkError::Weightless)?;
+			if idle_cores.last() != Some(&core) {
+				idle_cores.push(core);
+			}
+		}
+
+		let until = T::TimeslicePeriod::get() * sale.region_begin.saturating_add(1).into();
+		let admin_origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(admin_origin as T::RuntimeOrigin, until, idle_cores);
+
+		assert_eq!(AutoPools::<T>::iter().count(), 0);
+		assert_eq!(InstaPoolContribution::<T>::iter().count(), n as usize);
+
+		Ok(())
+	}
+
 	// Implements a test for each benchmark. Execute with:
 	// `cargo test -p pallet-broker --features runtime-benchmarks`.
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -16,11 +16,12 @@
 // limitations under the License.
 
 use super::*;
+use alloc::vec::Vec;
 use frame_support::{
 	pallet_prelude::*,
 	traits::{fungible::Mutate, tokens::Preservation::Expendable, DefensiveResult},
 };
-use sp_arithmetic::traits::{CheckedDiv, Saturating, Zero};
+use sp_arithmetic::traits::{CheckedDiv, One, SaturatedConversion, Saturating, Zero};
 use sp_runtime::traits::{BlockNumberProvider, Convert};
 use CompletionStatus::{Complete, Partial};
 
@@ -234,6 +235,8 @@ impl<T: Config> Pallet<T> {
 		let old_owner = region.owner;
 		region.owner = Some(new_owner);
 		Regions::<T>::insert(&region_id, &region);
+		// The new owner has not authorized automatic pooling.
+		AutoPools::<T>::remove(region_id.core, region_id);
 		let duration = region.end.saturating_sub(region_id.begin);
 		Self::deposit_event(Event::Transferred {
 			region_id,
@@ -264,6 +267,7 @@ impl<T: Config> Pallet<T> {
 
 		Regions::<T>::insert(&new_region_ids.0, &RegionRecord { end: pivot, ..region.clone() });
 		Regions::<T>::insert(&new_region_ids.1, &region);
+		AutoPools::<T>::remove(region_id.core, region_id);
 		Self::deposit_event(Event::Partitioned { old_region_id: region_id, new_region_ids });
 
 		Ok(new_region_ids)
@@ -286,6 +290,7 @@ impl<T: Config> Pallet<T> {
 
 		// The old region should be removed.
 		Regions::<T>::remove(&region_id);
+		AutoPools::<T>::remove(region_id.core, region_id);
 
 		let one = RegionId { mask: pivot, ..region_id };
 		Regions::<T>::insert(&one, &region);
@@ -365,6 +370,7 @@ impl<T: Config> Pallet<T> {
 		finality: Finality,
 	) -> Result<(), Error<T>> {
 		if let Some((region_id, region)) = Self::utilize(region_id, maybe_check_owner, finality)? {
+			AutoPools::<T>::remove(region_id.core, region_id);
 			let workplan_key = (region_id.begin, region_id.core);
 			let mut workplan = Workplan::<T>::get(&workplan_key).unwrap_or_default();
 			let duration = region.end.saturating_sub(region_id.begin);
@@ -457,6 +463,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(status.last_committed_timeslice >= region.end, Error::<T>::StillValid);
 
 		Regions::<T>::remove(&region_id);
+		AutoPools::<T>::remove(region_id.core, region_id);
 		let duration = region.end.saturating_sub(region_id.begin);
 		Self::deposit_event(Event::RegionDropped { region_id, duration });
 		Ok(())
@@ -508,6 +515,156 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_enable_auto_pool(
+		region_id: RegionId,
+		owner: T::AccountId,
+		payee: T::AccountId,
+		idle_timeslices: Timeslice,
+	) -> DispatchResult {
+		ensure!(idle_timeslices > 0, Error::<T>::NoIdleTimeslices);
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		ensure!(Some(&owner) == region.owner.as_ref(), Error::<T>::NotOwner);
+		ensure!(
+			AutoPools::<T>::contains_key(region_id.core, region_id) ||
+				AutoPools::<T>::iter_prefix(region_id.core).count() <
+					T::MaxAutoPoolsPerCore::get() as usize,
+			Error::<T>::TooManyAutoPools
+		);
+
+		let record = AutoPoolRecord {
+			end: region.end,
+			owner,
+			payee: payee.clone(),
+			idle_timeslices,
+			idle_streak: 0,
+			last_idle: 0,
+		};
+		AutoPools::<T>::insert(region_id.core, region_id, record);
+		Self::deposit_event(Event::AutoPoolEnabled { region_id, payee, idle_timeslices });
+		Ok(())
+	}
+
+	pub(crate) fn do_disable_auto_pool(region_id: RegionId, who: T::AccountId) -> DispatchResult {
+		let record =
+			AutoPools::<T>::get(region_id.core, region_id).ok_or(Error::<T>::AutoPoolNotEnabled)?;
+		ensure!(record.owner == who, Error::<T>::NotOwner);
+
+		AutoPools::<T>::remove(region_id.core, region_id);
+		Self::deposit_event(Event::AutoPoolDisabled { region_id });
+		Ok(())
+	}
+
+	/// Returns the number of Regions which were considered for automatic pooling.
+	pub(crate) fn do_notify_core_utilization(
+		until: RelayBlockNumberOf<T>,
+		idle_cores: Vec<CoreIndex>,
+	) -> Result<u32, DispatchError> {
+		let status = Status::<T>::get().ok_or(Error::<T>::Uninitialized)?;
+		let when: Timeslice =
+			(until / T::TimeslicePeriod::get()).saturating_sub(One::one()).saturated_into();
+		let first_uncommitted = status.last_committed_timeslice.saturating_add(1);
+		let mut processed = 0u32;
+
+		for core in idle_cores {
+			// Bounded by `MaxAutoPoolsPerCore`.
+			let records = AutoPools::<T>::iter_prefix(core).collect::<Vec<_>>();
+			processed.saturating_accrue(records.len() as u32);
+			for (region_id, mut record) in records {
+				if record.end <= first_uncommitted {
+					// Nothing is left which could still be pooled.
+					AutoPools::<T>::remove(core, region_id);
+					continue
+				}
+				// The Region did not own the core in the reported timeslice, or the timeslice has
+				// already been accounted for.
+				if when < region_id.begin ||
+					when >= record.end ||
+					(record.idle_streak > 0 && record.last_idle >= when)
+				{
+					continue
+				}
+
+				record.idle_streak =
+					if record.idle_streak > 0 && record.last_idle.saturating_add(1) == when {
+						record.idle_streak.saturating_add(1)
+					} else {
+						1
+					};
+				record.last_idle = when;
+				if record.idle_streak < record.idle_timeslices {
+					AutoPools::<T>::insert(core, region_id, record);
+					continue
+				}
+
+				AutoPools::<T>::remove(core, region_id);
+				if let Err(error) = Self::auto_pool(region_id, record, first_uncommitted) {
+					log::warn!(
+						target: LOG_TARGET,
+						"Failed to pool Region {:?} automatically: {:?}",
+						region_id,
+						error,
+					);
+				}
+			}
+		}
+		Ok(processed)
+	}
+
+	/// Place whatever has not yet been committed of the Region `region_id` into the
+	/// Instantaneous Coretime Pool.
+	pub(crate) fn auto_pool(
+		region_id: RegionId,
+		record: AutoPoolRecordOf<T>,
+		first_uncommitted: Timeslice,
+	) -> DispatchResult {
+		if Regions::<T>::contains_key(&region_id) {
+			// The Region was not assigned with `Finality::Final`, so it can be pooled as a whole.
+			return Ok(Self::do_pool(region_id, None, record.payee, Finality::Final)?)
+		}
+
+		// The Region no longer serves its task, so its part of the workload must not be renewed.
+		Self::forget_potential_renewal(region_id, record.end);
+
+		let region_id = RegionId { begin: region_id.begin.max(first_uncommitted), ..region_id };
+		let workplan_key = (region_id.begin, region_id.core);
+		let mut workplan = Workplan::<T>::get(&workplan_key).unwrap_or_default();
+		// Replace any assignment of the Region which has not yet been committed.
+		workplan.retain(|i| (i.mask & region_id.mask).is_void());
+		let duration = record.end.saturating_sub(region_id.begin);
+		if workplan
+			.try_push(ScheduleItem { mask: region_id.mask, assignment: CoreAssignment::Pool })
+			.is_ok()
+		{
+			Workplan::<T>::insert(&workplan_key, &workplan);
+			let size = region_id.mask.count_ones() as i32;
+			InstaPoolIo::<T>::mutate(region_id.begin, |a| a.private.saturating_accrue(size));
+			InstaPoolIo::<T>::mutate(record.end, |a| a.private.saturating_reduce(size));
+			let contribution = ContributionRecord { length: duration, payee: record.payee };
+			InstaPoolContribution::<T>::insert(&region_id, contribution);
+		}
+
+		Self::deposit_event(Event::Pooled { region_id, duration });
+		Ok(())
+	}
+
+	/// Remove the part of the Region `region_id` ending at `end` from the workload which may be
+	/// renewed, dropping the potential renewal altogether if nothing else is left.
+	fn forget_potential_renewal(region_id: RegionId, end: Timeslice) {
+		let renewal_id = PotentialRenewalId { core: region_id.core, when: end };
+		let Some(mut record) = PotentialRenewals::<T>::get(renewal_id) else { return };
+		let assigned = match record.completion {
+			Partial(mask) => mask,
+			Complete(ref workload) =>
+				workload.iter().fold(CoreMask::void(), |mask, item| mask | item.mask),
+		} & !region_id.mask;
+		if assigned.is_void() {
+			PotentialRenewals::<T>::remove(renewal_id);
+		} else {
+			record.completion = Partial(assigned);
+			PotentialRenewals::<T>::insert(renewal_id, record);
+		}
+	}
+
 	pub(crate) fn do_swap_leases(id: TaskId, other: TaskId) -> DispatchResult {
 		let mut id_leases_count = 0;
 		let mut other_leases_count = 0;
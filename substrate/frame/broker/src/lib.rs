@@ -125,6 +125,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAutoRenewals: Get<u32>;
 
+		/// Maximum number of Regions per core which may be pooled automatically.
+		///
+		/// All of them are processed whenever the Relay-chain reports the core as idle.
+		#[pallet::constant]
+		type MaxAutoPoolsPerCore: Get<u32>;
+
 		/// The smallest amount of credits a user can purchase.
 		///
 		/// Needed to prevent spam attacks.
@@ -201,6 +207,19 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type RevenueInbox<T> = StorageValue<_, OnDemandRevenueRecordOf<T>, OptionQuery>;
 
+	/// Regions whose owners have authorized them to be pooled automatically once their core has
+	/// been reported idle by the Relay-chain. Keyed by core to look them up upon such reports.
+	#[pallet::storage]
+	pub type AutoPools<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CoreIndex,
+		Blake2_128Concat,
+		RegionId,
+		AutoPoolRecordOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -485,6 +504,20 @@ pub mod pallet {
 		/// This should never happen, given that enable_auto_renew checks for this before enabling
 		/// auto-renewal.
 		AutoRenewalLimitReached,
+		/// A Region has been authorized to be pooled automatically once its core is idle.
+		AutoPoolEnabled {
+			/// The Region which may be pooled automatically.
+			region_id: RegionId,
+			/// The account which will be able to collect the revenue of the pooled Region.
+			payee: T::AccountId,
+			/// The number of consecutive idle timeslices after which the Region is pooled.
+			idle_timeslices: Timeslice,
+		},
+		/// The authorization to pool a Region automatically has been revoked.
+		AutoPoolDisabled {
+			/// The Region which will no longer be pooled automatically.
+			region_id: RegionId,
+		},
 	}
 
 	#[pallet::error]
@@ -568,6 +601,12 @@ pub mod pallet {
 		/// Needed to prevent spam attacks.The amount of credits the user attempted to purchase is
 		/// below `T::MinimumCreditPurchase`.
 		CreditPurchaseTooSmall,
+		/// The number of idle timeslices after which a Region is pooled must be 1 or more.
+		NoIdleTimeslices,
+		/// Attempted to disable automatic pooling for a Region that didn't have it enabled.
+		AutoPoolNotEnabled,
+		/// The maximum number of Regions of a core which may be pooled automatically was reached.
+		TooManyAutoPools,
 	}
 
 	#[derive(frame_support::DefaultNoBound)]
@@ -1013,6 +1052,64 @@ pub mod pallet {
 			Self::do_remove_assignment(region_id)
 		}
 
+		/// Authorize a Region to be placed into the Instantaneous Coretime Pool once the
+		/// Relay-chain reports its core as idle for `idle_timeslices` consecutive timeslices.
+		///
+		/// The authorization survives assigning the Region, so it should be given before the
+		/// Region is assigned with `Finality::Final`. It is revoked by transferring, partitioning
+		/// or interlacing the Region. Any revenue is collected through `claim_revenue`.
+		///
+		/// - `origin`: Must be a Signed origin of the account which owns the Region `region_id`.
+		/// - `region_id`: The Region which may be pooled.
+		/// - `payee`: The account which is able to collect any revenue due for the usage of this
+		///   Coretime.
+		/// - `idle_timeslices`: The number of consecutive idle timeslices after which the Region is
+		///   pooled. Must be greater than 0.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::enable_auto_pool())]
+		pub fn enable_auto_pool(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			payee: T::AccountId,
+			idle_timeslices: Timeslice,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_enable_auto_pool(region_id, who, payee, idle_timeslices)
+		}
+
+		/// Revoke the authorization to pool a Region automatically.
+		///
+		/// - `origin`: Must be a Signed origin of the account which enabled automatic pooling.
+		/// - `region_id`: The Region which should no longer be pooled automatically.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::disable_auto_pool())]
+		pub fn disable_auto_pool(origin: OriginFor<T>, region_id: RegionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_disable_auto_pool(region_id, who)
+		}
+
+		/// Notify the cores on which no candidate was included in the timeslice ending at `until`.
+		///
+		/// - `origin`: Must be Root or pass `AdminOrigin`.
+		/// - `until`: The Relay-chain block at which the reported timeslice ended.
+		/// - `idle_cores`: The cores which were idle throughout that timeslice.
+		///
+		/// The weight is charged for every Region of the idle cores which may be pooled
+		/// automatically.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::notify_core_utilization(
+			(idle_cores.len() as u32).saturating_mul(T::MaxAutoPoolsPerCore::get())
+		))]
+		pub fn notify_core_utilization(
+			origin: OriginFor<T>,
+			until: RelayBlockNumberOf<T>,
+			idle_cores: Vec<CoreIndex>,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+			let records = Self::do_notify_core_utilization(until, idle_cores)?;
+			Ok(Some(T::WeightInfo::notify_core_utilization(records)).into())
+		}
+
 		#[pallet::call_index(99)]
 		#[pallet::weight(T::WeightInfo::swap_leases())]
 		pub fn swap_leases(origin: OriginFor<T>, id: TaskId, other: TaskId) -> DispatchResult {
@@ -2192,3 +2192,154 @@ fn force_reserve_works() {
 		assert_eq!(Workplan::<Test>::get((10, 0)), Some(system_workload.clone()));
 	});
 }
+
+#[test]
+fn auto_pool_pools_idle_assigned_region() {
+	TestExt::new().region_length(5).endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_enable_auto_pool(region, 1, 2, 2));
+		System::assert_last_event(
+			Event::AutoPoolEnabled { region_id: region, payee: 2, idle_timeslices: 2 }.into(),
+		);
+		assert_ok!(Broker::do_assign(region, Some(1), 1001, Final));
+		let renewal =
+			PotentialRenewalId { core: 0, when: AutoPools::<Test>::get(0, region).unwrap().end };
+		assert!(PotentialRenewals::<Test>::get(renewal).is_some());
+
+		// Timeslices 6 and 7 have passed, timeslice 9 is the last committed one.
+		advance_to(16);
+		assert_ok!(Broker::do_notify_core_utilization(14, vec![0]));
+		assert_eq!(AutoPools::<Test>::get(0, region).unwrap().idle_streak, 1);
+		assert!(InstaPoolContribution::<Test>::iter().next().is_none());
+
+		// A second consecutive idle timeslice pools whatever is left of the region.
+		assert_ok!(Broker::do_notify_core_utilization(16, vec![0]));
+		let pooled = RegionId { begin: 10, ..region };
+		System::assert_last_event(Event::Pooled { region_id: pooled, duration: 1 }.into());
+		assert!(AutoPools::<Test>::get(0, region).is_none());
+		assert_eq!(
+			InstaPoolContribution::<Test>::get(pooled),
+			Some(ContributionRecord { length: 1, payee: 2 })
+		);
+		// The pooled workload is no longer renewable.
+		assert!(PotentialRenewals::<Test>::get(renewal).is_none());
+
+		advance_to(18);
+		assert!(CoretimeTrace::get().contains(&(
+			18,
+			AssignCore { core: 0, begin: 20, assignment: vec![(Pool, 57600)], end_hint: None }
+		)));
+	});
+}
+
+#[test]
+fn auto_pool_requires_consecutive_idle_timeslices() {
+	TestExt::new().region_length(5).endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_enable_auto_pool(region, 1, 2, 2));
+		assert_ok!(Broker::do_assign(region, Some(1), 1001, Final));
+		advance_to(12);
+
+		// Timeslices before the region began do not count.
+		assert_ok!(Broker::do_notify_core_utilization(12, vec![0]));
+		assert_eq!(AutoPools::<Test>::get(0, region).unwrap().idle_streak, 0);
+
+		// Neither does reporting the same timeslice twice.
+		assert_ok!(Broker::do_notify_core_utilization(14, vec![0]));
+		assert_ok!(Broker::do_notify_core_utilization(14, vec![0]));
+		assert_eq!(AutoPools::<Test>::get(0, region).unwrap().idle_streak, 1);
+
+		// The core was busy in timeslice 7, so the streak starts over.
+		assert_ok!(Broker::do_notify_core_utilization(18, vec![0]));
+		let record = AutoPools::<Test>::get(0, region).unwrap();
+		assert_eq!((record.idle_streak, record.last_idle), (1, 8));
+		assert!(InstaPoolContribution::<Test>::iter().next().is_none());
+
+		// Other cores being idle is of no concern.
+		assert_ok!(Broker::do_notify_core_utilization(20, vec![1]));
+		assert_eq!(AutoPools::<Test>::get(0, region).unwrap().idle_streak, 1);
+	});
+}
+
+#[test]
+fn auto_pool_pools_unassigned_region() {
+	TestExt::new().region_length(5).endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_enable_auto_pool(region, 1, 2, 1));
+
+		advance_to(14);
+		assert_ok!(Broker::do_notify_core_utilization(14, vec![0]));
+		let pooled = RegionId { begin: 9, ..region };
+		System::assert_last_event(Event::Pooled { region_id: pooled, duration: 2 }.into());
+		assert!(Regions::<Test>::get(region).is_none());
+		assert_eq!(
+			InstaPoolContribution::<Test>::get(pooled),
+			Some(ContributionRecord { length: 2, payee: 2 })
+		);
+	});
+}
+
+#[test]
+fn auto_pool_authorization_works() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+
+		assert_noop!(Broker::do_enable_auto_pool(region, 2, 2, 1), Error::<Test>::NotOwner);
+		assert_noop!(Broker::do_enable_auto_pool(region, 1, 2, 0), Error::<Test>::NoIdleTimeslices);
+		assert_noop!(Broker::do_disable_auto_pool(region, 1), Error::<Test>::AutoPoolNotEnabled);
+
+		assert_ok!(Broker::do_enable_auto_pool(region, 1, 2, 1));
+		assert_noop!(Broker::do_disable_auto_pool(region, 2), Error::<Test>::NotOwner);
+		assert_ok!(Broker::do_disable_auto_pool(region, 1));
+		System::assert_last_event(Event::AutoPoolDisabled { region_id: region }.into());
+		assert!(AutoPools::<Test>::get(0, region).is_none());
+
+		// Handing the region over to someone else revokes the authorization.
+		assert_ok!(Broker::do_enable_auto_pool(region, 1, 2, 1));
+		assert_ok!(Broker::do_transfer(region, Some(1), 3));
+		assert!(AutoPools::<Test>::get(0, region).is_none());
+
+		// As does splitting it up.
+		assert_ok!(Broker::do_enable_auto_pool(region, 3, 3, 1));
+		assert_ok!(Broker::do_partition(region, Some(3), 1));
+		assert!(AutoPools::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn auto_pool_is_limited_per_core() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let (first, second) =
+			Broker::do_interlace(region, Some(1), CoreMask::from_chunk(0, 20)).unwrap();
+		let (second, third) =
+			Broker::do_interlace(second, Some(1), CoreMask::from_chunk(20, 40)).unwrap();
+		let (third, fourth) =
+			Broker::do_interlace(third, Some(1), CoreMask::from_chunk(40, 60)).unwrap();
+
+		for region in [first, second, third] {
+			assert_ok!(Broker::do_enable_auto_pool(region, 1, 2, 1));
+		}
+		// Updating an existing authorization is fine, adding another one is not.
+		assert_ok!(Broker::do_enable_auto_pool(third, 1, 2, 2));
+		assert_noop!(Broker::do_enable_auto_pool(fourth, 1, 2, 1), Error::<Test>::TooManyAutoPools);
+
+		assert_ok!(Broker::do_disable_auto_pool(first, 1));
+		assert_ok!(Broker::do_enable_auto_pool(fourth, 1, 2, 1));
+
+		// The number of records on the idle cores is reported, so that only they are charged.
+		advance_to(6);
+		assert_eq!(Broker::do_notify_core_utilization(6, vec![0]), Ok(3));
+		assert_eq!(Broker::do_notify_core_utilization(6, vec![1]), Ok(0));
+	});
+}
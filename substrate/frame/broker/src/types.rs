@@ -376,3 +376,23 @@ pub struct AutoRenewalRecord {
 	/// tasks to ensure that the renewal process does not begin until the lease expires.
 	pub next_renewal: Timeslice,
 }
+
+/// A record of a Region whose owner has authorized it to be placed into the Instantaneous Coretime
+/// Pool once its core has been idle for long enough.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AutoPoolRecord<AccountId> {
+	/// The end of the Region. Kept here since the Region itself is gone once it is assigned with
+	/// `Finality::Final`.
+	pub end: Timeslice,
+	/// The account which authorized the automatic pooling and may revoke it.
+	pub owner: AccountId,
+	/// The account which is able to collect any revenue due once the Region is pooled.
+	pub payee: AccountId,
+	/// The number of consecutive idle timeslices after which the Region is pooled.
+	pub idle_timeslices: Timeslice,
+	/// The number of consecutive idle timeslices reported so far.
+	pub idle_streak: Timeslice,
+	/// The last timeslice in which the core was reported as idle.
+	pub last_idle: Timeslice,
+}
+pub type AutoPoolRecordOf<T> = AutoPoolRecord<<T as SConfig>::AccountId>;
@@ -121,11 +121,17 @@ impl<T: Config> Pallet<T> {
 		let last_committed_timeslice = status.last_committed_timeslice;
 		if region_id.begin <= last_committed_timeslice {
 			let duration = region.end.saturating_sub(region_id.begin);
+			let old_region_id = region_id;
 			region_id.begin = last_committed_timeslice + 1;
 			if region_id.begin >= region.end {
+				AutoPools::<T>::remove(region_id.core, old_region_id);
 				Self::deposit_event(Event::RegionDropped { region_id, duration });
 				return Ok(None)
 			}
+			// Keep any authorization for automatic pooling under the Region's new identity.
+			if let Some(record) = AutoPools::<T>::take(region_id.core, old_region_id) {
+				AutoPools::<T>::insert(region_id.core, region_id, record);
+			}
 		} else {
 			Workplan::<T>::mutate_extant((region_id.begin, region_id.core), |p| {
 				p.retain(|i| (i.mask & region_id.mask).is_void())
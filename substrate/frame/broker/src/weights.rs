@@ -107,6 +107,9 @@ pub trait WeightInfo {
 	fn disable_auto_renew() -> Weight;
 	fn on_new_timeslice() -> Weight;
 	fn remove_assignment() -> Weight;
+	fn enable_auto_pool() -> Weight;
+	fn disable_auto_pool() -> Weight;
+	fn notify_core_utilization(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_broker` using the Substrate node and recommended hardware.
@@ -606,6 +609,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// The auto-pool weights below are placeholders until their benchmarks are run. Each of them
+	// is composed of benchmarked calls accessing a superset of the storage that it accesses.
+	fn enable_auto_pool() -> Weight {
+		// Reads the Region like `transfer` and the records of its core like `disable_auto_pool`,
+		// assuming a `MaxAutoPoolsPerCore` of at most 10.
+		Self::transfer().saturating_add(Self::disable_auto_pool().saturating_mul(10))
+	}
+	fn disable_auto_pool() -> Weight {
+		// Removes a record, which is smaller than the `Workplan` entry of `remove_assignment`.
+		Self::remove_assignment()
+	}
+	fn notify_core_utilization(n: u32, ) -> Weight {
+		// Reads the status, then removes each record and pools its Region like `pool`, dropping
+		// the potential renewal of the Region like `drop_renewal`.
+		T::DbWeight::get().reads(1).saturating_add(
+			Self::disable_auto_pool()
+				.saturating_add(Self::pool())
+				.saturating_add(Self::drop_renewal())
+				.saturating_mul(n.into()),
+		)
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1104,4 +1128,25 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// The auto-pool weights below are placeholders until their benchmarks are run. Each of them
+	// is composed of benchmarked calls accessing a superset of the storage that it accesses.
+	fn enable_auto_pool() -> Weight {
+		// Reads the Region like `transfer` and the records of its core like `disable_auto_pool`,
+		// assuming a `MaxAutoPoolsPerCore` of at most 10.
+		Self::transfer().saturating_add(Self::disable_auto_pool().saturating_mul(10))
+	}
+	fn disable_auto_pool() -> Weight {
+		// Removes a record, which is smaller than the `Workplan` entry of `remove_assignment`.
+		Self::remove_assignment()
+	}
+	fn notify_core_utilization(n: u32, ) -> Weight {
+		// Reads the status, then removes each record and pools its Region like `pool`, dropping
+		// the potential renewal of the Region like `drop_renewal`.
+		RocksDbWeight::get().reads(1).saturating_add(
+			Self::disable_auto_pool()
+				.saturating_add(Self::pool())
+				.saturating_add(Self::drop_renewal())
+				.saturating_mul(n.into()),
+		)
+	}
 }
@@ -161,3 +161,26 @@ fn task_with_offchain_worker() {
 		assert!(tx.is_bare());
 	});
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn valid_tasks_respect_the_weight_budget() {
+	use crate::WeightInfo;
+	use frame_support::weights::Weight;
+
+	new_test_ext().execute_with(|| {
+		Numbers::<Runtime>::insert(0, 1);
+		Numbers::<Runtime>::insert(1, 4);
+		Numbers::<Runtime>::insert(2, 9);
+
+		let task_weight = <() as WeightInfo>::add_number_into_total();
+		assert_eq!(System::valid_tasks(Weight::MAX).len(), 3);
+		assert_eq!(System::valid_tasks(task_weight.saturating_mul(2)).len(), 2);
+		assert!(System::valid_tasks(Weight::zero()).is_empty());
+
+		let extrinsics = System::valid_task_extrinsics(task_weight);
+		assert_eq!(extrinsics.len(), 1);
+		use sp_runtime::traits::ExtrinsicLike;
+		assert!(extrinsics[0].is_bare());
+	});
+}
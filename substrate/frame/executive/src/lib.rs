@@ -181,10 +181,12 @@ use sp_runtime::{
 #[cfg(feature = "try-runtime")]
 use ::{
 	frame_support::{
-		traits::{TryDecodeEntireStorage, TryDecodeEntireStorageError, TryState},
+		traits::{TryDecodeEntireStorage, TryState},
 		StorageNoopGuard,
 	},
-	frame_try_runtime::{TryStateSelect, UpgradeCheckSelect},
+	frame_try_runtime::{
+		DecodeEntireStateReport, DecodeSelect, TryStateSelect, UpgradeCheckSelect, UpgradeReport,
+	},
 	log,
 	sp_runtime::TryRuntimeError,
 };
@@ -386,8 +388,8 @@ where
 			log::error!(target: LOG_TARGET, "failure: {:?}", e);
 		})?;
 		if select.any() {
-			let res = AllPalletsWithSystem::try_decode_entire_state();
-			Self::log_decode_result(res)?;
+			let report = AllPalletsWithSystem::decode_entire_state_report(&DecodeSelect::All);
+			Self::log_decode_report(&report)?;
 		}
 		drop(_guard);
 
@@ -432,13 +434,57 @@ where
 	/// [`frame_system::LastRuntimeUpgrade`] is set to the current runtime version after
 	/// migrations execute. This is important for idempotency checks, because some migrations use
 	/// this value to determine whether or not they should execute.
+	///
+	/// Fails if the migrations consume more weight than [`frame_system::Config::BlockWeights`]
+	/// allows for a block.
 	pub fn try_runtime_upgrade(checks: UpgradeCheckSelect) -> Result<Weight, TryRuntimeError> {
+		Self::do_try_runtime_upgrade(checks, || None).map(|report| report.weight)
+	}
+
+	/// Execute all Migrations of this runtime and report the resources that they consumed.
+	///
+	/// Same as [`Self::try_runtime_upgrade`], but additionally reports the size of the storage
+	/// proof that was recorded while executing the migrations. Fails if the proof exceeds the
+	/// proof size of the maximal block weight, which is the PoV limit of the runtime.
+	///
+	/// `proof_size` returns the size of the storage proof recorded so far, or `None` if the
+	/// executor does not record one. Parachain runtimes can pass
+	/// `cumulus_pallet_parachain_system::storage_proof_size`, others `|| None`. The reported
+	/// size includes everything that the `pre/post_upgrade` hooks read, if they are executed.
+	pub fn try_runtime_upgrade_with_report(
+		checks: UpgradeCheckSelect,
+		proof_size: impl Fn() -> Option<u64>,
+	) -> Result<UpgradeReport, TryRuntimeError> {
+		Self::do_try_runtime_upgrade(checks, proof_size)
+	}
+
+	/// Decode the storage items selected by `select`, reporting the outcome per storage item.
+	///
+	/// Unlike the decoding checks of [`Self::try_runtime_upgrade`], this does not fail if values
+	/// cannot be decoded. They are reported along with their keys and raw values instead.
+	pub fn try_decode_entire_state(select: DecodeSelect) -> DecodeEntireStateReport {
+		log::info!(target: LOG_TARGET, "Decoding the storage items selected by {select:?}");
+
+		let report = AllPalletsWithSystem::decode_entire_state_report(&select);
+		let _ = Self::log_decode_report(&report);
+		report
+	}
+
+	/// Execute all Migrations of this runtime, measuring the proof size with `proof_size`.
+	fn do_try_runtime_upgrade(
+		checks: UpgradeCheckSelect,
+		proof_size: impl Fn() -> Option<u64>,
+	) -> Result<UpgradeReport, TryRuntimeError> {
+		let proof_size_before = proof_size();
 		let before_all_weight =
 			<AllPalletsWithSystem as BeforeAllRuntimeMigrations>::before_all_runtime_migrations();
 		let try_on_runtime_upgrade_weight =
 			<(COnRuntimeUpgrade, AllPalletsWithSystem) as OnRuntimeUpgrade>::try_on_runtime_upgrade(
 				checks.pre_and_post(),
 			)?;
+		let proof_size = proof_size()
+			.zip(proof_size_before)
+			.map(|(after, before)| after.saturating_sub(before));
 
 		frame_system::LastRuntimeUpgrade::<System>::put(
 			frame_system::LastRuntimeUpgradeInfo::from(
@@ -451,8 +497,8 @@ where
 
 		// The state must be decodable:
 		if checks.any() {
-			let res = AllPalletsWithSystem::try_decode_entire_state();
-			Self::log_decode_result(res)?;
+			let report = AllPalletsWithSystem::decode_entire_state_report(&DecodeSelect::All);
+			Self::log_decode_report(&report)?;
 		}
 
 		// Check all storage invariants:
@@ -463,39 +509,84 @@ where
 			)?;
 		}
 
-		Ok(before_all_weight.saturating_add(try_on_runtime_upgrade_weight))
+		let report = UpgradeReport {
+			weight: before_all_weight.saturating_add(try_on_runtime_upgrade_weight),
+			proof_size,
+			max_block: <System::BlockWeights as frame_support::traits::Get<_>>::get().max_block,
+		};
+		Self::ensure_upgrade_fits_block(&report)?;
+
+		Ok(report)
 	}
 
-	/// Logs the result of trying to decode the entire state.
-	fn log_decode_result(
-		res: Result<usize, alloc::vec::Vec<TryDecodeEntireStorageError>>,
-	) -> Result<(), TryRuntimeError> {
-		match res {
-			Ok(bytes) => {
-				log::info!(
-					target: LOG_TARGET,
-					"✅ Entire runtime state decodes without error. {} bytes total.",
-					bytes
-				);
+	/// Ensures that the migrations of the `report` do not exceed the limits of a block.
+	fn ensure_upgrade_fits_block(report: &UpgradeReport) -> Result<(), TryRuntimeError> {
+		log::info!(
+			target: LOG_TARGET,
+			"Runtime upgrade consumed {:?} weight and a storage proof of {:?} bytes, the block \
+			limit is {:?}",
+			report.weight,
+			report.proof_size,
+			report.max_block,
+		);
 
-				Ok(())
-			},
-			Err(errors) => {
-				log::error!(
-					target: LOG_TARGET,
-					"`try_decode_entire_state` failed with {} errors",
-					errors.len(),
-				);
+		if report.weight.any_gt(report.max_block) {
+			log::error!(
+				target: LOG_TARGET,
+				"❌ Runtime upgrade weight {:?} exceeds the maximal block weight {:?}",
+				report.weight,
+				report.max_block,
+			);
+			return Err("runtime upgrade exceeds the maximal block weight".into())
+		}
+
+		if report.proof_size.is_some_and(|size| size > report.max_block.proof_size()) {
+			log::error!(
+				target: LOG_TARGET,
+				"❌ Runtime upgrade storage proof of {:?} bytes exceeds the PoV limit of {} bytes",
+				report.proof_size,
+				report.max_block.proof_size(),
+			);
+			return Err("runtime upgrade exceeds the PoV limit".into())
+		}
+
+		Ok(())
+	}
+
+	/// Logs the report of decoding the entire state, failing if any value is undecodable.
+	fn log_decode_report(report: &DecodeEntireStateReport) -> Result<(), TryRuntimeError> {
+		for item in &report.items {
+			log::debug!(target: LOG_TARGET, "- {item}");
+		}
 
-				for (i, err) in errors.iter().enumerate() {
-					// We log the short version to `error` and then the full debug info to `debug`:
-					log::error!(target: LOG_TARGET, "- {i}. error: {err}");
-					log::debug!(target: LOG_TARGET, "- {i}. error: {err:?}");
-				}
+		if report.is_ok() {
+			log::info!(
+				target: LOG_TARGET,
+				"✅ Entire runtime state decodes without error. {} keys, {} bytes total.",
+				report.keys(),
+				report.bytes(),
+			);
 
-				Err("`try_decode_entire_state` failed".into())
-			},
+			return Ok(())
 		}
+
+		log::error!(
+			target: LOG_TARGET,
+			"`try_decode_entire_state` failed with {} errors",
+			report.failures(),
+		);
+
+		for item in report.items.iter().filter(|item| item.failures > 0) {
+			log::error!(target: LOG_TARGET, "{item}");
+
+			for (i, err) in item.failed.iter().enumerate() {
+				// We log the short version to `error` and then the full debug info to `debug`:
+				log::error!(target: LOG_TARGET, "- {i}. error: {err}");
+				log::debug!(target: LOG_TARGET, "- {i}. error: {err:?}");
+			}
+		}
+
+		Err("`try_decode_entire_state` failed".into())
 	}
 }
 
@@ -501,6 +501,16 @@ type Executive = super::Executive<
 	CustomOnRuntimeUpgrade,
 >;
 
+/// A runtime upgrade that consumes all the weight of a block.
+#[cfg(feature = "try-runtime")]
+struct HeavyOnRuntimeUpgrade;
+#[cfg(feature = "try-runtime")]
+impl OnRuntimeUpgrade for HeavyOnRuntimeUpgrade {
+	fn on_runtime_upgrade() -> Weight {
+		<Runtime as frame_system::Config>::BlockWeights::get().max_block
+	}
+}
+
 parameter_types! {
 	pub static SystemCallbacksCalled: u32 = 0;
 }
@@ -1323,6 +1333,110 @@ fn try_execute_tx_forbidden_errors() {
 	});
 }
 
+/// Reads the proof size from the `ProofSizeExt` extension, like the host function of parachains.
+#[cfg(feature = "try-runtime")]
+fn recorded_proof_size() -> Option<u64> {
+	use sp_externalities::ExternalitiesExt;
+
+	sp_externalities::with_externalities(|ext| {
+		ext.extension::<sp_trie::proof_size_extension::ProofSizeExt>()
+			.map(|ext| ext.storage_proof_size())
+	})
+	.flatten()
+}
+
+#[test]
+#[cfg(feature = "try-runtime")]
+fn try_runtime_upgrade_reports_proof_size() {
+	let max_block = <Runtime as frame_system::Config>::BlockWeights::get().max_block;
+
+	// Without a proof recorder, no proof size is reported.
+	new_test_ext(1).execute_with(|| {
+		let report = Executive::try_runtime_upgrade_with_report(
+			frame_try_runtime::UpgradeCheckSelect::All,
+			recorded_proof_size,
+		)
+		.unwrap();
+
+		assert_eq!(report.proof_size, None);
+		assert_eq!(report.max_block, max_block);
+	});
+
+	// With a proof recorder, the size of the proof that the migrations read is reported.
+	let mut ext = new_test_ext(1);
+	ext.commit_all().unwrap();
+	let recorder = sp_trie::recorder::Recorder::<sp_core::Blake2Hasher>::default();
+	let (db, root) = (ext.backend.backend_storage().clone(), *ext.backend.root());
+	ext.backend = sp_state_machine::TrieBackendBuilder::new(db, root)
+		.with_recorder(recorder.clone())
+		.build();
+	ext.register_extension(sp_trie::proof_size_extension::ProofSizeExt::new(recorder));
+
+	ext.execute_with(|| {
+		let report = Executive::try_runtime_upgrade_with_report(
+			frame_try_runtime::UpgradeCheckSelect::None,
+			recorded_proof_size,
+		)
+		.unwrap();
+
+		assert!(report.proof_size.is_some_and(|size| size > 0));
+		assert!(!report.exceeds_block_limits());
+	});
+}
+
+#[test]
+#[cfg(feature = "try-runtime")]
+fn try_runtime_upgrade_exceeding_block_weight_fails() {
+	type ExecutiveWithHeavyUpgrade = super::Executive<
+		Runtime,
+		Block<UncheckedXt>,
+		ChainContext<Runtime>,
+		Runtime,
+		AllPalletsWithSystem,
+		HeavyOnRuntimeUpgrade,
+	>;
+
+	new_test_ext(1).execute_with(|| {
+		assert_eq!(
+			ExecutiveWithHeavyUpgrade::try_runtime_upgrade(
+				frame_try_runtime::UpgradeCheckSelect::None
+			),
+			Err("runtime upgrade exceeds the maximal block weight".into()),
+		);
+	});
+
+	new_test_ext(1).execute_with(|| {
+		assert_eq!(
+			ExecutiveWithHeavyUpgrade::try_runtime_upgrade_with_report(
+				frame_try_runtime::UpgradeCheckSelect::None,
+				|| None,
+			),
+			Err("runtime upgrade exceeds the maximal block weight".into()),
+		);
+	});
+}
+
+#[test]
+#[cfg(feature = "try-runtime")]
+fn try_runtime_upgrade_exceeding_pov_limit_fails() {
+	let max_proof_size =
+		<Runtime as frame_system::Config>::BlockWeights::get().max_block.proof_size();
+
+	// The first measurement is taken before, the second after the migrations.
+	let measurements = core::cell::RefCell::new(vec![max_proof_size + 1, 0]);
+	let proof_size = || measurements.borrow_mut().pop();
+
+	new_test_ext(1).execute_with(|| {
+		assert_eq!(
+			Executive::try_runtime_upgrade_with_report(
+				frame_try_runtime::UpgradeCheckSelect::None,
+				proof_size,
+			),
+			Err("runtime upgrade exceeds the PoV limit".into()),
+		);
+	});
+}
+
 /// Check that `ensure_inherents_are_first` reports the correct indices.
 #[test]
 fn ensure_inherents_are_first_works() {
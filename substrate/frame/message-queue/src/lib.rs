@@ -1572,10 +1572,12 @@ impl<T: Config> Pallet<T> {
 			},
 			Err(error @ BadFormat | error @ Corrupt | error @ Unsupported) => {
 				// Permanent error - drop
+				T::MessageProcessor::process_message_failed(origin.clone(), meter, &id, error);
 				Self::deposit_event(Event::<T>::ProcessingFailed { id: id.into(), origin, error });
 				MessageExecutionStatus::Unprocessable { permanent: true }
 			},
 			Err(error @ StackLimitReached) => {
+				T::MessageProcessor::process_message_failed(origin.clone(), meter, &id, error);
 				Self::deposit_event(Event::<T>::ProcessingFailed { id: id.into(), origin, error });
 				MessageExecutionStatus::StackLimitReached
 			},
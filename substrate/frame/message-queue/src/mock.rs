@@ -150,6 +150,8 @@ impl crate::weights::WeightInfo for MockedWeightInfo {
 
 parameter_types! {
 	pub static MessagesProcessed: Vec<(Vec<u8>, MessageOrigin)> = vec![];
+	/// Messages that failed permanently, as reported by `process_message_failed`.
+	pub static MessagesFailed: Vec<([u8; 32], MessageOrigin, ProcessMessageError)> = vec![];
 	/// Queues that should return `Yield` upon being processed.
 	pub static YieldingQueues: Vec<MessageOrigin> = vec![];
 }
@@ -208,6 +210,15 @@ impl ProcessMessage for RecordingMessageProcessor {
 			Err(ProcessMessageError::Overweight(required))
 		}
 	}
+
+	fn process_message_failed(
+		origin: Self::Origin,
+		_meter: &mut WeightMeter,
+		id: &[u8; 32],
+		error: ProcessMessageError,
+	) {
+		MessagesFailed::mutate(|m| m.push((*id, origin, error)));
+	}
 }
 
 parameter_types! {
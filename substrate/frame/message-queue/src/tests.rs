@@ -210,6 +210,12 @@ fn service_queues_failing_messages_works() {
 		assert_eq!(System::events().len(), 4);
 		// Last page with the `yield` stays in.
 		assert_pages(&[2]);
+		// The processor is told about every permanent failure, but not about the `yield`.
+		let failed = ["badformat", "corrupt", "unsupported", "stacklimitreached"];
+		assert_eq!(
+			MessagesFailed::get().into_iter().map(|(id, _, _)| id).collect::<Vec<_>>(),
+			failed.map(|m| blake2_256(m.as_bytes())).to_vec()
+		);
 	});
 }
 
@@ -187,6 +187,23 @@ mod benches {
 		_(RawOrigin::Root);
 	}
 
+	#[benchmark]
+	fn retry_failed_migration() -> Result<(), BenchmarkError> {
+		T::Migrations::set_fail_after(0);
+		let id = T::Migrations::nth_id(0)
+			.and_then(|id| IdentifierOf::<T>::try_from(id).ok())
+			.ok_or(BenchmarkError::Stop("Setup failed"))?;
+		LastFailure::<T>::put(FailedMigration { id, index: 0, inner_cursor: None, steps: 1 });
+		Cursor::<T>::put(MigrationCursor::Stuck);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+
+		assert_last_event::<T>(Event::MigrationRetried { index: 0 }.into());
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn clear_historic(n: Linear<0, { DEFAULT_HISTORIC_BATCH_CLEAR_SIZE * 2 }>) {
 		let id_max_len = <T as Config>::IdentifierMaxLen::get();
@@ -136,6 +136,17 @@
 //! migration must be removed and fixed manually. This already applies, even before considering the
 //! historic set.
 //!
+//! The identifier and the last cursor of a failed migration are kept in [`LastFailure`]. Once a
+//! fixed version of the migration was deployed through a runtime upgrade, governance can restart
+//! the migrations with [`retry_failed_migration`][Pallet::retry_failed_migration]. They resume
+//! at the failed migration from its last cursor, such that the migrations before it do not run
+//! again. The fixed migration must therefore keep its identifier, position and cursor format.
+//!
+//! ### Monitoring
+//!
+//! The [`runtime_api::MigrationsApi`] reports the progress of the ongoing migration. Migrations
+//! can improve this report by implementing the [`SteppedMigration::progress`] hint.
+//!
 //! ### Remark: Transactional processing
 //!
 //! You can see the transactional semantics for migration steps as mostly useless, since in the
@@ -148,6 +159,7 @@ mod benchmarking;
 pub mod migrations;
 mod mock;
 pub mod mock_helpers;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
@@ -243,6 +255,60 @@ impl<Cursor, BlockNumber> ActiveCursor<Cursor, BlockNumber> {
 	}
 }
 
+/// The record of the last failed migration.
+#[derive(
+	Debug,
+	Clone,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct FailedMigration<Id, Cursor> {
+	/// The identifier of the migration.
+	pub id: Id,
+	/// The index of the migration in the MBM tuple at the time that it failed.
+	pub index: u32,
+	/// The last cursor that the migration returned before it failed.
+	pub inner_cursor: Option<Cursor>,
+	/// The number of steps that the migration took before it failed.
+	pub steps: u32,
+}
+
+/// The progress of a migration.
+#[derive(
+	Debug, Clone, Eq, PartialEq, Encode, Decode, DecodeWithMemTracking, scale_info::TypeInfo,
+)]
+pub struct MigrationProgress {
+	/// The index of the migration in the MBM tuple.
+	pub index: u32,
+	/// The number of migrations in the MBM tuple.
+	pub migrations: u32,
+	/// The encoded length of the inner cursor of the migration.
+	pub cursor_len: u32,
+	/// The number of steps that the migration took so far.
+	pub steps: u32,
+	/// The estimated number of remaining steps, if the migration provides a
+	/// [`SteppedMigration::progress`] hint.
+	pub remaining_steps: Option<u32>,
+}
+
+/// The status of the multi-block migrations.
+#[derive(
+	Debug, Clone, Eq, PartialEq, Encode, Decode, DecodeWithMemTracking, scale_info::TypeInfo,
+)]
+pub enum MigrationStatus {
+	/// No migrations are ongoing.
+	Idle,
+	/// A migration is ongoing.
+	Ongoing(MigrationProgress),
+	/// The migrations are stuck and require governance intervention.
+	Stuck,
+}
+
 /// How to clear the records of historic migrations.
 #[derive(
 	Debug, Clone, Eq, PartialEq, Encode, Decode, DecodeWithMemTracking, scale_info::TypeInfo,
@@ -293,6 +359,9 @@ pub type IdentifierOf<T> = BoundedVec<u8, <T as Config>::IdentifierMaxLen>;
 /// Convenience alias for [`ActiveCursor`].
 pub type ActiveCursorOf<T> = ActiveCursor<RawCursorOf<T>, BlockNumberFor<T>>;
 
+/// Convenience alias for [`FailedMigration`].
+pub type FailedMigrationOf<T> = FailedMigration<IdentifierOf<T>, RawCursorOf<T>>;
+
 /// Trait for a tuple of No-OP migrations with one element.
 #[impl_trait_for_tuples::impl_for_tuples(30)]
 pub trait MockedMigrations: SteppedMigrations {
@@ -430,6 +499,16 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Historic<T: Config> = StorageMap<_, Twox64Concat, IdentifierOf<T>, (), OptionQuery>;
 
+	/// The number of steps that the currently active migration took so far.
+	#[pallet::storage]
+	pub type Steps<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The last migration that failed.
+	///
+	/// Can be retried via `retry_failed_migration` once it was fixed.
+	#[pallet::storage]
+	pub type LastFailure<T: Config> = StorageValue<_, FailedMigrationOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -484,12 +563,22 @@ pub mod pallet {
 			/// Should be passed to `clear_historic` in a successive call.
 			next_cursor: Option<Vec<u8>>,
 		},
+		/// The migrations were restarted to retry a failed migration.
+		MigrationRetried {
+			/// The index of the failed migration within the [`Config::Migrations`] list at the
+			/// time that it failed.
+			index: u32,
+		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The operation cannot complete since some MBMs are ongoing.
 		Ongoing,
+		/// There is no failed migration to retry.
+		NoFailedMigration,
+		/// The failed migration is no longer at the index that it failed at.
+		FailedMigrationMoved,
 	}
 
 	#[pallet::hooks]
@@ -632,6 +721,40 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Restart the migrations to retry the one in [`LastFailure`].
+		///
+		/// Should be used once a fixed version of the migration was deployed through a runtime
+		/// upgrade. The migrations resume at the failed migration from the last cursor that it
+		/// returned, the ones before it do not run again. The fixed migration must have the same
+		/// identifier and index as the failed one. No MBMs must be ongoing for this to succeed, a
+		/// `Stuck` cursor is overwritten.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::retry_failed_migration())]
+		pub fn retry_failed_migration(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				Cursor::<T>::get().map_or(true, |c| c.as_active().is_none()),
+				Error::<T>::Ongoing
+			);
+			let failure = LastFailure::<T>::take().ok_or(Error::<T>::NoFailedMigration)?;
+			ensure!(
+				T::Migrations::nth_id(failure.index).as_deref() == Some(&failure.id[..]),
+				Error::<T>::FailedMigrationMoved
+			);
+
+			Steps::<T>::put(failure.steps);
+			Cursor::<T>::put(MigrationCursor::Active(ActiveCursor {
+				index: failure.index,
+				inner_cursor: failure.inner_cursor,
+				started_at: System::<T>::block_number(),
+			}));
+			Self::deposit_event(Event::MigrationRetried { index: failure.index });
+			T::MigrationStatusHandler::started();
+
+			Ok(())
+		}
 	}
 }
 
@@ -643,14 +766,17 @@ impl<T: Config> Pallet<T> {
 		if let Some(cursor) = Cursor::<T>::get() {
 			log::error!("Ongoing migrations interrupted - chain stuck");
 
-			let maybe_index = cursor.as_active().map(|c| c.index);
-			Self::upgrade_failed(maybe_index);
+			Self::upgrade_failed(cursor.as_active());
 			return T::WeightInfo::onboard_new_mbms()
 		}
 
 		let migrations = T::Migrations::len();
 		log::debug!("Onboarding {migrations} new MBM migrations");
 
+		// A fresh upgrade runs all migrations from their start.
+		LastFailure::<T>::kill();
+		Steps::<T>::kill();
+
 		if migrations > 0 {
 			// Set the cursor to the first migration:
 			Cursor::<T>::set(Some(
@@ -733,6 +859,7 @@ impl<T: Config> Pallet<T> {
 			defensive_assert!(cursor.index == T::Migrations::len(), "Inconsistent MBMs tuple");
 			Self::deposit_event(Event::UpgradeCompleted);
 			Cursor::<T>::kill();
+			Steps::<T>::kill();
 			T::MigrationStatusHandler::completed();
 			return None;
 		};
@@ -740,12 +867,13 @@ impl<T: Config> Pallet<T> {
 		let id = T::Migrations::nth_id(cursor.index).map(TryInto::try_into);
 		let Some(Ok(bounded_id)): Option<Result<IdentifierOf<T>, _>> = id else {
 			defensive!("integrity_test ensures that all identifiers are present and bounde; qed.");
-			Self::upgrade_failed(Some(cursor.index));
+			Self::upgrade_failed(Some(&cursor));
 			return None
 		};
 
 		if Historic::<T>::contains_key(&bounded_id) {
 			Self::deposit_event(Event::MigrationSkipped { index: cursor.index });
+			Steps::<T>::kill();
 			cursor.goto_next_migration(System::<T>::block_number());
 			return Some(ControlFlow::Continue(cursor))
 		}
@@ -768,7 +896,7 @@ impl<T: Config> Pallet<T> {
 		);
 		let Some((max_steps, next_cursor)) = max_steps.zip(next_cursor) else {
 			defensive!("integrity_test ensures that the tuple is valid; qed");
-			Self::upgrade_failed(Some(cursor.index));
+			Self::upgrade_failed(Some(&cursor));
 			return None
 		};
 
@@ -777,16 +905,17 @@ impl<T: Config> Pallet<T> {
 			Ok(Some(next_cursor)) => {
 				let Ok(bound_next_cursor) = next_cursor.try_into() else {
 					defensive!("The integrity check ensures that all cursors' MEL bound fits into CursorMaxLen; qed");
-					Self::upgrade_failed(Some(cursor.index));
+					Self::upgrade_failed(Some(&cursor));
 					return None
 				};
 
 				Self::deposit_event(Event::MigrationAdvanced { index: cursor.index, took });
 				cursor.inner_cursor = Some(bound_next_cursor);
+				Steps::<T>::mutate(|steps| steps.saturating_inc());
 
 				if max_steps.is_some_and(|max| took > max.into()) {
 					Self::deposit_event(Event::MigrationFailed { index: cursor.index, took });
-					Self::upgrade_failed(Some(cursor.index));
+					Self::upgrade_failed(Some(&cursor));
 					None
 				} else {
 					// A migration cannot progress more than one step per block, we therefore break.
@@ -807,13 +936,14 @@ impl<T: Config> Pallet<T> {
 
 				Self::deposit_event(Event::MigrationCompleted { index: cursor.index, took });
 				Historic::<T>::insert(&bounded_id, ());
+				Steps::<T>::kill();
 				cursor.goto_next_migration(System::<T>::block_number());
 				Some(ControlFlow::Continue(cursor))
 			},
 			Err(SteppedMigrationError::InsufficientWeight { required }) => {
 				if is_first || required.any_gt(meter.limit()) {
 					Self::deposit_event(Event::MigrationFailed { index: cursor.index, took });
-					Self::upgrade_failed(Some(cursor.index));
+					Self::upgrade_failed(Some(&cursor));
 					None
 				} else {
 					// Retry and hope that there is more weight in the next block.
@@ -822,20 +952,34 @@ impl<T: Config> Pallet<T> {
 			},
 			Err(SteppedMigrationError::InvalidCursor | SteppedMigrationError::Failed) => {
 				Self::deposit_event(Event::MigrationFailed { index: cursor.index, took });
-				Self::upgrade_failed(Some(cursor.index));
+				Self::upgrade_failed(Some(&cursor));
 				None
 			},
 		}
 	}
 
-	/// Fail the current runtime upgrade, caused by `migration`.
+	/// Fail the current runtime upgrade, caused by the migration of `cursor`.
 	///
-	/// When the `try-runtime` feature is enabled, this function will panic.
+	/// The failed migration is recorded in [`LastFailure`] to allow retrying it later on. When the
+	/// `try-runtime` feature is enabled, this function will panic.
 	// Allow unreachable code so it can compile without warnings when `try-runtime` is enabled.
-	fn upgrade_failed(migration: Option<u32>) {
+	fn upgrade_failed(cursor: Option<&ActiveCursorOf<T>>) {
 		use FailedMigrationHandling::*;
 		Self::deposit_event(Event::UpgradeFailed);
 
+		let migration = cursor.map(|c| c.index);
+		if let Some(cursor) = cursor {
+			let id = T::Migrations::nth_id(cursor.index).and_then(|id| id.try_into().ok());
+			if let Some(id) = id {
+				LastFailure::<T>::put(FailedMigration {
+					id,
+					index: cursor.index,
+					inner_cursor: cursor.inner_cursor.clone(),
+					steps: Steps::<T>::take(),
+				});
+			}
+		}
+
 		if cfg!(feature = "try-runtime") {
 			panic!("Migration with index {:?} failed.", migration);
 		} else {
@@ -847,6 +991,40 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The status of the migrations, including the progress of the ongoing one.
+	pub fn migration_status() -> MigrationStatus {
+		match Cursor::<T>::get() {
+			None => MigrationStatus::Idle,
+			Some(MigrationCursor::Stuck) => MigrationStatus::Stuck,
+			Some(MigrationCursor::Active(cursor)) => MigrationStatus::Ongoing(Self::progress_of(
+				cursor.index,
+				cursor.inner_cursor,
+				Steps::<T>::get(),
+			)),
+		}
+	}
+
+	/// The progress of the migration in [`LastFailure`] at the time that it failed.
+	pub fn last_failure() -> Option<MigrationProgress> {
+		LastFailure::<T>::get()
+			.map(|failure| Self::progress_of(failure.index, failure.inner_cursor, failure.steps))
+	}
+
+	fn progress_of(
+		index: u32,
+		inner_cursor: Option<RawCursorOf<T>>,
+		steps: u32,
+	) -> MigrationProgress {
+		let inner_cursor = inner_cursor.map(|c| c.into_inner());
+		MigrationProgress {
+			index,
+			migrations: T::Migrations::len(),
+			cursor_len: inner_cursor.as_ref().map_or(0, |c| c.len() as u32),
+			steps,
+			remaining_steps: T::Migrations::nth_progress(index, inner_cursor),
+		}
+	}
+
 	/// The maximal weight of calling the private `Self::exec_migration` function.
 	pub fn exec_migration_max_weight() -> Weight {
 		T::WeightInfo::exec_migration_complete()
@@ -36,7 +36,7 @@ pub type MockedIdentifier = BoundedVec<u8, ConstU32<256>>;
 pub enum MockedMigrationKind {
 	/// Succeed after its number of steps elapsed.
 	SucceedAfter,
-	/// Fail after its number of steps elapsed.
+	/// Fail after its number of steps elapsed, unless [`MockedMigrations::fix`] was called.
 	FailAfter,
 	/// Never terminate.
 	TimeoutAfter,
@@ -60,6 +60,8 @@ pub fn mocked_id(kind: MockedMigrationKind, steps: u32) -> MockedIdentifier {
 frame_support::parameter_types! {
 	/// The configs for the migrations to run.
 	storage MIGRATIONS: Vec<(MockedMigrationKind, u32)> = vec![];
+	/// Whether failing migrations were fixed.
+	storage FIXED: bool = false;
 }
 
 /// Allows to set the migrations to run at runtime instead of compile-time.
@@ -100,6 +102,10 @@ impl SteppedMigrations for MockedMigrations {
 				log::debug!("MockedMigration: Not enough weight after {} steps", count);
 				Err(SteppedMigrationError::InsufficientWeight { required })
 			},
+			FailAfter if FIXED::get() => {
+				log::debug!("MockedMigration: Fixed migration succeeded after {} steps", count);
+				Ok(None)
+			},
 			FailAfter => {
 				log::debug!("MockedMigration: Failed after {} steps", count);
 				Err(SteppedMigrationError::Failed)
@@ -123,6 +129,16 @@ impl SteppedMigrations for MockedMigrations {
 		MIGRATIONS::get().get(n as usize).map(|(_, s)| Some(*s))
 	}
 
+	fn nth_progress(n: u32, cursor: Option<Vec<u8>>) -> Option<u32> {
+		let (kind, steps) = MIGRATIONS::get().get(n as usize).copied()?;
+		if matches!(kind, TimeoutAfter) {
+			return None
+		}
+
+		let count: u32 = cursor.map_or(Ok(0), |c| Decode::decode(&mut &c[..])).ok()?;
+		Some(steps.saturating_sub(count) + 1)
+	}
+
 	#[cfg(feature = "try-runtime")]
 	fn nth_pre_upgrade(n: u32) -> Option<Result<Vec<u8>, sp_runtime::TryRuntimeError>> {
 		let (kind, _) = MIGRATIONS::get()[n as usize];
@@ -162,6 +178,11 @@ impl MockedMigrations {
 	pub fn set(migrations: Vec<(MockedMigrationKind, u32)>) {
 		MIGRATIONS::set(&migrations);
 	}
+
+	/// Let the `FailAfter` migrations succeed instead, as if a fix was deployed.
+	pub fn fix() {
+		FIXED::set(&true);
+	}
 }
 
 impl crate::MockedMigrations for MockedMigrations {
@@ -0,0 +1,33 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the multi-block migrations pallet.
+
+use crate::{MigrationProgress, MigrationStatus};
+
+sp_api::decl_runtime_apis! {
+	/// Reports the progress of the multi-block migrations.
+	pub trait MigrationsApi {
+		/// The status of the migrations, including the progress of the ongoing one.
+		fn migration_status() -> MigrationStatus;
+
+		/// The progress of the last failed migration at the time that it failed, if any.
+		///
+		/// Such a migration can be retried via `retry_failed_migration`.
+		fn last_failure() -> Option<MigrationProgress>;
+	}
+}
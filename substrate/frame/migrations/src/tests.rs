@@ -17,12 +17,14 @@
 
 #![cfg(test)]
 
-use frame_support::{pallet_prelude::Weight, traits::OnRuntimeUpgrade};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, pallet_prelude::Weight, traits::OnRuntimeUpgrade};
 
 use crate::{
 	mock::{Test as T, *},
 	mock_helpers::{MockedMigrationKind::*, *},
-	Cursor, Event, FailedMigrationHandling, MigrationCursor,
+	Cursor, Error, Event, FailedMigration, FailedMigrationHandling, LastFailure, MigrationCursor,
+	MigrationProgress, MigrationStatus,
 };
 
 #[docify::export]
@@ -367,6 +369,138 @@ fn migration_timeout_errors() {
 	});
 }
 
+#[test]
+fn migration_status_reports_progress() {
+	test_closure(|| {
+		MockedMigrations::set(vec![(SucceedAfter, 3)]);
+		assert_eq!(Migrations::migration_status(), MigrationStatus::Idle);
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		let progress = MigrationProgress {
+			index: 0,
+			migrations: 1,
+			cursor_len: 0,
+			steps: 0,
+			remaining_steps: Some(4),
+		};
+		assert_eq!(Migrations::migration_status(), MigrationStatus::Ongoing(progress.clone()));
+
+		run_to_block(3);
+		assert_eq!(
+			Migrations::migration_status(),
+			MigrationStatus::Ongoing(MigrationProgress {
+				cursor_len: 4,
+				steps: 2,
+				remaining_steps: Some(2),
+				..progress
+			})
+		);
+
+		run_to_block(10);
+		assert_eq!(Migrations::migration_status(), MigrationStatus::Idle);
+		assert_eq!(Migrations::last_failure(), None);
+	});
+}
+
+#[test]
+#[cfg_attr(feature = "try-runtime", should_panic)]
+fn retry_failed_migration_works() {
+	test_closure(|| {
+		FailedUpgradeResponse::set(FailedMigrationHandling::KeepStuck);
+		MockedMigrations::set(vec![(SucceedAfter, 0), (FailAfter, 2)]);
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(10);
+		assert_eq!(Migrations::migration_status(), MigrationStatus::Stuck);
+		assert_eq!(upgrades_started_completed_failed(), (1, 0, 1));
+
+		// The failed migration and its last cursor are recorded.
+		assert_eq!(
+			LastFailure::<T>::get(),
+			Some(FailedMigration {
+				id: mocked_id(FailAfter, 2),
+				index: 1,
+				inner_cursor: Some(2u32.encode().try_into().unwrap()),
+				steps: 2,
+			})
+		);
+		assert_eq!(
+			Migrations::last_failure(),
+			Some(MigrationProgress {
+				index: 1,
+				migrations: 2,
+				cursor_len: 4,
+				steps: 2,
+				remaining_steps: Some(1)
+			})
+		);
+
+		// Deploy the fix. The runtime upgrade does not unstuck the chain.
+		MockedMigrations::fix();
+		Migrations::on_runtime_upgrade();
+		assert_eq!(Migrations::migration_status(), MigrationStatus::Stuck);
+		assert!(LastFailure::<T>::exists());
+		System::reset_events();
+		assert_eq!(upgrades_started_completed_failed(), (0, 0, 1));
+
+		// The migrations resume at the failed one from its last cursor.
+		assert_ok!(Migrations::retry_failed_migration(RuntimeOrigin::root()));
+		assert!(!LastFailure::<T>::exists());
+		assert_eq!(
+			Migrations::migration_status(),
+			MigrationStatus::Ongoing(MigrationProgress {
+				index: 1,
+				migrations: 2,
+				cursor_len: 4,
+				steps: 2,
+				remaining_steps: Some(1)
+			})
+		);
+
+		// The completed migration does not run again and the fixed one continues from its
+		// cursor, which is at its last step.
+		run_to_block(20);
+		assert_events(vec![
+			Event::MigrationRetried { index: 1 },
+			Event::MigrationCompleted { index: 1, took: 1 },
+			Event::UpgradeCompleted,
+		]);
+		assert_eq!(upgrades_started_completed_failed(), (1, 1, 0));
+		assert_eq!(historic(), vec![mocked_id(SucceedAfter, 0), mocked_id(FailAfter, 2)]);
+
+		assert_noop!(
+			Migrations::retry_failed_migration(RuntimeOrigin::root()),
+			Error::<T>::NoFailedMigration
+		);
+	});
+}
+
+#[test]
+#[cfg_attr(feature = "try-runtime", should_panic)]
+fn retry_failed_migration_rejects_moved_migration() {
+	test_closure(|| {
+		FailedUpgradeResponse::set(FailedMigrationHandling::KeepStuck);
+		MockedMigrations::set(vec![(SucceedAfter, 0), (FailAfter, 2)]);
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(10);
+		assert_eq!(Migrations::migration_status(), MigrationStatus::Stuck);
+
+		// The fix is deployed behind a new migration, so its cursor cannot be resumed.
+		MockedMigrations::set(vec![(SucceedAfter, 0), (SucceedAfter, 1), (FailAfter, 2)]);
+		MockedMigrations::fix();
+		assert_noop!(
+			Migrations::retry_failed_migration(RuntimeOrigin::root()),
+			Error::<T>::FailedMigrationMoved
+		);
+		assert!(LastFailure::<T>::exists());
+		assert_eq!(Migrations::migration_status(), MigrationStatus::Stuck);
+	});
+}
+
 #[cfg(feature = "try-runtime")]
 #[test]
 fn try_runtime_success_case() {
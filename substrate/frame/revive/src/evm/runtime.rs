@@ -105,6 +105,12 @@ impl<Address, Signature, E: EthExtra> ExtrinsicMetadata
 		Signature,
 		E::Extension,
 	>::VERSIONS;
+	const EXTENSION_VERSIONS: &'static [generic::ExtensionVersion] = generic::UncheckedExtrinsic::<
+		Address,
+		CallOf<E::Config>,
+		Signature,
+		E::Extension,
+	>::EXTENSION_VERSIONS;
 	type TransactionExtensions = E::Extension;
 }
 
@@ -107,6 +107,29 @@ pub fn expand_runtime_metadata(
 						<#extrinsic as #scrate::traits::SignedTransactionBuilder>::Extension
 					>();
 
+				let extensions = <
+						<
+							#extrinsic as #scrate::sp_runtime::traits::ExtrinsicMetadata
+						>::TransactionExtensions
+						as
+						#scrate::sp_runtime::traits::TransactionExtension::<
+							<#runtime as #system_path::Config>::RuntimeCall
+						>
+					>::metadata()
+						.into_iter()
+						.map(|meta| #scrate::__private::metadata_ir::TransactionExtensionMetadataIR {
+							identifier: meta.identifier,
+							ty: meta.ty,
+							implicit: meta.implicit,
+						})
+						.collect::<#scrate::__private::Vec<_>>();
+				let extensions_by_version = <
+						#extrinsic as #scrate::sp_runtime::traits::ExtrinsicMetadata
+					>::EXTENSION_VERSIONS
+						.iter()
+						.map(|version| (*version, (0..extensions.len() as u32).collect()))
+						.collect();
+
 				use #scrate::__private::metadata_ir::InternalImplRuntimeApis;
 
 				#scrate::__private::metadata_ir::MetadataIR {
@@ -118,22 +141,8 @@ pub fn expand_runtime_metadata(
 						call_ty,
 						signature_ty,
 						extra_ty,
-						extensions: <
-								<
-									#extrinsic as #scrate::sp_runtime::traits::ExtrinsicMetadata
-								>::TransactionExtensions
-								as
-								#scrate::sp_runtime::traits::TransactionExtension::<
-									<#runtime as #system_path::Config>::RuntimeCall
-								>
-							>::metadata()
-								.into_iter()
-								.map(|meta| #scrate::__private::metadata_ir::TransactionExtensionMetadataIR {
-									identifier: meta.identifier,
-									ty: meta.ty,
-									implicit: meta.implicit,
-								})
-								.collect(),
+						extensions,
+						extensions_by_version,
 					},
 					ty: #scrate::__private::scale_info::meta_type::<#runtime>(),
 					apis: (&rt).runtime_metadata(),
@@ -80,6 +80,12 @@ fn counter_prefix(prefix: &str) -> String {
 	format!("CounterFor{}", prefix)
 }
 
+/// Generate the meta_prefix related to the storage.
+/// meta_prefix is used by ordered storage map.
+fn ordered_map_meta_prefix(prefix: &str) -> String {
+	format!("MetaFor{}", prefix)
+}
+
 /// Construct a runtime, with the given name and the given pallets.
 ///
 /// NOTE: A new version of this macro is available at `frame_support::runtime`. This macro will
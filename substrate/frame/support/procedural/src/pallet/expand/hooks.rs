@@ -15,7 +15,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::pallet::Def;
+use crate::pallet::{parse::storage::Metadata, Def};
 
 /// * implement the individual traits using the Hooks trait
 pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
@@ -42,6 +42,18 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 		>::name::<Self>().unwrap_or("<unknown pallet name>")
 	};
 
+	// Only call into the storage checks when there is something to check, as they are implemented
+	// under the where clause of all storages.
+	let storage_integrity_test = if def
+		.storages
+		.iter()
+		.any(|storage| matches!(storage.metadata, Metadata::OrderedMap { .. }))
+	{
+		quote::quote!(Self::storage_integrity_test();)
+	} else {
+		quote::quote!()
+	};
+
 	let initialize_on_chain_storage_version = if let Some(in_code_version) =
 		&def.pallet_struct.storage_version
 	{
@@ -296,6 +308,7 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 			{
 				fn integrity_test() {
 					#frame_support::__private::sp_io::TestExternalities::default().execute_with(|| {
+						#storage_integrity_test
 						<
 							Self as #frame_support::traits::Hooks<
 								#frame_system::pallet_prelude::BlockNumberFor::<T>
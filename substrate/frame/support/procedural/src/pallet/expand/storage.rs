@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use crate::{
-	counter_prefix,
+	counter_prefix, ordered_map_meta_prefix,
 	pallet::{
 		parse::{
 			helper::two128_str,
@@ -45,6 +45,14 @@ fn counter_prefix_ident(storage_ident: &syn::Ident) -> syn::Ident {
 	)
 }
 
+/// meta_prefix_ident is used for the prefix struct to be given to ordered storage map.
+fn meta_prefix_ident(storage_ident: &syn::Ident) -> syn::Ident {
+	syn::Ident::new(
+		&format!("_GeneratedMetaPrefixForStorage{}", storage_ident),
+		storage_ident.span(),
+	)
+}
+
 /// Check for duplicated storage prefixes. This step is necessary since users can specify an
 /// alternative storage prefix using the #[pallet::storage_prefix] syntax, and we need to ensure
 /// that the prefix specified by the user is not a duplicate of an existing one.
@@ -83,6 +91,24 @@ fn check_prefix_duplicates(
 		}
 	}
 
+	if let Metadata::OrderedMap { .. } = storage_def.metadata {
+		let meta_prefix = ordered_map_meta_prefix(&prefix);
+		let meta_dup_err = syn::Error::new(
+			storage_def.prefix_span(),
+			format!(
+				"Duplicate storage prefixes found for `{}`, used for metadata associated to \
+				ordered storage map",
+				meta_prefix,
+			),
+		);
+
+		if let Some(other_dup_err) = used_prefixes.insert(meta_prefix, meta_dup_err.clone()) {
+			let mut err = meta_dup_err;
+			err.combine(other_dup_err);
+			return Err(err);
+		}
+	}
+
 	Ok(())
 }
 
@@ -257,6 +283,16 @@ pub fn process_generics(def: &mut Def) -> syn::Result<Vec<ResultOnEmptyStructMet
 					let max_values = max_values.unwrap_or_else(|| default_max_values.clone());
 					args.args.push(syn::GenericArgument::Type(max_values));
 				},
+				StorageGenerics::OrderedMap { key, value, node_capacity, max_values } => {
+					args.args.push(syn::GenericArgument::Type(key));
+					args.args.push(syn::GenericArgument::Type(value));
+					let node_capacity = node_capacity.unwrap_or_else(
+						|| syn::parse_quote!(#frame_support::storage::types::DefaultNodeCapacity),
+					);
+					args.args.push(syn::GenericArgument::Type(node_capacity));
+					let max_values = max_values.unwrap_or_else(|| default_max_values.clone());
+					args.args.push(syn::GenericArgument::Type(max_values));
+				},
 			}
 		} else {
 			args.args[0] = syn::parse_quote!( #prefix_ident<#type_use_gen> );
@@ -266,6 +302,8 @@ pub fn process_generics(def: &mut Def) -> syn::Result<Vec<ResultOnEmptyStructMet
 				Metadata::NMap { .. } | Metadata::CountedNMap { .. } => (2, 3, 4),
 				Metadata::Map { .. } | Metadata::CountedMap { .. } => (3, 4, 5),
 				Metadata::DoubleMap { .. } => (5, 6, 7),
+				// Ordered maps have neither a hasher nor a query kind.
+				Metadata::OrderedMap { .. } => continue,
 			};
 
 			if storage_def.use_default_hasher {
@@ -376,6 +414,14 @@ fn augment_final_docs(def: &mut Def) {
 			);
 			push_string_literal(&doc_line, storage);
 		},
+		Metadata::OrderedMap { key, value } => {
+			let doc_line = format!(
+				"Storage type is [`OrderedStorageMap`] with key type {} and value type {}.",
+				key.to_token_stream(),
+				value.to_token_stream()
+			);
+			push_string_literal(&doc_line, storage);
+		},
 	});
 }
 
@@ -630,6 +676,8 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 						}
 					)
 				},
+				Metadata::OrderedMap { .. } =>
+					unreachable!("Getters are rejected for ordered maps"),
 			}
 		} else {
 			Default::default()
@@ -735,6 +783,49 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 					}
 				)
 			},
+			Metadata::OrderedMap { .. } => {
+				let meta_prefix_struct_ident = meta_prefix_ident(&storage_def.ident);
+				let meta_prefix_struct_const = ordered_map_meta_prefix(&prefix_struct_const);
+				let storage_prefix_hash = two128_str(&meta_prefix_struct_const);
+				quote::quote_spanned!(storage_def.attr_span =>
+					#(#cfg_attrs)*
+					#[doc(hidden)]
+					#prefix_struct_vis struct #meta_prefix_struct_ident<#type_use_gen>(
+						core::marker::PhantomData<(#type_use_gen,)>
+					);
+					#(#cfg_attrs)*
+					impl<#type_impl_gen> #frame_support::traits::StorageInstance
+						for #meta_prefix_struct_ident<#type_use_gen>
+						#config_where_clause
+					{
+						fn pallet_prefix() -> &'static str {
+							<
+								<T as #frame_system::Config>::PalletInfo
+								as #frame_support::traits::PalletInfo
+							>::name::<Pallet<#type_use_gen>>()
+								.expect("No name found for the pallet in the runtime! This usually means that the pallet wasn't added to `construct_runtime!`.")
+						}
+						fn pallet_prefix_hash() -> [u8; 16] {
+							<
+								<T as #frame_system::Config>::PalletInfo
+								as #frame_support::traits::PalletInfo
+							>::name_hash::<Pallet<#type_use_gen>>()
+								.expect("No name_hash found for the pallet in the runtime! This usually means that the pallet wasn't added to `construct_runtime!`.")
+						}
+						const STORAGE_PREFIX: &'static str = #meta_prefix_struct_const;
+						fn storage_prefix_hash() -> [u8; 16] {
+							#storage_prefix_hash
+						}
+					}
+					#(#cfg_attrs)*
+					impl<#type_impl_gen> #frame_support::storage::types::OrderedStorageMapInstance
+						for #prefix_struct_ident<#type_use_gen>
+						#config_where_clause
+					{
+						type MetaPrefix = #meta_prefix_struct_ident<#type_use_gen>;
+					}
+				)
+			},
 			_ => proc_macro2::TokenStream::default(),
 		};
 
@@ -863,45 +954,71 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 				for #pallet_ident<#type_use_gen> #completed_where_clause
 				{
 					fn try_decode_entire_state() -> Result<usize, #frame_support::__private::Vec<#frame_support::traits::TryDecodeEntireStorageError>> {
+						Self::decode_entire_state_report(&#frame_support::traits::DecodeSelect::All).into_result()
+					}
+
+					fn decode_entire_state_report(
+						select: &#frame_support::traits::DecodeSelect,
+					) -> #frame_support::traits::DecodeEntireStateReport {
 						let pallet_name = <<T as #frame_system::Config>::PalletInfo	as #frame_support::traits::PalletInfo>
 							::name::<#pallet_ident<#type_use_gen>>()
 							.expect("Every active pallet has a name in the runtime; qed");
 
-						#frame_support::__private::log::debug!(target: "runtime::try-decode-state", "trying to decode pallet: {pallet_name}");
-
 						// NOTE: for now, we have to exclude storage items that are feature gated.
-						let mut errors = #frame_support::__private::Vec::new();
-						let mut decoded = 0usize;
+						let mut report = #frame_support::traits::DecodeEntireStateReport::default();
+						if !select.includes_pallet(pallet_name.as_bytes()) {
+							return report;
+						}
+
+						#frame_support::__private::log::debug!(target: "runtime::try-decode-state", "trying to decode pallet: {pallet_name}");
 
 						#(
 							#frame_support::__private::log::debug!(target: "runtime::try-decode-state", "trying to decode storage: \
 							{pallet_name}::{}", stringify!(#storage_names));
 
-							match <#storage_names as #frame_support::traits::TryDecodeEntireStorage>::try_decode_entire_state() {
-								Ok(count) => {
-									decoded += count;
-								},
-								Err(err) => {
-									errors.extend(err);
-								},
-							}
+							report.items.extend(
+								<#storage_names as #frame_support::traits::TryDecodeEntireStorage>::decode_entire_state_report(select).items
+							);
 						)*
 
-						if errors.is_empty() {
-							Ok(decoded)
-						} else {
-							Err(errors)
-						}
+						report.items.extend(
+							<
+								Self as #frame_support::traits::Hooks<
+									#frame_system::pallet_prelude::BlockNumberFor::<T>
+								>
+							>::try_decode_child_tries(select)
+						);
+
+						report
 					}
 				}
 			}
 		)
 	};
 
+	let ordered_maps = def
+		.storages
+		.iter()
+		.filter(|storage| {
+			matches!(storage.metadata, Metadata::OrderedMap { .. }) && storage.cfg_attrs.is_empty()
+		})
+		.map(|storage| {
+			let ident = &storage.ident;
+			let gen = &def.type_use_generics(storage.attr_span);
+			quote::quote_spanned!(storage.attr_span => #ident<#gen> )
+		})
+		.collect::<Vec<_>>();
+
 	quote::quote!(
 		impl<#type_impl_gen> #pallet_ident<#type_use_gen>
 			#completed_where_clause
 		{
+			/// Checks the configuration of the ordered map storages of this pallet.
+			#[doc(hidden)]
+			pub fn storage_integrity_test() {
+				#( <#ordered_maps>::integrity_test(); )*
+			}
+
 			#[doc(hidden)]
 			pub fn storage_metadata() -> #frame_support::__private::metadata_ir::PalletStorageMetadataIR {
 				#frame_support::__private::metadata_ir::PalletStorageMetadataIR {
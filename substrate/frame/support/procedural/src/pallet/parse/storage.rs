@@ -156,6 +156,7 @@ pub enum Metadata {
 	DoubleMap { value: syn::Type, key1: syn::Type, key2: syn::Type },
 	NMap { keys: Vec<syn::Type>, keygen: syn::Type, value: syn::Type },
 	CountedNMap { keys: Vec<syn::Type>, keygen: syn::Type, value: syn::Type },
+	OrderedMap { value: syn::Type, key: syn::Type },
 }
 
 pub enum QueryKind {
@@ -259,6 +260,12 @@ pub enum StorageGenerics {
 		on_empty: Option<syn::Type>,
 		max_values: Option<syn::Type>,
 	},
+	OrderedMap {
+		key: syn::Type,
+		value: syn::Type,
+		node_capacity: Option<syn::Type>,
+		max_values: Option<syn::Type>,
+	},
 }
 
 impl StorageGenerics {
@@ -273,6 +280,7 @@ impl StorageGenerics {
 				Metadata::NMap { keys: collect_keys(&keygen)?, keygen, value },
 			Self::CountedNMap { keygen, value, .. } =>
 				Metadata::CountedNMap { keys: collect_keys(&keygen)?, keygen, value },
+			Self::OrderedMap { value, key, .. } => Metadata::OrderedMap { value, key },
 		};
 
 		Ok(res)
@@ -287,6 +295,7 @@ impl StorageGenerics {
 			Self::Value { query_kind, .. } |
 			Self::NMap { query_kind, .. } |
 			Self::CountedNMap { query_kind, .. } => query_kind.clone(),
+			Self::OrderedMap { .. } => None,
 		}
 	}
 }
@@ -298,6 +307,7 @@ enum StorageKind {
 	DoubleMap,
 	NMap,
 	CountedNMap,
+	OrderedMap,
 }
 
 /// Check the generics in the `map` contains the generics in `gen` may contains generics in
@@ -549,6 +559,28 @@ fn process_named_generics(
 				max_values: parsed.remove("MaxValues").map(|binding| binding.ty),
 			}
 		},
+		StorageKind::OrderedMap => {
+			check_generics(
+				&parsed,
+				&["Key", "Value"],
+				&["NodeCapacity", "MaxValues"],
+				"OrderedStorageMap",
+				args_span,
+			)?;
+
+			StorageGenerics::OrderedMap {
+				key: parsed
+					.remove("Key")
+					.map(|binding| binding.ty)
+					.expect("checked above as mandatory generic"),
+				value: parsed
+					.remove("Value")
+					.map(|binding| binding.ty)
+					.expect("checked above as mandatory generic"),
+				node_capacity: parsed.remove("NodeCapacity").map(|binding| binding.ty),
+				max_values: parsed.remove("MaxValues").map(|binding| binding.ty),
+			}
+		},
 	};
 
 	let metadata = generics.metadata()?;
@@ -644,6 +676,12 @@ fn process_unnamed_generics(
 				false,
 			)
 		},
+		StorageKind::OrderedMap => (
+			None,
+			Metadata::OrderedMap { key: retrieve_arg(1)?, value: retrieve_arg(2)? },
+			None,
+			false,
+		),
 	};
 
 	Ok(res)
@@ -661,11 +699,12 @@ fn process_generics(
 		"StorageDoubleMap" => StorageKind::DoubleMap,
 		"StorageNMap" => StorageKind::NMap,
 		"CountedStorageNMap" => StorageKind::CountedNMap,
+		"OrderedStorageMap" => StorageKind::OrderedMap,
 		found => {
 			let msg = format!(
 				"Invalid pallet::storage, expected ident: `StorageValue` or \
 				`StorageMap` or `CountedStorageMap` or `StorageDoubleMap` or `StorageNMap` or `CountedStorageNMap` \
-				in order to expand metadata, found `{}`.",
+				or `OrderedStorageMap` in order to expand metadata, found `{}`.",
 				found,
 			);
 			return Err(syn::Error::new(segment.ident.span(), msg));
@@ -926,6 +965,13 @@ impl StorageDef {
 			return Err(syn::Error::new(getter.span(), msg));
 		}
 
+		if let (Metadata::OrderedMap { .. }, Some(getter)) = (&metadata, getter.as_ref()) {
+			let msg =
+				"Invalid pallet::storage, cannot generate getter for `OrderedStorageMap`, use \
+				its `get` function instead.";
+			return Err(syn::Error::new(getter.span(), msg));
+		}
+
 		Ok(StorageDef {
 			attr_span,
 			index,
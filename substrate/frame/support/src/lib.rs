@@ -900,8 +900,9 @@ pub mod pallet_prelude {
 			bounded_btree_set::BoundedBTreeSet,
 			bounded_vec::BoundedVec,
 			types::{
-				CountedStorageMap, CountedStorageNMap, Key as NMapKey, OptionQuery, ResultQuery,
-				StorageDoubleMap, StorageMap, StorageNMap, StorageValue, ValueQuery,
+				CountedStorageMap, CountedStorageNMap, Key as NMapKey, OptionQuery,
+				OrderedStorageMap, ResultQuery, StorageDoubleMap, StorageMap, StorageNMap,
+				StorageValue, ValueQuery,
 			},
 			weak_bounded_vec::WeakBoundedVec,
 			StorageList,
@@ -2456,6 +2457,7 @@ pub mod pallet_macros {
 	/// * [`StorageDoubleMap`](crate::storage::types::StorageDoubleMap)
 	/// * [`StorageNMap`](crate::storage::types::StorageNMap)
 	/// * [`CountedStorageNMap`](crate::storage::types::CountedStorageNMap)
+	/// * [`OrderedStorageMap`](crate::storage::types::OrderedStorageMap)
 	///
 	/// ## Storage Type Usage
 	///
@@ -504,6 +504,15 @@ pub trait SteppedMigration {
 		None
 	}
 
+	/// An estimate of the number of steps that remain until the migration completes when
+	/// resuming from `cursor`.
+	///
+	/// This is only a hint to report the progress of an ongoing migration and not enforced in any
+	/// way. `None` means that the migration cannot tell.
+	fn progress(_cursor: Option<&Self::Cursor>) -> Option<u32> {
+		None
+	}
+
 	/// Try to migrate as much as possible with the given weight.
 	///
 	/// **ANY STORAGE CHANGES MUST BE ROLLED-BACK BY THE CALLER UPON ERROR.** This is necessary
@@ -701,6 +710,14 @@ pub trait SteppedMigrations {
 	/// Is guaranteed to return `Some` if `n < Self::len()`.
 	fn nth_max_steps(n: u32) -> Option<Option<u32>>;
 
+	/// The [`SteppedMigration::progress`] hint of the `n`th migration for `cursor`.
+	///
+	/// Returns `None` if the index is out of bounds, the cursor is invalid or the migration gives
+	/// no hint.
+	fn nth_progress(_n: u32, _cursor: Option<Vec<u8>>) -> Option<u32> {
+		None
+	}
+
 	/// Do a [`SteppedMigration::step`] on the `n`th migration.
 	///
 	/// Is guaranteed to return `Some` if `n < Self::len()`.
@@ -836,6 +853,20 @@ impl<T: SteppedMigration> SteppedMigrations for T {
 			.defensive_proof("nth_max_steps should only be called with n==0")
 	}
 
+	fn nth_progress(n: u32, cursor: Option<Vec<u8>>) -> Option<u32> {
+		if !n.is_zero() {
+			defensive!("nth_progress should only be called with n==0");
+			return None
+		}
+
+		let cursor = match cursor {
+			Some(cursor) => Some(T::Cursor::decode(&mut &cursor[..]).ok()?),
+			None => None,
+		};
+
+		T::progress(cursor.as_ref())
+	}
+
 	fn nth_step(
 		n: u32,
 		cursor: Option<Vec<u8>>,
@@ -1006,6 +1037,20 @@ impl SteppedMigrations for Tuple {
 		None
 	}
 
+	fn nth_progress(n: u32, cursor: Option<Vec<u8>>) -> Option<u32> {
+		let mut i = 0;
+
+		for_tuples!( #(
+			if (i + Tuple::len()) > n {
+				return Tuple::nth_progress(n - i, cursor)
+			}
+
+			i += Tuple::len();
+		)* );
+
+		None
+	}
+
 	fn cursor_max_encoded_len() -> usize {
 		let mut max_len = 0;
 
@@ -1101,6 +1146,10 @@ mod tests {
 		fn max_steps() -> Option<u32> {
 			Some(2)
 		}
+
+		fn progress(cursor: Option<&Self::Cursor>) -> Option<u32> {
+			Some(if cursor.is_some() { 1 } else { 2 })
+		}
 	}
 
 	pub struct F0;
@@ -1140,6 +1189,17 @@ mod tests {
 		assert_eq!(<(M0, M1)>::nth_max_steps(2), None);
 	}
 
+	#[test]
+	fn progress_hints_work() {
+		assert_eq!(<Triple as SteppedMigrations>::nth_progress(0, None), None);
+		assert_eq!(<Triple as SteppedMigrations>::nth_progress(2, None), Some(2));
+		assert_eq!(<Triple as SteppedMigrations>::nth_progress(2, Some(().encode())), Some(1));
+		assert_eq!(<Hextuple as SteppedMigrations>::nth_progress(5, None), Some(2));
+
+		// Out of range.
+		assert_eq!(<Triple as SteppedMigrations>::nth_progress(3, None), None);
+	}
+
 	#[test]
 	fn tuple_migrations_work() {
 		assert_eq!(<() as SteppedMigrations>::len(), 0);
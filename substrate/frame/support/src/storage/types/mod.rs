@@ -28,6 +28,7 @@ mod double_map;
 mod key;
 mod map;
 mod nmap;
+mod ordered_map;
 mod value;
 
 pub use counted_map::{CountedStorageMap, CountedStorageMapInstance, Counter};
@@ -39,6 +40,10 @@ pub use key::{
 };
 pub use map::StorageMap;
 pub use nmap::StorageNMap;
+pub use ordered_map::{
+	DefaultNodeCapacity, Node, NodeId, OrderedStorageMap, OrderedStorageMapInstance,
+	OrderedStorageMapRange, TreeMeta, MIN_NODE_CAPACITY,
+};
 pub use value::StorageValue;
 
 /// Trait implementing how the storage optional value is converted into the queried type.
@@ -0,0 +1,933 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage ordered map type.
+
+use crate::{
+	storage::{
+		bounded_vec::BoundedVec,
+		types::{OptionQuery, StorageEntryMetadataBuilder, StorageMap, StorageValue, ValueQuery},
+	},
+	traits::{ConstU32, DefensiveTruncateFrom, Get, GetDefault, StorageInfo, StorageInstance},
+	Twox64Concat,
+};
+use alloc::{vec, vec::Vec};
+use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
+use core::{
+	marker::PhantomData,
+	ops::{Bound, RangeBounds},
+};
+use scale_info::TypeInfo;
+use sp_io::MultiRemovalResults;
+use sp_metadata_ir::StorageEntryMetadataIR;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
+
+/// A map that keeps its keys ordered, to efficiently query the smallest or largest keys and all
+/// keys within a range.
+///
+/// The entries are stored in a B+ tree whose nodes are individual storage items of an underlying
+/// [`StorageMap`], and the root of the tree and the number of entries are tracked in an additional
+/// [`StorageValue`]. A node holds at most `NodeCapacity` entries or children, and all nodes but
+/// the root are at least half full.
+///
+/// Compared to a regular storage map, every access reads all the nodes from the root to the leaf
+/// of the key, which is [`OrderedStorageMap::depth`] nodes. Iterating `n` consecutive entries
+/// with [`OrderedStorageMap::range`] reads at most `depth + n / (NodeCapacity / 2)` nodes, since
+/// the leaves are linked to each other. Iteration is lazy, so the weight of an iteration can be
+/// bounded by only taking a limited number of entries and resuming from the last key later on.
+///
+/// `NodeCapacity` must be at least [`MIN_NODE_CAPACITY`] and must not be changed once the map
+/// contains entries. The integrity test of the pallet declaring the map checks the former.
+///
+/// For general information regarding the `#[pallet::storage]` attribute, refer to
+/// [`crate::pallet_macros::storage`].
+///
+/// # Examples
+///
+/// Declaring an ordered map:
+///
+/// ```
+/// #[frame_support::pallet]
+/// mod pallet {
+/// # 	use frame_support::pallet_prelude::*;
+/// # 	#[pallet::config]
+/// # 	pub trait Config: frame_system::Config {}
+/// # 	#[pallet::pallet]
+/// # 	pub struct Pallet<T>(_);
+/// 	/// The bids of an auction, ordered by their price.
+/// 	#[pallet::storage]
+/// 	pub type Bids<T: Config> = OrderedStorageMap<_, u128, T::AccountId, ConstU32<32>>;
+///
+/// 	/// Alternative named syntax.
+/// 	#[pallet::storage]
+/// 	pub type Asks<T: Config> = OrderedStorageMap<Key = u128, Value = T::AccountId>;
+/// }
+/// ```
+///
+/// Using an ordered map in action:
+#[doc = docify::embed!("src/storage/types/ordered_map.rs", test_ordered_map_works)]
+pub struct OrderedStorageMap<
+	Prefix,
+	Key,
+	Value,
+	NodeCapacity = DefaultNodeCapacity,
+	MaxValues = GetDefault,
+>(PhantomData<(Prefix, Key, Value, NodeCapacity, MaxValues)>);
+
+/// The requirement for an instance of [`OrderedStorageMap`].
+pub trait OrderedStorageMapInstance: StorageInstance {
+	/// The prefix to use for the metadata storage value.
+	type MetaPrefix: StorageInstance;
+}
+
+/// The default capacity of the nodes of an [`OrderedStorageMap`].
+pub type DefaultNodeCapacity = ConstU32<16>;
+
+/// The minimal capacity of the nodes of an [`OrderedStorageMap`].
+pub const MIN_NODE_CAPACITY: u32 = 4;
+
+/// The identifier of a node of an [`OrderedStorageMap`].
+pub type NodeId = u32;
+
+/// A node of the B+ tree of an [`OrderedStorageMap`].
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(NodeCapacity))]
+#[codec(mel_bound(Key: MaxEncodedLen, Value: MaxEncodedLen))]
+pub enum Node<Key, Value, NodeCapacity: Get<u32>> {
+	/// A leaf with the entries sorted by key, linked to the leaf with the next larger keys.
+	Leaf { entries: BoundedVec<(Key, Value), NodeCapacity>, next: Option<NodeId> },
+	/// An internal node with its children sorted by key.
+	///
+	/// The key of a child is larger than all keys of the previous child and not larger than any
+	/// key of the child itself. The key of the first child is not used.
+	Internal { children: BoundedVec<(Key, NodeId), NodeCapacity> },
+}
+
+/// The metadata of an [`OrderedStorageMap`].
+#[derive(
+	Encode, Decode, TypeInfo, MaxEncodedLen, Default, Clone, Copy, PartialEq, Eq, RuntimeDebug,
+)]
+pub struct TreeMeta {
+	/// The root node of the tree, `None` if the map is empty.
+	pub root: Option<NodeId>,
+	/// The number of levels of the tree.
+	pub depth: u32,
+	/// The number of entries in the map.
+	pub len: u32,
+	/// The identifier of the next node to create.
+	pub next_id: NodeId,
+}
+
+impl TreeMeta {
+	fn allocate(&mut self) -> NodeId {
+		let id = self.next_id;
+		self.next_id = self.next_id.wrapping_add(1);
+		id
+	}
+}
+
+/// The maximum number of nodes needed to store `MaxValues` entries.
+struct MaxNodes<NodeCapacity, MaxValues>(PhantomData<(NodeCapacity, MaxValues)>);
+
+impl<NodeCapacity: Get<u32>, MaxValues: Get<Option<u32>>> Get<Option<u32>>
+	for MaxNodes<NodeCapacity, MaxValues>
+{
+	fn get() -> Option<u32> {
+		// All leaves but the root are at least half full and there are fewer internal nodes than
+		// leaves.
+		let min_len = (NodeCapacity::get() / 2).max(1);
+		MaxValues::get().map(|values| values.div_ceil(min_len).saturating_mul(2).saturating_add(1))
+	}
+}
+
+type Nodes<P, K, V, C, M> =
+	StorageMap<P, Twox64Concat, NodeId, Node<K, V, C>, OptionQuery, GetDefault, MaxNodes<C, M>>;
+
+type MetaFor<P> = StorageValue<<P as OrderedStorageMapInstance>::MetaPrefix, TreeMeta, ValueQuery>;
+
+/// The path from the root to a leaf, as the internal nodes with the index of the visited child.
+type Path<K> = Vec<(NodeId, Vec<(K, NodeId)>, usize)>;
+
+/// Which leaf to descend to.
+enum Target<'a, K> {
+	Key(&'a K),
+	First,
+	Last,
+}
+
+impl<K> Clone for Target<'_, K> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<K> Copy for Target<'_, K> {}
+
+impl<Prefix, Key, Value, NodeCapacity, MaxValues>
+	OrderedStorageMap<Prefix, Key, Value, NodeCapacity, MaxValues>
+where
+	Prefix: OrderedStorageMapInstance,
+	Key: FullCodec + Ord + Clone,
+	Value: FullCodec,
+	NodeCapacity: Get<u32>,
+	MaxValues: Get<Option<u32>>,
+{
+	/// Get the value stored under `key`.
+	pub fn get(key: &Key) -> Option<Value> {
+		let (_, leaf_id) = Self::descend(Self::meta().root?, Target::Key(key));
+		let (mut entries, _) = Self::read_leaf(leaf_id);
+		let index = entries.binary_search_by(|(k, _)| k.cmp(key)).ok()?;
+		Some(entries.swap_remove(index).1)
+	}
+
+	/// Does the map contain a value for `key`?
+	pub fn contains_key(key: &Key) -> bool {
+		Self::get(key).is_some()
+	}
+
+	/// Store `value` under `key`, returning the previous value.
+	pub fn insert(key: Key, value: Value) -> Option<Value> {
+		let mut meta = Self::meta();
+		let Some(root) = meta.root else {
+			let id = meta.allocate();
+			Self::write_leaf(id, vec![(key, value)], None);
+			meta = TreeMeta { root: Some(id), depth: 1, len: 1, ..meta };
+			MetaFor::<Prefix>::put(meta);
+			return None
+		};
+
+		let (mut path, leaf_id) = Self::descend(root, Target::Key(&key));
+		let (mut entries, next) = Self::read_leaf(leaf_id);
+		match entries.binary_search_by(|(k, _)| k.cmp(&key)) {
+			Ok(index) => {
+				let old = core::mem::replace(&mut entries[index].1, value);
+				Self::write_leaf(leaf_id, entries, next);
+				return Some(old)
+			},
+			Err(index) => entries.insert(index, (key, value)),
+		}
+		meta.len.saturating_inc();
+
+		// Split the overflowing nodes from the leaf upwards.
+		let mut split = if entries.len() > Self::capacity() {
+			let right = entries.split_off(entries.len() / 2);
+			let right_id = meta.allocate();
+			let separator = right[0].0.clone();
+			Self::write_leaf(right_id, right, next);
+			Self::write_leaf(leaf_id, entries, Some(right_id));
+			Some((separator, right_id))
+		} else {
+			Self::write_leaf(leaf_id, entries, next);
+			None
+		};
+		while let Some((separator, right_id)) = split.take() {
+			let Some((parent_id, mut children, index)) = path.pop() else {
+				// The root was split, add a new root above it.
+				let new_root = meta.allocate();
+				Self::write_internal(
+					new_root,
+					vec![(separator.clone(), root), (separator, right_id)],
+				);
+				meta.root = Some(new_root);
+				meta.depth.saturating_inc();
+				break
+			};
+
+			children.insert(index + 1, (separator, right_id));
+			if children.len() > Self::capacity() {
+				let right = children.split_off(children.len() / 2);
+				let new_right_id = meta.allocate();
+				let separator = right[0].0.clone();
+				Self::write_internal(new_right_id, right);
+				split = Some((separator, new_right_id));
+			}
+			Self::write_internal(parent_id, children);
+		}
+
+		MetaFor::<Prefix>::put(meta);
+		None
+	}
+
+	/// Remove the value stored under `key`, returning it.
+	pub fn remove(key: &Key) -> Option<Value> {
+		Self::take(Target::Key(key)).map(|(_, value)| value)
+	}
+
+	/// The entry with the smallest key.
+	pub fn first() -> Option<(Key, Value)> {
+		Self::range(..).next()
+	}
+
+	/// The entry with the largest key.
+	pub fn last() -> Option<(Key, Value)> {
+		let (_, leaf_id) = Self::descend(Self::meta().root?, Target::Last);
+		Self::read_leaf(leaf_id).0.pop()
+	}
+
+	/// Remove the entry with the smallest key, returning it.
+	pub fn pop_first() -> Option<(Key, Value)> {
+		Self::take(Target::First)
+	}
+
+	/// Remove the entry with the largest key, returning it.
+	pub fn pop_last() -> Option<(Key, Value)> {
+		Self::take(Target::Last)
+	}
+
+	/// Iterate over the entries with a key in `range`, in ascending order of keys.
+	///
+	/// The nodes are read lazily. To bound the weight of an iteration, only take a limited number
+	/// of entries and resume later on with a range that excludes the last returned key.
+	///
+	/// If you alter the map while doing this, you'll get undefined results.
+	pub fn range<R: RangeBounds<Key>>(
+		range: R,
+	) -> OrderedStorageMapRange<Prefix, Key, Value, NodeCapacity, MaxValues> {
+		let mut iter = OrderedStorageMapRange {
+			entries: Vec::new().into_iter(),
+			next: None,
+			end: range.end_bound().cloned(),
+			_phantom: PhantomData,
+		};
+
+		if let Some(root) = Self::meta().root {
+			let target = match range.start_bound() {
+				Bound::Included(key) | Bound::Excluded(key) => Target::Key(key),
+				Bound::Unbounded => Target::First,
+			};
+			let (_, leaf_id) = Self::descend(root, target);
+			let (mut entries, next) = Self::read_leaf(leaf_id);
+			let skip = match range.start_bound() {
+				Bound::Included(start) => entries.partition_point(|(key, _)| key < start),
+				Bound::Excluded(start) => entries.partition_point(|(key, _)| key <= start),
+				Bound::Unbounded => 0,
+			};
+			iter.entries = entries.split_off(skip).into_iter();
+			iter.next = next;
+		}
+
+		iter
+	}
+
+	/// Iterate over all entries in ascending order of keys.
+	///
+	/// If you alter the map while doing this, you'll get undefined results.
+	pub fn iter() -> OrderedStorageMapRange<Prefix, Key, Value, NodeCapacity, MaxValues> {
+		Self::range(..)
+	}
+
+	/// The number of entries in the map.
+	pub fn len() -> u32 {
+		Self::meta().len
+	}
+
+	/// Is the map empty?
+	pub fn is_empty() -> bool {
+		Self::meta().root.is_none()
+	}
+
+	/// The number of nodes from the root to any leaf of the tree.
+	pub fn depth() -> u32 {
+		Self::meta().depth
+	}
+
+	/// Attempt to remove all entries from the map.
+	///
+	/// See [`StorageMap::clear`] for the meaning of `limit` and `maybe_cursor`. The metadata is
+	/// removed in any case, so the map appears empty even if not all nodes were removed.
+	pub fn clear(limit: u32, maybe_cursor: Option<&[u8]>) -> MultiRemovalResults {
+		MetaFor::<Prefix>::kill();
+		Nodes::<Prefix, Key, Value, NodeCapacity, MaxValues>::clear(limit, maybe_cursor)
+	}
+
+	/// Check that the node capacity is at least [`MIN_NODE_CAPACITY`].
+	///
+	/// This is called from the integrity test of the pallet that declares the map.
+	pub fn integrity_test() {
+		assert!(
+			NodeCapacity::get() >= MIN_NODE_CAPACITY,
+			"The node capacity of an ordered map must be at least {}, found {}",
+			MIN_NODE_CAPACITY,
+			NodeCapacity::get(),
+		);
+	}
+
+	fn meta() -> TreeMeta {
+		MetaFor::<Prefix>::get()
+	}
+
+	fn capacity() -> usize {
+		let capacity = NodeCapacity::get();
+		debug_assert!(capacity >= MIN_NODE_CAPACITY, "checked by `integrity_test`; qed");
+		capacity as usize
+	}
+
+	fn min_len() -> usize {
+		Self::capacity() / 2
+	}
+
+	fn read_node(id: NodeId) -> Node<Key, Value, NodeCapacity> {
+		Nodes::<Prefix, Key, Value, NodeCapacity, MaxValues>::get(id).unwrap_or_else(|| {
+			crate::defensive!("Node of an ordered map is referenced but missing");
+			Node::Leaf { entries: Default::default(), next: None }
+		})
+	}
+
+	fn read_leaf(id: NodeId) -> (Vec<(Key, Value)>, Option<NodeId>) {
+		match Self::read_node(id) {
+			Node::Leaf { entries, next } => (entries.into_inner(), next),
+			Node::Internal { .. } => {
+				crate::defensive!("Expected a leaf of an ordered map");
+				(Vec::new(), None)
+			},
+		}
+	}
+
+	fn read_internal(id: NodeId) -> Vec<(Key, NodeId)> {
+		match Self::read_node(id) {
+			Node::Internal { children } => children.into_inner(),
+			Node::Leaf { .. } => {
+				crate::defensive!("Expected an internal node of an ordered map");
+				Vec::new()
+			},
+		}
+	}
+
+	fn write_leaf(id: NodeId, entries: Vec<(Key, Value)>, next: Option<NodeId>) {
+		let entries = BoundedVec::defensive_truncate_from(entries);
+		Nodes::<Prefix, Key, Value, NodeCapacity, MaxValues>::insert(
+			id,
+			Node::Leaf { entries, next },
+		);
+	}
+
+	fn write_internal(id: NodeId, children: Vec<(Key, NodeId)>) {
+		let children = BoundedVec::defensive_truncate_from(children);
+		Nodes::<Prefix, Key, Value, NodeCapacity, MaxValues>::insert(
+			id,
+			Node::Internal { children },
+		);
+	}
+
+	fn remove_node(id: NodeId) {
+		Nodes::<Prefix, Key, Value, NodeCapacity, MaxValues>::remove(id);
+	}
+
+	/// Descend from `root` to the leaf of `target`, returning the path and the leaf.
+	fn descend(root: NodeId, target: Target<Key>) -> (Path<Key>, NodeId) {
+		let mut path = Vec::new();
+		let mut id = root;
+		loop {
+			let children = match Self::read_node(id) {
+				Node::Leaf { .. } => return (path, id),
+				Node::Internal { children } => children.into_inner(),
+			};
+			let index = match target {
+				Target::Key(key) =>
+					children.get(1..).map_or(0, |c| c.partition_point(|(k, _)| k <= key)),
+				Target::First => 0,
+				Target::Last => children.len().saturating_sub(1),
+			};
+			let Some((_, child)) = children.get(index) else {
+				crate::defensive!("Internal node of an ordered map without children");
+				return (path, id)
+			};
+			let child = *child;
+			path.push((id, children, index));
+			id = child;
+		}
+	}
+
+	/// Remove the entry of `target` from the tree.
+	fn take(target: Target<Key>) -> Option<(Key, Value)> {
+		let mut meta = Self::meta();
+		let (path, leaf_id) = Self::descend(meta.root?, target);
+		let (mut entries, next) = Self::read_leaf(leaf_id);
+		let index = match target {
+			Target::Key(key) => entries.binary_search_by(|(k, _)| k.cmp(key)).ok()?,
+			Target::First => 0,
+			Target::Last => entries.len().checked_sub(1)?,
+		};
+		if index >= entries.len() {
+			return None
+		}
+
+		let entry = entries.remove(index);
+		meta.len.saturating_dec();
+		Self::rebalance_leaf(&mut meta, path, leaf_id, entries, next);
+		MetaFor::<Prefix>::put(meta);
+		Some(entry)
+	}
+
+	/// Write a leaf that lost an entry, merging it with or borrowing from a sibling if needed.
+	fn rebalance_leaf(
+		meta: &mut TreeMeta,
+		mut path: Path<Key>,
+		leaf_id: NodeId,
+		mut entries: Vec<(Key, Value)>,
+		next: Option<NodeId>,
+	) {
+		let Some((parent_id, mut children, index)) = path.pop() else {
+			if entries.is_empty() {
+				Self::remove_node(leaf_id);
+				*meta = TreeMeta { root: None, depth: 0, ..*meta };
+			} else {
+				Self::write_leaf(leaf_id, entries, next);
+			}
+			return
+		};
+
+		if entries.len() >= Self::min_len() || children.len() < 2 {
+			Self::write_leaf(leaf_id, entries, next);
+			return
+		}
+
+		if index + 1 < children.len() {
+			let right_id = children[index + 1].1;
+			let (mut right, right_next) = Self::read_leaf(right_id);
+			if right.len() > Self::min_len() {
+				entries.push(right.remove(0));
+				children[index + 1].0 = right[0].0.clone();
+				Self::write_leaf(leaf_id, entries, next);
+				Self::write_leaf(right_id, right, right_next);
+				Self::write_internal(parent_id, children);
+				return
+			}
+
+			entries.append(&mut right);
+			Self::write_leaf(leaf_id, entries, right_next);
+			Self::remove_node(right_id);
+			children.remove(index + 1);
+		} else {
+			let left_id = children[index - 1].1;
+			let (mut left, left_next) = Self::read_leaf(left_id);
+			if left.len() > Self::min_len() {
+				let moved = left.pop().expect("Longer than the minimum length; qed");
+				children[index].0 = moved.0.clone();
+				entries.insert(0, moved);
+				Self::write_leaf(leaf_id, entries, next);
+				Self::write_leaf(left_id, left, left_next);
+				Self::write_internal(parent_id, children);
+				return
+			}
+
+			left.append(&mut entries);
+			Self::write_leaf(left_id, left, next);
+			Self::remove_node(leaf_id);
+			children.remove(index);
+		}
+
+		Self::rebalance_internal(meta, path, parent_id, children);
+	}
+
+	/// Write an internal node that lost a child, merging it with or borrowing from a sibling if
+	/// needed.
+	fn rebalance_internal(
+		meta: &mut TreeMeta,
+		mut path: Path<Key>,
+		node_id: NodeId,
+		mut children: Vec<(Key, NodeId)>,
+	) {
+		let Some((parent_id, mut parent_children, index)) = path.pop() else {
+			// The root is replaced by its only child, which shrinks the tree by one level.
+			if children.len() == 1 {
+				Self::remove_node(node_id);
+				meta.root = Some(children[0].1);
+				meta.depth.saturating_dec();
+			} else {
+				Self::write_internal(node_id, children);
+			}
+			return
+		};
+
+		if children.len() >= Self::min_len() || parent_children.len() < 2 || children.is_empty() {
+			Self::write_internal(node_id, children);
+			return
+		}
+
+		if index + 1 < parent_children.len() {
+			let (right_separator, right_id) = parent_children[index + 1].clone();
+			let mut right = Self::read_internal(right_id);
+			if right.len() > Self::min_len() {
+				let (_, child) = right.remove(0);
+				children.push((right_separator, child));
+				parent_children[index + 1].0 = right[0].0.clone();
+				Self::write_internal(node_id, children);
+				Self::write_internal(right_id, right);
+				Self::write_internal(parent_id, parent_children);
+				return
+			}
+
+			if let Some(first) = right.first_mut() {
+				first.0 = right_separator;
+			}
+			children.append(&mut right);
+			Self::write_internal(node_id, children);
+			Self::remove_node(right_id);
+			parent_children.remove(index + 1);
+		} else {
+			let separator = parent_children[index].0.clone();
+			let left_id = parent_children[index - 1].1;
+			let mut left = Self::read_internal(left_id);
+			children[0].0 = separator;
+			if left.len() > Self::min_len() {
+				let moved = left.pop().expect("Longer than the minimum length; qed");
+				parent_children[index].0 = moved.0.clone();
+				children.insert(0, moved);
+				Self::write_internal(node_id, children);
+				Self::write_internal(left_id, left);
+				Self::write_internal(parent_id, parent_children);
+				return
+			}
+
+			left.append(&mut children);
+			Self::write_internal(left_id, left);
+			Self::remove_node(node_id);
+			parent_children.remove(index);
+		}
+
+		Self::rebalance_internal(meta, path, parent_id, parent_children);
+	}
+}
+
+/// An iterator over a range of the entries of an [`OrderedStorageMap`], in ascending order of
+/// keys.
+pub struct OrderedStorageMapRange<Prefix, Key, Value, NodeCapacity, MaxValues> {
+	entries: alloc::vec::IntoIter<(Key, Value)>,
+	next: Option<NodeId>,
+	end: Bound<Key>,
+	_phantom: PhantomData<(Prefix, NodeCapacity, MaxValues)>,
+}
+
+impl<Prefix, Key, Value, NodeCapacity, MaxValues> Iterator
+	for OrderedStorageMapRange<Prefix, Key, Value, NodeCapacity, MaxValues>
+where
+	Prefix: OrderedStorageMapInstance,
+	Key: FullCodec + Ord + Clone,
+	Value: FullCodec,
+	NodeCapacity: Get<u32>,
+	MaxValues: Get<Option<u32>>,
+{
+	type Item = (Key, Value);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some((key, value)) = self.entries.next() {
+				let in_range = match &self.end {
+					Bound::Included(end) => key <= *end,
+					Bound::Excluded(end) => key < *end,
+					Bound::Unbounded => true,
+				};
+				if !in_range {
+					self.entries = Vec::new().into_iter();
+					self.next = None;
+					return None
+				}
+				return Some((key, value))
+			}
+
+			let (entries, next) =
+				OrderedStorageMap::<Prefix, Key, Value, NodeCapacity, MaxValues>::read_leaf(
+					self.next.take()?,
+				);
+			self.entries = entries.into_iter();
+			self.next = next;
+		}
+	}
+}
+
+impl<Prefix, Key, Value, NodeCapacity, MaxValues> StorageEntryMetadataBuilder
+	for OrderedStorageMap<Prefix, Key, Value, NodeCapacity, MaxValues>
+where
+	Prefix: OrderedStorageMapInstance,
+	Key: FullCodec + scale_info::StaticTypeInfo,
+	Value: FullCodec + scale_info::StaticTypeInfo,
+	NodeCapacity: Get<u32> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	fn build_metadata(
+		deprecation_status: sp_metadata_ir::DeprecationStatusIR,
+		docs: Vec<&'static str>,
+		entries: &mut Vec<StorageEntryMetadataIR>,
+	) {
+		Nodes::<Prefix, Key, Value, NodeCapacity, MaxValues>::build_metadata(
+			deprecation_status.clone(),
+			docs,
+			entries,
+		);
+		MetaFor::<Prefix>::build_metadata(
+			deprecation_status,
+			if cfg!(feature = "no-metadata-docs") {
+				vec![]
+			} else {
+				vec!["Metadata of the related ordered storage map"]
+			},
+			entries,
+		);
+	}
+}
+
+impl<Prefix, Key, Value, NodeCapacity, MaxValues> crate::traits::StorageInfoTrait
+	for OrderedStorageMap<Prefix, Key, Value, NodeCapacity, MaxValues>
+where
+	Prefix: OrderedStorageMapInstance,
+	Key: FullCodec + MaxEncodedLen,
+	Value: FullCodec + MaxEncodedLen,
+	NodeCapacity: Get<u32>,
+	MaxValues: Get<Option<u32>>,
+{
+	fn storage_info() -> Vec<StorageInfo> {
+		[
+			Nodes::<Prefix, Key, Value, NodeCapacity, MaxValues>::storage_info(),
+			MetaFor::<Prefix>::storage_info(),
+		]
+		.concat()
+	}
+}
+
+/// It doesn't require to implement `MaxEncodedLen` and give no information for `max_size`.
+impl<Prefix, Key, Value, NodeCapacity, MaxValues> crate::traits::PartialStorageInfoTrait
+	for OrderedStorageMap<Prefix, Key, Value, NodeCapacity, MaxValues>
+where
+	Prefix: OrderedStorageMapInstance,
+	Key: FullCodec,
+	Value: FullCodec,
+	NodeCapacity: Get<u32>,
+	MaxValues: Get<Option<u32>>,
+{
+	fn partial_storage_info() -> Vec<StorageInfo> {
+		[
+			Nodes::<Prefix, Key, Value, NodeCapacity, MaxValues>::partial_storage_info(),
+			MetaFor::<Prefix>::storage_info(),
+		]
+		.concat()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::traits::StorageInfoTrait;
+	use sp_io::TestExternalities;
+
+	struct Prefix;
+	impl StorageInstance for Prefix {
+		fn pallet_prefix() -> &'static str {
+			"test"
+		}
+		const STORAGE_PREFIX: &'static str = "foo";
+	}
+
+	struct MetaPrefix;
+	impl StorageInstance for MetaPrefix {
+		fn pallet_prefix() -> &'static str {
+			"test"
+		}
+		const STORAGE_PREFIX: &'static str = "meta_for_foo";
+	}
+	impl OrderedStorageMapInstance for Prefix {
+		type MetaPrefix = MetaPrefix;
+	}
+
+	type Map = OrderedStorageMap<Prefix, u32, u64, ConstU32<4>>;
+
+	#[docify::export]
+	#[test]
+	fn test_ordered_map_works() {
+		TestExternalities::default().execute_with(|| {
+			type Bids = OrderedStorageMap<Prefix, u32, u64>;
+
+			for price in [30, 10, 20, 40] {
+				assert_eq!(Bids::insert(price, price as u64 * 2), None);
+			}
+			assert_eq!(Bids::len(), 4);
+
+			// The entries are ordered by key.
+			assert_eq!(Bids::first(), Some((10, 20)));
+			assert_eq!(Bids::last(), Some((40, 80)));
+			assert_eq!(Bids::range(15..40).collect::<Vec<_>>(), vec![(20, 40), (30, 60)]);
+
+			assert_eq!(Bids::pop_first(), Some((10, 20)));
+			assert_eq!(Bids::remove(&30), Some(60));
+			assert_eq!(Bids::iter().map(|(k, _)| k).collect::<Vec<_>>(), vec![20, 40]);
+		});
+	}
+
+	#[test]
+	fn integrity_test_accepts_valid_node_capacity() {
+		Map::integrity_test();
+		OrderedStorageMap::<Prefix, u32, u64>::integrity_test();
+	}
+
+	#[test]
+	#[should_panic(expected = "The node capacity of an ordered map must be at least 4, found 3")]
+	fn integrity_test_rejects_small_node_capacity() {
+		OrderedStorageMap::<Prefix, u32, u64, ConstU32<3>>::integrity_test();
+	}
+
+	/// Check the invariants of the tree and return its entries.
+	fn check_tree() -> Vec<(u32, u64)> {
+		let meta = MetaFor::<Prefix>::get();
+		let Some(root) = meta.root else {
+			assert_eq!(meta, TreeMeta { next_id: meta.next_id, ..Default::default() });
+			return vec![]
+		};
+
+		// Collect the leaves from left to right, checking the depth and fill of every node.
+		fn walk(
+			id: NodeId,
+			level: u32,
+			is_root: bool,
+			bounds: (Option<u32>, Option<u32>),
+			leaves: &mut Vec<(NodeId, u32)>,
+		) {
+			match Nodes::<Prefix, u32, u64, ConstU32<4>, GetDefault>::get(id).unwrap() {
+				Node::Leaf { entries, .. } => {
+					assert!(is_root || entries.len() >= 2);
+					assert!(!entries.is_empty());
+					for (key, _) in entries.iter() {
+						assert!(bounds.0.map_or(true, |low| *key >= low));
+						assert!(bounds.1.map_or(true, |high| *key < high));
+					}
+					leaves.push((id, level));
+				},
+				Node::Internal { children } => {
+					assert!(children.len() >= 2);
+					for (i, (_, child)) in children.iter().enumerate() {
+						let low = if i == 0 { bounds.0 } else { Some(children[i].0) };
+						let high = children.get(i + 1).map(|(k, _)| *k).or(bounds.1);
+						walk(*child, level + 1, false, (low, high), leaves);
+					}
+				},
+			}
+		}
+
+		let mut leaves = Vec::new();
+		walk(root, 1, true, (None, None), &mut leaves);
+		assert!(leaves.iter().all(|(_, level)| *level == meta.depth));
+
+		// The linked leaves yield all entries in order.
+		let entries = Map::iter().collect::<Vec<_>>();
+		assert!(entries.windows(2).all(|w| w[0].0 < w[1].0));
+		assert_eq!(entries.len() as u32, meta.len);
+		let linked = leaves.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+		let mut next = Some(linked[0]);
+		for id in linked {
+			assert_eq!(next, Some(id));
+			next = match Nodes::<Prefix, u32, u64, ConstU32<4>, GetDefault>::get(id).unwrap() {
+				Node::Leaf { next, .. } => next,
+				Node::Internal { .. } => unreachable!(),
+			};
+		}
+		assert_eq!(next, None);
+
+		entries
+	}
+
+	#[test]
+	fn insert_and_remove_keep_the_tree_balanced() {
+		TestExternalities::default().execute_with(|| {
+			let mut expected = alloc::collections::BTreeMap::new();
+			// A simple pseudo random sequence that inserts and removes keys.
+			let mut seed = 7u32;
+			for _ in 0..2000 {
+				seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+				let key = (seed >> 16) % 200;
+				if seed % 3 == 0 {
+					assert_eq!(Map::remove(&key), expected.remove(&key));
+				} else {
+					assert_eq!(Map::insert(key, seed as u64), expected.insert(key, seed as u64));
+				}
+			}
+			assert_eq!(check_tree(), expected.clone().into_iter().collect::<Vec<_>>());
+			assert!(Map::depth() > 2);
+
+			while let Some((key, value)) = Map::pop_last() {
+				assert_eq!(expected.pop_last(), Some((key, value)));
+				if key % 16 == 0 {
+					check_tree();
+				}
+			}
+			assert!(Map::is_empty());
+			assert_eq!(check_tree(), vec![]);
+			// All nodes were removed.
+			assert_eq!(Nodes::<Prefix, u32, u64, ConstU32<4>, GetDefault>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn queries_work() {
+		TestExternalities::default().execute_with(|| {
+			assert_eq!(Map::first(), None);
+			assert_eq!(Map::last(), None);
+			assert_eq!(Map::pop_first(), None);
+			assert_eq!(Map::range(..).next(), None);
+
+			for key in (0..100).rev() {
+				Map::insert(key * 2, key as u64);
+			}
+			assert_eq!(Map::get(&42), Some(21));
+			assert_eq!(Map::get(&43), None);
+			assert!(!Map::contains_key(&199));
+			assert_eq!(Map::insert(42, 0), Some(21));
+			assert_eq!(Map::len(), 100);
+
+			let keys = |range: (Bound<u32>, Bound<u32>)| {
+				Map::range(range).map(|(k, _)| k).collect::<Vec<_>>()
+			};
+			assert_eq!(keys((Bound::Included(10), Bound::Excluded(16))), vec![10, 12, 14]);
+			assert_eq!(keys((Bound::Excluded(10), Bound::Included(16))), vec![12, 14, 16]);
+			assert_eq!(keys((Bound::Excluded(11), Bound::Excluded(13))), vec![12]);
+			assert_eq!(keys((Bound::Included(195), Bound::Unbounded)), vec![196, 198]);
+			assert_eq!(keys((Bound::Included(300), Bound::Unbounded)), Vec::<u32>::new());
+
+			// Iteration can be resumed after the last key.
+			let page = Map::range(..).take(30).collect::<Vec<_>>();
+			let rest = Map::range((Bound::Excluded(page[29].0), Bound::Unbounded));
+			assert_eq!(page.len() + rest.count(), 100);
+
+			assert_eq!(Map::pop_first(), Some((0, 0)));
+			assert_eq!(Map::first(), Some((2, 1)));
+			assert_eq!(Map::last(), Some((198, 99)));
+			check_tree();
+
+			let _ = Map::clear(u32::MAX, None);
+			assert!(Map::is_empty());
+			assert_eq!(Map::len(), 0);
+			assert_eq!(Map::first(), None);
+		});
+	}
+
+	#[test]
+	fn storage_info_works() {
+		type Bounded = OrderedStorageMap<Prefix, u32, u64, ConstU32<4>, ConstU32Option<100>>;
+		let info = Bounded::storage_info();
+		assert_eq!(info.len(), 2);
+		// Up to two nodes per 2 entries, plus the root.
+		assert_eq!(info[0].max_values, Some(101));
+		// The hashed key, the node variant, the length and the entries, and the next leaf.
+		assert_eq!(info[0].max_size, Some(8 + 4 + 1 + 1 + 4 * 12 + 5));
+		assert_eq!(info[1].max_values, Some(1));
+	}
+
+	struct ConstU32Option<const N: u32>;
+	impl<const N: u32> Get<Option<u32>> for ConstU32Option<N> {
+		fn get() -> Option<u32> {
+			Some(N)
+		}
+	}
+}
@@ -141,6 +141,6 @@ pub use proving::*;
 mod try_runtime;
 #[cfg(feature = "try-runtime")]
 pub use try_runtime::{
-	Select as TryStateSelect, TryDecodeEntireStorage, TryDecodeEntireStorageError, TryState,
-	UpgradeCheckSelect,
+	DecodeEntireStateReport, DecodeSelect, Select as TryStateSelect, StorageItemDecodeReport,
+	TryDecodeEntireStorage, TryDecodeEntireStorageError, TryState, UpgradeCheckSelect,
 };
@@ -26,6 +26,8 @@ use impl_trait_for_tuples::impl_for_tuples;
 use sp_runtime::traits::AtLeast32BitUnsigned;
 use sp_weights::WeightMeter;
 
+#[cfg(feature = "try-runtime")]
+use super::{DecodeSelect, StorageItemDecodeReport};
 #[cfg(feature = "try-runtime")]
 use alloc::vec::Vec;
 #[cfg(feature = "try-runtime")]
@@ -519,6 +521,18 @@ pub trait Hooks<BlockNumber> {
 		Ok(())
 	}
 
+	/// Decode the data that this pallet keeps in child tries.
+	///
+	/// The storage items of a pallet are decoded automatically, but they only cover the main trie.
+	/// Pallets that keep data in child tries should report on the items included in `select`
+	/// here, usually with [`StorageItemDecodeReport::decode_child_trie`].
+	///
+	/// This hook is never executed on-chain but instead used by testing tools.
+	#[cfg(feature = "try-runtime")]
+	fn try_decode_child_tries(_select: &DecodeSelect) -> Vec<StorageItemDecodeReport> {
+		Vec::new()
+	}
+
 	/// Execute some pre-checks prior to a runtime upgrade.
 	///
 	/// Return a `Vec<u8>` that can contain arbitrary encoded data (usually some pre-upgrade state),
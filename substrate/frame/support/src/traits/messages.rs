@@ -64,6 +64,18 @@ pub trait ProcessMessage {
 		meter: &mut WeightMeter,
 		id: &mut [u8; 32],
 	) -> Result<bool, ProcessMessageError>;
+
+	/// Called after [`Self::process_message`] failed permanently with `error` and its storage
+	/// changes were rolled back.
+	///
+	/// Storage changes made here are kept. Any weight used must be consumed from `meter`.
+	fn process_message_failed(
+		_origin: Self::Origin,
+		_meter: &mut WeightMeter,
+		_id: &[u8; 32],
+		_error: ProcessMessageError,
+	) {
+	}
 }
 
 /// Errors that can happen when attempting to execute an overweight message with
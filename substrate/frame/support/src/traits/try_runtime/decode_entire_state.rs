@@ -19,9 +19,12 @@
 
 use super::StorageInstance;
 use crate::{
-	storage::types::{
-		CountedStorageMapInstance, CountedStorageNMapInstance, Counter, KeyGenerator,
-		QueryKindTrait,
+	storage::{
+		child::ChildInfo,
+		types::{
+			CountedStorageMapInstance, CountedStorageNMapInstance, Counter, KeyGenerator, Node,
+			OrderedStorageMapInstance, QueryKindTrait, TreeMeta,
+		},
 	},
 	traits::{PartialStorageInfoTrait, StorageInfo},
 	StorageHasher,
@@ -30,17 +33,54 @@ use alloc::{vec, vec::Vec};
 use codec::{Decode, DecodeAll, FullCodec};
 use impl_trait_for_tuples::impl_for_tuples;
 use sp_core::Get;
+use sp_runtime::Saturating;
+
+/// The maximal number of undecodable keys that are reported per storage item.
+///
+/// The total number of undecodable keys is still counted in
+/// [`StorageItemDecodeReport::failures`].
+pub const MAX_FAILED_KEY_SAMPLES: usize = 16;
 
 /// Decode the entire data under the given storage type.
 ///
 /// For values, this is trivial. For all kinds of maps, it should decode all the values associated
 /// with all keys existing in the map.
 ///
-/// Tuple implementations are provided and simply decode each type in the tuple, summing up the
-/// decoded bytes if `Ok(_)`.
+/// Tuple implementations are provided and simply decode each type in the tuple, concatenating the
+/// reports of all storage items.
 pub trait TryDecodeEntireStorage {
 	/// Decode the entire data under the given storage, returning `Ok(bytes_decoded)` if success.
 	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>>;
+
+	/// Decode the data of all storage items that are included in `select`.
+	///
+	/// Undecodable values do not stop the decoding, instead they are reported per storage item.
+	///
+	/// The default implementation decodes everything with [`Self::try_decode_entire_state`]. As
+	/// that does not tell which storage items were decoded, only the selected storage items with
+	/// undecodable values are reported.
+	fn decode_entire_state_report(select: &DecodeSelect) -> DecodeEntireStateReport {
+		let mut report = DecodeEntireStateReport::default();
+		let Err(errors) = Self::try_decode_entire_state() else { return report };
+
+		for error in errors.into_iter().filter(|error| select.includes(&error.info)) {
+			let index = match report.items.iter().position(|item| item.info == error.info) {
+				Some(index) => index,
+				None => {
+					report.items.push(StorageItemDecodeReport::new(error.info.clone()));
+					report.items.len() - 1
+				},
+			};
+			let item = &mut report.items[index];
+			item.keys.saturating_inc();
+			item.failures.saturating_inc();
+			if item.failed.len() < MAX_FAILED_KEY_SAMPLES {
+				item.failed.push(error);
+			}
+		}
+
+		report
+	}
 }
 
 #[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
@@ -48,26 +88,211 @@ pub trait TryDecodeEntireStorage {
 #[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
 impl TryDecodeEntireStorage for Tuple {
 	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
-		let mut errors = Vec::new();
-		let mut len = 0usize;
+		Self::decode_entire_state_report(&DecodeSelect::All).into_result()
+	}
+
+	fn decode_entire_state_report(select: &DecodeSelect) -> DecodeEntireStateReport {
+		let mut report = DecodeEntireStateReport::default();
 
 		for_tuples!(#(
-			match Tuple::try_decode_entire_state() {
-				Ok(bytes) => len += bytes,
-				Err(errs) => errors.extend(errs),
-			}
+			report.items.extend(Tuple::decode_entire_state_report(select).items);
 		)*);
 
-		if errors.is_empty() {
-			Ok(len)
+		report
+	}
+}
+
+/// Which storage items to decode.
+#[derive(codec::Encode, codec::Decode, Clone, scale_info::TypeInfo, PartialEq, Eq)]
+pub enum DecodeSelect {
+	/// All storage items of all pallets.
+	All,
+	/// All storage items of the pallet with the given name.
+	///
+	/// Pallet names are obtained from [`super::PalletInfoAccess`].
+	Pallet(Vec<u8>),
+	/// A single storage item of a pallet.
+	Item {
+		/// The name of the pallet.
+		pallet: Vec<u8>,
+		/// The name of the storage item.
+		storage: Vec<u8>,
+	},
+}
+
+impl DecodeSelect {
+	/// Whether any storage item of the pallet `pallet_name` is selected.
+	pub fn includes_pallet(&self, pallet_name: &[u8]) -> bool {
+		match self {
+			Self::All => true,
+			Self::Pallet(pallet) | Self::Item { pallet, .. } => pallet == pallet_name,
+		}
+	}
+
+	/// Whether the storage item described by `info` is selected.
+	pub fn includes(&self, info: &StorageInfo) -> bool {
+		match self {
+			Self::Item { pallet, storage } =>
+				*pallet == info.pallet_name && *storage == info.storage_name,
+			_ => self.includes_pallet(&info.pallet_name),
+		}
+	}
+}
+
+impl Default for DecodeSelect {
+	fn default() -> Self {
+		Self::All
+	}
+}
+
+impl core::fmt::Debug for DecodeSelect {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		fn name(x: &[u8]) -> &str {
+			alloc::str::from_utf8(x).unwrap_or("<invalid?>")
+		}
+		match self {
+			Self::All => write!(f, "All"),
+			Self::Pallet(pallet) => write!(f, "Pallet({})", name(pallet)),
+			Self::Item { pallet, storage } =>
+				write!(f, "Item({}::{})", name(pallet), name(storage)),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::str::FromStr for DecodeSelect {
+	type Err = &'static str;
+
+	/// Parses `all`, `Pallet` or `Pallet::Item`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"" => Err("empty selection"),
+			"all" | "All" => Ok(Self::All),
+			_ => match s.split_once("::") {
+				Some((pallet, storage)) => Ok(Self::Item {
+					pallet: pallet.as_bytes().to_vec(),
+					storage: storage.as_bytes().to_vec(),
+				}),
+				None => Ok(Self::Pallet(s.as_bytes().to_vec())),
+			},
+		}
+	}
+}
+
+/// The outcome of decoding a set of storage items.
+#[derive(
+	codec::Encode, codec::Decode, Clone, PartialEq, Eq, Debug, Default, scale_info::TypeInfo,
+)]
+pub struct DecodeEntireStateReport {
+	/// The report of each decoded storage item.
+	pub items: Vec<StorageItemDecodeReport>,
+}
+
+impl DecodeEntireStateReport {
+	/// The number of values that were read.
+	pub fn keys(&self) -> u64 {
+		self.items.iter().map(|item| item.keys as u64).sum()
+	}
+
+	/// The number of bytes that were successfully decoded.
+	pub fn bytes(&self) -> u64 {
+		self.items.iter().map(|item| item.bytes).sum()
+	}
+
+	/// The number of values that could not be decoded.
+	pub fn failures(&self) -> u64 {
+		self.items.iter().map(|item| item.failures as u64).sum()
+	}
+
+	/// Whether all values could be decoded.
+	pub fn is_ok(&self) -> bool {
+		self.items.iter().all(|item| item.failures == 0)
+	}
+
+	/// Convert into the number of decoded bytes, or the sampled errors if any value could not be
+	/// decoded.
+	pub fn into_result(self) -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		if self.is_ok() {
+			Ok(self.bytes() as usize)
 		} else {
-			Err(errors)
+			Err(self.items.into_iter().flat_map(|item| item.failed).collect())
 		}
 	}
 }
 
+/// The outcome of decoding a single storage item.
+#[derive(codec::Encode, codec::Decode, Clone, PartialEq, Eq, Debug, scale_info::TypeInfo)]
+pub struct StorageItemDecodeReport {
+	/// The storage info of the item.
+	pub info: StorageInfo,
+	/// The number of values that were read.
+	pub keys: u32,
+	/// The number of bytes that were successfully decoded.
+	pub bytes: u64,
+	/// The number of values that could not be decoded.
+	pub failures: u32,
+	/// The first [`MAX_FAILED_KEY_SAMPLES`] values that could not be decoded.
+	pub failed: Vec<TryDecodeEntireStorageError>,
+}
+
+impl StorageItemDecodeReport {
+	/// An empty report for the storage item described by `info`.
+	pub fn new(info: StorageInfo) -> Self {
+		Self { info, keys: 0, bytes: 0, failures: 0, failed: Vec::new() }
+	}
+
+	/// Try to decode `raw`, the value under `key`, as `V` and record the outcome.
+	pub fn decode_value<V: Decode>(&mut self, key: &[u8], raw: Vec<u8>) {
+		self.keys.saturating_inc();
+		if <V as DecodeAll>::decode_all(&mut raw.as_ref()).is_ok() {
+			self.bytes.saturating_accrue(raw.len() as u64);
+			return
+		}
+
+		self.failures.saturating_inc();
+		if self.failed.len() < MAX_FAILED_KEY_SAMPLES {
+			self.failed.push(TryDecodeEntireStorageError {
+				key: key.to_vec(),
+				raw: Some(raw),
+				info: self.info.clone(),
+			});
+		}
+	}
+
+	/// Try to decode all the values in the child trie `child_info` as `V` and record the outcome.
+	///
+	/// FRAME storage types only live in the main trie, pallets report on the data they keep in
+	/// child tries from [`crate::traits::Hooks::try_decode_child_tries`]. The keys of undecodable
+	/// values are reported relative to the child trie.
+	pub fn decode_child_trie<V: Decode>(&mut self, child_info: &ChildInfo) {
+		let storage_key = child_info.storage_key();
+
+		let mut next_key = Some(Vec::new());
+		while let Some(key) = next_key {
+			if let Some(raw) = sp_io::default_child_storage::get(storage_key, &key) {
+				self.decode_value::<V>(&key, raw);
+			}
+			next_key = sp_io::default_child_storage::next_key(storage_key, &key);
+		}
+	}
+}
+
+impl core::fmt::Display for StorageItemDecodeReport {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(
+			f,
+			"`{}::{}`: {} keys, {} bytes decoded, {} undecodable",
+			&alloc::str::from_utf8(&self.info.pallet_name).unwrap_or("<invalid>"),
+			&alloc::str::from_utf8(&self.info.storage_name).unwrap_or("<invalid>"),
+			self.keys,
+			self.bytes,
+			self.failures,
+		)
+	}
+}
+
 /// A value could not be decoded.
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, codec::Encode, codec::Decode, scale_info::TypeInfo)]
 pub struct TryDecodeEntireStorageError {
 	/// The key of the undecodable value.
 	pub key: Vec<u8>,
@@ -106,49 +331,24 @@ impl core::fmt::Debug for TryDecodeEntireStorageError {
 /// Basically, it decodes and sums up all the values who's key start with `info.prefix`. For values,
 /// this would be the value itself. For all sorts of maps, this should be all map items in the
 /// absence of key collision.
-fn decode_storage_info<V: Decode>(
-	info: StorageInfo,
-) -> Result<usize, Vec<TryDecodeEntireStorageError>> {
-	let mut decoded = 0;
-
-	let decode_key = |key: &[u8]| match sp_io::storage::get(key) {
-		None => Ok(0),
-		Some(bytes) => {
-			let len = bytes.len();
-			let _ = <V as DecodeAll>::decode_all(&mut bytes.as_ref()).map_err(|_| {
-				TryDecodeEntireStorageError {
-					key: key.to_vec(),
-					raw: Some(bytes.to_vec()),
-					info: info.clone(),
-				}
-			})?;
+fn decode_storage_info<V: Decode>(info: StorageInfo) -> StorageItemDecodeReport {
+	let prefix = info.prefix.clone();
+	let mut report = StorageItemDecodeReport::new(info);
 
-			Ok::<usize, _>(len)
-		},
-	};
-
-	let mut errors = vec![];
-	let mut next_key = Some(info.prefix.clone());
+	let mut next_key = Some(prefix.clone());
 	loop {
 		match next_key {
-			Some(key) if key.starts_with(&info.prefix) => {
-				match decode_key(&key) {
-					Ok(bytes) => {
-						decoded += bytes;
-					},
-					Err(e) => errors.push(e),
-				};
+			Some(key) if key.starts_with(&prefix) => {
+				if let Some(raw) = sp_io::storage::get(&key) {
+					report.decode_value::<V>(&key, raw.to_vec());
+				}
 				next_key = sp_io::storage::next_key(&key);
 			},
 			_ => break,
 		}
 	}
 
-	if errors.is_empty() {
-		Ok(decoded)
-	} else {
-		Err(errors)
-	}
+	report
 }
 
 impl<Prefix, Value, QueryKind, OnEmpty> TryDecodeEntireStorage
@@ -160,11 +360,18 @@ where
 	OnEmpty: Get<QueryKind::Query> + 'static,
 {
 	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		Self::decode_entire_state_report(&DecodeSelect::All).into_result()
+	}
+
+	fn decode_entire_state_report(select: &DecodeSelect) -> DecodeEntireStateReport {
 		let info = Self::partial_storage_info()
 			.first()
 			.cloned()
 			.expect("Value has only one storage info; qed");
-		decode_storage_info::<Value>(info)
+		if !select.includes(&info) {
+			return Default::default()
+		}
+		DecodeEntireStateReport { items: vec![decode_storage_info::<Value>(info)] }
 	}
 }
 
@@ -180,11 +387,18 @@ where
 	MaxValues: Get<Option<u32>>,
 {
 	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		Self::decode_entire_state_report(&DecodeSelect::All).into_result()
+	}
+
+	fn decode_entire_state_report(select: &DecodeSelect) -> DecodeEntireStateReport {
 		let info = Self::partial_storage_info()
 			.first()
 			.cloned()
 			.expect("Map has only one storage info; qed");
-		decode_storage_info::<Value>(info)
+		if !select.includes(&info) {
+			return Default::default()
+		}
+		DecodeEntireStateReport { items: vec![decode_storage_info::<Value>(info)] }
 	}
 }
 
@@ -208,13 +422,24 @@ where
 	MaxValues: Get<Option<u32>>,
 {
 	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		Self::decode_entire_state_report(&DecodeSelect::All).into_result()
+	}
+
+	fn decode_entire_state_report(select: &DecodeSelect) -> DecodeEntireStateReport {
 		let (map_info, counter_info) = match &Self::partial_storage_info()[..] {
 			[a, b] => (a.clone(), b.clone()),
 			_ => panic!("Counted map has two storage info items; qed"),
 		};
-		let mut decoded = decode_storage_info::<Counter>(counter_info)?;
-		decoded += decode_storage_info::<Value>(map_info)?;
-		Ok(decoded)
+		if !select.includes(&map_info) {
+			return Default::default()
+		}
+
+		DecodeEntireStateReport {
+			items: vec![
+				decode_storage_info::<Counter>(counter_info),
+				decode_storage_info::<Value>(map_info),
+			],
+		}
 	}
 }
 
@@ -243,11 +468,18 @@ where
 	MaxValues: Get<Option<u32>>,
 {
 	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		Self::decode_entire_state_report(&DecodeSelect::All).into_result()
+	}
+
+	fn decode_entire_state_report(select: &DecodeSelect) -> DecodeEntireStateReport {
 		let info = Self::partial_storage_info()
 			.first()
 			.cloned()
 			.expect("Double-map has only one storage info; qed");
-		decode_storage_info::<Value>(info)
+		if !select.includes(&info) {
+			return Default::default()
+		}
+		DecodeEntireStateReport { items: vec![decode_storage_info::<Value>(info)] }
 	}
 }
 
@@ -262,11 +494,18 @@ where
 	MaxValues: Get<Option<u32>>,
 {
 	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		Self::decode_entire_state_report(&DecodeSelect::All).into_result()
+	}
+
+	fn decode_entire_state_report(select: &DecodeSelect) -> DecodeEntireStateReport {
 		let info = Self::partial_storage_info()
 			.first()
 			.cloned()
 			.expect("N-map has only one storage info; qed");
-		decode_storage_info::<Value>(info)
+		if !select.includes(&info) {
+			return Default::default()
+		}
+		DecodeEntireStateReport { items: vec![decode_storage_info::<Value>(info)] }
 	}
 }
 
@@ -281,14 +520,55 @@ where
 	MaxValues: Get<Option<u32>>,
 {
 	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		Self::decode_entire_state_report(&DecodeSelect::All).into_result()
+	}
+
+	fn decode_entire_state_report(select: &DecodeSelect) -> DecodeEntireStateReport {
 		let (map_info, counter_info) = match &Self::partial_storage_info()[..] {
 			[a, b] => (a.clone(), b.clone()),
 			_ => panic!("Counted NMap has two storage info items; qed"),
 		};
+		if !select.includes(&map_info) {
+			return Default::default()
+		}
+
+		DecodeEntireStateReport {
+			items: vec![
+				decode_storage_info::<Counter>(counter_info),
+				decode_storage_info::<Value>(map_info),
+			],
+		}
+	}
+}
+
+impl<Prefix, Key, Value, NodeCapacity, MaxValues> TryDecodeEntireStorage
+	for crate::storage::types::OrderedStorageMap<Prefix, Key, Value, NodeCapacity, MaxValues>
+where
+	Prefix: OrderedStorageMapInstance,
+	Key: FullCodec,
+	Value: FullCodec,
+	NodeCapacity: Get<u32>,
+	MaxValues: Get<Option<u32>>,
+{
+	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		Self::decode_entire_state_report(&DecodeSelect::All).into_result()
+	}
+
+	fn decode_entire_state_report(select: &DecodeSelect) -> DecodeEntireStateReport {
+		let (nodes_info, meta_info) = match &Self::partial_storage_info()[..] {
+			[a, b] => (a.clone(), b.clone()),
+			_ => panic!("Ordered map has two storage info items; qed"),
+		};
+		if !select.includes(&nodes_info) {
+			return Default::default()
+		}
 
-		let mut decoded = decode_storage_info::<Counter>(counter_info)?;
-		decoded += decode_storage_info::<Value>(map_info)?;
-		Ok(decoded)
+		DecodeEntireStateReport {
+			items: vec![
+				decode_storage_info::<TreeMeta>(meta_info),
+				decode_storage_info::<Node<Key, Value, NodeCapacity>>(nodes_info),
+			],
+		}
 	}
 }
 
@@ -525,4 +805,147 @@ mod tests {
 			assert_eq!(<(Value, Map) as TryDecodeEntireStorage>::try_decode_entire_state(), Ok(8));
 		});
 	}
+
+	#[test]
+	fn decode_entire_state_report_works() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			Value::put(42);
+			CMap::insert(0, 1);
+			CMap::insert(1, 2);
+			// one byte, cannot be decoded into u16.
+			sp_io::storage::set(&CMap::hashed_key_for(2), &[0u8]);
+
+			let report = <(Value, CMap)>::decode_entire_state_report(&DecodeSelect::All);
+			let summary = report
+				.items
+				.iter()
+				.map(|item| (item.info.storage_name.clone(), item.keys, item.bytes, item.failures))
+				.collect::<Vec<_>>();
+			assert_eq!(
+				summary,
+				vec![
+					(b"ValuePrefix".to_vec(), 1, 4, 0),
+					(b"CMapCounterPrefix".to_vec(), 1, 4, 0),
+					(b"CMapPrefix".to_vec(), 3, 4, 1),
+				]
+			);
+			assert_eq!((report.keys(), report.bytes(), report.failures()), (5, 12, 1));
+			assert!(!report.is_ok());
+
+			let failed = &report.items[2].failed;
+			assert_eq!(failed.len(), 1);
+			assert_eq!(failed[0].key, CMap::hashed_key_for(2));
+			assert_eq!(failed[0].raw, Some(vec![0u8]));
+		})
+	}
+
+	#[test]
+	fn decode_entire_state_report_respects_select() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			Value::put(42);
+			Map::insert(0, 42);
+
+			let decoded_items = |select: DecodeSelect| {
+				<(Value, Map)>::decode_entire_state_report(&select)
+					.items
+					.into_iter()
+					.map(|item| item.info.storage_name)
+					.collect::<Vec<_>>()
+			};
+
+			assert_eq!(
+				decoded_items(DecodeSelect::All),
+				vec![b"ValuePrefix".to_vec(), b"MapPrefix".to_vec()]
+			);
+			assert_eq!(
+				decoded_items(DecodeSelect::Pallet(b"test_pallet".to_vec())),
+				vec![b"ValuePrefix".to_vec(), b"MapPrefix".to_vec()]
+			);
+			assert_eq!(
+				decoded_items(DecodeSelect::Item {
+					pallet: b"test_pallet".to_vec(),
+					storage: b"MapPrefix".to_vec()
+				}),
+				vec![b"MapPrefix".to_vec()]
+			);
+			assert!(decoded_items(DecodeSelect::Pallet(b"other_pallet".to_vec())).is_empty());
+		})
+	}
+
+	#[test]
+	fn decode_select_from_str_works() {
+		use core::str::FromStr;
+
+		assert_eq!(DecodeSelect::from_str("all"), Ok(DecodeSelect::All));
+		assert_eq!(DecodeSelect::from_str("System"), Ok(DecodeSelect::Pallet(b"System".to_vec())));
+		assert_eq!(
+			DecodeSelect::from_str("System::Account"),
+			Ok(DecodeSelect::Item { pallet: b"System".to_vec(), storage: b"Account".to_vec() })
+		);
+		assert!(DecodeSelect::from_str("").is_err());
+	}
+
+	#[test]
+	fn undecodable_keys_are_sampled() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let total = MAX_FAILED_KEY_SAMPLES as u32 + 4;
+			for i in 0..total {
+				sp_io::storage::set(&Map::hashed_key_for(i), &[0u8, 1]);
+			}
+
+			let report = Map::decode_entire_state_report(&DecodeSelect::All);
+			assert_eq!(report.failures(), total as u64);
+			assert_eq!(report.items[0].failed.len(), MAX_FAILED_KEY_SAMPLES);
+			assert_eq!(Map::try_decode_entire_state().unwrap_err().len(), MAX_FAILED_KEY_SAMPLES);
+		})
+	}
+
+	#[test]
+	fn decode_child_trie_works() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let first = ChildInfo::new_default(b"first");
+			let second = ChildInfo::new_default(b"second");
+			crate::storage::child::put(&first, b"alice", &10u32);
+			crate::storage::child::put(&first, b"bob", &20u32);
+			crate::storage::child::put_raw(&second, b"charlie", &[0u8]);
+
+			let mut report = StorageItemDecodeReport::new(Value::partial_storage_info()[0].clone());
+			report.decode_child_trie::<u32>(&first);
+			assert_eq!((report.keys, report.bytes, report.failures), (2, 8, 0));
+
+			report.decode_child_trie::<u32>(&second);
+			assert_eq!((report.keys, report.bytes, report.failures), (3, 8, 1));
+			assert_eq!(report.failed[0].key, b"charlie".to_vec());
+		})
+	}
+
+	#[test]
+	fn default_report_is_derived_from_errors() {
+		struct Custom;
+		impl TryDecodeEntireStorage for Custom {
+			fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+				let error = |info: StorageInfo, key: &[u8]| TryDecodeEntireStorageError {
+					key: key.to_vec(),
+					raw: Some(vec![0]),
+					info,
+				};
+				let value = Value::partial_storage_info()[0].clone();
+				let map = Map::partial_storage_info()[0].clone();
+				Err(vec![error(value.clone(), b"a"), error(map, b"b"), error(value, b"c")])
+			}
+		}
+
+		let report = Custom::decode_entire_state_report(&DecodeSelect::All);
+		assert_eq!(report.items.len(), 2);
+		assert_eq!((report.items[0].failures, report.items[0].failed.len()), (2, 2));
+		assert_eq!((report.items[1].failures, report.items[1].failed.len()), (1, 1));
+		assert_eq!(report.failures(), 3);
+
+		let report = Custom::decode_entire_state_report(&"test_pallet::MapPrefix".parse().unwrap());
+		assert_eq!(report.items.len(), 1);
+		assert_eq!(report.items[0].failed[0].key, b"b".to_vec());
+
+		let report = Custom::decode_entire_state_report(&"other_pallet".parse().unwrap());
+		assert!(report.is_ok() && report.items.is_empty());
+	}
 }
@@ -50,11 +50,11 @@ parameter_types! {
 	storage UpdateStorageVersion: bool = false;
 }
 
-/// Latest stable metadata version used for testing.
-const LATEST_METADATA_VERSION: u32 = 15;
+/// Metadata version V15 used for testing.
+const V15_METADATA_VERSION: u32 = 15;
 
-/// Unstable metadata version.
-const UNSTABLE_METADATA_VERSION: u32 = u32::MAX;
+/// Latest stable metadata version.
+const LATEST_METADATA_VERSION: u32 = 16;
 
 pub struct SomeType1;
 impl From<SomeType1> for u64 {
@@ -1963,8 +1963,8 @@ fn metadata() {
 		_ => panic!("metadata has been bumped, test needs to be updated"),
 	};
 
-	let bytes = &Runtime::metadata_at_version(LATEST_METADATA_VERSION)
-		.expect("Metadata must be present; qed");
+	let bytes =
+		&Runtime::metadata_at_version(V15_METADATA_VERSION).expect("Metadata must be present; qed");
 
 	let actual_metadata: RuntimeMetadataPrefixed =
 		Decode::decode(&mut &bytes[..]).expect("Metadata encoded properly; qed");
@@ -1999,7 +1999,7 @@ fn metadata_at_version() {
 #[test]
 fn metadata_versions() {
 	assert_eq!(
-		vec![14, LATEST_METADATA_VERSION, UNSTABLE_METADATA_VERSION],
+		vec![14, V15_METADATA_VERSION, LATEST_METADATA_VERSION],
 		Runtime::metadata_versions()
 	);
 }
@@ -23,6 +23,12 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
+use sp_runtime::traits::Block as BlockT;
+use sp_weights::Weight;
+
 #[docify::export(AccountNonceApi)]
 sp_api::decl_runtime_apis! {
 	/// The API to query account nonce.
@@ -34,3 +40,15 @@ sp_api::decl_runtime_apis! {
 		fn account_nonce(account: AccountId) -> Nonce;
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// The API to discover the tasks of the runtime that can be executed.
+	///
+	/// Block authors use it to submit the valid tasks, see `frame_system::Call::do_task`.
+	pub trait TasksApi {
+		/// Returns unsigned `do_task` extrinsics for the currently valid tasks.
+		///
+		/// The total weight of the returned tasks doesn't exceed `max_weight`.
+		fn valid_task_extrinsics(max_weight: Weight) -> Vec<<Block as BlockT>::Extrinsic>;
+	}
+}
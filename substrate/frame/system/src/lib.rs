@@ -2280,6 +2280,53 @@ impl<T: Config> Pallet<T> {
 		Ok(actual_hash)
 	}
 
+	/// Returns the currently valid tasks whose total weight doesn't exceed `max_weight`.
+	///
+	/// Tasks are returned in the order they are enumerated by [`Task::iter`]. A task that doesn't
+	/// fit into the remaining weight is skipped, but later tasks may still be included.
+	///
+	/// [`Task::iter`]: frame_support::traits::Task::iter
+	#[cfg(feature = "experimental")]
+	pub fn valid_tasks(max_weight: Weight) -> Vec<T::RuntimeTask>
+	where
+		<T::RuntimeTask as frame_support::traits::Task>::Enumeration:
+			Iterator<Item = T::RuntimeTask>,
+	{
+		use frame_support::traits::Task;
+
+		let mut remaining = max_weight;
+		T::RuntimeTask::iter()
+			.filter(|task| task.is_valid())
+			.filter(|task| {
+				let weight = task.weight();
+				let fits = weight.all_lte(remaining);
+				if fits {
+					remaining.saturating_reduce(weight);
+				}
+				fits
+			})
+			.collect()
+	}
+
+	/// Returns unsigned [`Call::do_task`] extrinsics for the tasks returned by
+	/// [`Self::valid_tasks`].
+	///
+	/// This is meant to be used to implement the `TasksApi` runtime API.
+	#[cfg(feature = "experimental")]
+	pub fn valid_task_extrinsics(
+		max_weight: Weight,
+	) -> Vec<<T as offchain::CreateTransactionBase<Call<T>>>::Extrinsic>
+	where
+		T: offchain::CreateInherent<Call<T>>,
+		<T::RuntimeTask as frame_support::traits::Task>::Enumeration:
+			Iterator<Item = T::RuntimeTask>,
+	{
+		Self::valid_tasks(max_weight)
+			.into_iter()
+			.map(|task| T::create_inherent(Call::do_task { task }.into()))
+			.collect()
+	}
+
 	/// Reclaim the weight for the extrinsic given info and post info.
 	///
 	/// This function will check the already reclaimed weight, and reclaim more if the
@@ -17,11 +17,36 @@
 
 //! Supporting types for try-runtime, testing and dry-running commands.
 
-pub use frame_support::traits::{TryStateSelect, UpgradeCheckSelect};
+pub use frame_support::traits::{
+	DecodeEntireStateReport, DecodeSelect, StorageItemDecodeReport, TryStateSelect,
+	UpgradeCheckSelect,
+};
 use frame_support::weights::Weight;
 
+/// The resources that the migrations of a runtime upgrade consumed.
+#[derive(codec::Encode, codec::Decode, Clone, Debug, PartialEq, Eq, scale_info::TypeInfo)]
+pub struct UpgradeReport {
+	/// The weight that the migrations reported to consume.
+	pub weight: Weight,
+	/// The size of the storage proof that was recorded while executing the migrations.
+	///
+	/// `None` if the executor does not record a storage proof.
+	pub proof_size: Option<u64>,
+	/// The maximal weight of a block. Its proof size is the PoV limit of the runtime.
+	pub max_block: Weight,
+}
+
+impl UpgradeReport {
+	/// Whether the weight or the storage proof of the migrations exceed the limits of a block.
+	pub fn exceeds_block_limits(&self) -> bool {
+		self.weight.any_gt(self.max_block) ||
+			self.proof_size.is_some_and(|size| size > self.max_block.proof_size())
+	}
+}
+
 sp_api::decl_runtime_apis! {
 	/// Runtime api for testing the execution of a runtime upgrade.
+	#[api_version(2)]
 	pub trait TryRuntime {
 		/// dry-run runtime upgrades, returning the total weight consumed.
 		///
@@ -36,6 +61,16 @@ sp_api::decl_runtime_apis! {
 		/// tracking is likely inaccurate.
 		fn on_runtime_upgrade(checks: UpgradeCheckSelect) -> (Weight, Weight);
 
+		/// dry-run runtime upgrades, reporting the consumed weight and storage proof size.
+		///
+		/// Like [`Self::on_runtime_upgrade`], but additionally reports the size of the storage proof
+		/// that was recorded while executing the migrations. Fails if the migrations exceed the
+		/// limits of a block, see [`UpgradeReport::exceeds_block_limits`]. The proof size is only
+		/// known if the runtime can measure it, e.g. through the `storage_proof_size` host function
+		/// of parachains.
+		#[api_version(2)]
+		fn on_runtime_upgrade_with_report(checks: UpgradeCheckSelect) -> UpgradeReport;
+
 		/// Execute the given block, but optionally disable state-root and signature checks.
 		///
 		/// Optionally, a number of `try_state` hooks can also be executed after the block
@@ -47,4 +82,16 @@ sp_api::decl_runtime_apis! {
 			try_state: TryStateSelect,
 		) -> Weight;
 	}
+
+	/// Runtime api for decoding the state of a runtime.
+	///
+	/// This is independent of [`TryRuntime`], runtimes opt into it by forwarding to
+	/// `frame_executive::Executive::try_decode_entire_state`.
+	pub trait TryDecodeState {
+		/// Decode the storage items selected by `select`, reporting the outcome per item.
+		///
+		/// Values that cannot be decoded are reported with their keys and raw values instead of
+		/// failing the call.
+		fn decode_entire_state(select: DecodeSelect) -> DecodeEntireStateReport;
+	}
 }
@@ -30,9 +30,9 @@ mod types;
 use frame_metadata::RuntimeMetadataPrefixed;
 pub use types::*;
 
-mod unstable;
 mod v14;
 mod v15;
+mod v16;
 
 /// Metadata V14.
 const V14: u32 = 14;
@@ -40,25 +40,25 @@ const V14: u32 = 14;
 /// Metadata V15.
 const V15: u32 = 15;
 
-/// Unstable metadata V16.
-const UNSTABLE_V16: u32 = u32::MAX;
+/// Metadata V16.
+const V16: u32 = 16;
 
 /// Transform the IR to the specified version.
 ///
 /// Use [`supported_versions`] to find supported versions.
 pub fn into_version(metadata: MetadataIR, version: u32) -> Option<RuntimeMetadataPrefixed> {
-	// Note: Unstable metadata version is `u32::MAX` until stabilized.
+	// Note: Unstable metadata versions are exposed under `u32::MAX` until stabilized.
 	match version {
 		// Version V14. This needs to be around until the
 		// deprecation of the `Metadata_metadata` runtime call in favor of
 		// `Metadata_metadata_at_version.
 		V14 => Some(into_v14(metadata)),
 
-		// Version V15 - latest stable.
-		V15 => Some(into_latest(metadata)),
+		// Version V15.
+		V15 => Some(into_v15(metadata)),
 
-		// Unstable metadata under `u32::MAX`.
-		UNSTABLE_V16 => Some(into_unstable(metadata)),
+		// Version V16 - latest stable.
+		V16 => Some(into_latest(metadata)),
 
 		_ => None,
 	}
@@ -66,13 +66,12 @@ pub fn into_version(metadata: MetadataIR, version: u32) -> Option<RuntimeMetadat
 
 /// Returns the supported metadata versions.
 pub fn supported_versions() -> alloc::vec::Vec<u32> {
-	alloc::vec![V14, V15, UNSTABLE_V16]
+	alloc::vec![V14, V15, V16]
 }
 
 /// Transform the IR to the latest stable metadata version.
 pub fn into_latest(metadata: MetadataIR) -> RuntimeMetadataPrefixed {
-	let latest: frame_metadata::v15::RuntimeMetadataV15 = metadata.into();
-	latest.into()
+	into_v16(metadata)
 }
 
 /// Transform the IR to metadata version 14.
@@ -81,8 +80,14 @@ pub fn into_v14(metadata: MetadataIR) -> RuntimeMetadataPrefixed {
 	latest.into()
 }
 
-/// Transform the IR to unstable metadata version 16.
-pub fn into_unstable(metadata: MetadataIR) -> RuntimeMetadataPrefixed {
+/// Transform the IR to metadata version 15.
+pub fn into_v15(metadata: MetadataIR) -> RuntimeMetadataPrefixed {
+	let latest: frame_metadata::v15::RuntimeMetadataV15 = metadata.into();
+	latest.into()
+}
+
+/// Transform the IR to metadata version 16.
+pub fn into_v16(metadata: MetadataIR) -> RuntimeMetadataPrefixed {
 	let latest: frame_metadata::v16::RuntimeMetadataV16 = metadata.into();
 	latest.into()
 }
@@ -114,6 +119,7 @@ mod test {
 				signature_ty: meta_type::<()>(),
 				extra_ty: meta_type::<()>(),
 				extensions: vec![],
+				extensions_by_version: Default::default(),
 			},
 			ty: meta_type::<()>(),
 			apis: vec![],
@@ -144,4 +150,20 @@ mod test {
 
 		assert!(matches!(metadata.1, RuntimeMetadata::V15(_)));
 	}
+
+	#[test]
+	fn into_version_16() {
+		let ir = ir_metadata();
+		let metadata = into_version(ir, V16).expect("Should return prefixed metadata");
+
+		assert_eq!(metadata.0, META_RESERVED);
+
+		assert!(matches!(metadata.1, RuntimeMetadata::V16(_)));
+	}
+
+	#[test]
+	fn unstable_version_is_not_supported() {
+		assert!(into_version(ir_metadata(), u32::MAX).is_none());
+		assert_eq!(supported_versions(), vec![V14, V15, V16]);
+	}
 }
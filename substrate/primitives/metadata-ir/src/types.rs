@@ -245,6 +245,9 @@ pub struct ExtrinsicMetadataIR<T: Form = MetaForm> {
 	pub extra_ty: T::Type,
 	/// The transaction extensions in the order they appear in the extrinsic.
 	pub extensions: Vec<TransactionExtensionMetadataIR<T>>,
+	/// The indexes of the `extensions` that are used by each version of the transaction
+	/// extensions.
+	pub extensions_by_version: BTreeMap<u8, Vec<u32>>,
 }
 
 impl IntoPortable for ExtrinsicMetadataIR {
@@ -259,6 +262,7 @@ impl IntoPortable for ExtrinsicMetadataIR {
 			signature_ty: registry.register_type(&self.signature_ty),
 			extra_ty: registry.register_type(&self.extra_ty),
 			extensions: registry.map_into_portable(self.extensions),
+			extensions_by_version: self.extensions_by_version,
 		}
 	}
 }
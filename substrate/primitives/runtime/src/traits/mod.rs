@@ -1434,6 +1434,12 @@ pub trait ExtrinsicMetadata {
 	/// By format we mean the encoded representation of the `Extrinsic`.
 	const VERSIONS: &'static [u8];
 
+	/// The versions of the transaction extensions of general transactions.
+	///
+	/// All of the `TransactionExtensions` are used by each of these versions. Defaults to the
+	/// single version `0`.
+	const EXTENSION_VERSIONS: &'static [crate::generic::ExtensionVersion] = &[0];
+
 	/// Transaction extensions attached to this `Extrinsic`.
 	type TransactionExtensions;
 }
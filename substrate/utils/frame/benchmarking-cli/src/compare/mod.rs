@@ -0,0 +1,135 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the [`CompareCmd`] to compare the results of two pallet benchmark runs.
+
+mod report;
+mod results;
+
+use std::{fs, path::PathBuf};
+
+use clap::{Parser, ValueEnum};
+use log::{info, warn};
+use sc_cli::{CliConfiguration, Result, SharedParams};
+
+use report::Report;
+
+/// The format of the comparison report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+	/// A markdown table, suitable for PR comments.
+	Markdown,
+	/// Machine readable JSON.
+	Json,
+}
+
+/// Compare the results of two `benchmark pallet` runs.
+///
+/// Both inputs can either be JSON files written with `--json-file` or weight files generated by the
+/// `pallet` command. The benchmarks and their components are lined up by name and the changes of
+/// the base values and the per-component slopes of the execution time, proof size and database
+/// accesses are reported.
+///
+/// The command fails if any value increased by more than the `--threshold`, which makes it usable
+/// as a CI check when regenerating weights. It also fails if no benchmark exists in both inputs, so
+/// a check can not pass by comparing nothing.
+#[derive(Debug, Parser)]
+pub struct CompareCmd {
+	/// The results of the old run.
+	#[arg(value_name = "OLD")]
+	pub old: PathBuf,
+
+	/// The results of the new run.
+	#[arg(value_name = "NEW")]
+	pub new: PathBuf,
+
+	/// The pallet that weight files implement the weights for.
+	///
+	/// Required for weight files that don't name the pallet, like the `weights.rs` file of a
+	/// pallet. Runtime weight files name the pallet in their `WeightInfo` implementation.
+	#[arg(long, value_name = "PALLET")]
+	pub pallet: Option<String>,
+
+	/// Increases above this percentage are reported as regressions.
+	#[arg(long, default_value_t = 10.0, value_name = "PERCENT")]
+	pub threshold: f64,
+
+	/// The format of the report.
+	#[arg(long, value_enum, default_value_t = ReportFormat::Markdown)]
+	pub format: ReportFormat,
+
+	/// Write the report to this file instead of stdout.
+	#[arg(long, value_name = "PATH")]
+	pub output: Option<PathBuf>,
+
+	/// Do not return an error if there are regressions.
+	#[arg(long)]
+	pub allow_regressions: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+}
+
+impl CompareCmd {
+	/// Compare the results and output the report.
+	pub fn run(&self) -> Result<()> {
+		if !self.threshold.is_finite() || self.threshold < 0.0 {
+			return Err("The threshold must be a positive percentage".into())
+		}
+
+		let old = results::load(&self.old, self.pallet.as_deref())?;
+		let new = results::load(&self.new, self.pallet.as_deref())?;
+		info!("Comparing {} old with {} new benchmarks", old.formulas.len(), new.formulas.len());
+
+		if !old.formulas.keys().any(|key| new.formulas.contains_key(key)) {
+			return Err("None of the benchmarks exist in both the old and the new results, \
+				check that both name the same pallet"
+				.into())
+		}
+		if old.proof_size != new.proof_size {
+			warn!("Not comparing the recorded proof size of JSON results with the estimated one");
+		}
+
+		let report = Report::new(&old, &new, self.threshold);
+		let rendered = match self.format {
+			ReportFormat::Markdown => report.to_markdown(),
+			ReportFormat::Json => serde_json::to_string_pretty(&report)
+				.map_err(|e| format!("Failed to serialize the report: {}", e))?,
+		};
+
+		match &self.output {
+			Some(path) => fs::write(path, rendered)?,
+			None => println!("{}", rendered),
+		}
+
+		let regressions = report.regressions().count();
+		if regressions > 0 && !self.allow_regressions {
+			return Err(
+				format!("{} values regressed by more than {}%", regressions, self.threshold).into()
+			)
+		}
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for CompareCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+}
@@ -0,0 +1,287 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compares two sets of weight formulas and renders the differences.
+
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use super::results::{Metrics, Results};
+use crate::shared::underscore;
+
+/// A metric of a weight formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Metric {
+	Time,
+	ProofSize,
+	Reads,
+	Writes,
+}
+
+impl Metric {
+	const ALL: [Metric; 4] = [Metric::Time, Metric::ProofSize, Metric::Reads, Metric::Writes];
+
+	fn of(self, metrics: &Metrics) -> u128 {
+		match self {
+			Metric::Time => metrics.time,
+			Metric::ProofSize => metrics.proof_size,
+			Metric::Reads => metrics.reads,
+			Metric::Writes => metrics.writes,
+		}
+	}
+
+	fn name(self) -> &'static str {
+		match self {
+			Metric::Time => "time",
+			Metric::ProofSize => "proof size",
+			Metric::Reads => "reads",
+			Metric::Writes => "writes",
+		}
+	}
+}
+
+/// The change of one metric of one term of a weight formula.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Change {
+	pub(crate) pallet: String,
+	pub(crate) benchmark: String,
+	/// The component of the slope that changed, or `None` for the base value.
+	pub(crate) component: Option<String>,
+	pub(crate) metric: Metric,
+	pub(crate) old: u128,
+	pub(crate) new: u128,
+	/// The relative change in percent, `None` if the old value was zero.
+	pub(crate) percent: Option<f64>,
+	/// Whether the value increased by more than the threshold.
+	pub(crate) regression: bool,
+}
+
+/// The result of comparing two sets of weight formulas.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Report {
+	/// The threshold in percent above which an increase is a regression.
+	pub(crate) threshold: f64,
+	/// All the values that changed.
+	pub(crate) changes: Vec<Change>,
+	/// Benchmarks that only exist in the new results, as `pallet::benchmark`.
+	pub(crate) added: Vec<String>,
+	/// Benchmarks that only exist in the old results, as `pallet::benchmark`.
+	pub(crate) removed: Vec<String>,
+	/// Whether the proof sizes were compared, which is only done if both were obtained the same
+	/// way.
+	pub(crate) proof_size_compared: bool,
+}
+
+impl Report {
+	/// Compare the `old` formulas to the `new` ones.
+	///
+	/// Benchmarks and components are lined up by name; a component that only exists on one side
+	/// is compared against a zero slope. The recorded proof size is not compared to the estimated
+	/// one.
+	pub(crate) fn new(old: &Results, new: &Results, threshold: f64) -> Self {
+		let proof_size_compared = old.proof_size == new.proof_size;
+		let metrics = Metric::ALL
+			.into_iter()
+			.filter(|metric| proof_size_compared || *metric != Metric::ProofSize)
+			.collect::<Vec<_>>();
+		let (old, new) = (&old.formulas, &new.formulas);
+		let mut changes = Vec::new();
+
+		for (key, new_formula) in new {
+			let Some(old_formula) = old.get(key) else { continue };
+			let (pallet, benchmark) = key;
+
+			let mut terms = vec![(None, old_formula.base, new_formula.base)];
+			let components = old_formula.slopes.keys().chain(new_formula.slopes.keys());
+			for component in components.collect::<std::collections::BTreeSet<_>>() {
+				let old_slope = old_formula.slopes.get(component).copied().unwrap_or_default();
+				let new_slope = new_formula.slopes.get(component).copied().unwrap_or_default();
+				terms.push((Some(component.clone()), old_slope, new_slope));
+			}
+
+			for (component, old_metrics, new_metrics) in terms {
+				for &metric in &metrics {
+					let (old, new) = (metric.of(&old_metrics), metric.of(&new_metrics));
+					if old == new {
+						continue
+					}
+
+					let percent =
+						(old != 0).then(|| (new as f64 - old as f64) * 100.0 / old as f64);
+					let regression = new > old && percent.map_or(true, |p| p > threshold);
+					changes.push(Change {
+						pallet: pallet.clone(),
+						benchmark: benchmark.clone(),
+						component: component.clone(),
+						metric,
+						old,
+						new,
+						percent,
+						regression,
+					});
+				}
+			}
+		}
+
+		let name = |(pallet, benchmark): &(String, String)| format!("{}::{}", pallet, benchmark);
+		let added = new.keys().filter(|k| !old.contains_key(*k)).map(name).collect();
+		let removed = old.keys().filter(|k| !new.contains_key(*k)).map(name).collect();
+
+		Self { threshold, changes, added, removed, proof_size_compared }
+	}
+
+	/// The changes that are regressions.
+	pub(crate) fn regressions(&self) -> impl Iterator<Item = &Change> {
+		self.changes.iter().filter(|c| c.regression)
+	}
+
+	/// Render the report as markdown.
+	pub(crate) fn to_markdown(&self) -> String {
+		let mut out = String::new();
+		let regressions = self.regressions().count();
+
+		let _ = writeln!(out, "## Benchmark comparison\n");
+		let _ = writeln!(
+			out,
+			"{} changed values, {} regressions above the threshold of {}%.\n",
+			self.changes.len(),
+			regressions,
+			self.threshold,
+		);
+		if !self.proof_size_compared {
+			let _ = writeln!(
+				out,
+				"The proof sizes are not compared, as one side contains the recorded and the other \
+				 the estimated proof size.\n"
+			);
+		}
+
+		if !self.changes.is_empty() {
+			let _ =
+				writeln!(out, "| Pallet | Benchmark | Component | Metric | Old | New | Change |");
+			let _ =
+				writeln!(out, "|--------|-----------|-----------|--------|-----|-----|--------|");
+			for change in &self.changes {
+				let percent = change.percent.map_or("new".to_string(), |p| format!("{:+.2}%", p));
+				let _ = writeln!(
+					out,
+					"| {} | {} | {} | {} | {} | {} | {}{} |",
+					change.pallet,
+					change.benchmark,
+					change.component.as_deref().unwrap_or("base"),
+					change.metric.name(),
+					underscore(change.old),
+					underscore(change.new),
+					percent,
+					if change.regression { " ⚠️" } else { "" },
+				);
+			}
+			let _ = writeln!(out);
+		}
+
+		for (title, benchmarks) in [("Added", &self.added), ("Removed", &self.removed)] {
+			if benchmarks.is_empty() {
+				continue
+			}
+			let _ = writeln!(out, "### {} benchmarks\n", title);
+			for benchmark in benchmarks {
+				let _ = writeln!(out, "- `{}`", benchmark);
+			}
+			let _ = writeln!(out);
+		}
+
+		out
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::compare::results::{Formulas, ProofSize, WeightFormula};
+
+	fn formula(time: u128, slope: Option<(&str, u128)>) -> WeightFormula {
+		WeightFormula {
+			base: Metrics { time, proof_size: 100, reads: 1, writes: 1 },
+			slopes: slope
+				.into_iter()
+				.map(|(c, s)| (c.to_string(), Metrics { time: s, ..Default::default() }))
+				.collect(),
+		}
+	}
+
+	fn results(entries: Vec<(&str, WeightFormula)>, proof_size: ProofSize) -> Results {
+		let formulas: Formulas = entries
+			.into_iter()
+			.map(|(b, f)| (("pallet".to_string(), b.to_string()), f))
+			.collect();
+		Results { formulas, proof_size }
+	}
+
+	#[test]
+	fn regressions_above_threshold_are_detected() {
+		let old = results(
+			vec![
+				("a", formula(1000, Some(("n", 100)))),
+				("b", formula(1000, None)),
+				("gone", formula(1, None)),
+			],
+			ProofSize::Estimated,
+		);
+		let new = results(
+			vec![
+				("a", formula(1050, Some(("n", 200)))),
+				("b", formula(800, Some(("m", 5)))),
+				("fresh", formula(1, None)),
+			],
+			ProofSize::Estimated,
+		);
+
+		let report = Report::new(&old, &new, 10.0);
+
+		let regressions = report
+			.regressions()
+			.map(|c| (c.benchmark.as_str(), c.component.as_deref()))
+			.collect::<Vec<_>>();
+		// `a` base only increased by 5%, its slope doubled and `b` got a new component.
+		assert_eq!(regressions, vec![("a", Some("n")), ("b", Some("m"))]);
+		assert_eq!(report.changes.len(), 4);
+		assert_eq!(report.added, vec!["pallet::fresh"]);
+		assert_eq!(report.removed, vec!["pallet::gone"]);
+		assert!(report
+			.to_markdown()
+			.contains("| pallet | a | n | time | 100 | 200 | +100.00% ⚠️ |"));
+	}
+
+	#[test]
+	fn recorded_and_estimated_proof_sizes_are_not_compared() {
+		let mut old_formula = formula(1000, None);
+		old_formula.base.proof_size = 3593;
+		let old = results(vec![("a", old_formula)], ProofSize::Estimated);
+		let new = results(vec![("a", formula(1000, None))], ProofSize::Recorded);
+
+		let report = Report::new(&old, &new, 10.0);
+		assert!(!report.proof_size_compared);
+		assert!(report.changes.is_empty());
+
+		let report = Report::new(&old, &Results { proof_size: ProofSize::Estimated, ..new }, 10.0);
+		assert!(report.proof_size_compared);
+		assert_eq!(report.changes.len(), 1);
+		assert_eq!(report.changes[0].metric, Metric::ProofSize);
+	}
+}
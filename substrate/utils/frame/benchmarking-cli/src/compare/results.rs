@@ -0,0 +1,309 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Loads benchmark results into comparable weight formulas.
+
+use std::{
+	collections::{BTreeMap, HashMap, HashSet},
+	fs,
+	path::Path,
+	sync::LazyLock,
+};
+
+use frame_benchmarking::{Analysis, BenchmarkBatchSplitResults, BenchmarkSelector};
+use regex::Regex;
+use sc_cli::Result;
+
+/// The metrics of a single term of a weight formula.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Metrics {
+	/// Execution time in picoseconds.
+	pub(crate) time: u128,
+	/// Proof size in bytes.
+	pub(crate) proof_size: u128,
+	/// Number of storage reads.
+	pub(crate) reads: u128,
+	/// Number of storage writes.
+	pub(crate) writes: u128,
+}
+
+/// The weight formula of a benchmark: the base metrics plus the slope of each component.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct WeightFormula {
+	pub(crate) base: Metrics,
+	pub(crate) slopes: BTreeMap<String, Metrics>,
+}
+
+/// The weight formulas of all benchmarks, keyed by `(pallet, benchmark)`.
+pub(crate) type Formulas = BTreeMap<(String, String), WeightFormula>;
+
+/// How the proof size of a set of weight formulas was obtained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ProofSize {
+	/// The proof size that was recorded while running the benchmarks, as in JSON results.
+	Recorded,
+	/// The worst case proof size estimated from the storage info, as in weight files.
+	Estimated,
+}
+
+/// The weight formulas of one benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Results {
+	pub(crate) formulas: Formulas,
+	pub(crate) proof_size: ProofSize,
+}
+
+/// Load the weight formulas from either a JSON file written with `--json-file` or a weight file
+/// generated by the `pallet` command.
+///
+/// Files with the `rs` extension are parsed as weight files, everything else as JSON. `pallet` is
+/// the name of the pallet that a weight file implements the weights for, see
+/// [`from_weight_file`].
+pub(crate) fn load(path: &Path, pallet: Option<&str>) -> Result<Results> {
+	let content =
+		fs::read_to_string(path).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+
+	if path.extension().map_or(false, |ext| ext == "rs") {
+		let formulas = from_weight_file(&content, pallet).ok_or_else(|| {
+			format!(
+				"{:?} does not name the pallet that it implements the weights for, pass it with \
+				 `--pallet`",
+				path
+			)
+		})?;
+		Ok(Results { formulas, proof_size: ProofSize::Estimated })
+	} else {
+		let batches: Vec<BenchmarkBatchSplitResults> = serde_json::from_str(&content)
+			.map_err(|e| format!("Failed to deserialize {:?}: {}", path, e))?;
+		Ok(Results { formulas: from_batches(&batches)?, proof_size: ProofSize::Recorded })
+	}
+}
+
+/// Analyze the raw benchmark results the same way the `pallet` command does by default.
+pub(crate) fn from_batches(batches: &[BenchmarkBatchSplitResults]) -> Result<Formulas> {
+	// Only qualify the pallet by its instance when the pallet is benchmarked multiple times.
+	let mut instances = HashMap::<&[u8], HashSet<&[u8]>>::new();
+	for batch in batches {
+		instances.entry(&batch.pallet).or_default().insert(&batch.instance);
+	}
+
+	let mut formulas = Formulas::new();
+	for batch in batches {
+		// Skip if there are no results
+		if batch.time_results.is_empty() {
+			continue
+		}
+
+		let mut pallet = String::from_utf8_lossy(&batch.pallet).into_owned();
+		if instances.get(&batch.pallet[..]).map_or(0, |i| i.len()) > 1 {
+			pallet = format!("{}[{}]", pallet, String::from_utf8_lossy(&batch.instance));
+		}
+		let benchmark = String::from_utf8_lossy(&batch.benchmark).into_owned();
+
+		let analyze = |results, selector| {
+			Analysis::min_squares_iqr(results, selector)
+				.ok_or_else(|| format!("Failed to analyze {}::{}", pallet, benchmark))
+		};
+		let time = analyze(&batch.time_results, BenchmarkSelector::ExtrinsicTime)?;
+		let reads = analyze(&batch.db_results, BenchmarkSelector::Reads)?;
+		let writes = analyze(&batch.db_results, BenchmarkSelector::Writes)?;
+		let proof_size = analyze(&batch.db_results, BenchmarkSelector::ProofSize)?;
+
+		let mut formula = WeightFormula {
+			base: Metrics {
+				time: time.base,
+				proof_size: proof_size.base,
+				reads: reads.base,
+				writes: writes.base,
+			},
+			slopes: Default::default(),
+		};
+		add_slopes(&mut formula, &time, |m| &mut m.time);
+		add_slopes(&mut formula, &proof_size, |m| &mut m.proof_size);
+		add_slopes(&mut formula, &reads, |m| &mut m.reads);
+		add_slopes(&mut formula, &writes, |m| &mut m.writes);
+
+		formulas.insert((pallet, benchmark), formula);
+	}
+
+	Ok(formulas)
+}
+
+fn add_slopes(
+	formula: &mut WeightFormula,
+	analysis: &Analysis,
+	metric: fn(&mut Metrics) -> &mut u128,
+) {
+	for (name, slope) in analysis.names.iter().zip(analysis.slopes.iter()) {
+		// Unused components are not part of the weight formula.
+		if *slope != 0 {
+			*metric(formula.slopes.entry(name.clone()).or_default()) = *slope;
+		}
+	}
+}
+
+static PALLET: LazyLock<Regex> =
+	LazyLock::new(|| Regex::new(r"impl<T: frame_system::Config> (\w+)::WeightInfo for").unwrap());
+static FUNCTION: LazyLock<Regex> =
+	LazyLock::new(|| Regex::new(r"fn\s+(\w+)\s*\([^)]*\)\s*->\s*Weight\s*\{([^}]*)\}").unwrap());
+static FROM_PARTS: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(
+		r"Weight::from_parts\(\s*([\d_]+)\s*,\s*([\d_]+)\s*\)(?:\.saturating_mul\(\s*(\w+)\.into\(\)\s*\))?",
+	)
+	.unwrap()
+});
+static DB_ACCESS: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(
+		r"\.(reads|writes)\(\s*\(?\s*(\d+(?:_\d+)*)(?:_u64)?\s*\)?(?:\.saturating_mul\(\s*(\w+)\.into\(\)\s*\))?",
+	)
+	.unwrap()
+});
+
+/// Parse the weight formulas of a weight file generated by the `pallet` command.
+///
+/// Only the first implementation of each weight function is considered, which skips the
+/// `RocksDbWeight` based fallback implementation of pallet weight files.
+///
+/// The weights are attributed to `pallet` if given, otherwise to the pallet named in the file.
+/// Returns `None` if neither names the pallet, which is the case for the `weights.rs` files of
+/// pallets.
+pub(crate) fn from_weight_file(content: &str, pallet: Option<&str>) -> Option<Formulas> {
+	let pallet = match pallet {
+		Some(pallet) => pallet.to_string(),
+		None => PALLET.captures(content)?[1].to_string(),
+	};
+
+	let mut formulas = Formulas::new();
+	for function in FUNCTION.captures_iter(content) {
+		let key = (pallet.clone(), function[1].to_string());
+		if formulas.contains_key(&key) {
+			continue
+		}
+
+		let body = function[2]
+			.lines()
+			.filter(|line| !line.trim_start().starts_with("//"))
+			.collect::<Vec<_>>()
+			.join("\n");
+
+		let mut formula = WeightFormula::default();
+		for term in FROM_PARTS.captures_iter(&body) {
+			let metrics = match term.get(3) {
+				Some(component) =>
+					formula.slopes.entry(component.as_str().to_string()).or_default(),
+				None => &mut formula.base,
+			};
+			metrics.time += parse_number(&term[1]);
+			metrics.proof_size += parse_number(&term[2]);
+		}
+		for term in DB_ACCESS.captures_iter(&body) {
+			let metrics = match term.get(3) {
+				Some(component) =>
+					formula.slopes.entry(component.as_str().to_string()).or_default(),
+				None => &mut formula.base,
+			};
+			match &term[1] {
+				"reads" => metrics.reads += parse_number(&term[2]),
+				_ => metrics.writes += parse_number(&term[2]),
+			}
+		}
+
+		formulas.insert(key, formula);
+	}
+
+	Some(formulas)
+}
+
+fn parse_number(s: &str) -> u128 {
+	// The regular expressions only match digits and underscores.
+	s.replace('_', "").parse().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const WEIGHT_FILE: &str = r#"
+impl<T: frame_system::Config> pallet_balances::WeightInfo for WeightInfo<T> {
+	/// Storage: `System::Account` (r:1 w:1)
+	fn transfer_allow_death() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `52`
+		//  Estimated: `3593`
+		// Minimum execution time: 46_329_000 picoseconds.
+		Weight::from_parts(47_297_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// The range of component `u` is `[1, 1000]`.
+	fn upgrade_accounts(u: u32, _c: u32, ) -> Weight {
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(16_500_000, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			// Standard Error: 11_000
+			.saturating_add(Weight::from_parts(13_498_000, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(u.into()))
+	}
+}
+
+impl WeightInfo for () {
+	fn transfer_allow_death() -> Weight {
+		Weight::from_parts(1, 2)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+}
+"#;
+
+	#[test]
+	fn weight_file_is_parsed() {
+		let formulas = from_weight_file(WEIGHT_FILE, None).unwrap();
+		assert_eq!(formulas.len(), 2);
+
+		let transfer = &formulas[&("pallet_balances".into(), "transfer_allow_death".into())];
+		assert_eq!(
+			transfer.base,
+			Metrics { time: 47_297_000, proof_size: 3593, reads: 1, writes: 1 }
+		);
+		assert!(transfer.slopes.is_empty());
+
+		let upgrade = &formulas[&("pallet_balances".into(), "upgrade_accounts".into())];
+		assert_eq!(
+			upgrade.base,
+			Metrics { time: 16_500_000, proof_size: 990, reads: 0, writes: 0 }
+		);
+		assert_eq!(
+			upgrade.slopes["u"],
+			Metrics { time: 13_498_000, proof_size: 2603, reads: 1, writes: 1 }
+		);
+	}
+
+	#[test]
+	fn pallet_weight_file_needs_pallet_name() {
+		let content = "impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {\n\
+			fn remark() -> Weight { Weight::from_parts(10, 20) } }";
+		assert_eq!(from_weight_file(content, None), None);
+
+		let formulas = from_weight_file(content, Some("frame_system")).unwrap();
+		assert_eq!(
+			formulas[&("frame_system".into(), "remark".into())].base,
+			Metrics { time: 10, proof_size: 20, reads: 0, writes: 0 }
+		);
+	}
+}
@@ -60,7 +60,7 @@ impl<C: Config<Hash = subxt::utils::H256>> DynamicRemarkBuilder<C> {
 
 			let latest = supported_metadata_versions
 				.into_iter()
-				.filter(|v| *v != u32::MAX)
+				.filter(|v| *v <= sc_runtime_utilities::LATEST_SUPPORTED_METADATA_VERSION)
 				.max()
 				.ok_or("No stable metadata versions supported".to_string())?;
 
@@ -16,6 +16,7 @@
 // limitations under the License.
 
 use super::{
+	snapshot::TrieLayers,
 	types::{ComponentRange, ComponentRangeMap},
 	writer, ListOutput, PalletCmd,
 };
@@ -42,6 +43,7 @@ use sp_core::{
 		testing::{TestOffchainExt, TestTransactionPoolExt},
 		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
 	},
+	storage::{well_known_keys, Storage},
 	traits::{CallContext, CodeExecutor, ReadRuntimeVersionExt, WrappedRuntimeCode},
 	Hasher,
 };
@@ -56,6 +58,7 @@ use std::{
 	collections::{BTreeMap, BTreeSet, HashMap},
 	fmt::Debug,
 	fs,
+	path::Path,
 	str::FromStr,
 	time,
 };
@@ -63,6 +66,9 @@ use std::{
 /// Logging target
 const LOG_TARGET: &'static str = "polkadot_sdk_frame::benchmark::pallet";
 
+/// The additional trie layers that are used if neither passed nor estimated from a snapshot.
+const DEFAULT_ADDITIONAL_TRIE_LAYERS: u8 = 2;
+
 type SubstrateAndExtraHF<T> =
 	(sp_io::SubstrateHostFunctions, frame_benchmarking::benchmarking::HostFunctions, T);
 /// How the PoV size of a storage item should be estimated.
@@ -229,6 +235,43 @@ impl PalletCmd {
 		Err("Neither a runtime nor a chain-spec were specified".to_string().into())
 	}
 
+	/// Load the state of a snapshot, with the code replaced by the `--runtime` if provided.
+	fn load_snapshot<H: Hash>(&self, path: &Path) -> Result<Storage> {
+		log::info!(target: LOG_TARGET, "Loading the state snapshot from {:?}", path);
+
+		let mut storage = remote_externalities::load_snapshot_storage::<H>(path)
+			.map_err(|e| format!("Failed to load the snapshot {:?}: {}", path, e))?;
+		if let Some(runtime_path) = &self.runtime {
+			log::info!(target: LOG_TARGET, "Replacing the snapshot code with {:?}", runtime_path);
+			storage.top.insert(well_known_keys::CODE.to_vec(), fs::read(runtime_path)?);
+		}
+
+		Ok(storage)
+	}
+
+	/// The additional trie layers to use for the PoV estimation.
+	///
+	/// Explicitly passed `--additional-trie-layers` take precedence over the layers estimated from
+	/// the snapshot.
+	fn trie_layers(&self, snapshot: Option<&Storage>) -> TrieLayers {
+		let estimate = snapshot.map(TrieLayers::estimate);
+		if let Some(TrieLayers { layers, snapshot_items: Some(items) }) = estimate {
+			log::info!(
+				target: LOG_TARGET,
+				"Estimated {} additional trie layers from {} top-level storage items of the snapshot",
+				layers,
+				items,
+			);
+		}
+
+		match (self.additional_trie_layers, estimate) {
+			(Some(layers), _) => TrieLayers { layers, snapshot_items: None },
+			(None, Some(estimate)) => estimate,
+			(None, None) =>
+				TrieLayers { layers: DEFAULT_ADDITIONAL_TRIE_LAYERS, snapshot_items: None },
+		}
+	}
+
 	/// Runs the pallet benchmarking command.
 	pub fn run_with_spec<Hasher, ExtraHostFunctions>(
 		&self,
@@ -254,6 +297,13 @@ impl PalletCmd {
 			})
 		});
 
+		let snapshot = self
+			.snapshot
+			.as_deref()
+			.map(|path| self.load_snapshot::<Hasher>(path))
+			.transpose()?;
+		let trie_layers = self.trie_layers(snapshot.as_ref());
+
 		if let Some(json_input) = &self.json_input {
 			let raw_data = match std::fs::read(json_input) {
 				Ok(raw_data) => raw_data,
@@ -265,13 +315,19 @@ impl PalletCmd {
 				Err(error) =>
 					return Err(format!("Failed to deserialize {:?}: {}", json_input, error).into()),
 			};
-			return self.output_from_results(&batches)
+			return self.output_from_results(&batches, trie_layers)
 		}
 
-		let state_handler =
-			self.state_handler_from_cli::<SubstrateAndExtraHF<ExtraHostFunctions>>(chain_spec)?;
-		let genesis_storage =
-			state_handler.build_storage::<SubstrateAndExtraHF<ExtraHostFunctions>>(None)?;
+		let genesis_storage = match snapshot {
+			Some(storage) => storage,
+			None => {
+				let state_handler = self
+					.state_handler_from_cli::<SubstrateAndExtraHF<ExtraHostFunctions>>(
+						chain_spec,
+					)?;
+				state_handler.build_storage::<SubstrateAndExtraHF<ExtraHostFunctions>>(None)?
+			},
+		};
 
 		let cache_size = Some(self.database_cache_size as usize);
 		let state_with_tracking = BenchmarkingState::<Hasher>::new(
@@ -585,7 +641,7 @@ impl PalletCmd {
 		// Combine all of the benchmark results, so that benchmarks of the same pallet/function
 		// are together.
 		let batches = combine_batches(batches, batches_db);
-		self.output(&batches, &storage_info, &component_ranges, pov_modes)
+		self.output(&batches, &storage_info, &component_ranges, pov_modes, trie_layers)
 	}
 
 	fn select_benchmarks_to_run(&self, list: Vec<BenchmarkList>) -> Result<Vec<SelectedBenchmark>> {
@@ -732,11 +788,12 @@ impl PalletCmd {
 		storage_info: &[StorageInfo],
 		component_ranges: &ComponentRangeMap,
 		pov_modes: PovModesMap,
+		trie_layers: TrieLayers,
 	) -> Result<()> {
 		// Jsonify the result and write it to a file or stdout if desired.
 		if !self.jsonify(&batches)? && !self.quiet {
 			// Print the summary only if `jsonify` did not write to stdout.
-			self.print_summary(&batches, &storage_info, pov_modes.clone())
+			self.print_summary(&batches, &storage_info, pov_modes.clone(), trie_layers)
 		}
 
 		// Create the weights.rs file.
@@ -748,6 +805,7 @@ impl PalletCmd {
 				pov_modes,
 				self.default_pov_mode,
 				output_path,
+				trie_layers,
 				self,
 			)?;
 		}
@@ -756,7 +814,11 @@ impl PalletCmd {
 	}
 
 	/// Re-analyze a batch historic benchmark timing data. Will not take the PoV into account.
-	fn output_from_results(&self, batches: &[BenchmarkBatchSplitResults]) -> Result<()> {
+	fn output_from_results(
+		&self,
+		batches: &[BenchmarkBatchSplitResults],
+		trie_layers: TrieLayers,
+	) -> Result<()> {
 		let mut component_ranges = HashMap::<(String, String), HashMap<String, (u32, u32)>>::new();
 		for batch in batches {
 			let range = component_ranges
@@ -790,7 +852,7 @@ impl PalletCmd {
 			})
 			.collect();
 
-		self.output(batches, &[], &component_ranges, Default::default())
+		self.output(batches, &[], &component_ranges, Default::default(), trie_layers)
 	}
 
 	/// Jsonifies the passed batches and writes them to stdout or into a file.
@@ -818,6 +880,7 @@ impl PalletCmd {
 		batches: &[BenchmarkBatchSplitResults],
 		storage_info: &[StorageInfo],
 		pov_modes: PovModesMap,
+		trie_layers: TrieLayers,
 	) {
 		for batch in batches.iter() {
 			// Print benchmark metadata
@@ -850,7 +913,7 @@ impl PalletCmd {
 					&pov_mode,
 					self.default_pov_mode,
 					self.worst_case_map_values,
-					self.additional_trie_layers,
+					trie_layers.layers,
 				);
 				println!("Raw Storage Info\n========");
 				for comment in comments {
@@ -958,10 +1021,15 @@ impl PalletCmd {
 			unreachable!("Clap should not allow both `--runtime` and `--chain` to be provided.")
 		}
 
-		if chain_spec.is_none() && self.runtime.is_none() && self.shared_params.chain.is_none() {
+		if chain_spec.is_none() &&
+			self.runtime.is_none() &&
+			self.shared_params.chain.is_none() &&
+			self.snapshot.is_none()
+		{
 			return Err((
 				ErrorKind::MissingRequiredArgument,
-				"Provide either a runtime via `--runtime` or a chain spec via `--chain`"
+				"Provide either a runtime via `--runtime`, a chain spec via `--chain` or a state \
+				snapshot via `--snapshot`"
 					.to_string(),
 			))
 		}
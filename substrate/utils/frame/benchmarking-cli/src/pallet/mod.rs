@@ -16,6 +16,7 @@
 // limitations under the License.
 
 mod command;
+mod snapshot;
 mod types;
 mod writer;
 
@@ -234,8 +235,18 @@ pub struct PalletCmd {
 	/// Each layer will result in an additional 495 bytes PoV per distinct top-level access.
 	/// Therefore multiple `StorageMap` accesses only suffer from this increase once. The exact
 	/// number of storage items depends on the runtime and the deployed pallets.
-	#[clap(long, default_value = "2")]
-	pub additional_trie_layers: u8,
+	///
+	/// Defaults to 2, or to the number of layers estimated from the `--snapshot`.
+	#[clap(long)]
+	pub additional_trie_layers: Option<u8>,
+
+	/// Benchmark on top of the state of a `frame-remote-externalities` snapshot file instead of
+	/// the genesis state.
+	///
+	/// The runtime of the snapshot is used unless one is provided with `--runtime`. The
+	/// `--additional-trie-layers` are estimated from the key distribution of the snapshot.
+	#[arg(long, value_name = "PATH", conflicts_with_all = ["chain", "genesis_builder"])]
+	pub snapshot: Option<PathBuf>,
 
 	/// A path to a `.json` file with existing benchmark results generated with `--json` or
 	/// `--json-file`. When specified the benchmarks are not actually executed, and the data for
@@ -0,0 +1,132 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Estimates the trie depth of the top-level storage items from a state snapshot.
+
+use sp_storage::Storage;
+
+/// The length of the prefix of a storage item: `twox128(pallet) ++ twox128(item)`.
+const STORAGE_ITEM_PREFIX_LEN: usize = 32;
+
+/// The number of additional trie layers used for the PoV estimation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TrieLayers {
+	/// The number of additional trie layers.
+	pub(crate) layers: u8,
+	/// The number of top-level storage items of the snapshot that the layers were estimated
+	/// from, `None` if the layers were not estimated.
+	pub(crate) snapshot_items: Option<usize>,
+}
+
+impl TrieLayers {
+	/// Estimate the additional trie layers from the key distribution of `storage`.
+	///
+	/// The result is the average number of branch nodes on the path from the trie root to a
+	/// top-level storage item, rounded up. Well known keys like `:code` and the roots of the
+	/// child tries count as storage items of their own.
+	pub(crate) fn estimate(storage: &Storage) -> Self {
+		let mut items = storage
+			.top
+			.keys()
+			.map(|key| key[..key.len().min(STORAGE_ITEM_PREFIX_LEN)].to_vec())
+			.chain(
+				storage
+					.children_default
+					.values()
+					.map(|c| c.child_info.prefixed_storage_key().into_inner()),
+			)
+			.collect::<Vec<_>>();
+		items.sort();
+		items.dedup();
+
+		let mut depths = Vec::with_capacity(items.len());
+		branch_depths(&items, 0, 0, &mut depths);
+
+		let total = depths.iter().map(|d| *d as usize).sum::<usize>();
+		let layers = total.div_ceil(depths.len().max(1));
+		Self { layers: layers.min(u8::MAX as usize) as u8, snapshot_items: Some(items.len()) }
+	}
+}
+
+/// The nibble at `index` of `key`.
+fn nibble(key: &[u8], index: usize) -> Option<u8> {
+	let byte = key.get(index / 2)?;
+	Some(if index % 2 == 0 { byte >> 4 } else { byte & 0x0f })
+}
+
+/// Collect the number of branch nodes above each of the sorted and distinct `items`.
+///
+/// All `items` share the first `index` nibbles and have `depth` branch nodes above them.
+fn branch_depths(items: &[Vec<u8>], index: usize, depth: u32, depths: &mut Vec<u32>) {
+	if items.len() <= 1 {
+		depths.extend(items.iter().map(|_| depth));
+		return
+	}
+
+	// Since the items are sorted, the items that share the next nibble are contiguous. An item
+	// that ends here is stored in the node at this position and forms its own group.
+	let mut groups = Vec::new();
+	let mut start = 0;
+	for i in 1..=items.len() {
+		if i == items.len() || nibble(&items[i], index) != nibble(&items[start], index) {
+			groups.push(&items[start..i]);
+			start = i;
+		}
+	}
+
+	// A single group means that all items continue through an extension node.
+	let depth = if groups.len() > 1 { depth + 1 } else { depth };
+	for group in groups {
+		if group.len() == 1 || nibble(&group[0], index).is_none() {
+			depths.extend(group.iter().map(|_| depth));
+		} else {
+			branch_depths(group, index + 1, depth, depths);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn storage(keys: impl IntoIterator<Item = Vec<u8>>) -> Storage {
+		Storage { top: keys.into_iter().map(|k| (k, vec![])).collect(), ..Default::default() }
+	}
+
+	#[test]
+	fn estimate_works() {
+		// A single storage item has no branch node above it.
+		assert_eq!(TrieLayers::estimate(&storage([vec![1; 32]])).layers, 0);
+
+		// All keys of a map belong to the same storage item.
+		let map = (0..100u8).map(|i| [vec![7; 32], vec![i]].concat());
+		let estimate = TrieLayers::estimate(&storage(map));
+		assert_eq!(estimate, TrieLayers { layers: 0, snapshot_items: Some(1) });
+
+		// 256 items that differ in their first byte form two full levels of branch nodes.
+		let items = (0..=255u8).map(|i| [vec![i], vec![0; 31]].concat());
+		assert_eq!(TrieLayers::estimate(&storage(items)).layers, 2);
+
+		// Items that share the first nibble are only separated by a single branch node.
+		let items = (0..16u8).map(|i| [vec![0x10 | i], vec![0; 31]].concat());
+		assert_eq!(TrieLayers::estimate(&storage(items)).layers, 1);
+
+		// 17 items: one level for the first nibble plus one more for 16 of them.
+		let items = (0..17u8).map(|i| [vec![i], vec![0; 31]].concat());
+		assert_eq!(TrieLayers::estimate(&storage(items)).layers, 2);
+	}
+}
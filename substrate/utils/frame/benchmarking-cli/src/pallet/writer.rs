@@ -30,6 +30,7 @@ use serde::Serialize;
 use crate::{
 	pallet::{
 		command::{PovEstimationMode, PovModesMap},
+		snapshot::TrieLayers,
 		types::{ComponentRange, ComponentRangeMap},
 	},
 	shared::UnderscoreHelper,
@@ -99,6 +100,8 @@ struct CmdData {
 	analysis_choice: String,
 	worst_case_map_values: u32,
 	additional_trie_layers: u8,
+	// The number of top-level storage items of the snapshot the trie layers were estimated from.
+	snapshot_items: Option<usize>,
 }
 
 // This encodes the component name and whether that component is used.
@@ -384,6 +387,7 @@ pub(crate) fn write_results(
 	pov_modes: PovModesMap,
 	default_pov_mode: PovEstimationMode,
 	path: &PathBuf,
+	trie_layers: TrieLayers,
 	cmd: &PalletCmd,
 ) -> Result<(), sc_cli::Error> {
 	// Use custom template if provided.
@@ -413,10 +417,10 @@ pub(crate) fn write_results(
 	let pov_analysis_choice: AnalysisChoice =
 		cmd.output_pov_analysis.clone().try_into().map_err(io_error)?;
 
-	if cmd.additional_trie_layers > 4 {
+	if trie_layers.layers > 4 {
 		println!(
 			"WARNING: `additional_trie_layers` is unexpectedly large. It assumes {} storage items.",
-			16f64.powi(cmd.additional_trie_layers as i32)
+			16f64.powi(trie_layers.layers as i32)
 		)
 	}
 
@@ -431,7 +435,8 @@ pub(crate) fn write_results(
 		db_cache: cmd.database_cache_size,
 		analysis_choice: format!("{:?}", analysis_choice),
 		worst_case_map_values: cmd.worst_case_map_values,
-		additional_trie_layers: cmd.additional_trie_layers,
+		additional_trie_layers: trie_layers.layers,
+		snapshot_items: trie_layers.snapshot_items,
 	};
 
 	// New Handlebars instance with helpers.
@@ -451,7 +456,7 @@ pub(crate) fn write_results(
 		&analysis_choice,
 		&pov_analysis_choice,
 		cmd.worst_case_map_values,
-		cmd.additional_trie_layers,
+		trie_layers.layers,
 	)?;
 	let mut created_files = Vec::new();
 
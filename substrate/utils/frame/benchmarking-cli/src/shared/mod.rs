@@ -54,7 +54,7 @@ impl handlebars::HelperDef for UnderscoreHelper {
 }
 
 /// Add an underscore after every 3rd character, i.e. a separator for large numbers.
-fn underscore<Number>(i: Number) -> String
+pub(crate) fn underscore<Number>(i: Number) -> String
 where
 	Number: std::string::ToString,
 {
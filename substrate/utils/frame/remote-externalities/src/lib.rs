@@ -31,14 +31,15 @@ use sp_core::{
 	hexdisplay::HexDisplay,
 	storage::{
 		well_known_keys::{is_default_child_storage_key, DEFAULT_CHILD_STORAGE_KEY_PREFIX},
-		ChildInfo, ChildType, PrefixedStorageKey, StorageData, StorageKey,
+		ChildInfo, ChildType, PrefixedStorageKey, Storage, StorageChild, StorageData, StorageKey,
+		StorageMap,
 	},
 };
 use sp_runtime::{
-	traits::{Block as BlockT, HashingFor},
+	traits::{Block as BlockT, Hash, HashingFor},
 	StateVersion,
 };
-use sp_state_machine::TestExternalities;
+use sp_state_machine::{Backend, IterArgs, TestExternalities};
 use std::{
 	cmp::{max, min},
 	fs,
@@ -105,6 +106,52 @@ impl<B: BlockT> Snapshot<B> {
 	}
 }
 
+/// Load the state of a snapshot file as plain key-value [`Storage`].
+///
+/// In contrast to the [`Builder`], only the hashing of the chain needs to be known since the
+/// header of the snapshot is never decoded. The child tries are returned in
+/// [`Storage::children_default`] and their roots are not part of [`Storage::top`].
+pub fn load_snapshot_storage<H: Hash>(path: &Path) -> Result<Storage> {
+	let bytes = fs::read(path).map_err(|_| "fs::read failed.")?;
+	// The header is the last field of the snapshot and is left undecoded.
+	let (snapshot_version, state_version, raw_storage, storage_root): (
+		SnapshotVersion,
+		StateVersion,
+		Vec<(Vec<u8>, (Vec<u8>, i32))>,
+		H::Output,
+	) = Decode::decode(&mut &*bytes).map_err(|_| "Decode failed")?;
+
+	if snapshot_version != SNAPSHOT_VERSION {
+		return Err("Unsupported snapshot version detected. Please create a new snapshot.")
+	}
+
+	let mut ext =
+		TestExternalities::<H>::from_raw_snapshot(raw_storage, storage_root, state_version);
+	let backend = ext.as_backend();
+
+	let mut storage = Storage::default();
+	for pair in backend.pairs(Default::default()).map_err(|_| "Failed to iterate the state")? {
+		let (key, value) = pair.map_err(|_| "Failed to read the state")?;
+		if !is_default_child_storage_key(&key) {
+			storage.top.insert(key, value);
+			continue
+		}
+
+		let child_info = ChildInfo::new_default(&key[DEFAULT_CHILD_STORAGE_KEY_PREFIX.len()..]);
+		let args = IterArgs { child_info: Some(child_info.clone()), ..Default::default() };
+		let mut data = StorageMap::new();
+		for pair in backend.pairs(args).map_err(|_| "Failed to iterate a child trie")? {
+			let (key, value) = pair.map_err(|_| "Failed to read a child trie")?;
+			data.insert(key, value);
+		}
+		storage
+			.children_default
+			.insert(child_info.storage_key().to_vec(), StorageChild { data, child_info });
+	}
+
+	Ok(storage)
+}
+
 /// An externalities that acts exactly the same as [`sp_io::TestExternalities`] but has a few extra
 /// bits and pieces to it, and can be loaded remotely.
 pub struct RemoteExternalities<B: BlockT> {
@@ -1268,6 +1315,27 @@ mod tests {
 			.execute_with(|| {});
 	}
 
+	#[tokio::test]
+	async fn can_load_snapshot_storage() {
+		init_logger();
+		let storage = load_snapshot_storage::<HashingFor<Block>>(Path::new("test_data/test.snap"))
+			.expect("Can't read state snapshot file");
+		assert!(!storage.top.is_empty());
+
+		Builder::<Block>::new()
+			.mode(Mode::Offline(OfflineConfig {
+				state_snapshot: SnapshotConfig::new("test_data/test.snap"),
+			}))
+			.build()
+			.await
+			.unwrap()
+			.execute_with(|| {
+				for (key, value) in &storage.top {
+					assert_eq!(sp_io::storage::get(key).as_deref(), Some(&value[..]));
+				}
+			});
+	}
+
 	#[tokio::test]
 	async fn can_exclude_from_snapshot() {
 		init_logger();
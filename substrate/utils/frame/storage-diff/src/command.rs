@@ -0,0 +1,106 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{diff::diff, runtime};
+use clap::Parser;
+use sc_cli::Result;
+use std::path::PathBuf;
+
+/// # FRAME Storage Diff
+///
+/// Compares the storage layout of two runtimes and reports all changes that need a migration:
+/// removed pallets and storage items, changed key and value encodings, changed hashers and
+/// decreased maximal sizes. Type renames and other changes that do not affect the encoding are not
+/// reported.
+///
+/// A change is considered to be handled when the in-code `StorageVersion` of its pallet was
+/// increased, since this is how FRAME pallets gate their `OnRuntimeUpgrade` migrations. All other
+/// changes are reported as errors and make the command fail.
+///
+/// ## Usage
+///
+/// ```sh
+/// frame-storage-diff \
+///     --old old-runtime.compact.compressed.wasm \
+///     --new target/release/wbuild/westend-runtime/westend-runtime.compact.compressed.wasm
+/// ```
+///
+/// The maximal sizes of the storage items are only compared when both runtimes were built with
+/// the `runtime-benchmarks` feature.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, verbatim_doc_comment)]
+pub struct Command {
+	/// Path to the currently deployed runtime.
+	#[arg(long, value_name = "PATH")]
+	old: PathBuf,
+
+	/// Path to the runtime that is about to be deployed.
+	#[arg(long, value_name = "PATH")]
+	new: PathBuf,
+
+	/// Genesis preset that is used to read the in-code storage versions.
+	///
+	/// Falls back to the default genesis config if the preset does not exist.
+	#[arg(long, default_value = sc_chain_spec::DEV_RUNTIME_PRESET)]
+	preset: String,
+
+	/// Ignore changes to a pallet (`Pallet`) or storage item (`Pallet::Item`).
+	///
+	/// Can be used to acknowledge changes that are handled in another way, for example a removed
+	/// pallet that is cleaned up by a `RemovePallet` migration.
+	#[arg(long, value_name = "PALLET[::ITEM]")]
+	ignore: Vec<String>,
+}
+
+impl Command {
+	pub fn run(self) -> Result<()> {
+		let old = runtime::load(&self.old, &self.preset)?;
+		let new = runtime::load(&self.new, &self.preset)?;
+
+		let changes = diff(&old, &new)
+			.into_iter()
+			.filter(|change| {
+				!self.ignore.iter().any(|ignored| match ignored.split_once("::") {
+					Some((pallet, item)) =>
+						change.pallet == pallet && change.item.as_deref() == Some(item),
+					None => change.pallet == *ignored,
+				})
+			})
+			.collect::<Vec<_>>();
+
+		let mut missing = 0;
+		for change in &changes {
+			if change.covered {
+				println!("[migrated] {change}");
+			} else {
+				println!("[missing migration] {change}");
+				missing += 1;
+			}
+		}
+
+		if missing > 0 {
+			return Err(format!(
+				"Found {missing} storage changes without a storage version bump. Add a migration and \
+				bump the `StorageVersion` of the pallet or ignore them with `--ignore`."
+			)
+			.into())
+		}
+
+		println!("No unmigrated storage changes found ({} migrated).", changes.len());
+		Ok(())
+	}
+}
@@ -0,0 +1,355 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Comparison of two runtime storage layouts.
+
+use crate::layout::{ItemLayout, PalletLayout, RuntimeLayout};
+use frame_metadata::v15::StorageHasher;
+use std::fmt;
+
+/// A change to the storage layout that needs a migration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+	/// Name of the pallet.
+	pub pallet: String,
+	/// Name of the storage item or `None` if the change affects the whole pallet.
+	pub item: Option<String>,
+	/// What changed.
+	pub kind: ChangeKind,
+	/// Whether the change is accompanied by a storage version bump of the pallet.
+	pub covered: bool,
+}
+
+/// The different kinds of incompatible storage changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+	/// The pallet and all its storage items were removed.
+	PalletRemoved,
+	/// The storage item was removed.
+	ItemRemoved,
+	/// The storage item changed from a plain value to a map or the other way around.
+	KindChanged,
+	/// The hashers of the storage map changed.
+	HashersChanged { old: Vec<StorageHasher>, new: Vec<StorageHasher> },
+	/// The encoding of the key changed in a way that existing keys can't be decoded anymore.
+	KeyChanged { old: String, new: String },
+	/// The encoding of the value changed in a way that existing values can't be decoded anymore.
+	ValueChanged { old: String, new: String },
+	/// The maximal encoded size decreased, for example by lowering the bound of a `BoundedVec`.
+	MaxSizeDecreased { old: u32, new: u32 },
+}
+
+impl fmt::Display for Change {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match &self.item {
+			Some(item) => write!(f, "{}::{}: ", self.pallet, item)?,
+			None => write!(f, "{}: ", self.pallet)?,
+		}
+		match &self.kind {
+			ChangeKind::PalletRemoved => write!(f, "pallet removed"),
+			ChangeKind::ItemRemoved => write!(f, "storage item removed"),
+			ChangeKind::KindChanged => write!(f, "changed between plain value and map"),
+			ChangeKind::HashersChanged { old, new } =>
+				write!(f, "hashers changed from {:?} to {:?}", old, new),
+			ChangeKind::KeyChanged { old, new } =>
+				write!(f, "key type changed from `{}` to `{}`", old, new),
+			ChangeKind::ValueChanged { old, new } =>
+				write!(f, "value type changed from `{}` to `{}`", old, new),
+			ChangeKind::MaxSizeDecreased { old, new } =>
+				write!(f, "max size decreased from {} to {} bytes", old, new),
+		}
+	}
+}
+
+/// Compare the storage layouts of two runtimes.
+///
+/// Added pallets, storage items and enum variants are not reported since they don't need a
+/// migration. A change
+/// is considered covered if the in-code storage version of the pallet was increased.
+pub fn diff(old: &RuntimeLayout, new: &RuntimeLayout) -> Vec<Change> {
+	let mut changes = Vec::new();
+
+	for (name, old_pallet) in old {
+		let Some(new_pallet) = new.get(name) else {
+			changes.push(Change {
+				pallet: name.clone(),
+				item: None,
+				kind: ChangeKind::PalletRemoved,
+				covered: false,
+			});
+			continue
+		};
+
+		let covered = version_bumped(old_pallet, new_pallet);
+		for (item, old_item) in &old_pallet.items {
+			let kinds = match new_pallet.items.get(item) {
+				Some(new_item) => diff_item(old_item, new_item),
+				None => vec![ChangeKind::ItemRemoved],
+			};
+
+			changes.extend(kinds.into_iter().map(|kind| Change {
+				pallet: name.clone(),
+				item: Some(item.clone()),
+				kind,
+				covered,
+			}));
+		}
+	}
+
+	changes
+}
+
+fn version_bumped(old: &PalletLayout, new: &PalletLayout) -> bool {
+	match (old.storage_version, new.storage_version) {
+		(Some(old), Some(new)) => new > old,
+		_ => false,
+	}
+}
+
+fn diff_item(old: &ItemLayout, new: &ItemLayout) -> Vec<ChangeKind> {
+	if old.is_map() != new.is_map() {
+		return vec![ChangeKind::KindChanged]
+	}
+
+	let mut kinds = Vec::new();
+	if old.hashers != new.hashers {
+		kinds.push(ChangeKind::HashersChanged {
+			old: old.hashers.clone(),
+			new: new.hashers.clone(),
+		});
+	}
+	if let (Some(old_key), Some(new_key)) = (&old.key, &new.key) {
+		if !old_key.is_compatible_with(new_key) {
+			kinds.push(ChangeKind::KeyChanged {
+				old: old_key.to_string(),
+				new: new_key.to_string(),
+			});
+		}
+	}
+	if !old.value.is_compatible_with(&new.value) {
+		kinds.push(ChangeKind::ValueChanged {
+			old: old.value.to_string(),
+			new: new.value.to_string(),
+		});
+	}
+	if let (Some(old_size), Some(new_size)) = (old.max_size, new.max_size) {
+		if new_size < old_size {
+			kinds.push(ChangeKind::MaxSizeDecreased { old: old_size, new: new_size });
+		}
+	}
+
+	kinds
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::layout::Layout;
+	use std::collections::BTreeMap;
+
+	fn primitive(name: &str) -> Layout {
+		match name.strip_prefix('[').and_then(|n| n.strip_suffix(']')) {
+			Some(inner) => Layout::Sequence(Box::new(primitive(inner))),
+			None => Layout::Primitive(name.into()),
+		}
+	}
+
+	fn value(value: &str) -> ItemLayout {
+		ItemLayout { hashers: Vec::new(), key: None, value: primitive(value), max_size: None }
+	}
+
+	fn map(hasher: StorageHasher, key: &str, value: &str) -> ItemLayout {
+		ItemLayout {
+			hashers: vec![hasher],
+			key: Some(primitive(key)),
+			value: primitive(value),
+			max_size: None,
+		}
+	}
+
+	fn enum_of(variants: &[(u8, &str)]) -> Layout {
+		Layout::Enum(variants.iter().map(|(i, v)| (*i, primitive(v))).collect())
+	}
+
+	fn runtime(version: u16, items: Vec<(&str, ItemLayout)>) -> RuntimeLayout {
+		let items = items.into_iter().map(|(n, i)| (n.to_string(), i)).collect();
+		let pallet =
+			PalletLayout { prefix: "Example".into(), storage_version: Some(version), items };
+		BTreeMap::from([("Example".to_string(), pallet)])
+	}
+
+	fn kinds(changes: Vec<Change>) -> Vec<(Option<String>, ChangeKind, bool)> {
+		changes.into_iter().map(|c| (c.item, c.kind, c.covered)).collect()
+	}
+
+	#[test]
+	fn identical_layouts_have_no_changes() {
+		let layout = runtime(1, vec![("Value", value("u32"))]);
+
+		assert!(diff(&layout, &layout).is_empty());
+	}
+
+	#[test]
+	fn added_items_are_ignored() {
+		let old = runtime(0, vec![("Value", value("u32"))]);
+		let new = runtime(
+			0,
+			vec![("Value", value("u32")), ("Map", map(StorageHasher::Twox64Concat, "u32", "u8"))],
+		);
+
+		assert!(diff(&old, &new).is_empty());
+	}
+
+	#[test]
+	fn changes_without_version_bump_are_not_covered() {
+		let old = runtime(
+			1,
+			vec![
+				("Value", value("u32")),
+				("Map", map(StorageHasher::Twox64Concat, "u32", "u8")),
+				("Removed", value("u8")),
+			],
+		);
+		let new = runtime(
+			1,
+			vec![
+				("Value", value("u64")),
+				("Map", map(StorageHasher::Blake2_128Concat, "u64", "u8")),
+			],
+		);
+
+		assert_eq!(
+			kinds(diff(&old, &new)),
+			vec![
+				(
+					Some("Map".into()),
+					ChangeKind::HashersChanged {
+						old: vec![StorageHasher::Twox64Concat],
+						new: vec![StorageHasher::Blake2_128Concat]
+					},
+					false
+				),
+				(
+					Some("Map".into()),
+					ChangeKind::KeyChanged { old: "u32".into(), new: "u64".into() },
+					false
+				),
+				(Some("Removed".into()), ChangeKind::ItemRemoved, false),
+				(
+					Some("Value".into()),
+					ChangeKind::ValueChanged { old: "u32".into(), new: "u64".into() },
+					false
+				),
+			]
+		);
+	}
+
+	#[test]
+	fn changes_with_version_bump_are_covered() {
+		let old = runtime(1, vec![("Value", value("u32"))]);
+		let new = runtime(2, vec![("Value", map(StorageHasher::Identity, "u8", "u32"))]);
+
+		assert_eq!(
+			kinds(diff(&old, &new)),
+			vec![(Some("Value".into()), ChangeKind::KindChanged, true)]
+		);
+	}
+
+	#[test]
+	fn unknown_storage_version_is_not_covered() {
+		let old = runtime(1, vec![("Value", value("u32"))]);
+		let mut new = runtime(2, vec![("Value", value("u64"))]);
+		new.get_mut("Example").unwrap().storage_version = None;
+
+		assert!(diff(&old, &new).iter().all(|c| !c.covered));
+	}
+
+	#[test]
+	fn decreased_max_size_is_reported() {
+		let mut old_item = value("[u8]");
+		old_item.max_size = Some(64);
+		let mut new_item = value("[u8]");
+		new_item.max_size = Some(32);
+
+		let old = runtime(1, vec![("Value", old_item.clone())]);
+		let new = runtime(1, vec![("Value", new_item)]);
+		assert_eq!(
+			kinds(diff(&old, &new)),
+			vec![(Some("Value".into()), ChangeKind::MaxSizeDecreased { old: 64, new: 32 }, false)]
+		);
+
+		// Increasing the bound is fine.
+		assert!(diff(&runtime(1, vec![("Value", value("[u8]"))]), &old).is_empty());
+		old_item.max_size = Some(128);
+		assert!(diff(&old, &runtime(1, vec![("Value", old_item)])).is_empty());
+	}
+
+	#[test]
+	fn removed_pallet_is_reported() {
+		let old = runtime(1, vec![("Value", value("u32"))]);
+
+		assert_eq!(
+			diff(&old, &RuntimeLayout::new()),
+			vec![Change {
+				pallet: "Example".into(),
+				item: None,
+				kind: ChangeKind::PalletRemoved,
+				covered: false
+			}]
+		);
+	}
+
+	#[test]
+	fn added_enum_variants_are_ignored() {
+		let item = |variants: &[(u8, &str)]| ItemLayout {
+			hashers: vec![StorageHasher::Twox64Concat],
+			key: Some(enum_of(variants)),
+			value: Layout::Sequence(Box::new(enum_of(variants))),
+			max_size: None,
+		};
+		let old = runtime(1, vec![("Map", item(&[(0, "u32")]))]);
+
+		let added = runtime(1, vec![("Map", item(&[(0, "u32"), (1, "u64")]))]);
+		assert!(diff(&old, &added).is_empty());
+
+		let changed = runtime(1, vec![("Map", item(&[(0, "u64"), (1, "u64")]))]);
+		assert_eq!(
+			kinds(diff(&old, &changed)),
+			vec![
+				(
+					Some("Map".into()),
+					ChangeKind::KeyChanged {
+						old: "enum{0:u32}".into(),
+						new: "enum{0:u64,1:u64}".into()
+					},
+					false
+				),
+				(
+					Some("Map".into()),
+					ChangeKind::ValueChanged {
+						old: "[enum{0:u32}]".into(),
+						new: "[enum{0:u64,1:u64}]".into()
+					},
+					false
+				),
+			]
+		);
+
+		// Removing a variant breaks existing values.
+		assert_eq!(diff(&added, &old).len(), 2);
+	}
+}
@@ -0,0 +1,346 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encoding-relevant view on the storage of a runtime.
+//!
+//! Only the information that influences how a storage item is encoded is retained. Type and
+//! variant names, docs and similar cosmetic changes are dropped, so renaming a type does not show
+//! up as a layout change while changing the type of a field does.
+
+use frame_metadata::v15::{RuntimeMetadataV15, StorageEntryType, StorageHasher};
+use scale_info::{PortableRegistry, TypeDef};
+use std::{collections::BTreeMap, fmt};
+
+/// Storage layout of a whole runtime, indexed by pallet name.
+pub type RuntimeLayout = BTreeMap<String, PalletLayout>;
+
+/// Storage layout of a single pallet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PalletLayout {
+	/// Prefix under which the storage items of this pallet live.
+	pub prefix: String,
+	/// The in-code storage version of the pallet, if it could be determined.
+	pub storage_version: Option<u16>,
+	/// All storage items of the pallet, indexed by their name.
+	pub items: BTreeMap<String, ItemLayout>,
+}
+
+/// Storage layout of a single storage item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemLayout {
+	/// Hashers of the keys. Empty for plain storage values.
+	pub hashers: Vec<StorageHasher>,
+	/// Layout of the key or `None` for plain storage values.
+	pub key: Option<Layout>,
+	/// Layout of the value.
+	pub value: Layout,
+	/// Maximal encoded length of a key/value pair, as reported by `StorageInfo`.
+	pub max_size: Option<u32>,
+}
+
+impl ItemLayout {
+	/// Whether this is a map or a plain storage value.
+	pub fn is_map(&self) -> bool {
+		self.key.is_some()
+	}
+}
+
+/// Extract the storage layout from the given metadata.
+///
+/// Storage versions and maximal sizes are not part of the metadata and left empty.
+pub fn from_metadata(metadata: &RuntimeMetadataV15) -> RuntimeLayout {
+	metadata
+		.pallets
+		.iter()
+		.filter_map(|pallet| {
+			let storage = pallet.storage.as_ref()?;
+			let items = storage
+				.entries
+				.iter()
+				.map(|entry| {
+					let item = match &entry.ty {
+						StorageEntryType::Plain(value) => ItemLayout {
+							hashers: Vec::new(),
+							key: None,
+							value: type_layout(&metadata.types, value.id),
+							max_size: None,
+						},
+						StorageEntryType::Map { hashers, key, value } => ItemLayout {
+							hashers: hashers.clone(),
+							key: Some(type_layout(&metadata.types, key.id)),
+							value: type_layout(&metadata.types, value.id),
+							max_size: None,
+						},
+					};
+					(entry.name.clone(), item)
+				})
+				.collect();
+
+			let layout =
+				PalletLayout { prefix: storage.prefix.clone(), storage_version: None, items };
+			Some((pallet.name.clone(), layout))
+		})
+		.collect()
+}
+
+/// Encoding of a type.
+///
+/// Two types with the same layout have the same SCALE encoding. The [`Display`](fmt::Display)
+/// implementation renders the layout into a canonical string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Layout {
+	/// A primitive type like `u32` or `bool`.
+	Primitive(String),
+	/// A struct or tuple, encoded as the concatenation of its fields.
+	Fields(Vec<Layout>),
+	/// An enum with its variants indexed by their encoded index.
+	Enum(BTreeMap<u8, Layout>),
+	/// A dynamically sized sequence.
+	Sequence(Box<Layout>),
+	/// A fixed size array.
+	Array(Box<Layout>, u32),
+	/// A compact encoded value.
+	Compact(Box<Layout>),
+	/// A bit sequence with its store and order type.
+	BitSequence(Box<Layout>, Box<Layout>),
+	/// Back reference to the enclosing type `n` levels up, for recursive types.
+	Recursive(usize),
+	/// A type that is missing from the registry.
+	Unknown(u32),
+}
+
+impl Layout {
+	/// Whether values encoded with this layout can still be decoded with the `new` layout.
+	///
+	/// This is the case if both are the same or if `new` only adds enum variants, since existing
+	/// variants keep their index and encoding.
+	pub fn is_compatible_with(&self, new: &Layout) -> bool {
+		use Layout::*;
+
+		match (self, new) {
+			(Fields(old), Fields(new)) =>
+				old.len() == new.len() && old.iter().zip(new).all(|(o, n)| o.is_compatible_with(n)),
+			(Enum(old), Enum(new)) => old
+				.iter()
+				.all(|(index, old)| new.get(index).is_some_and(|new| old.is_compatible_with(new))),
+			(Sequence(old), Sequence(new)) | (Compact(old), Compact(new)) =>
+				old.is_compatible_with(new),
+			(Array(old, old_len), Array(new, new_len)) =>
+				old_len == new_len && old.is_compatible_with(new),
+			(old, new) => old == new,
+		}
+	}
+}
+
+impl fmt::Display for Layout {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Layout::Primitive(name) => write!(f, "{name}"),
+			Layout::Fields(fields) => {
+				write!(f, "(")?;
+				for (i, field) in fields.iter().enumerate() {
+					if i > 0 {
+						write!(f, ",")?;
+					}
+					write!(f, "{field}")?;
+				}
+				write!(f, ")")
+			},
+			Layout::Enum(variants) => {
+				write!(f, "enum{{")?;
+				for (i, (index, fields)) in variants.iter().enumerate() {
+					if i > 0 {
+						write!(f, ",")?;
+					}
+					write!(f, "{index}:{fields}")?;
+				}
+				write!(f, "}}")
+			},
+			Layout::Sequence(inner) => write!(f, "[{inner}]"),
+			Layout::Array(inner, len) => write!(f, "[{inner};{len}]"),
+			Layout::Compact(inner) => write!(f, "compact<{inner}>"),
+			Layout::BitSequence(store, order) => write!(f, "bits<{store},{order}>"),
+			Layout::Recursive(depth) => write!(f, "rec{depth}"),
+			Layout::Unknown(id) => write!(f, "unknown#{id}"),
+		}
+	}
+}
+
+/// Resolve the encoding of the type `id`.
+pub fn type_layout(registry: &PortableRegistry, id: u32) -> Layout {
+	resolve(registry, id, &mut Vec::new())
+}
+
+fn resolve(registry: &PortableRegistry, id: u32, stack: &mut Vec<u32>) -> Layout {
+	// Recursive types are resolved as a back reference to the enclosing type.
+	if let Some(pos) = stack.iter().position(|i| *i == id) {
+		return Layout::Recursive(stack.len() - pos)
+	}
+	let Some(ty) = registry.resolve(id) else { return Layout::Unknown(id) };
+
+	stack.push(id);
+	let layout = match &ty.type_def {
+		TypeDef::Composite(composite) =>
+			resolve_fields(registry, composite.fields.iter().map(|f| f.ty.id), stack),
+		TypeDef::Tuple(tuple) => resolve_fields(registry, tuple.fields.iter().map(|f| f.id), stack),
+		TypeDef::Variant(variant) => Layout::Enum(
+			variant
+				.variants
+				.iter()
+				.map(|v| {
+					(v.index, resolve_fields(registry, v.fields.iter().map(|f| f.ty.id), stack))
+				})
+				.collect(),
+		),
+		TypeDef::Sequence(seq) =>
+			Layout::Sequence(Box::new(resolve(registry, seq.type_param.id, stack))),
+		TypeDef::Array(array) =>
+			Layout::Array(Box::new(resolve(registry, array.type_param.id, stack)), array.len),
+		TypeDef::Compact(compact) =>
+			Layout::Compact(Box::new(resolve(registry, compact.type_param.id, stack))),
+		TypeDef::Primitive(primitive) =>
+			Layout::Primitive(format!("{:?}", primitive).to_lowercase()),
+		TypeDef::BitSequence(bits) => Layout::BitSequence(
+			Box::new(resolve(registry, bits.bit_store_type.id, stack)),
+			Box::new(resolve(registry, bits.bit_order_type.id, stack)),
+		),
+	};
+	stack.pop();
+
+	layout
+}
+
+/// Resolve a list of fields. A single field has the same encoding as the field itself and is
+/// therefore unwrapped.
+fn resolve_fields(
+	registry: &PortableRegistry,
+	mut fields: impl ExactSizeIterator<Item = u32>,
+	stack: &mut Vec<u32>,
+) -> Layout {
+	if fields.len() == 1 {
+		let field = fields.next().expect("Checked the length above; qed");
+		return resolve(registry, field, stack)
+	}
+
+	Layout::Fields(fields.map(|field| resolve(registry, field, stack)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use scale_info::{meta_type, Registry, TypeInfo};
+
+	fn type_of<T: TypeInfo + 'static>() -> Layout {
+		let mut registry = Registry::new();
+		let id = registry.register_type(&meta_type::<T>()).id;
+		type_layout(&PortableRegistry::from(registry), id)
+	}
+
+	fn layout_of<T: TypeInfo + 'static>() -> String {
+		type_of::<T>().to_string()
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	struct Wrapper(u32);
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	struct Account {
+		nonce: u32,
+		free: u128,
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	struct RenamedAccount {
+		index: u32,
+		balance: u128,
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	enum Status {
+		Active,
+		#[codec(index = 5)]
+		Frozen(u64),
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	enum ExtendedStatus {
+		Active,
+		#[codec(index = 5)]
+		Frozen(u64),
+		#[codec(index = 6)]
+		Closed,
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	enum ChangedStatus {
+		Active,
+		#[codec(index = 5)]
+		Frozen(u32),
+		#[codec(index = 6)]
+		Closed,
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	struct Node {
+		children: Vec<Node>,
+	}
+
+	#[test]
+	fn layout_only_reflects_encoding() {
+		assert_eq!(layout_of::<Wrapper>(), "u32");
+		assert_eq!(layout_of::<Account>(), "(u32,u128)");
+		assert_eq!(layout_of::<Account>(), layout_of::<RenamedAccount>());
+		assert_eq!(layout_of::<(u32, u128)>(), layout_of::<Account>());
+		assert_ne!(layout_of::<Option<u32>>(), layout_of::<Option<u64>>());
+	}
+
+	#[test]
+	fn layout_of_containers_works() {
+		assert_eq!(layout_of::<Vec<u8>>(), "[u8]");
+		assert_eq!(layout_of::<[u8; 32]>(), "[u8;32]");
+		assert_eq!(layout_of::<Option<Wrapper>>(), "enum{0:(),1:u32}");
+		assert_eq!(layout_of::<Status>(), "enum{0:(),5:u64}");
+	}
+
+	#[test]
+	fn layout_of_recursive_type_terminates() {
+		assert_eq!(layout_of::<Node>(), "[rec2]");
+	}
+
+	#[test]
+	fn added_variants_are_compatible() {
+		assert!(type_of::<Status>().is_compatible_with(&type_of::<ExtendedStatus>()));
+		assert!(type_of::<Vec<(u32, Status)>>()
+			.is_compatible_with(&type_of::<Vec<(u32, ExtendedStatus)>>()));
+		assert!(
+			type_of::<Option<Status>>().is_compatible_with(&type_of::<Option<ExtendedStatus>>())
+		);
+
+		// Removing a variant or changing an existing one is not.
+		assert!(!type_of::<ExtendedStatus>().is_compatible_with(&type_of::<Status>()));
+		assert!(!type_of::<Status>().is_compatible_with(&type_of::<ChangedStatus>()));
+		assert!(!type_of::<(u32, u64)>().is_compatible_with(&type_of::<(u32, u64, u8)>()));
+		assert!(!type_of::<u32>().is_compatible_with(&type_of::<u64>()));
+	}
+}
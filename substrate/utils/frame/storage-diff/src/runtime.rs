@@ -0,0 +1,183 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Extraction of the storage layout from a runtime blob.
+
+use crate::layout::{self, RuntimeLayout};
+use codec::Decode;
+use frame_benchmarking::BenchmarkList;
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use frame_support::traits::StorageInfo;
+use sc_chain_spec::GenesisConfigBuilderRuntimeCaller;
+use sc_cli::Result;
+use sc_executor::WasmExecutor;
+use sc_runtime_utilities::{fetch_latest_metadata_from_code_blob, RuntimeCaller};
+use sp_crypto_hashing::twox_128;
+use std::{borrow::Cow, path::Path};
+
+/// Logging target
+const LOG_TARGET: &str = "frame::storage-diff";
+
+/// Key under which a pallet stores its storage version, relative to the pallet prefix.
+const STORAGE_VERSION_STORAGE_KEY_POSTFIX: &[u8] = b":__STORAGE_VERSION__:";
+
+/// Host functions that are made available to the runtime.
+///
+/// Missing host functions are allowed since no blocks are executed.
+type ExtraHostFunctions =
+	cumulus_primitives_proof_size_hostfunction::storage_proof_size::HostFunctions;
+type HostFunctions = (sp_io::SubstrateHostFunctions, ExtraHostFunctions);
+
+/// Load the storage layout of the runtime at `path`.
+///
+/// The in-code storage versions are read from the genesis state built with `preset`. The maximal
+/// storage sizes are only available if the runtime was built with `runtime-benchmarks`.
+pub fn load(path: &Path, preset: &str) -> Result<RuntimeLayout> {
+	let code = std::fs::read(path)
+		.map_err(|e| format!("Failed to read runtime from {}: {e}", path.display()))?;
+	let executor = WasmExecutor::<HostFunctions>::builder()
+		.with_allow_missing_host_functions(true)
+		.build();
+
+	let metadata = fetch_latest_metadata_from_code_blob(&executor, Cow::Borrowed(&code))
+		.map_err(|e| format!("Failed to fetch the metadata of {}: {e}", path.display()))?;
+	let metadata = match RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+		.map_err(|e| format!("Failed to decode the metadata of {}: {e}", path.display()))?
+		.1
+	{
+		RuntimeMetadata::V15(metadata) => metadata,
+		other =>
+			return Err(format!(
+				"Unsupported metadata version V{} of {}, only V15 is supported",
+				other.version(),
+				path.display()
+			)
+			.into()),
+	};
+	let mut layout = layout::from_metadata(&metadata);
+
+	match genesis_storage(&code, preset) {
+		Ok(storage) =>
+			for pallet in layout.values_mut() {
+				let mut key = twox_128(pallet.prefix.as_bytes()).to_vec();
+				key.extend(twox_128(STORAGE_VERSION_STORAGE_KEY_POSTFIX));
+
+				// Pallets that never had their version set are implicitly at version zero.
+				pallet.storage_version = Some(
+					storage
+						.get(&key)
+						.map(|v| u16::decode(&mut &v[..]))
+						.transpose()
+						.map_err(|e| format!("Invalid storage version of {}: {e}", pallet.prefix))?
+						.unwrap_or_default(),
+				);
+			},
+		Err(e) => log::warn!(
+			target: LOG_TARGET,
+			"Could not build the genesis state of {}, storage versions are unknown: {e}",
+			path.display()
+		),
+	}
+
+	match storage_info(&executor, &code) {
+		Ok(infos) =>
+			for info in infos {
+				let pallet = String::from_utf8_lossy(&info.pallet_name);
+				let item = String::from_utf8_lossy(&info.storage_name);
+				if let Some(item) =
+					layout.get_mut(pallet.as_ref()).and_then(|p| p.items.get_mut(item.as_ref()))
+				{
+					item.max_size = info.max_size;
+				}
+			},
+		Err(e) => log::info!(
+			target: LOG_TARGET,
+			"No storage info available for {}, max sizes are not compared: {e}",
+			path.display()
+		),
+	}
+
+	Ok(layout)
+}
+
+/// Build the genesis storage of the runtime with the given preset, falling back to the default
+/// genesis config.
+fn genesis_storage(
+	code: &[u8],
+	preset: &str,
+) -> std::result::Result<std::collections::BTreeMap<Vec<u8>, Vec<u8>>, String> {
+	let caller = GenesisConfigBuilderRuntimeCaller::<ExtraHostFunctions>::new(code);
+	let storage = caller.get_storage_for_named_preset(Some(&preset.to_string())).or_else(|e| {
+		log::debug!(target: LOG_TARGET, "Preset `{preset}` not usable, using default: {e}");
+		caller.get_storage_for_named_preset(None)
+	})?;
+
+	Ok(storage.top)
+}
+
+/// Fetch the `StorageInfo` of all pallets through the benchmarking runtime API.
+fn storage_info(executor: &WasmExecutor<HostFunctions>, code: &[u8]) -> Result<Vec<StorageInfo>> {
+	let encoded = RuntimeCaller::new(executor, Cow::Borrowed(code))
+		.call("Benchmark_benchmark_metadata", false)
+		.map_err(|e| e.to_string())?;
+	let (_, infos) = <(Vec<BenchmarkList>, Vec<StorageInfo>)>::decode(&mut &encoded[..])
+		.map_err(|e| e.to_string())?;
+
+	Ok(infos)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::diff::diff;
+	use frame_metadata::v15::StorageHasher;
+
+	fn test_runtime() -> tempfile::NamedTempFile {
+		let code = cumulus_test_runtime::WASM_BINARY
+			.expect("To run this test, build the wasm binary of cumulus-test-runtime");
+		let mut file = tempfile::NamedTempFile::new().unwrap();
+		std::io::Write::write_all(&mut file, code).unwrap();
+		file
+	}
+
+	#[test]
+	fn load_reads_layout_from_wasm() {
+		let file = test_runtime();
+		let layout = load(file.path(), "development").unwrap();
+
+		let system = &layout["System"];
+		assert_eq!(system.prefix, "System");
+		assert_eq!(system.storage_version, Some(0));
+		let account = &system.items["Account"];
+		assert_eq!(account.hashers, vec![StorageHasher::Blake2_128Concat]);
+		assert_eq!(account.key.as_ref().map(ToString::to_string).as_deref(), Some("[u8;32]"));
+		assert!(!system.items["Number"].is_map());
+		assert!(layout.contains_key("Balances"));
+
+		// Comparing a runtime with itself yields no changes.
+		assert!(diff(&layout, &layout).is_empty());
+	}
+
+	#[test]
+	fn load_fails_for_invalid_code() {
+		let mut file = tempfile::NamedTempFile::new().unwrap();
+		std::io::Write::write_all(&mut file, b"not a runtime").unwrap();
+
+		assert!(load(file.path(), "development").is_err());
+		assert!(load(Path::new("/does/not/exist.wasm"), "development").is_err());
+	}
+}
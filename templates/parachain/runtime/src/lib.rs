@@ -185,8 +185,15 @@ mod block_times {
 	/// slot_duration()`.
 	///
 	/// Change this to adjust the block time.
+	#[cfg(not(feature = "elastic-scaling"))]
 	pub const MILLI_SECS_PER_BLOCK: u64 = 6000;
 
+	/// With elastic scaling, one block per core the parachain can build on is produced in each
+	/// relay chain slot.
+	#[cfg(feature = "elastic-scaling")]
+	pub const MILLI_SECS_PER_BLOCK: u64 =
+		(super::RELAY_CHAIN_SLOT_DURATION_MILLIS / super::BLOCK_PROCESSING_VELOCITY) as u64;
+
 	// NOTE: Currently it is not possible to change the slot duration after the chain has started.
 	// Attempting to do so will brick block production.
 	pub const SLOT_DURATION: u64 = MILLI_SECS_PER_BLOCK;
@@ -225,10 +232,16 @@ const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
 mod async_backing_params {
 	/// Maximum number of blocks simultaneously accepted by the Runtime, not yet included
 	/// into the relay chain.
-	pub(crate) const UNINCLUDED_SEGMENT_CAPACITY: u32 = 3;
+	pub(crate) const UNINCLUDED_SEGMENT_CAPACITY: u32 = 2 * BLOCK_PROCESSING_VELOCITY + 1;
 	/// How many parachain blocks are processed by the relay chain per parent. Limits the
 	/// number of blocks authored per slot.
+	#[cfg(not(feature = "elastic-scaling"))]
 	pub(crate) const BLOCK_PROCESSING_VELOCITY: u32 = 1;
+	/// With elastic scaling this is the maximum number of cores the parachain builds on in one
+	/// relay chain slot. The node builds on as many of them as are assigned to the parachain in
+	/// the claim queue.
+	#[cfg(feature = "elastic-scaling")]
+	pub(crate) const BLOCK_PROCESSING_VELOCITY: u32 = 3;
 	/// Relay chain slot duration, in milliseconds.
 	pub(crate) const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32 = 6000;
 }